@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::*;
+
+pub struct DebugElimination {}
+
+impl<'a> ReconstructingReducerExpression<'a> for DebugElimination {}
+
+impl<'a> ReconstructingReducerProgram<'a> for DebugElimination {}
+
+impl<'a> ReconstructingReducerStatement<'a> for DebugElimination {
+    ///
+    /// Removes `console.debug` statements along with the expressions they format.
+    ///
+    fn reduce_statement_alloc(
+        &mut self,
+        context: AsgContext<'a>,
+        _input: &'a Statement<'a>,
+        value: Statement<'a>,
+    ) -> &'a Statement<'a> {
+        match &value {
+            Statement::Console(ConsoleStatement {
+                function: ConsoleFunction::Debug(_),
+                span,
+                ..
+            }) => context.alloc_statement(Statement::Empty(span.clone())),
+            _ => context.alloc_statement(value),
+        }
+    }
+}
+
+impl<'a> AsgPass<'a> for DebugElimination {
+    fn do_pass(asg: Program<'a>) -> Result<Program<'a>, FormattedError> {
+        let pass = DebugElimination {};
+        let mut director = ReconstructingDirector::new(asg.context, pass);
+        Ok(director.reduce_program(asg))
+    }
+}
@@ -0,0 +1,403 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::*;
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+};
+
+/// The largest function body, counted in expressions, that is inlined at its call sites.
+pub const INLINE_THRESHOLD: usize = 32;
+
+///
+/// Counts the expressions in a function body and collects the functions it calls.
+///
+#[derive(Default)]
+struct FunctionSummary<'a> {
+    expressions: usize,
+    calls: Vec<&'a Function<'a>>,
+}
+
+impl<'a> FunctionSummary<'a> {
+    fn of(function: &'a Function<'a>) -> Self {
+        let mut director = VisitorDirector::new(FunctionSummary::default());
+        if let Some(body) = function.body.get() {
+            director.visit_statement(&Cell::new(body)).ok();
+        }
+        director.visitor()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for FunctionSummary<'a> {
+    fn visit_expression(&mut self, _input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        self.expressions += 1;
+        VisitResult::VisitChildren
+    }
+
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        self.calls.push(input.function.get());
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for FunctionSummary<'a> {}
+
+///
+/// Returns true if the function can reach itself through its calls.
+///
+fn is_recursive<'a>(function: &'a Function<'a>) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = FunctionSummary::of(function).calls;
+    while let Some(callee) = stack.pop() {
+        if callee.id == function.id {
+            return true;
+        }
+        if visited.insert(callee.id) {
+            stack.extend(FunctionSummary::of(callee).calls);
+        }
+    }
+    false
+}
+
+///
+/// Splits the body of a function into its definitions and returned expression.
+/// Returns [`None`] if the body contains any other statement.
+///
+fn inline_body<'a>(function: &'a Function<'a>) -> Option<(&'a [Cell<&'a Statement<'a>>], &'a Expression<'a>)> {
+    let block = match function.body.get() {
+        Some(Statement::Block(block)) => block,
+        _ => return None,
+    };
+    let (last, definitions) = block.statements.split_last()?;
+    if !definitions
+        .iter()
+        .all(|statement| matches!(statement.get(), Statement::Definition(_)))
+    {
+        return None;
+    }
+    match last.get() {
+        Statement::Return(statement) => Some((definitions, statement.expression.get())),
+        _ => None,
+    }
+}
+
+///
+/// Allocates a definition of the given variable, recording it as the variable's assignment.
+///
+fn define<'a>(
+    context: AsgContext<'a>,
+    variable: &'a Variable<'a>,
+    value: &'a Expression<'a>,
+    span: Option<Span>,
+) -> &'a Statement<'a> {
+    let statement = context.alloc_statement(Statement::Definition(DefinitionStatement {
+        parent: Cell::new(None),
+        span,
+        variables: vec![variable],
+        value: Cell::new(value),
+    }));
+    variable.borrow_mut().assignments.push(statement);
+    statement
+}
+
+///
+/// Copies the body of an inlined function, giving each of its variables a fresh identity and a
+/// name that cannot capture or shadow a variable at the call site.
+///
+struct VariableRenamer<'a> {
+    context: AsgContext<'a>,
+    function: &'a Function<'a>,
+    variables: HashMap<u32, &'a Variable<'a>>,
+}
+
+impl<'a> VariableRenamer<'a> {
+    fn new(context: AsgContext<'a>, function: &'a Function<'a>) -> Self {
+        Self {
+            context,
+            function,
+            variables: HashMap::new(),
+        }
+    }
+
+    fn fresh_variable(&self, name: &Identifier, type_: Type<'a>, mutable: bool) -> &'a Variable<'a> {
+        let id = self.context.get_id();
+        let renamed = format!("inline_{}_{}_{}", self.function.name.borrow().name, name.name, id);
+        self.context.alloc_variable(RefCell::new(InnerVariable {
+            id,
+            name: Identifier::new_with_span(&renamed, name.span.clone()),
+            type_,
+            mutable,
+            const_: false,
+            declaration: VariableDeclaration::Definition,
+            references: vec![],
+            assignments: vec![],
+            referenced_in_loop: false,
+        }))
+    }
+
+    fn rename(&mut self, variable: &'a Variable<'a>) -> &'a Variable<'a> {
+        let inner = variable.borrow();
+        let renamed = self.fresh_variable(&inner.name, inner.type_.clone(), inner.mutable);
+        self.variables.insert(inner.id, renamed);
+        renamed
+    }
+}
+
+impl<'a> ReconstructingReducerExpression<'a> for VariableRenamer<'a> {
+    fn reduce_variable_ref(&mut self, input: VariableRef<'a>) -> Expression<'a> {
+        let variable = self
+            .variables
+            .get(&input.variable.borrow().id)
+            .copied()
+            .unwrap_or(input.variable);
+        Expression::VariableRef(VariableRef {
+            parent: input.parent,
+            span: input.span,
+            variable,
+        })
+    }
+}
+
+impl<'a> ReconstructingReducerStatement<'a> for VariableRenamer<'a> {
+    fn reduce_statement_alloc(
+        &mut self,
+        context: AsgContext<'a>,
+        _input: &'a Statement<'a>,
+        value: Statement<'a>,
+    ) -> &'a Statement<'a> {
+        let statement = context.alloc_statement(value);
+        if let Statement::Definition(definition) = statement {
+            for variable in definition.variables.iter() {
+                variable.borrow_mut().assignments.push(statement);
+            }
+        }
+        statement
+    }
+
+    fn reduce_definition(&mut self, input: DefinitionStatement<'a>, value: &'a Expression<'a>) -> Statement<'a> {
+        Statement::Definition(DefinitionStatement {
+            parent: input.parent,
+            span: input.span,
+            variables: input.variables.iter().map(|variable| self.rename(variable)).collect(),
+            value: Cell::new(value),
+        })
+    }
+}
+
+///
+/// Replaces calls to small functions with the body of the called function. Arguments, locals and
+/// the returned value are bound to fresh definitions, which are hoisted in front of the statement
+/// containing the call.
+///
+struct CallInliner<'a, 'b> {
+    context: AsgContext<'a>,
+    inlinable: &'b mut HashMap<u32, bool>,
+    hoisted: Vec<&'a Statement<'a>>,
+    scope: Option<&'a Scope<'a>>,
+}
+
+impl<'a, 'b> CallInliner<'a, 'b> {
+    fn is_inlinable(&mut self, function: &'a Function<'a>) -> bool {
+        if let Some(inlinable) = self.inlinable.get(&function.id) {
+            return *inlinable;
+        }
+        let inlinable = function.circuit.get().is_none()
+            && inline_body(function).is_some()
+            && FunctionSummary::of(function).expressions <= INLINE_THRESHOLD
+            && !is_recursive(function);
+        self.inlinable.insert(function.id, inlinable);
+        inlinable
+    }
+}
+
+impl<'a, 'b> ReconstructingReducerExpression<'a> for CallInliner<'a, 'b> {
+    fn reduce_call(
+        &mut self,
+        input: CallExpression<'a>,
+        target: Option<&'a Expression<'a>>,
+        arguments: Vec<&'a Expression<'a>>,
+    ) -> Expression<'a> {
+        let function = input.function.get();
+        let (definitions, output) = match inline_body(function) {
+            Some(body) if target.is_none() && self.is_inlinable(function) => body,
+            _ => {
+                return Expression::Call(CallExpression {
+                    parent: input.parent,
+                    function: input.function,
+                    target: Cell::new(target),
+                    arguments: arguments.into_iter().map(Cell::new).collect(),
+                    span: input.span,
+                });
+            }
+        };
+
+        let mut renamer = VariableRenamer::new(self.context, function);
+        for (parameter, argument) in function.arguments.values().zip(arguments) {
+            let variable = renamer.rename(parameter.get());
+            self.hoisted
+                .push(define(self.context, variable, argument, input.span.clone()));
+        }
+
+        let mut director = ReconstructingDirector::new(self.context, renamer);
+        for definition in definitions.iter() {
+            self.hoisted.push(director.reduce_statement(definition.get()));
+        }
+        let value = director.reduce_expression(output);
+
+        let result = director.reducer().fresh_variable(
+            &Identifier::new_with_span("result", input.span.clone().unwrap_or_default()),
+            function.output.clone(),
+            false,
+        );
+        self.hoisted
+            .push(define(self.context, result, value, input.span.clone()));
+        self.scope = Some(function.scope);
+
+        Expression::VariableRef(VariableRef {
+            parent: input.parent,
+            span: input.span,
+            variable: result,
+        })
+    }
+}
+
+///
+/// Inlines functions of at most [`INLINE_THRESHOLD`] expressions at their call sites. Only free
+/// functions whose body is a sequence of definitions followed by a return are inlined, and
+/// recursive functions never are.
+///
+#[derive(Default)]
+pub struct FunctionInlining<'a> {
+    inlinable: HashMap<u32, bool>,
+    spliced: Vec<&'a Statement<'a>>,
+}
+
+impl<'a> FunctionInlining<'a> {
+    ///
+    /// Inlines the calls in the expressions of the given statement, not including nested statements.
+    /// Returns the definitions to insert in front of the statement and a scope for them.
+    ///
+    fn inline_calls(
+        &mut self,
+        context: AsgContext<'a>,
+        statement: &Statement<'a>,
+    ) -> (Vec<&'a Statement<'a>>, Option<&'a Scope<'a>>) {
+        let mut director = ReconstructingDirector::new(context, CallInliner {
+            context,
+            inlinable: &mut self.inlinable,
+            hoisted: vec![],
+            scope: None,
+        });
+        let mut inline = |expression: &Cell<&'a Expression<'a>>| {
+            expression.set(director.reduce_expression(expression.get()));
+        };
+        match statement {
+            Statement::Assign(statement) => inline(&statement.value),
+            Statement::Conditional(statement) => inline(&statement.condition),
+            Statement::Console(statement) => match &statement.function {
+                ConsoleFunction::Assert(expression) => inline(expression),
+                ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                    inline(left);
+                    inline(right);
+                }
+                ConsoleFunction::Debug(formatted)
+                | ConsoleFunction::Error(formatted)
+                | ConsoleFunction::Log(formatted) => formatted.parameters.iter().for_each(&mut inline),
+            },
+            Statement::Definition(statement) => inline(&statement.value),
+            Statement::Require(statement) => {
+                inline(&statement.condition);
+                statement.message.parameters.iter().for_each(&mut inline);
+            }
+            Statement::Expression(statement) => inline(&statement.expression),
+            Statement::Return(statement) => inline(&statement.expression),
+            // Loop bounds and const assertions must remain constant expressions.
+            Statement::Block(_)
+            | Statement::ConstAssert(_)
+            | Statement::Iteration(_)
+            | Statement::Break(_)
+            | Statement::Continue(_)
+            | Statement::Empty(_) => (),
+        }
+        let inliner = director.reducer();
+        (inliner.hoisted, inliner.scope)
+    }
+}
+
+impl<'a> ReconstructingReducerExpression<'a> for FunctionInlining<'a> {}
+
+impl<'a> ReconstructingReducerProgram<'a> for FunctionInlining<'a> {}
+
+impl<'a> ReconstructingReducerStatement<'a> for FunctionInlining<'a> {
+    ///
+    /// Wraps a statement containing inlined calls in a block with the hoisted definitions.
+    ///
+    fn reduce_statement_alloc(
+        &mut self,
+        context: AsgContext<'a>,
+        _input: &'a Statement<'a>,
+        value: Statement<'a>,
+    ) -> &'a Statement<'a> {
+        let (mut statements, scope) = self.inline_calls(context, &value);
+        let statement = context.alloc_statement(value);
+        let scope = match scope {
+            Some(scope) => scope,
+            None => return statement,
+        };
+        statements.push(statement);
+
+        let block = context.alloc_statement(Statement::Block(BlockStatement {
+            parent: Cell::new(None),
+            span: statement.span().cloned(),
+            statements: statements.into_iter().map(Cell::new).collect(),
+            scope: scope.make_subscope(),
+        }));
+        self.spliced.push(block);
+        block
+    }
+
+    ///
+    /// Splices the blocks of hoisted definitions into the enclosing block.
+    ///
+    fn reduce_block(&mut self, input: BlockStatement<'a>, statements: Vec<&'a Statement<'a>>) -> Statement<'a> {
+        let mut spliced = vec![];
+        for statement in statements {
+            match statement {
+                Statement::Block(block) if self.spliced.iter().any(|x| std::ptr::eq(*x, statement)) => {
+                    spliced.extend(block.statements.iter().map(Cell::get))
+                }
+                _ => spliced.push(statement),
+            }
+        }
+
+        Statement::Block(BlockStatement {
+            parent: input.parent,
+            span: input.span,
+            statements: spliced.into_iter().map(Cell::new).collect(),
+            scope: input.scope,
+        })
+    }
+}
+
+impl<'a> AsgPass<'a> for FunctionInlining<'a> {
+    fn do_pass(asg: Program<'a>) -> Result<Program<'a>, FormattedError> {
+        let pass = FunctionInlining::default();
+        let mut director = ReconstructingDirector::new(asg.context, pass);
+        Ok(director.reduce_program(asg))
+    }
+}
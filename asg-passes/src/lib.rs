@@ -19,3 +19,15 @@ pub use constant_folding::*;
 
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
+
+pub mod debug_elimination;
+pub use debug_elimination::*;
+
+pub mod function_inlining;
+pub use function_inlining::*;
+
+pub mod loop_invariant_hoisting;
+pub use loop_invariant_hoisting::*;
+
+pub mod strict_conversions;
+pub use strict_conversions::*;
@@ -0,0 +1,351 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::*;
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+};
+
+///
+/// Collects the variables referenced by an expression and whether it calls a function.
+///
+#[derive(Default)]
+struct ExpressionSummary {
+    variables: Vec<u32>,
+    calls: bool,
+}
+
+impl ExpressionSummary {
+    fn of<'a>(expression: &'a Expression<'a>) -> Self {
+        let mut director = VisitorDirector::new(ExpressionSummary::default());
+        director.visit_expression(&Cell::new(expression)).ok();
+        director.visitor()
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ExpressionSummary {
+    fn visit_call(&mut self, _input: &CallExpression<'a>) -> VisitResult {
+        self.calls = true;
+        VisitResult::VisitChildren
+    }
+
+    fn visit_variable_ref(&mut self, input: &VariableRef<'a>) -> VisitResult {
+        self.variables.push(input.variable.borrow().id);
+        VisitResult::VisitChildren
+    }
+}
+
+///
+/// Collects the variables a loop body defines or modifies, and whether it can leave an iteration early.
+///
+#[derive(Default)]
+struct LoopEffects {
+    variables: HashSet<u32>,
+    exits: bool,
+}
+
+impl LoopEffects {
+    fn of<'a>(statement: &IterationStatement<'a>) -> Self {
+        let mut director = VisitorDirector::new(LoopEffects::default());
+        director.visit_statement(&statement.body).ok();
+        let mut effects = director.visitor();
+        effects.variables.insert(statement.variable.borrow().id);
+        effects
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for LoopEffects {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        // A method call may modify the circuit it is called on.
+        if let Some(target) = input.target.get() {
+            self.variables.extend(ExpressionSummary::of(target).variables);
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_array_fold(&mut self, input: &ArrayFoldExpression<'a>) -> VisitResult {
+        // The lambda parameters are bound to a new value for every element.
+        self.variables.insert(input.accumulator.borrow().id);
+        self.variables.insert(input.element.borrow().id);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_array_map(&mut self, input: &ArrayMapExpression<'a>) -> VisitResult {
+        self.variables.insert(input.element.borrow().id);
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for LoopEffects {
+    fn visit_statement(&mut self, input: &Cell<&'a Statement<'a>>) -> VisitResult {
+        if matches!(
+            input.get(),
+            Statement::Break(_) | Statement::Continue(_) | Statement::Return(_)
+        ) {
+            self.exits = true;
+        }
+        VisitResult::VisitChildren
+    }
+
+    fn visit_assign(&mut self, input: &AssignStatement<'a>) -> VisitResult {
+        self.variables.insert(input.target_variable.get().borrow().id);
+        VisitResult::VisitChildren
+    }
+
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        self.variables
+            .extend(input.variables.iter().map(|variable| variable.borrow().id));
+        VisitResult::VisitChildren
+    }
+
+    fn visit_iteration(&mut self, input: &IterationStatement<'a>) -> VisitResult {
+        self.variables.insert(input.variable.borrow().id);
+        VisitResult::VisitChildren
+    }
+}
+
+///
+/// Returns the number of times a loop body runs, if the loop bounds are constant.
+///
+fn iteration_count(statement: &IterationStatement) -> Option<usize> {
+    let index = |expression: &Expression| expression.const_value()?.int()?.to_usize();
+    let from = index(statement.start.get())?;
+    let to = index(statement.stop.get())?;
+    let step = match statement.step.get() {
+        Some(step) => index(step)?,
+        None => 1,
+    };
+    if step == 0 {
+        return None;
+    }
+
+    let length = match (from <= to, statement.inclusive) {
+        (true, false) => to - from,
+        (true, true) => to - from + 1,
+        (false, false) => from - to,
+        (false, true) => from - to + 1,
+    };
+    Some((length + step - 1) / step)
+}
+
+///
+/// Replaces the largest loop-invariant subexpressions it visits with references to fresh
+/// variables, and collects the definitions of those variables.
+///
+struct InvariantHoister<'a, 'b> {
+    context: AsgContext<'a>,
+    variant: &'b HashSet<u32>,
+    hoisted: Vec<&'a Statement<'a>>,
+}
+
+impl<'a, 'b> InvariantHoister<'a, 'b> {
+    fn is_invariant(&self, expression: &'a Expression<'a>) -> bool {
+        let summary = ExpressionSummary::of(expression);
+        !summary.calls && summary.variables.iter().all(|id| !self.variant.contains(id))
+    }
+
+    fn hoist(&mut self, expression: &'a Expression<'a>, type_: Type<'a>) -> &'a Expression<'a> {
+        let span = expression.span().cloned();
+        let id = self.context.get_id();
+        let variable = self.context.alloc_variable(RefCell::new(InnerVariable {
+            id,
+            name: Identifier::new_with_span(&format!("hoisted_{}", id), span.clone().unwrap_or_default()),
+            type_,
+            mutable: false,
+            const_: false,
+            declaration: VariableDeclaration::Definition,
+            references: vec![],
+            assignments: vec![],
+            referenced_in_loop: false,
+        }));
+
+        let definition = self.context.alloc_statement(Statement::Definition(DefinitionStatement {
+            parent: Cell::new(None),
+            span: span.clone(),
+            variables: vec![variable],
+            value: Cell::new(expression),
+        }));
+        let reference = self.context.alloc_expression(Expression::VariableRef(VariableRef {
+            parent: Cell::new(expression.get_parent()),
+            span,
+            variable,
+        }));
+
+        let mut inner = variable.borrow_mut();
+        inner.assignments.push(definition);
+        inner.references.push(reference);
+        self.hoisted.push(definition);
+        reference
+    }
+
+    ///
+    /// Hoists from the expressions evaluated on every iteration of the loop. The bodies of
+    /// conditionals and nested loops are left alone, as they do not run exactly once per iteration.
+    ///
+    fn hoist_statement(director: &mut VisitorDirector<'a, Self>, statement: &'a Statement<'a>) {
+        let mut hoist = |expression: &Cell<&'a Expression<'a>>| {
+            director.visit_expression(expression).ok();
+        };
+        match statement {
+            Statement::Assign(statement) => hoist(&statement.value),
+            Statement::Conditional(statement) => hoist(&statement.condition),
+            Statement::Console(statement) => match &statement.function {
+                ConsoleFunction::Assert(expression) => hoist(expression),
+                ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                    hoist(left);
+                    hoist(right);
+                }
+                ConsoleFunction::Debug(formatted)
+                | ConsoleFunction::Error(formatted)
+                | ConsoleFunction::Log(formatted) => formatted.parameters.iter().for_each(&mut hoist),
+            },
+            Statement::Definition(statement) => hoist(&statement.value),
+            Statement::Require(statement) => {
+                hoist(&statement.condition);
+                statement.message.parameters.iter().for_each(&mut hoist);
+            }
+            Statement::Expression(statement) => hoist(&statement.expression),
+            Statement::Block(block) => {
+                for statement in block.statements.iter() {
+                    Self::hoist_statement(director, statement.get());
+                }
+            }
+            Statement::Iteration(_)
+            | Statement::ConstAssert(_)
+            | Statement::Return(_)
+            | Statement::Break(_)
+            | Statement::Continue(_)
+            | Statement::Empty(_) => (),
+        }
+    }
+}
+
+impl<'a, 'b> ExpressionVisitor<'a> for InvariantHoister<'a, 'b> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        let expression = input.get();
+        match expression {
+            // Nothing to gain from hoisting plain values, and calls are never hoisted.
+            Expression::Constant(_) | Expression::VariableRef(_) | Expression::Call(_) => VisitResult::SkipChildren,
+            _ => match expression.get_type() {
+                Some(type_) if self.is_invariant(expression) => {
+                    input.set(self.hoist(expression, type_));
+                    VisitResult::SkipChildren
+                }
+                // Only the selected branch of a ternary may be evaluated.
+                _ if matches!(expression, Expression::Ternary(_)) => VisitResult::SkipChildren,
+                _ => VisitResult::VisitChildren,
+            },
+        }
+    }
+}
+
+///
+/// Hoists loop-invariant subexpressions out of loop bodies into definitions in front of the loop,
+/// so that they are synthesized once instead of once per unrolled iteration.
+///
+/// Only loops with constant bounds that run more than once and never break, continue or return
+/// are optimized. Expressions containing calls are never hoisted.
+///
+#[derive(Default)]
+pub struct LoopInvariantHoisting<'a> {
+    spliced: Vec<&'a Statement<'a>>,
+}
+
+impl<'a> ReconstructingReducerExpression<'a> for LoopInvariantHoisting<'a> {}
+
+impl<'a> ReconstructingReducerProgram<'a> for LoopInvariantHoisting<'a> {}
+
+impl<'a> ReconstructingReducerStatement<'a> for LoopInvariantHoisting<'a> {
+    ///
+    /// Wraps a loop with hoisted expressions in a block with their definitions.
+    ///
+    fn reduce_statement_alloc(
+        &mut self,
+        context: AsgContext<'a>,
+        _input: &'a Statement<'a>,
+        value: Statement<'a>,
+    ) -> &'a Statement<'a> {
+        let statement = context.alloc_statement(value);
+        let (iteration, body) = match statement {
+            Statement::Iteration(iteration) => match iteration.body.get() {
+                Statement::Block(body) => (iteration, body),
+                _ => return statement,
+            },
+            _ => return statement,
+        };
+        if iteration_count(iteration).unwrap_or(0) < 2 {
+            return statement;
+        }
+        let effects = LoopEffects::of(iteration);
+        if effects.exits {
+            return statement;
+        }
+
+        let mut director = VisitorDirector::new(InvariantHoister {
+            context,
+            variant: &effects.variables,
+            hoisted: vec![],
+        });
+        InvariantHoister::hoist_statement(&mut director, iteration.body.get());
+        let mut statements = director.visitor().hoisted;
+        if statements.is_empty() {
+            return statement;
+        }
+        statements.push(statement);
+
+        let scope = body.scope.parent_scope.get().unwrap_or(body.scope);
+        let block = context.alloc_statement(Statement::Block(BlockStatement {
+            parent: Cell::new(None),
+            span: statement.span().cloned(),
+            statements: statements.into_iter().map(Cell::new).collect(),
+            scope: scope.make_subscope(),
+        }));
+        self.spliced.push(block);
+        block
+    }
+
+    ///
+    /// Splices the blocks of hoisted definitions into the enclosing block.
+    ///
+    fn reduce_block(&mut self, input: BlockStatement<'a>, statements: Vec<&'a Statement<'a>>) -> Statement<'a> {
+        let mut spliced = vec![];
+        for statement in statements {
+            match statement {
+                Statement::Block(block) if self.spliced.iter().any(|x| std::ptr::eq(*x, statement)) => {
+                    spliced.extend(block.statements.iter().map(Cell::get))
+                }
+                _ => spliced.push(statement),
+            }
+        }
+
+        Statement::Block(BlockStatement {
+            parent: input.parent,
+            span: input.span,
+            statements: spliced.into_iter().map(Cell::new).collect(),
+            scope: input.scope,
+        })
+    }
+}
+
+impl<'a> AsgPass<'a> for LoopInvariantHoisting<'a> {
+    fn do_pass(asg: Program<'a>) -> Result<Program<'a>, FormattedError> {
+        let pass = LoopInvariantHoisting::default();
+        let mut director = ReconstructingDirector::new(asg.context, pass);
+        Ok(director.reduce_program(asg))
+    }
+}
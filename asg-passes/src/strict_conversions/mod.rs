@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::*;
+
+///
+/// Rejects integers that are implicitly converted to array indices when the conversion may lose
+/// information. Only unsigned types no wider than `u32` are accepted as indices; any other integer
+/// type must be cast explicitly.
+///
+#[derive(Default)]
+pub struct StrictConversions {
+    error: Option<FormattedError>,
+}
+
+impl StrictConversions {
+    fn check_index<'a>(&mut self, index: &'a Expression<'a>) -> VisitResult {
+        match index.get_type() {
+            Some(Type::Integer(IntegerType::U8)) | Some(Type::Integer(IntegerType::U16)) => VisitResult::VisitChildren,
+            Some(Type::Integer(IntegerType::U32)) => VisitResult::VisitChildren,
+            Some(type_ @ Type::Integer(_)) => {
+                self.error = Some(FormattedError::new_from_span(
+                    format!(
+                        "implicit conversion of '{}' to an array index may lose information, add an explicit cast to 'u32'",
+                        type_
+                    ),
+                    &index.span().cloned().unwrap_or_default(),
+                ));
+                VisitResult::Exit
+            }
+            _ => VisitResult::VisitChildren,
+        }
+    }
+
+    fn check_opt_index<'a>(&mut self, index: Option<&'a Expression<'a>>) -> VisitResult {
+        match index {
+            Some(index) => self.check_index(index),
+            None => VisitResult::VisitChildren,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for StrictConversions {
+    fn visit_array_access(&mut self, input: &ArrayAccessExpression<'a>) -> VisitResult {
+        self.check_index(input.index.get())
+    }
+
+    fn visit_array_range_access(&mut self, input: &ArrayRangeAccessExpression<'a>) -> VisitResult {
+        match self.check_opt_index(input.left.get()) {
+            VisitResult::Exit => VisitResult::Exit,
+            _ => self.check_opt_index(input.right.get()),
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for StrictConversions {
+    fn visit_assign_access(&mut self, input: &AssignAccess<'a>) -> VisitResult {
+        match input {
+            AssignAccess::ArrayIndex(index) => self.check_index(index.get()),
+            AssignAccess::ArrayRange(left, right) => match self.check_opt_index(left.get()) {
+                VisitResult::Exit => VisitResult::Exit,
+                _ => self.check_opt_index(right.get()),
+            },
+            _ => VisitResult::VisitChildren,
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for StrictConversions {}
+
+impl<'a> AsgPass<'a> for StrictConversions {
+    fn do_pass(asg: Program<'a>) -> Result<Program<'a>, FormattedError> {
+        let mut director = VisitorDirector::new(StrictConversions::default());
+        director.visit_program(&asg).ok();
+        match director.visitor().error {
+            Some(error) => Err(error),
+            None => Ok(asg),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{qualified_name, CallCollector, CircuitMember, Function, Program, VisitorDirector};
+
+use indexmap::IndexMap;
+use std::cell::Cell;
+
+/// A call from one function to another, by qualified name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallEdge {
+    pub caller: String,
+    pub callee: String,
+    /// Whether the call closes a call cycle, i.e. the callee is still being visited.
+    pub recursive: bool,
+}
+
+///
+/// The calls between the functions and circuit methods of a program, in declaration order.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallGraph {
+    functions: Vec<String>,
+    edges: Vec<CallEdge>,
+}
+
+impl CallGraph {
+    ///
+    /// Returns the call graph of the functions and circuit methods declared in the given program.
+    ///
+    pub fn new(program: &Program) -> Self {
+        let mut functions: Vec<&Function> = program.functions.values().copied().collect();
+        for circuit in program.circuits.values() {
+            for member in circuit.members.borrow().values() {
+                if let CircuitMember::Function(function) = member {
+                    functions.push(*function);
+                }
+            }
+        }
+
+        let mut graph = Self::default();
+        let mut visiting = IndexMap::new();
+        for function in functions {
+            if !visiting.contains_key(&function.id) {
+                graph.visit(function, &mut visiting);
+            }
+        }
+        graph
+    }
+
+    fn visit<'a>(&mut self, function: &'a Function<'a>, visiting: &mut IndexMap<u32, bool>) {
+        visiting.insert(function.id, true);
+        let caller = qualified_name(function);
+        self.functions.push(caller.clone());
+
+        let mut director = VisitorDirector::new(CallCollector::default());
+        if let Some(body) = function.body.get() {
+            director.visit_statement(&Cell::new(body)).ok();
+        }
+
+        for (callee, _) in director.visitor().calls {
+            let recursive = visiting.get(&callee.id).copied().unwrap_or(false);
+            let edge = CallEdge {
+                caller: caller.clone(),
+                callee: qualified_name(callee),
+                recursive,
+            };
+            if !self.edges.contains(&edge) {
+                self.edges.push(edge);
+            }
+            if !visiting.contains_key(&callee.id) {
+                self.visit(callee, visiting);
+            }
+        }
+
+        visiting.insert(function.id, false);
+    }
+
+    ///
+    /// Returns the qualified names of the functions in the graph.
+    ///
+    pub fn functions(&self) -> &[String] {
+        &self.functions
+    }
+
+    ///
+    /// Returns the calls in the graph, each listed once.
+    ///
+    pub fn edges(&self) -> &[CallEdge] {
+        &self.edges
+    }
+
+    ///
+    /// Returns the graph in the Graphviz dot format, with recursive calls drawn in red.
+    ///
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph calls {\n");
+        for function in self.functions.iter() {
+            output.push_str(&format!("    \"{}\";\n", function));
+        }
+        for edge in self.edges.iter() {
+            let attributes = if edge.recursive { " [color=red]" } else { "" };
+            output.push_str(&format!(
+                "    \"{}\" -> \"{}\"{};\n",
+                edge.caller, edge.callee, attributes
+            ));
+        }
+        output.push_str("}\n");
+        output
+    }
+}
@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! Helper methods to determine the correct return value path in an asg.
+//! Helper methods to determine the correct return value path in an asg,
+//! to reject recursive function calls, to warn about unused variables,
+//! and to build the call graph of a program.
+
+mod call_graph;
+pub use call_graph::*;
+
+mod recursion;
+pub use recursion::*;
 
 mod return_path;
 pub use return_path::*;
+
+mod unused_variables;
+pub use unused_variables::*;
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgConvertError,
+    CallExpression,
+    ExpressionVisitor,
+    Function,
+    Span,
+    StatementVisitor,
+    VisitResult,
+    VisitorDirector,
+};
+
+use indexmap::IndexMap;
+use std::cell::Cell;
+
+/// Collects the functions called in a function body, along with the span of each call.
+#[derive(Default)]
+pub(crate) struct CallCollector<'a> {
+    pub(crate) calls: Vec<(&'a Function<'a>, Span)>,
+}
+
+impl<'a> ExpressionVisitor<'a> for CallCollector<'a> {
+    fn visit_call(&mut self, input: &CallExpression<'a>) -> VisitResult {
+        self.calls
+            .push((input.function.get(), input.span.clone().unwrap_or_default()));
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for CallCollector<'a> {}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CallState {
+    Visiting,
+    Visited,
+}
+
+///
+/// Rejects recursive function calls, direct or mutual. A call cycle can never be unrolled
+/// into a finite circuit, so loops are the only iteration construct.
+///
+pub struct RecursionChecker<'a> {
+    states: IndexMap<u32, CallState>,
+    path: Vec<&'a Function<'a>>,
+}
+
+impl<'a> RecursionChecker<'a> {
+    pub fn new() -> Self {
+        Self {
+            states: IndexMap::new(),
+            path: vec![],
+        }
+    }
+
+    ///
+    /// Returns an error naming the first call cycle reachable from the given functions.
+    ///
+    pub fn check(&mut self, functions: impl IntoIterator<Item = &'a Function<'a>>) -> Result<(), AsgConvertError> {
+        for function in functions {
+            if !self.states.contains_key(&function.id) {
+                self.check_function(function)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_function(&mut self, function: &'a Function<'a>) -> Result<(), AsgConvertError> {
+        self.states.insert(function.id, CallState::Visiting);
+        self.path.push(function);
+
+        let mut director = VisitorDirector::new(CallCollector::default());
+        if let Some(body) = function.body.get() {
+            director.visit_statement(&Cell::new(body)).ok();
+        }
+
+        for (callee, span) in director.visitor().calls {
+            match self.states.get(&callee.id) {
+                Some(CallState::Visiting) => {
+                    let start = self.path.iter().position(|x| x.id == callee.id).unwrap();
+                    let cycle = self.path[start..]
+                        .iter()
+                        .chain(std::iter::once(&callee))
+                        .map(|x| qualified_name(x))
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    return Err(AsgConvertError::recursive_function_call(&cycle, &span));
+                }
+                Some(CallState::Visited) => (),
+                None => self.check_function(callee)?,
+            }
+        }
+
+        self.path.pop();
+        self.states.insert(function.id, CallState::Visited);
+        Ok(())
+    }
+}
+
+impl<'a> Default for RecursionChecker<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn qualified_name(function: &Function) -> String {
+    match function.circuit.get() {
+        Some(circuit) => format!("{}::{}", circuit.name.borrow().name, function.name.borrow().name),
+        None => function.name.borrow().name.to_string(),
+    }
+}
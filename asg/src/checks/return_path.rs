@@ -113,6 +113,7 @@ impl<'a> MonoidalReducerStatement<'a, BoolAnd> for ReturnPathReducer {
         input: &IterationStatement,
         start: BoolAnd,
         stop: BoolAnd,
+        step: Option<BoolAnd>,
         body: BoolAnd,
     ) -> BoolAnd {
         // loops are const defined ranges, so we could probably check if they run one and emit here
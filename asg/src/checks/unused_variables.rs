@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    DefinitionStatement,
+    ExpressionVisitor,
+    FormattedError,
+    Program,
+    ProgramVisitor,
+    StatementVisitor,
+    VisitResult,
+    VisitorDirector,
+};
+
+///
+/// Collects a warning for every defined variable that is never read.
+///
+#[derive(Default)]
+pub struct UnusedVariables {
+    warnings: Vec<FormattedError>,
+}
+
+impl UnusedVariables {
+    ///
+    /// Returns the warnings for the unused variables in the given program and its imports.
+    ///
+    pub fn check(program: &Program) -> Vec<FormattedError> {
+        let mut director = VisitorDirector::new(UnusedVariables::default());
+        director.visit_program(program).ok();
+        director.visitor().warnings
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnusedVariables {}
+
+impl<'a> StatementVisitor<'a> for UnusedVariables {
+    fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
+        for variable in input.variables.iter() {
+            let variable = variable.borrow();
+            if variable.references.is_empty() {
+                self.warnings.push(FormattedError::new_from_span(
+                    format!("unused variable `{}`", variable.name.name),
+                    &variable.name.span,
+                ));
+            }
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for UnusedVariables {}
@@ -35,6 +35,31 @@ pub enum ConstInt {
     U128(u128),
 }
 
+macro_rules! const_int_from {
+    ($($native: ty => $variant: ident),* $(,)?) => {
+        $(
+            impl From<$native> for ConstInt {
+                fn from(value: $native) -> Self {
+                    ConstInt::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+const_int_from!(
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+);
+
 /// Specifies how to calculate a group coordinate in a program.
 #[derive(Clone, Debug, PartialEq)]
 pub enum GroupCoordinate {
@@ -259,6 +284,38 @@ impl ConstInt {
         }
     }
 
+    /// Returns the smallest value representable by the given integer type.
+    pub fn min_value(int_type: &IntegerType) -> ConstInt {
+        match int_type {
+            IntegerType::I8 => ConstInt::I8(i8::MIN),
+            IntegerType::I16 => ConstInt::I16(i16::MIN),
+            IntegerType::I32 => ConstInt::I32(i32::MIN),
+            IntegerType::I64 => ConstInt::I64(i64::MIN),
+            IntegerType::I128 => ConstInt::I128(i128::MIN),
+            IntegerType::U8 => ConstInt::U8(u8::MIN),
+            IntegerType::U16 => ConstInt::U16(u16::MIN),
+            IntegerType::U32 => ConstInt::U32(u32::MIN),
+            IntegerType::U64 => ConstInt::U64(u64::MIN),
+            IntegerType::U128 => ConstInt::U128(u128::MIN),
+        }
+    }
+
+    /// Returns the largest value representable by the given integer type.
+    pub fn max_value(int_type: &IntegerType) -> ConstInt {
+        match int_type {
+            IntegerType::I8 => ConstInt::I8(i8::MAX),
+            IntegerType::I16 => ConstInt::I16(i16::MAX),
+            IntegerType::I32 => ConstInt::I32(i32::MAX),
+            IntegerType::I64 => ConstInt::I64(i64::MAX),
+            IntegerType::I128 => ConstInt::I128(i128::MAX),
+            IntegerType::U8 => ConstInt::U8(u8::MAX),
+            IntegerType::U16 => ConstInt::U16(u16::MAX),
+            IntegerType::U32 => ConstInt::U32(u32::MAX),
+            IntegerType::U64 => ConstInt::U64(u64::MAX),
+            IntegerType::U128 => ConstInt::U128(u128::MAX),
+        }
+    }
+
     pub fn cast_to(&self, target: &IntegerType) -> ConstInt {
         match target {
             IntegerType::I8 => ConstInt::I8(self.to_i8()),
@@ -279,17 +336,33 @@ impl ConstInt {
     }
 
     pub fn parse(int_type: &IntegerType, value: &str, span: &Span) -> Result<ConstInt, AsgConvertError> {
+        // A well-formed literal that fails to parse does not fit in its type.
+        let error = |_| {
+            let digits = value.strip_prefix('-').unwrap_or(value);
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                AsgConvertError::int_out_of_range(
+                    value,
+                    &int_type.to_string(),
+                    &ConstInt::min_value(int_type).raw_value(),
+                    &ConstInt::max_value(int_type).raw_value(),
+                    span,
+                )
+            } else {
+                AsgConvertError::invalid_int(value, span)
+            }
+        };
+
         Ok(match int_type {
-            IntegerType::I8 => ConstInt::I8(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::I16 => ConstInt::I16(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::I32 => ConstInt::I32(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::I64 => ConstInt::I64(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::I128 => ConstInt::I128(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::U8 => ConstInt::U8(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::U16 => ConstInt::U16(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::U32 => ConstInt::U32(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::U64 => ConstInt::U64(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
-            IntegerType::U128 => ConstInt::U128(value.parse().map_err(|_| AsgConvertError::invalid_int(&value, span))?),
+            IntegerType::I8 => ConstInt::I8(value.parse().map_err(error)?),
+            IntegerType::I16 => ConstInt::I16(value.parse().map_err(error)?),
+            IntegerType::I32 => ConstInt::I32(value.parse().map_err(error)?),
+            IntegerType::I64 => ConstInt::I64(value.parse().map_err(error)?),
+            IntegerType::I128 => ConstInt::I128(value.parse().map_err(error)?),
+            IntegerType::U8 => ConstInt::U8(value.parse().map_err(error)?),
+            IntegerType::U16 => ConstInt::U16(value.parse().map_err(error)?),
+            IntegerType::U32 => ConstInt::U32(value.parse().map_err(error)?),
+            IntegerType::U64 => ConstInt::U64(value.parse().map_err(error)?),
+            IntegerType::U128 => ConstInt::U128(value.parse().map_err(error)?),
         })
     }
 }
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Collects the circuits and functions declared in a program.
+
+use crate::{
+    Circuit,
+    Function,
+    Monoid,
+    MonoidalDirector,
+    MonoidalReducerExpression,
+    MonoidalReducerProgram,
+    MonoidalReducerStatement,
+    Program,
+    VecAppend,
+};
+use leo_ast::Identifier;
+
+/// The kind of item a [`Declaration`] declares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Circuit,
+    Function,
+}
+
+/// A circuit or function declared at the top level of a program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Declaration {
+    pub kind: DeclarationKind,
+    /// The name of the declared item, along with the span of the name.
+    pub identifier: Identifier,
+}
+
+///
+/// Reduces a program to its declared circuits and functions in declaration order.
+///
+/// Circuits are listed before functions. Functions declared inside circuits and items declared in
+/// imported programs are not included.
+///
+pub struct DeclarationCollector;
+
+impl DeclarationCollector {
+    ///
+    /// Returns the circuits and functions declared in the given program.
+    ///
+    pub fn collect(program: &Program<'_>) -> Vec<Declaration> {
+        MonoidalDirector::new(DeclarationCollector)
+            .reduce_program(program)
+            .into()
+    }
+}
+
+impl<'a> MonoidalReducerExpression<'a, VecAppend<Declaration>> for DeclarationCollector {}
+
+impl<'a> MonoidalReducerStatement<'a, VecAppend<Declaration>> for DeclarationCollector {}
+
+impl<'a> MonoidalReducerProgram<'a, VecAppend<Declaration>> for DeclarationCollector {
+    fn reduce_function(&mut self, input: &'a Function<'a>, _body: VecAppend<Declaration>) -> VecAppend<Declaration> {
+        vec![Declaration {
+            kind: DeclarationKind::Function,
+            identifier: input.name.borrow().clone(),
+        }]
+        .into()
+    }
+
+    fn reduce_circuit(
+        &mut self,
+        input: &'a Circuit<'a>,
+        _members: Vec<VecAppend<Declaration>>,
+    ) -> VecAppend<Declaration> {
+        vec![Declaration {
+            kind: DeclarationKind::Circuit,
+            identifier: input.name.borrow().clone(),
+        }]
+        .into()
+    }
+
+    fn reduce_program(
+        &mut self,
+        _input: &Program,
+        _imported_modules: Vec<VecAppend<Declaration>>,
+        functions: Vec<VecAppend<Declaration>>,
+        circuits: Vec<VecAppend<Declaration>>,
+    ) -> VecAppend<Declaration> {
+        VecAppend::default()
+            .append_all(circuits.into_iter())
+            .append_all(functions.into_iter())
+    }
+}
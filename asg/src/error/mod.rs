@@ -60,6 +60,16 @@ impl AsgConvertError {
         )
     }
 
+    pub fn unresolved_group_coordinate(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "illegal reference to non-existant coordinate '{}' of a group, expected 'x' or 'y'",
+                name
+            ),
+            span,
+        )
+    }
+
     pub fn missing_circuit_member(circuit_name: &str, name: &str, span: &Span) -> Self {
         Self::new_from_span(
             format!(
@@ -225,10 +235,77 @@ impl AsgConvertError {
         )
     }
 
+    pub fn unexpected_definition_type(annotation: &str, received: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "definition is annotated with type '{}', but its value has type '{}'",
+                annotation, received
+            ),
+            span,
+        )
+    }
+
+    pub fn mixed_signedness_comparison(left: &str, right: &str, operation: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "cannot compare '{}' {} '{}', integers of different signedness must be cast to a common type",
+                left, operation, right
+            ),
+            span,
+        )
+    }
+
+    pub fn invalid_bool_cast(value: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("cannot cast '{}' to bool, expected 0 or 1", value), span)
+    }
+
     pub fn unexpected_nonconst(span: &Span) -> Self {
         Self::new_from_span("expected const, found non-const value".to_string(), span)
     }
 
+    pub fn const_assert_nonconst(span: &Span) -> Self {
+        Self::new_from_span(
+            "const_assert condition must be a compile-time constant".to_string(),
+            span,
+        )
+    }
+
+    pub fn const_assert_failed(condition: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("const assertion failed: `{}`", condition), span)
+    }
+
+    pub fn loop_control_outside_loop(keyword: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("'{}' statements are only allowed inside a loop", keyword), span)
+    }
+
+    pub fn loop_bound_nonconst(bound: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "loop bounds must be compile-time constants, found a non-constant {} bound",
+                bound
+            ),
+            span,
+        )
+    }
+
+    pub fn invalid_loop_step(step: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("loop step must be a positive constant, found '{}'", step), span)
+    }
+
+    pub fn unexpected_lambda(span: &Span) -> Self {
+        Self::new_from_span(
+            "lambda expressions are only allowed as arguments to array builtins".to_string(),
+            span,
+        )
+    }
+
+    pub fn expected_lambda(parameters: usize, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("expected a lambda expression with {} parameters", parameters),
+            span,
+        )
+    }
+
     pub fn unresolved_reference(name: &str, span: &Span) -> Self {
         Self::new_from_span(format!("failed to resolve variable reference '{}'", name), span)
     }
@@ -241,6 +318,40 @@ impl AsgConvertError {
         Self::new_from_span(format!("failed to parse int value '{}'", value), span)
     }
 
+    pub fn int_out_of_range(value: &str, int_type: &str, min: &str, max: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "integer literal '{}' is out of range for type '{}', expected a value between {} and {}",
+                value, int_type, min, max
+            ),
+            span,
+        )
+    }
+
+    pub fn constant_overflow(left: &str, operation: &str, right: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "constant operation '{} {} {}' overflows its integer type",
+                left, operation, right
+            ),
+            span,
+        )
+    }
+
+    pub fn constant_division_by_zero(left: &str, span: &Span) -> Self {
+        Self::new_from_span(format!("constant division '{} / 0' divides by zero", left), span)
+    }
+
+    pub fn unknown_integer_constant(int_type: &str, name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "unknown integer constant '{}::{}', expected 'MIN' or 'MAX'",
+                int_type, name
+            ),
+            span,
+        )
+    }
+
     pub fn unsigned_negation(span: &Span) -> Self {
         Self::new_from_span("cannot negate unsigned integer".to_string(), span)
     }
@@ -274,6 +385,26 @@ impl AsgConvertError {
         )
     }
 
+    pub fn recursive_function_call(cycle: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "recursive function calls are not allowed, found the call cycle '{}'. Use a loop instead",
+                cycle
+            ),
+            span,
+        )
+    }
+
+    pub fn return_type_mismatch(expected: &str, received: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!(
+                "function is declared to return '{}', but this branch returns '{}'",
+                expected, received
+            ),
+            span,
+        )
+    }
+
     pub fn call_test_function(span: &Span) -> Self {
         Self::new_from_span("cannot call test function".to_string(), span)
     }
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgConvertError,
+    CallExpression,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+    Variable,
+};
+
+use std::cell::Cell;
+
+/// An array fold, `array.fold(initial, |accumulator, element| body)`, unrolled when the program is compiled.
+#[derive(Clone)]
+pub struct ArrayFoldExpression<'a> {
+    pub parent: Cell<Option<&'a Expression<'a>>>,
+    pub span: Option<Span>,
+    pub array: Cell<&'a Expression<'a>>,
+    pub initial: Cell<&'a Expression<'a>>,
+    pub accumulator: &'a Variable<'a>,
+    pub element: &'a Variable<'a>,
+    pub body: Cell<&'a Expression<'a>>,
+}
+
+impl<'a> Node for ArrayFoldExpression<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> ExpressionNode<'a> for ArrayFoldExpression<'a> {
+    fn set_parent(&self, parent: &'a Expression<'a>) {
+        self.parent.replace(Some(parent));
+    }
+
+    fn get_parent(&self) -> Option<&'a Expression<'a>> {
+        self.parent.get()
+    }
+
+    fn enforce_parents(&self, expr: &'a Expression<'a>) {
+        self.array.get().set_parent(expr);
+        self.initial.get().set_parent(expr);
+        self.body.get().set_parent(expr);
+    }
+
+    fn get_type(&self) -> Option<Type<'a>> {
+        Some(self.accumulator.borrow().type_.clone())
+    }
+
+    fn is_mut_ref(&self) -> bool {
+        false
+    }
+
+    fn const_value(&self) -> Option<ConstValue> {
+        None
+    }
+
+    fn is_consty(&self) -> bool {
+        self.array.get().is_consty() && self.initial.get().is_consty() && self.body.get().is_consty()
+    }
+}
+
+impl<'a> ArrayFoldExpression<'a> {
+    ///
+    /// Returns the fold of the already converted `array` by the call `value`.
+    ///
+    pub(crate) fn from_array(
+        scope: &'a Scope<'a>,
+        array: &'a Expression<'a>,
+        value: &leo_ast::CallExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<ArrayFoldExpression<'a>, AsgConvertError> {
+        let element_type = match array.get_type() {
+            Some(Type::Array(element_type, _)) => *element_type,
+            type_ => {
+                return Err(AsgConvertError::unexpected_type(
+                    "array",
+                    type_.map(|x| x.to_string()).as_deref(),
+                    &value.span,
+                ));
+            }
+        };
+        if value.arguments.len() != 2 {
+            return Err(AsgConvertError::unexpected_call_argument_count(
+                2,
+                value.arguments.len(),
+                &value.span,
+            ));
+        }
+        let lambda = CallExpression::lambda_argument(&value.arguments[1], 2)?;
+
+        let initial = <&Expression<'a>>::from_ast(scope, &value.arguments[0], expected_type)?;
+        let accumulator_type = initial
+            .get_type()
+            .ok_or_else(|| AsgConvertError::unresolved_type(&lambda.parameters[0].name, &value.span))?;
+
+        let const_ = array.is_consty() && initial.is_consty();
+        let (body_scope, parameters) =
+            CallExpression::lambda_scope(scope, lambda, vec![accumulator_type.clone(), element_type], const_);
+        let body = <&Expression<'a>>::from_ast(body_scope, &*lambda.body, Some(accumulator_type.partial()))?;
+
+        Ok(ArrayFoldExpression {
+            parent: Cell::new(None),
+            span: Some(value.span.clone()),
+            array: Cell::new(array),
+            initial: Cell::new(initial),
+            accumulator: parameters[0],
+            element: parameters[1],
+            body: Cell::new(body),
+        })
+    }
+}
+
+impl<'a> Into<leo_ast::CallExpression> for &ArrayFoldExpression<'a> {
+    fn into(self) -> leo_ast::CallExpression {
+        let span = self.span.clone().unwrap_or_default();
+        leo_ast::CallExpression {
+            function: Box::new(leo_ast::Expression::CircuitMemberAccess(
+                leo_ast::CircuitMemberAccessExpression {
+                    circuit: Box::new(self.array.get().into()),
+                    name: leo_ast::Identifier::new_with_span("fold", span.clone()),
+                    span: span.clone(),
+                },
+            )),
+            arguments: vec![
+                self.initial.get().into(),
+                leo_ast::Expression::Lambda(leo_ast::LambdaExpression {
+                    parameters: vec![
+                        self.accumulator.borrow().name.clone(),
+                        self.element.borrow().name.clone(),
+                    ],
+                    body: Box::new(self.body.get().into()),
+                    span: span.clone(),
+                }),
+            ],
+            span,
+        }
+    }
+}
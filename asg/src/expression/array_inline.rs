@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
-use leo_ast::SpreadOrExpression;
+use leo_ast::{IntegerType, SpreadOrExpression};
 
 use std::cell::Cell;
 
@@ -196,6 +196,48 @@ impl<'a> FromAst<'a, leo_ast::ArrayInlineExpression> for ArrayInlineExpression<'
     }
 }
 
+impl<'a> FromAst<'a, leo_ast::ArrayComprehensionExpression> for ArrayInlineExpression<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        value: &leo_ast::ArrayComprehensionExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<ArrayInlineExpression<'a>, AsgConvertError> {
+        let expected_index_type = Some(PartialType::Integer(Some(IntegerType::U32), None));
+        let start = <&Expression<'a>>::from_ast(scope, &*value.start, expected_index_type.clone())?;
+        let stop = <&Expression<'a>>::from_ast(scope, &*value.stop, expected_index_type)?;
+
+        // The range is unrolled into one element per index, so both bounds must be constant.
+        let start = match start.const_value() {
+            Some(ConstValue::Int(start)) => start.to_usize(),
+            _ => None,
+        }
+        .ok_or_else(|| AsgConvertError::loop_bound_nonconst("start", &start.span().cloned().unwrap_or_default()))?;
+        let stop = match stop.const_value() {
+            Some(ConstValue::Int(stop)) => stop.to_usize(),
+            _ => None,
+        }
+        .ok_or_else(|| AsgConvertError::loop_bound_nonconst("stop", &stop.span().cloned().unwrap_or_default()))?;
+
+        let elements = (start..stop)
+            .map(|index| {
+                value
+                    .element_at(index)
+                    .map(SpreadOrExpression::Expression)
+                    .map_err(|e| AsgConvertError::illegal_ast_structure(&e.to_string()))
+            })
+            .collect::<Result<Vec<_>, AsgConvertError>>()?;
+
+        ArrayInlineExpression::from_ast(
+            scope,
+            &leo_ast::ArrayInlineExpression {
+                elements,
+                span: value.span.clone(),
+            },
+            expected_type,
+        )
+    }
+}
+
 impl<'a> Into<leo_ast::ArrayInlineExpression> for &ArrayInlineExpression<'a> {
     fn into(self) -> leo_ast::ArrayInlineExpression {
         leo_ast::ArrayInlineExpression {
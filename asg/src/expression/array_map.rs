@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgConvertError,
+    CallExpression,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+    Variable,
+};
+
+use std::cell::Cell;
+
+/// An array map, `array.map(|element| body)`, unrolled when the program is compiled.
+#[derive(Clone)]
+pub struct ArrayMapExpression<'a> {
+    pub parent: Cell<Option<&'a Expression<'a>>>,
+    pub span: Option<Span>,
+    pub array: Cell<&'a Expression<'a>>,
+    pub element: &'a Variable<'a>,
+    pub body: Cell<&'a Expression<'a>>,
+    pub len: usize,
+}
+
+impl<'a> Node for ArrayMapExpression<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> ExpressionNode<'a> for ArrayMapExpression<'a> {
+    fn set_parent(&self, parent: &'a Expression<'a>) {
+        self.parent.replace(Some(parent));
+    }
+
+    fn get_parent(&self) -> Option<&'a Expression<'a>> {
+        self.parent.get()
+    }
+
+    fn enforce_parents(&self, expr: &'a Expression<'a>) {
+        self.array.get().set_parent(expr);
+        self.body.get().set_parent(expr);
+    }
+
+    fn get_type(&self) -> Option<Type<'a>> {
+        Some(Type::Array(Box::new(self.body.get().get_type()?), self.len))
+    }
+
+    fn is_mut_ref(&self) -> bool {
+        false
+    }
+
+    fn const_value(&self) -> Option<ConstValue> {
+        None
+    }
+
+    fn is_consty(&self) -> bool {
+        self.array.get().is_consty() && self.body.get().is_consty()
+    }
+}
+
+impl<'a> ArrayMapExpression<'a> {
+    ///
+    /// Returns the map of the already converted `array` by the call `value`.
+    ///
+    pub(crate) fn from_array(
+        scope: &'a Scope<'a>,
+        array: &'a Expression<'a>,
+        value: &leo_ast::CallExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<ArrayMapExpression<'a>, AsgConvertError> {
+        let (element_type, len) = match array.get_type() {
+            Some(Type::Array(element_type, len)) => (*element_type, len),
+            type_ => {
+                return Err(AsgConvertError::unexpected_type(
+                    "array",
+                    type_.map(|x| x.to_string()).as_deref(),
+                    &value.span,
+                ));
+            }
+        };
+        let expected_item = match expected_type {
+            Some(PartialType::Array(item, expected_len)) => {
+                if let Some(expected_len) = expected_len {
+                    if expected_len != len {
+                        return Err(AsgConvertError::unexpected_type(
+                            &*format!("array of length {}", expected_len),
+                            Some(&*format!("array of length {}", len)),
+                            &value.span,
+                        ));
+                    }
+                }
+                item.map(|x| *x)
+            }
+            None => None,
+            Some(type_) => {
+                return Err(AsgConvertError::unexpected_type(
+                    &type_.to_string(),
+                    Some("array"),
+                    &value.span,
+                ));
+            }
+        };
+        if value.arguments.len() != 1 {
+            return Err(AsgConvertError::unexpected_call_argument_count(
+                1,
+                value.arguments.len(),
+                &value.span,
+            ));
+        }
+        let lambda = CallExpression::lambda_argument(&value.arguments[0], 1)?;
+
+        let (body_scope, parameters) =
+            CallExpression::lambda_scope(scope, lambda, vec![element_type], array.is_consty());
+        let body = <&Expression<'a>>::from_ast(body_scope, &*lambda.body, expected_item)?;
+
+        Ok(ArrayMapExpression {
+            parent: Cell::new(None),
+            span: Some(value.span.clone()),
+            array: Cell::new(array),
+            element: parameters[0],
+            body: Cell::new(body),
+            len,
+        })
+    }
+}
+
+impl<'a> Into<leo_ast::CallExpression> for &ArrayMapExpression<'a> {
+    fn into(self) -> leo_ast::CallExpression {
+        let span = self.span.clone().unwrap_or_default();
+        leo_ast::CallExpression {
+            function: Box::new(leo_ast::Expression::CircuitMemberAccess(
+                leo_ast::CircuitMemberAccessExpression {
+                    circuit: Box::new(self.array.get().into()),
+                    name: leo_ast::Identifier::new_with_span("map", span.clone()),
+                    span: span.clone(),
+                },
+            )),
+            arguments: vec![leo_ast::Expression::Lambda(leo_ast::LambdaExpression {
+                parameters: vec![self.element.borrow().name.clone()],
+                body: Box::new(self.body.get().into()),
+                span: span.clone(),
+            })],
+            span,
+        }
+    }
+}
@@ -26,7 +26,9 @@ pub struct ArrayRangeAccessExpression<'a> {
     pub array: Cell<&'a Expression<'a>>,
     pub left: Cell<Option<&'a Expression<'a>>>,
     pub right: Cell<Option<&'a Expression<'a>>>,
+    pub inclusive: bool,
     // this is either const(right) - const(left) OR the length inferred by type checking
+    // (with const(right) counted one past the end for inclusive ranges)
     // special attention must be made to update this if semantic-altering changes are made to left or right.
     pub length: usize,
 }
@@ -81,6 +83,7 @@ impl<'a> ExpressionNode<'a> for ArrayRangeAccessExpression<'a> {
             _ => return None,
         };
         let const_right = match self.right.get().map(|x| x.const_value()) {
+            Some(Some(ConstValue::Int(x))) if self.inclusive => x.to_usize()?.checked_add(1)?,
             Some(Some(ConstValue::Int(x))) => x.to_usize()?,
             None => array.len(),
             _ => return None,
@@ -148,8 +151,14 @@ impl<'a> FromAst<'a, leo_ast::ArrayRangeAccessExpression> for ArrayRangeAccessEx
             _ => None,
         };
         let const_right = match right.map(|x| x.const_value()) {
-            Some(Some(ConstValue::Int(value))) => {
-                let value = value.to_usize();
+            Some(Some(ConstValue::Int(right_value))) => {
+                let value = if value.inclusive {
+                    right_value
+                        .to_usize()
+                        .and_then(|right_value| right_value.checked_add(1))
+                } else {
+                    right_value.to_usize()
+                };
                 if let Some(value) = value {
                     if value > parent_size {
                         return Err(AsgConvertError::array_index_out_of_bounds(
@@ -207,6 +216,7 @@ impl<'a> FromAst<'a, leo_ast::ArrayRangeAccessExpression> for ArrayRangeAccessEx
             array: Cell::new(array),
             left: Cell::new(left),
             right: Cell::new(right),
+            inclusive: value.inclusive,
             length: length.unwrap(),
         })
     }
@@ -218,6 +228,7 @@ impl<'a> Into<leo_ast::ArrayRangeAccessExpression> for &ArrayRangeAccessExpressi
             array: Box::new(self.array.get().into()),
             left: self.left.get().map(|left| Box::new(left.into())),
             right: self.right.get().map(|right| Box::new(right.into())),
+            inclusive: self.inclusive,
             span: self.span.clone().unwrap_or_default(),
         }
     }
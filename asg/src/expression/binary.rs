@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    AsgConvertError,
+    CallExpression,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
 pub use leo_ast::{BinaryOperation, BinaryOperationClass};
 
 use std::cell::Cell;
@@ -111,44 +123,125 @@ impl<'a> ExpressionNode<'a> for BinaryExpression<'a> {
     }
 }
 
-impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
-    fn from_ast(
+///
+/// Returns an error if an ordering comparison is made between a signed and an unsigned integer.
+///
+fn mixed_signedness_error(
+    operation: &BinaryOperation,
+    left: &Type,
+    right: Option<&Type>,
+    span: &Span,
+) -> Option<AsgConvertError> {
+    use BinaryOperation::*;
+
+    if !matches!(operation, Lt | Le | Gt | Ge) {
+        return None;
+    }
+    match (left, right) {
+        (Type::Integer(left), Some(Type::Integer(right))) if left.is_signed() != right.is_signed() => {
+            Some(AsgConvertError::mixed_signedness_comparison(
+                &left.to_string(),
+                &right.to_string(),
+                operation.as_ref(),
+                span,
+            ))
+        }
+        _ => None,
+    }
+}
+
+impl<'a> BinaryExpression<'a> {
+    ///
+    /// Returns the binary expression, or a call to the overloading method of the left operand if it is a
+    /// circuit that defines one for the operator, i.e. `add` for `+` and `eq` for `==`.
+    ///
+    pub(crate) fn overloaded_from_ast(
         scope: &'a Scope<'a>,
         value: &leo_ast::BinaryExpression,
         expected_type: Option<PartialType<'a>>,
-    ) -> Result<BinaryExpression<'a>, AsgConvertError> {
-        let class = value.op.class();
-        let expected_type = match class {
-            BinaryOperationClass::Boolean => match expected_type {
-                Some(PartialType::Type(Type::Boolean)) | None => None,
-                Some(x) => {
-                    return Err(AsgConvertError::unexpected_type(
-                        &x.to_string(),
-                        Some(&*Type::Boolean.to_string()),
+    ) -> Result<Expression<'a>, AsgConvertError> {
+        let (method, call_type) = match value.op {
+            BinaryOperation::Add => ("add", expected_type.clone()),
+            BinaryOperation::Eq => match expected_type {
+                Some(PartialType::Type(Type::Boolean)) | None => ("eq", Some(Type::Boolean.partial())),
+                Some(_) => return Self::from_ast(scope, value, expected_type).map(Expression::Binary),
+            },
+            _ => return Self::from_ast(scope, value, expected_type).map(Expression::Binary),
+        };
+
+        // The left operand is converted once and shared by the call and the binary expression.
+        let operand_type = Self::operand_type(value, expected_type);
+        let left = <&Expression<'a>>::from_ast(scope, &*value.left, operand_type.as_ref().ok().cloned().flatten());
+        if let Ok(left) = left {
+            if let Some(Type::Circuit(circuit)) = left.get_type() {
+                if circuit.members.borrow().contains_key(method) {
+                    let name = leo_ast::Identifier::new_with_span(method, value.span.clone());
+                    let function = CallExpression::resolve_method(left, &name, &value.span)?;
+                    return CallExpression::from_function(
+                        scope,
+                        Some(left),
+                        function,
+                        std::slice::from_ref(&*value.right),
                         &value.span,
-                    ));
+                        call_type,
+                    )
+                    .map(Expression::Call);
                 }
+            }
+        }
+
+        Self::from_left(scope, value, operand_type?, left).map(Expression::Binary)
+    }
+
+    /// Returns the type expected of the operands of `value` when the whole expression is expected to be `expected_type`.
+    fn operand_type(
+        value: &leo_ast::BinaryExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<Option<PartialType<'a>>, AsgConvertError> {
+        match value.op.class() {
+            BinaryOperationClass::Boolean => match expected_type {
+                Some(PartialType::Type(Type::Boolean)) | None => Ok(None),
+                Some(x) => Err(AsgConvertError::unexpected_type(
+                    &x.to_string(),
+                    Some(&*Type::Boolean.to_string()),
+                    &value.span,
+                )),
             },
             BinaryOperationClass::Numeric => match expected_type {
-                Some(x @ PartialType::Integer(_, _)) => Some(x),
-                Some(x @ PartialType::Type(Type::Field)) => Some(x),
-                Some(x @ PartialType::Type(Type::Group)) => Some(x),
-                Some(x) => {
-                    return Err(AsgConvertError::unexpected_type(
-                        &x.to_string(),
-                        Some("integer, field, or group"),
-                        &value.span,
-                    ));
-                }
-                None => None,
+                Some(x @ PartialType::Integer(_, _)) => Ok(Some(x)),
+                Some(x @ PartialType::Type(Type::Field)) => Ok(Some(x)),
+                Some(x @ PartialType::Type(Type::Group)) => Ok(Some(x)),
+                Some(x) => Err(AsgConvertError::unexpected_type(
+                    &x.to_string(),
+                    Some("integer, field, or group"),
+                    &value.span,
+                )),
+                None => Ok(None),
             },
-        };
+        }
+    }
+
+    /// Returns the binary expression `value` given the result of converting its left operand.
+    fn from_left(
+        scope: &'a Scope<'a>,
+        value: &leo_ast::BinaryExpression,
+        expected_type: Option<PartialType<'a>>,
+        left: Result<&'a Expression<'a>, AsgConvertError>,
+    ) -> Result<BinaryExpression<'a>, AsgConvertError> {
+        let class = value.op.class();
 
         // left
-        let (left, right) = match <&Expression<'a>>::from_ast(scope, &*value.left, expected_type.clone()) {
+        let (left, right) = match left {
             Ok(left) => {
                 if let Some(left_type) = left.get_type() {
-                    let right = <&Expression<'a>>::from_ast(scope, &*value.right, Some(left_type.partial()))?;
+                    let right = <&Expression<'a>>::from_ast(scope, &*value.right, Some(left_type.clone().partial()))
+                        .map_err(|e| match <&Expression<'a>>::from_ast(scope, &*value.right, None) {
+                            Ok(right) => {
+                                mixed_signedness_error(&value.op, &left_type, right.get_type().as_ref(), &value.span)
+                                    .unwrap_or(e)
+                            }
+                            Err(_) => e,
+                        })?;
                     (left, right)
                 } else {
                     let right = <&Expression<'a>>::from_ast(scope, &*value.right, expected_type)?;
@@ -206,6 +299,8 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
                     }
                 },
                 BinaryOperation::Eq | BinaryOperation::Ne => (), // all types allowed
+                // fields compare their canonical representatives
+                BinaryOperation::Lt | BinaryOperation::Gt if matches!(left_type, Some(Type::Field)) => (),
                 _ => match left_type {
                     Some(Type::Integer(_)) | None => (),
                     Some(x) => {
@@ -224,6 +319,9 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
         match (left_type, right_type) {
             (Some(left_type), Some(right_type)) => {
                 if !left_type.is_assignable_from(&right_type) {
+                    if let Some(error) = mixed_signedness_error(&value.op, &left_type, Some(&right_type), &value.span) {
+                        return Err(error);
+                    }
                     return Err(AsgConvertError::unexpected_type(
                         &left_type.to_string(),
                         Some(&*right_type.to_string()),
@@ -240,6 +338,36 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
             }
             (_, _) => (),
         }
+
+        // Constant integer arithmetic is folded with checked operations, so a failed fold is an error.
+        if let (Some(ConstValue::Int(left_value)), Some(ConstValue::Int(right_value))) =
+            (left.const_value(), right.const_value())
+        {
+            let folded = match value.op {
+                BinaryOperation::Add => Some(left_value.value_add(&right_value)),
+                BinaryOperation::Sub => Some(left_value.value_sub(&right_value)),
+                BinaryOperation::Mul => Some(left_value.value_mul(&right_value)),
+                BinaryOperation::Div => Some(left_value.value_div(&right_value)),
+                BinaryOperation::Pow => Some(left_value.value_pow(&right_value)),
+                _ => None,
+            };
+
+            if let Some(None) = folded {
+                if value.op == BinaryOperation::Div && right_value.to_usize() == Some(0) {
+                    return Err(AsgConvertError::constant_division_by_zero(
+                        &left_value.to_string(),
+                        &value.span,
+                    ));
+                }
+                return Err(AsgConvertError::constant_overflow(
+                    &left_value.to_string(),
+                    value.op.as_ref(),
+                    &right_value.to_string(),
+                    &value.span,
+                ));
+            }
+        }
+
         Ok(BinaryExpression {
             parent: Cell::new(None),
             span: Some(value.span.clone()),
@@ -250,6 +378,18 @@ impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
     }
 }
 
+impl<'a> FromAst<'a, leo_ast::BinaryExpression> for BinaryExpression<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        value: &leo_ast::BinaryExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<BinaryExpression<'a>, AsgConvertError> {
+        let expected_type = Self::operand_type(value, expected_type)?;
+        let left = <&Expression<'a>>::from_ast(scope, &*value.left, expected_type.clone());
+        Self::from_left(scope, value, expected_type, left)
+    }
+}
+
 impl<'a> Into<leo_ast::BinaryExpression> for &BinaryExpression<'a> {
     fn into(self) -> leo_ast::BinaryExpression {
         leo_ast::BinaryExpression {
@@ -15,6 +15,8 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    ArrayFoldExpression,
+    ArrayMapExpression,
     AsgConvertError,
     CircuitMember,
     ConstValue,
@@ -23,15 +25,18 @@ use crate::{
     FromAst,
     Function,
     FunctionQualifier,
+    InnerVariable,
     Node,
     PartialType,
     Scope,
     Span,
     Type,
+    Variable,
+    VariableDeclaration,
 };
 pub use leo_ast::{BinaryOperation, Node as AstNode};
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 
 #[derive(Clone)]
 pub struct CallExpression<'a> {
@@ -84,6 +89,94 @@ impl<'a> ExpressionNode<'a> for CallExpression<'a> {
     }
 }
 
+impl<'a> CallExpression<'a> {
+    ///
+    /// Returns the expression for a call to an array builtin taking a lambda, i.e.
+    /// `a.fold(init, |acc, x| acc + x)` or `a.map(|x| x * 2)`. Methods of the same name on circuits are called as usual.
+    ///
+    pub(crate) fn array_builtin_from_ast(
+        scope: &'a Scope<'a>,
+        value: &leo_ast::CallExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<Option<Expression<'a>>, AsgConvertError> {
+        let member = match &*value.function {
+            leo_ast::Expression::CircuitMemberAccess(member) if matches!(member.name.name.as_ref(), "fold" | "map") => {
+                member
+            }
+            _ => return Ok(None),
+        };
+
+        // The target is converted once, whether it is an array or a circuit.
+        let target = <&Expression<'a>>::from_ast(scope, &*member.circuit, None)?;
+        if !matches!(target.get_type(), Some(Type::Array(_, _))) {
+            let function = Self::resolve_method(target, &member.name, &member.span)?;
+            return Self::from_function(
+                scope,
+                Some(target),
+                function,
+                &value.arguments,
+                &value.span,
+                expected_type,
+            )
+            .map(|call| Some(Expression::Call(call)));
+        }
+
+        if member.name.name.as_ref() == "map" {
+            return ArrayMapExpression::from_array(scope, target, value, expected_type)
+                .map(|map| Some(Expression::ArrayMap(map)));
+        }
+        ArrayFoldExpression::from_array(scope, target, value, expected_type)
+            .map(|fold| Some(Expression::ArrayFold(fold)))
+    }
+
+    ///
+    /// Returns a scope for the body of `lambda` declaring its parameters with the given types.
+    ///
+    pub(crate) fn lambda_scope(
+        scope: &'a Scope<'a>,
+        lambda: &leo_ast::LambdaExpression,
+        types: Vec<Type<'a>>,
+        const_: bool,
+    ) -> (&'a Scope<'a>, Vec<&'a Variable<'a>>) {
+        let body_scope = scope.make_subscope();
+        let parameters = lambda
+            .parameters
+            .iter()
+            .zip(types)
+            .map(|(parameter, type_)| {
+                let variable = scope.context.alloc_variable(RefCell::new(InnerVariable {
+                    id: scope.context.get_id(),
+                    name: parameter.clone(),
+                    type_,
+                    mutable: false,
+                    const_,
+                    declaration: VariableDeclaration::Parameter,
+                    references: vec![],
+                    assignments: vec![],
+                    referenced_in_loop: false,
+                }));
+                body_scope
+                    .variables
+                    .borrow_mut()
+                    .insert(parameter.name.to_string(), variable);
+                variable
+            })
+            .collect();
+
+        (body_scope, parameters)
+    }
+
+    pub(crate) fn lambda_argument(
+        argument: &leo_ast::Expression,
+        parameters: usize,
+    ) -> Result<&leo_ast::LambdaExpression, AsgConvertError> {
+        match argument {
+            leo_ast::Expression::Lambda(lambda) if lambda.parameters.len() == parameters => Ok(lambda),
+            argument => Err(AsgConvertError::expected_lambda(parameters, argument.span())),
+        }
+    }
+}
+
 impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
@@ -103,42 +196,7 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
                 span,
             }) => {
                 let target = <&Expression<'a>>::from_ast(scope, &**ast_circuit, None)?;
-                let circuit = match target.get_type() {
-                    Some(Type::Circuit(circuit)) => circuit,
-                    type_ => {
-                        return Err(AsgConvertError::unexpected_type(
-                            "circuit",
-                            type_.map(|x| x.to_string()).as_deref(),
-                            span,
-                        ));
-                    }
-                };
-                let circuit_name = circuit.name.borrow().name.clone();
-                let member = circuit.members.borrow();
-                let member = member
-                    .get(name.name.as_ref())
-                    .ok_or_else(|| AsgConvertError::unresolved_circuit_member(&circuit_name, &name.name, span))?;
-                match member {
-                    CircuitMember::Function(body) => {
-                        if body.qualifier == FunctionQualifier::Static {
-                            return Err(AsgConvertError::circuit_static_call_invalid(
-                                &circuit_name,
-                                &name.name,
-                                span,
-                            ));
-                        } else if body.qualifier == FunctionQualifier::MutSelfRef && !target.is_mut_ref() {
-                            return Err(AsgConvertError::circuit_member_mut_call_invalid(
-                                &circuit_name,
-                                &name.name,
-                                span,
-                            ));
-                        }
-                        (Some(target), *body)
-                    }
-                    CircuitMember::Variable(_) => {
-                        return Err(AsgConvertError::circuit_variable_call(&circuit_name, &name.name, span));
-                    }
-                }
+                (Some(target), Self::resolve_method(target, name, span)?)
             }
             leo_ast::Expression::CircuitStaticFunctionAccess(leo_ast::CircuitStaticFunctionAccessExpression {
                 circuit: ast_circuit,
@@ -180,32 +238,95 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
                 ));
             }
         };
+
+        Self::from_function(scope, target, function, &value.arguments, &value.span, expected_type)
+    }
+}
+
+impl<'a> CallExpression<'a> {
+    ///
+    /// Returns the method `name` of the circuit that `target` evaluates to.
+    ///
+    pub(crate) fn resolve_method(
+        target: &'a Expression<'a>,
+        name: &leo_ast::Identifier,
+        span: &Span,
+    ) -> Result<&'a Function<'a>, AsgConvertError> {
+        let circuit = match target.get_type() {
+            Some(Type::Circuit(circuit)) => circuit,
+            type_ => {
+                return Err(AsgConvertError::unexpected_type(
+                    "circuit",
+                    type_.map(|x| x.to_string()).as_deref(),
+                    span,
+                ));
+            }
+        };
+        let circuit_name = circuit.name.borrow().name.clone();
+        let member = circuit.members.borrow();
+        let member = member
+            .get(name.name.as_ref())
+            .ok_or_else(|| AsgConvertError::unresolved_circuit_member(&circuit_name, &name.name, span))?;
+        match member {
+            CircuitMember::Function(body) => {
+                if body.qualifier == FunctionQualifier::Static {
+                    return Err(AsgConvertError::circuit_static_call_invalid(
+                        &circuit_name,
+                        &name.name,
+                        span,
+                    ));
+                } else if body.qualifier == FunctionQualifier::MutSelfRef && !target.is_mut_ref() {
+                    return Err(AsgConvertError::circuit_member_mut_call_invalid(
+                        &circuit_name,
+                        &name.name,
+                        span,
+                    ));
+                }
+                Ok(*body)
+            }
+            CircuitMember::Variable(_) => Err(AsgConvertError::circuit_variable_call(&circuit_name, &name.name, span)),
+        }
+    }
+
+    ///
+    /// Returns a call to the resolved `function` on the already converted `target`.
+    ///
+    pub(crate) fn from_function(
+        scope: &'a Scope<'a>,
+        target: Option<&'a Expression<'a>>,
+        function: &'a Function<'a>,
+        arguments: &[leo_ast::Expression],
+        span: &Span,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<CallExpression<'a>, AsgConvertError> {
         if let Some(expected) = expected_type {
             let output: Type = function.output.clone();
             if !expected.matches(&output) {
                 return Err(AsgConvertError::unexpected_type(
                     &expected.to_string(),
                     Some(&*output.to_string()),
-                    &value.span,
+                    span,
                 ));
             }
         }
-        if value.arguments.len() != function.arguments.len() {
+        if arguments.len() != function.arguments.len() {
             return Err(AsgConvertError::unexpected_call_argument_count(
                 function.arguments.len(),
-                value.arguments.len(),
-                &value.span,
+                arguments.len(),
+                span,
             ));
         }
 
-        let arguments = value
-            .arguments
+        let arguments = arguments
             .iter()
             .zip(function.arguments.iter())
             .map(|(expr, (_, argument))| {
-                let argument = argument.get().borrow();
-                let converted = <&Expression<'a>>::from_ast(scope, expr, Some(argument.type_.clone().partial()))?;
-                if argument.const_ && !converted.is_consty() {
+                let (type_, const_) = {
+                    let argument = argument.get().borrow();
+                    (argument.type_.clone(), argument.const_)
+                };
+                let converted = <&Expression<'a>>::from_ast(scope, expr, Some(type_.partial()))?;
+                if const_ && !converted.is_consty() {
                     return Err(AsgConvertError::unexpected_nonconst(expr.span()));
                 }
                 Ok(Cell::new(converted))
@@ -213,11 +334,11 @@ impl<'a> FromAst<'a, leo_ast::CallExpression> for CallExpression<'a> {
             .collect::<Result<Vec<_>, AsgConvertError>>()?;
 
         if function.is_test() {
-            return Err(AsgConvertError::call_test_function(&value.span));
+            return Err(AsgConvertError::call_test_function(span));
         }
         Ok(CallExpression {
             parent: Cell::new(None),
-            span: Some(value.span.clone()),
+            span: Some(span.clone()),
             arguments,
             function: Cell::new(function),
             target: Cell::new(target),
@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Type};
+use crate::{
+    AsgConvertError,
+    ConstInt,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Type,
+};
 pub use leo_ast::UnaryOperation;
 
 use std::cell::Cell;
@@ -59,6 +71,15 @@ impl<'a> ExpressionNode<'a> for CastExpression<'a> {
         match value {
             ConstValue::Int(int) => match &self.target_type {
                 Type::Integer(target) => Some(ConstValue::Int(int.cast_to(target))),
+                Type::Boolean => match int.to_usize() {
+                    Some(0) => Some(ConstValue::Boolean(false)),
+                    Some(1) => Some(ConstValue::Boolean(true)),
+                    _ => None,
+                },
+                _ => None,
+            },
+            ConstValue::Boolean(boolean) => match &self.target_type {
+                Type::Integer(target) => Some(ConstValue::Int(ConstInt::from(boolean as u8).cast_to(target))),
                 _ => None,
             },
             _ => None,
@@ -89,6 +110,25 @@ impl<'a> FromAst<'a, leo_ast::CastExpression> for CastExpression<'a> {
 
         let inner = <&Expression<'a>>::from_ast(scope, &*value.inner, None)?;
 
+        // Only integers of value `0` or `1` can be cast to a boolean.
+        if target_type == Type::Boolean {
+            match (inner.get_type(), inner.const_value()) {
+                (Some(Type::Integer(_)), Some(ConstValue::Int(int)))
+                    if !matches!(int.to_usize(), Some(0) | Some(1)) =>
+                {
+                    return Err(AsgConvertError::invalid_bool_cast(&int.to_string(), &value.span));
+                }
+                (Some(Type::Integer(_)), _) | (Some(Type::Boolean), _) => (),
+                (type_, _) => {
+                    return Err(AsgConvertError::unexpected_type(
+                        "integer",
+                        type_.map(|x| x.to_string()).as_deref(),
+                        &value.span,
+                    ));
+                }
+            }
+        }
+
         Ok(CastExpression {
             parent: Cell::new(None),
             span: Some(value.span.clone()),
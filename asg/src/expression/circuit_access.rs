@@ -99,6 +99,20 @@ impl<'a> FromAst<'a, leo_ast::CircuitMemberAccessExpression> for CircuitAccessEx
         expected_type: Option<PartialType<'a>>,
     ) -> Result<CircuitAccessExpression<'a>, AsgConvertError> {
         let target = <&'a Expression<'a>>::from_ast(scope, &*value.circuit, None)?;
+
+        Self::from_target(target, value, expected_type)
+    }
+}
+
+impl<'a> CircuitAccessExpression<'a> {
+    ///
+    /// Returns an access to the member `value.name` of the circuit value `target`.
+    ///
+    pub fn from_target(
+        target: &'a Expression<'a>,
+        value: &leo_ast::CircuitMemberAccessExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<CircuitAccessExpression<'a>, AsgConvertError> {
         let circuit = match target.get_type() {
             Some(Type::Circuit(circuit)) => circuit,
             x => {
@@ -38,6 +38,8 @@ pub struct CircuitInitExpression<'a> {
     pub parent: Cell<Option<&'a Expression<'a>>>,
     pub span: Option<Span>,
     pub circuit: Cell<&'a Circuit<'a>>,
+    /// Supplies the members that are not given explicitly.
+    pub base: Cell<Option<&'a Expression<'a>>>,
     pub values: Vec<(Identifier, Cell<&'a Expression<'a>>)>,
 }
 
@@ -57,6 +59,9 @@ impl<'a> ExpressionNode<'a> for CircuitInitExpression<'a> {
     }
 
     fn enforce_parents(&self, expr: &'a Expression<'a>) {
+        if let Some(base) = self.base.get() {
+            base.set_parent(expr);
+        }
         self.values.iter().for_each(|(_, element)| {
             element.get().set_parent(expr);
         })
@@ -75,7 +80,8 @@ impl<'a> ExpressionNode<'a> for CircuitInitExpression<'a> {
     }
 
     fn is_consty(&self) -> bool {
-        self.values.iter().all(|(_, value)| value.get().is_consty())
+        self.base.get().map(|base| base.is_consty()).unwrap_or(true)
+            && self.values.iter().all(|(_, value)| value.get().is_consty())
     }
 }
 
@@ -99,6 +105,12 @@ impl<'a> FromAst<'a, leo_ast::CircuitInitExpression> for CircuitInitExpression<'
                 ));
             }
         }
+        let base = value
+            .base
+            .as_ref()
+            .map(|base| <&Expression<'a>>::from_ast(scope, &**base, Some(Type::Circuit(circuit).partial())))
+            .transpose()?;
+
         let members: IndexMap<&str, (&Identifier, Option<&leo_ast::Expression>)> = value
             .members
             .iter()
@@ -135,7 +147,7 @@ impl<'a> FromAst<'a, leo_ast::CircuitInitExpression> for CircuitInitExpression<'
                         )?
                     };
                     values.push(((*identifier).clone(), Cell::new(received)));
-                } else {
+                } else if base.is_none() {
                     return Err(AsgConvertError::missing_circuit_member(
                         &circuit.name.borrow().name,
                         name,
@@ -159,6 +171,7 @@ impl<'a> FromAst<'a, leo_ast::CircuitInitExpression> for CircuitInitExpression<'
             parent: Cell::new(None),
             span: Some(value.span.clone()),
             circuit: Cell::new(circuit),
+            base: Cell::new(base),
             values,
         })
     }
@@ -168,6 +181,7 @@ impl<'a> Into<leo_ast::CircuitInitExpression> for &CircuitInitExpression<'a> {
     fn into(self) -> leo_ast::CircuitInitExpression {
         leo_ast::CircuitInitExpression {
             name: self.circuit.get().name.borrow().clone(),
+            base: self.base.get().map(|base| Box::new(base.into())),
             members: self
                 .values
                 .iter()
@@ -22,6 +22,7 @@ use crate::{
     ExpressionNode,
     FromAst,
     GroupValue,
+    IntegerType,
     Node,
     PartialType,
     Scope,
@@ -206,6 +207,63 @@ impl<'a> FromAst<'a, leo_ast::ValueExpression> for Constant<'a> {
     }
 }
 
+impl<'a> Constant<'a> {
+    ///
+    /// Returns the constant named by an integer bound access such as `u8::MAX` or `i8::MIN`.
+    ///
+    /// Returns `None` if the accessed name is not an integer type.
+    ///
+    pub(crate) fn from_integer_bound(
+        value: &leo_ast::CircuitStaticFunctionAccessExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<Option<Constant<'a>>, AsgConvertError> {
+        let int_type = match &*value.circuit {
+            leo_ast::Expression::Identifier(identifier) => match &*identifier.name {
+                "i8" => IntegerType::I8,
+                "i16" => IntegerType::I16,
+                "i32" => IntegerType::I32,
+                "i64" => IntegerType::I64,
+                "i128" => IntegerType::I128,
+                "u8" => IntegerType::U8,
+                "u16" => IntegerType::U16,
+                "u32" => IntegerType::U32,
+                "u64" => IntegerType::U64,
+                "u128" => IntegerType::U128,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let int_value = match &*value.name.name {
+            "MIN" => ConstInt::min_value(&int_type),
+            "MAX" => ConstInt::max_value(&int_type),
+            name => {
+                return Err(AsgConvertError::unknown_integer_constant(
+                    &int_type.to_string(),
+                    name,
+                    &value.span,
+                ));
+            }
+        };
+        match expected_type {
+            Some(PartialType::Integer(Some(sub_type), _)) if sub_type == int_type => (),
+            Some(PartialType::Integer(None, Some(_))) => (),
+            None => (),
+            Some(x) => {
+                return Err(AsgConvertError::unexpected_type(
+                    &x.to_string(),
+                    Some(&*int_type.to_string()),
+                    &value.span,
+                ));
+            }
+        }
+        Ok(Some(Constant {
+            parent: Cell::new(None),
+            span: Some(value.span.clone()),
+            value: ConstValue::Int(int_value),
+        }))
+    }
+}
+
 impl<'a> Into<leo_ast::ValueExpression> for &Constant<'a> {
     fn into(self) -> leo_ast::ValueExpression {
         match &self.value {
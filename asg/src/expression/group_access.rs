@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AsgConvertError, ConstValue, Expression, ExpressionNode, Identifier, Node, PartialType, Span, Type};
+
+use std::{cell::Cell, fmt};
+
+/// An affine coordinate of a group element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AffineCoordinate {
+    X,
+    Y,
+}
+
+impl fmt::Display for AffineCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AffineCoordinate::X => write!(f, "x"),
+            AffineCoordinate::Y => write!(f, "y"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GroupAccessExpression<'a> {
+    pub parent: Cell<Option<&'a Expression<'a>>>,
+    pub span: Option<Span>,
+    pub target: Cell<&'a Expression<'a>>,
+    pub coordinate: AffineCoordinate,
+}
+
+impl<'a> Node for GroupAccessExpression<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> ExpressionNode<'a> for GroupAccessExpression<'a> {
+    fn set_parent(&self, parent: &'a Expression<'a>) {
+        self.parent.replace(Some(parent));
+    }
+
+    fn get_parent(&self) -> Option<&'a Expression<'a>> {
+        self.parent.get()
+    }
+
+    fn enforce_parents(&self, expr: &'a Expression<'a>) {
+        self.target.get().set_parent(expr);
+    }
+
+    fn get_type(&self) -> Option<Type<'a>> {
+        Some(Type::Field)
+    }
+
+    fn is_mut_ref(&self) -> bool {
+        false
+    }
+
+    fn const_value(&self) -> Option<ConstValue> {
+        None
+    }
+
+    fn is_consty(&self) -> bool {
+        self.target.get().is_consty()
+    }
+}
+
+impl<'a> GroupAccessExpression<'a> {
+    ///
+    /// Returns an access to the coordinate `value.name` of the group element `target`.
+    ///
+    pub fn from_target(
+        target: &'a Expression<'a>,
+        value: &leo_ast::CircuitMemberAccessExpression,
+        expected_type: Option<PartialType<'a>>,
+    ) -> Result<GroupAccessExpression<'a>, AsgConvertError> {
+        let coordinate = match value.name.name.as_ref() {
+            "x" => AffineCoordinate::X,
+            "y" => AffineCoordinate::Y,
+            name => return Err(AsgConvertError::unresolved_group_coordinate(name, &value.span)),
+        };
+
+        if let Some(expected_type) = expected_type {
+            if !expected_type.matches(&Type::Field) {
+                return Err(AsgConvertError::unexpected_type(
+                    &expected_type.to_string(),
+                    Some("field"),
+                    &value.span,
+                ));
+            }
+        }
+
+        Ok(GroupAccessExpression {
+            parent: Cell::new(None),
+            span: Some(value.span.clone()),
+            target: Cell::new(target),
+            coordinate,
+        })
+    }
+}
+
+impl<'a> Into<leo_ast::CircuitMemberAccessExpression> for &GroupAccessExpression<'a> {
+    fn into(self) -> leo_ast::CircuitMemberAccessExpression {
+        let span = self.span.clone().unwrap_or_default();
+        leo_ast::CircuitMemberAccessExpression {
+            circuit: Box::new(self.target.get().into()),
+            name: Identifier::new_with_span(&self.coordinate.to_string(), span.clone()),
+            span,
+        }
+    }
+}
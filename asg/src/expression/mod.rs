@@ -23,12 +23,18 @@
 mod array_access;
 pub use array_access::*;
 
+mod array_fold;
+pub use array_fold::*;
+
 mod array_inline;
 pub use array_inline::*;
 
 mod array_init;
 pub use array_init::*;
 
+mod array_map;
+pub use array_map::*;
+
 mod array_range_access;
 pub use array_range_access::*;
 
@@ -47,6 +53,9 @@ pub use circuit_init::*;
 mod constant;
 pub use constant::*;
 
+mod group_access;
+pub use group_access::*;
+
 mod ternary;
 pub use ternary::*;
 
@@ -80,6 +89,8 @@ pub enum Expression<'a> {
     ArrayInit(ArrayInitExpression<'a>),
     ArrayAccess(ArrayAccessExpression<'a>),
     ArrayRangeAccess(ArrayRangeAccessExpression<'a>),
+    ArrayFold(ArrayFoldExpression<'a>),
+    ArrayMap(ArrayMapExpression<'a>),
 
     TupleInit(TupleInitExpression<'a>),
     TupleAccess(TupleAccessExpression<'a>),
@@ -87,6 +98,8 @@ pub enum Expression<'a> {
     CircuitInit(CircuitInitExpression<'a>),
     CircuitAccess(CircuitAccessExpression<'a>),
 
+    GroupAccess(GroupAccessExpression<'a>),
+
     Call(CallExpression<'a>),
 }
 
@@ -110,10 +123,13 @@ impl<'a> Node for Expression<'a> {
             ArrayInit(x) => x.span(),
             ArrayAccess(x) => x.span(),
             ArrayRangeAccess(x) => x.span(),
+            ArrayFold(x) => x.span(),
+            ArrayMap(x) => x.span(),
             TupleInit(x) => x.span(),
             TupleAccess(x) => x.span(),
             CircuitInit(x) => x.span(),
             CircuitAccess(x) => x.span(),
+            GroupAccess(x) => x.span(),
             Call(x) => x.span(),
         }
     }
@@ -144,10 +160,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.set_parent(parent),
             ArrayAccess(x) => x.set_parent(parent),
             ArrayRangeAccess(x) => x.set_parent(parent),
+            ArrayFold(x) => x.set_parent(parent),
+            ArrayMap(x) => x.set_parent(parent),
             TupleInit(x) => x.set_parent(parent),
             TupleAccess(x) => x.set_parent(parent),
             CircuitInit(x) => x.set_parent(parent),
             CircuitAccess(x) => x.set_parent(parent),
+            GroupAccess(x) => x.set_parent(parent),
             Call(x) => x.set_parent(parent),
         }
     }
@@ -165,10 +184,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.get_parent(),
             ArrayAccess(x) => x.get_parent(),
             ArrayRangeAccess(x) => x.get_parent(),
+            ArrayFold(x) => x.get_parent(),
+            ArrayMap(x) => x.get_parent(),
             TupleInit(x) => x.get_parent(),
             TupleAccess(x) => x.get_parent(),
             CircuitInit(x) => x.get_parent(),
             CircuitAccess(x) => x.get_parent(),
+            GroupAccess(x) => x.get_parent(),
             Call(x) => x.get_parent(),
         }
     }
@@ -186,10 +208,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.enforce_parents(expr),
             ArrayAccess(x) => x.enforce_parents(expr),
             ArrayRangeAccess(x) => x.enforce_parents(expr),
+            ArrayFold(x) => x.enforce_parents(expr),
+            ArrayMap(x) => x.enforce_parents(expr),
             TupleInit(x) => x.enforce_parents(expr),
             TupleAccess(x) => x.enforce_parents(expr),
             CircuitInit(x) => x.enforce_parents(expr),
             CircuitAccess(x) => x.enforce_parents(expr),
+            GroupAccess(x) => x.enforce_parents(expr),
             Call(x) => x.enforce_parents(expr),
         }
     }
@@ -207,10 +232,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.get_type(),
             ArrayAccess(x) => x.get_type(),
             ArrayRangeAccess(x) => x.get_type(),
+            ArrayFold(x) => x.get_type(),
+            ArrayMap(x) => x.get_type(),
             TupleInit(x) => x.get_type(),
             TupleAccess(x) => x.get_type(),
             CircuitInit(x) => x.get_type(),
             CircuitAccess(x) => x.get_type(),
+            GroupAccess(x) => x.get_type(),
             Call(x) => x.get_type(),
         }
     }
@@ -228,10 +256,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.is_mut_ref(),
             ArrayAccess(x) => x.is_mut_ref(),
             ArrayRangeAccess(x) => x.is_mut_ref(),
+            ArrayFold(x) => x.is_mut_ref(),
+            ArrayMap(x) => x.is_mut_ref(),
             TupleInit(x) => x.is_mut_ref(),
             TupleAccess(x) => x.is_mut_ref(),
             CircuitInit(x) => x.is_mut_ref(),
             CircuitAccess(x) => x.is_mut_ref(),
+            GroupAccess(x) => x.is_mut_ref(),
             Call(x) => x.is_mut_ref(),
         }
     }
@@ -249,10 +280,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.const_value(),
             ArrayAccess(x) => x.const_value(),
             ArrayRangeAccess(x) => x.const_value(),
+            ArrayFold(x) => x.const_value(),
+            ArrayMap(x) => x.const_value(),
             TupleInit(x) => x.const_value(),
             TupleAccess(x) => x.const_value(),
             CircuitInit(x) => x.const_value(),
             CircuitAccess(x) => x.const_value(),
+            GroupAccess(x) => x.const_value(),
             Call(x) => x.const_value(),
         }
     }
@@ -270,10 +304,13 @@ impl<'a> ExpressionNode<'a> for Expression<'a> {
             ArrayInit(x) => x.is_consty(),
             ArrayAccess(x) => x.is_consty(),
             ArrayRangeAccess(x) => x.is_consty(),
+            ArrayFold(x) => x.is_consty(),
+            ArrayMap(x) => x.is_consty(),
             TupleInit(x) => x.is_consty(),
             TupleAccess(x) => x.is_consty(),
             CircuitInit(x) => x.is_consty(),
             CircuitAccess(x) => x.is_consty(),
+            GroupAccess(x) => x.is_consty(),
             Call(x) => x.is_consty(),
         }
     }
@@ -291,9 +328,11 @@ impl<'a> FromAst<'a, leo_ast::Expression> for &'a Expression<'a> {
             Value(value) => scope
                 .context
                 .alloc_expression(Constant::from_ast(scope, value, expected_type).map(Expression::Constant)?),
-            Binary(binary) => scope
-                .context
-                .alloc_expression(BinaryExpression::from_ast(scope, binary, expected_type).map(Expression::Binary)?),
+            Binary(binary) => {
+                scope
+                    .context
+                    .alloc_expression(BinaryExpression::overloaded_from_ast(scope, binary, expected_type)?)
+            }
             Unary(unary) => scope
                 .context
                 .alloc_expression(UnaryExpression::from_ast(scope, unary, expected_type).map(Expression::Unary)?),
@@ -310,6 +349,10 @@ impl<'a> FromAst<'a, leo_ast::Expression> for &'a Expression<'a> {
             ArrayInit(array_init) => scope.context.alloc_expression(
                 ArrayInitExpression::from_ast(scope, array_init, expected_type).map(Expression::ArrayInit)?,
             ),
+            ArrayComprehension(array_comprehension) => scope.context.alloc_expression(
+                ArrayInlineExpression::from_ast(scope, array_comprehension, expected_type)
+                    .map(Expression::ArrayInline)?,
+            ),
             ArrayAccess(array_access) => scope.context.alloc_expression(
                 ArrayAccessExpression::from_ast(scope, array_access, expected_type).map(Expression::ArrayAccess)?,
             ),
@@ -328,18 +371,33 @@ impl<'a> FromAst<'a, leo_ast::Expression> for &'a Expression<'a> {
             CircuitInit(circuit_init) => scope.context.alloc_expression(
                 CircuitInitExpression::from_ast(scope, circuit_init, expected_type).map(Expression::CircuitInit)?,
             ),
-            CircuitMemberAccess(circuit_member) => scope.context.alloc_expression(
-                CircuitAccessExpression::from_ast(scope, circuit_member, expected_type)
-                    .map(Expression::CircuitAccess)?,
-            ),
-            CircuitStaticFunctionAccess(circuit_member) => scope.context.alloc_expression(
-                CircuitAccessExpression::from_ast(scope, circuit_member, expected_type)
-                    .map(Expression::CircuitAccess)?,
-            ),
-
-            Call(call) => scope
-                .context
-                .alloc_expression(CallExpression::from_ast(scope, call, expected_type).map(Expression::Call)?),
+            CircuitMemberAccess(circuit_member) => {
+                let target = <&Expression<'a>>::from_ast(scope, &*circuit_member.circuit, None)?;
+                let expression = match target.get_type() {
+                    Some(Type::Group) => GroupAccessExpression::from_target(target, circuit_member, expected_type)
+                        .map(Expression::GroupAccess)?,
+                    _ => CircuitAccessExpression::from_target(target, circuit_member, expected_type)
+                        .map(Expression::CircuitAccess)?,
+                };
+                scope.context.alloc_expression(expression)
+            }
+            CircuitStaticFunctionAccess(circuit_member) => {
+                match Constant::from_integer_bound(circuit_member, expected_type.clone())? {
+                    Some(constant) => scope.context.alloc_expression(Expression::Constant(constant)),
+                    None => scope.context.alloc_expression(
+                        CircuitAccessExpression::from_ast(scope, circuit_member, expected_type)
+                            .map(Expression::CircuitAccess)?,
+                    ),
+                }
+            }
+
+            Call(call) => match CallExpression::array_builtin_from_ast(scope, call, expected_type.clone())? {
+                Some(expression) => scope.context.alloc_expression(expression),
+                None => scope
+                    .context
+                    .alloc_expression(CallExpression::from_ast(scope, call, expected_type).map(Expression::Call)?),
+            },
+            Lambda(lambda) => return Err(AsgConvertError::unexpected_lambda(&lambda.span)),
         };
         expression.enforce_parents(&expression);
         Ok(expression)
@@ -360,10 +418,13 @@ impl<'a> Into<leo_ast::Expression> for &Expression<'a> {
             ArrayInit(x) => leo_ast::Expression::ArrayInit(x.into()),
             ArrayAccess(x) => leo_ast::Expression::ArrayAccess(x.into()),
             ArrayRangeAccess(x) => leo_ast::Expression::ArrayRangeAccess(x.into()),
+            ArrayFold(x) => leo_ast::Expression::Call(x.into()),
+            ArrayMap(x) => leo_ast::Expression::Call(x.into()),
             TupleInit(x) => leo_ast::Expression::TupleInit(x.into()),
             TupleAccess(x) => leo_ast::Expression::TupleAccess(x.into()),
             CircuitInit(x) => leo_ast::Expression::CircuitInit(x.into()),
             CircuitAccess(x) => x.into(),
+            GroupAccess(x) => leo_ast::Expression::CircuitMemberAccess(x.into()),
             Call(x) => leo_ast::Expression::Call(x.into()),
         }
     }
@@ -16,12 +16,15 @@
 
 use crate::{
     AsgConvertError,
+    AssignOperation,
+    AssignStatement,
     ConstValue,
     Constant,
     DefinitionStatement,
     Expression,
     ExpressionNode,
     FromAst,
+    InnerVariable,
     Node,
     PartialType,
     Scope,
@@ -68,33 +71,14 @@ impl<'a> ExpressionNode<'a> for VariableRef<'a> {
     // todo: we can use use hacky ssa here to catch more cases, or just enforce ssa before asg generation finished
     fn const_value(&self) -> Option<ConstValue> {
         let variable = self.variable.borrow();
-        if variable.mutable || variable.assignments.len() != 1 {
+        if variable.mutable {
+            return Self::mutable_const_value(&variable);
+        }
+        if variable.assignments.len() != 1 {
             return None;
         }
         let assignment = variable.assignments.get(0).unwrap();
-        match &*assignment {
-            Statement::Definition(DefinitionStatement { variables, value, .. }) => {
-                if variables.len() == 1 {
-                    let defined_variable = variables.get(0).unwrap().borrow();
-                    assert_eq!(variable.id, defined_variable.id);
-
-                    value.get().const_value()
-                } else {
-                    for (i, defined_variable) in variables.iter().enumerate() {
-                        let defined_variable = defined_variable.borrow();
-                        if defined_variable.id == variable.id {
-                            match value.get().const_value() {
-                                Some(ConstValue::Tuple(values)) => return values.get(i).cloned(),
-                                None => return None,
-                                _ => (),
-                            }
-                        }
-                    }
-                    panic!("no corresponding tuple variable found during const destructuring (corrupt asg?)");
-                }
-            }
-            _ => None, //todo unroll loops during asg phase
-        }
+        Self::definition_const_value(&variable, assignment)
     }
 
     fn is_consty(&self) -> bool {
@@ -102,7 +86,10 @@ impl<'a> ExpressionNode<'a> for VariableRef<'a> {
         if variable.const_ {
             return true;
         }
-        if variable.mutable || variable.assignments.len() != 1 {
+        if variable.mutable {
+            return Self::mutable_const_value(&variable).is_some();
+        }
+        if variable.assignments.len() != 1 {
             return false;
         }
         let assignment = variable.assignments.get(0).unwrap();
@@ -130,6 +117,65 @@ impl<'a> ExpressionNode<'a> for VariableRef<'a> {
     }
 }
 
+impl<'a> VariableRef<'a> {
+    /// Returns the constant value that the statement `assignment` defining `variable` binds to it.
+    fn definition_const_value(variable: &InnerVariable<'a>, assignment: &Statement<'a>) -> Option<ConstValue> {
+        match assignment {
+            Statement::Definition(DefinitionStatement { variables, value, .. }) => {
+                if variables.len() == 1 {
+                    let defined_variable = variables.get(0).unwrap().borrow();
+                    assert_eq!(variable.id, defined_variable.id);
+
+                    value.get().const_value()
+                } else {
+                    for (i, defined_variable) in variables.iter().enumerate() {
+                        let defined_variable = defined_variable.borrow();
+                        if defined_variable.id == variable.id {
+                            match value.get().const_value() {
+                                Some(ConstValue::Tuple(values)) => return values.get(i).cloned(),
+                                None => return None,
+                                _ => (),
+                            }
+                        }
+                    }
+                    panic!("no corresponding tuple variable found during const destructuring (corrupt asg?)");
+                }
+            }
+            _ => None, //todo unroll loops during asg phase
+        }
+    }
+
+    /// Returns the constant value of a `let` variable if its definition and every reassignment bind the same constant.
+    ///
+    /// Propagation stops at the first reassignment to anything but a literal, compound assignment, or assignment through an access.
+    /// Variables referenced inside a loop that does not enclose their declaration are never propagated,
+    /// since a reassignment later in the loop body reaches the reference on the next iteration.
+    fn mutable_const_value(variable: &InnerVariable<'a>) -> Option<ConstValue> {
+        if variable.referenced_in_loop {
+            return None;
+        }
+        let (definition, reassignments) = variable.assignments.split_first()?;
+        let value = Self::definition_const_value(variable, definition)?;
+
+        for assignment in reassignments {
+            match assignment {
+                Statement::Assign(AssignStatement {
+                    operation: AssignOperation::Assign,
+                    target_accesses,
+                    value: assigned,
+                    ..
+                }) if target_accesses.is_empty() => match assigned.get() {
+                    Expression::Constant(Constant { value: assigned, .. }) if assigned == &value => (),
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+
+        Some(value)
+    }
+}
+
 impl<'a> FromAst<'a, leo_ast::Identifier> for &'a Expression<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
@@ -182,6 +228,9 @@ impl<'a> FromAst<'a, leo_ast::Identifier> for &'a Expression<'a> {
 
         let mut variable_ref = variable.borrow_mut();
         variable_ref.references.push(expression);
+        if variable_ref.mutable && scope.resolve_in_loop_below(&value.name) {
+            variable_ref.referenced_in_loop = true;
+        }
 
         Ok(expression)
     }
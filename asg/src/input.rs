@@ -91,6 +91,7 @@ impl<'a> Input<'a> {
                 declaration: crate::VariableDeclaration::Input,
                 references: vec![],
                 assignments: vec![],
+                referenced_in_loop: false,
             })),
         }
     }
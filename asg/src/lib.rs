@@ -37,6 +37,9 @@ pub use const_value::*;
 pub mod error;
 pub use error::*;
 
+pub mod declarations;
+pub use declarations::*;
+
 pub mod expression;
 pub use expression::*;
 
@@ -61,6 +64,9 @@ pub use reducer::*;
 pub mod scope;
 pub use scope::*;
 
+pub mod source_map;
+pub use source_map::*;
+
 pub mod statement;
 pub use statement::*;
 
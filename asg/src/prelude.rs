@@ -31,6 +31,10 @@ pub fn resolve_core_module<'a>(context: AsgContext<'a>, module: &str) -> Result<
                     function hash(seed: [u8; 32], message: [u8; 32]) -> [u8; 32] {
                         return [0; 32];
                     }
+
+                    function digest(message: [u8; 32]) -> [u8; 32] {
+                        return [0; 32];
+                    }
                 }
                 "#,
                 &mut crate::NullImportResolver,
@@ -38,6 +42,55 @@ pub fn resolve_core_module<'a>(context: AsgContext<'a>, module: &str) -> Result<
             asg.set_core_mapping("blake2s");
             Ok(Some(asg))
         }
+        "unstable.merkle" => {
+            let asg = crate::load_asg(
+                context,
+                r#"
+                circuit MerklePath {
+                    function verify(leaf: field, path: [field; 8], index: [bool; 8], root: field) -> bool {
+                        return false;
+                    }
+                }
+                "#,
+                &mut crate::NullImportResolver,
+            )?;
+            asg.set_core_mapping("merkle");
+            Ok(Some(asg))
+        }
+        "unstable.pedersen" => {
+            let asg = crate::load_asg(
+                context,
+                r#"
+                circuit Pedersen {
+                    function hash(preimage: [field; 2]) -> field {
+                        return 0field;
+                    }
+
+                    function hash_bytes(bytes: [u8; 64]) -> field {
+                        return 0field;
+                    }
+                }
+                "#,
+                &mut crate::NullImportResolver,
+            )?;
+            asg.set_core_mapping("pedersen");
+            Ok(Some(asg))
+        }
+        "unstable.schnorr" => {
+            let asg = crate::load_asg(
+                context,
+                r#"
+                circuit Schnorr {
+                    function verify(public_key: group, message: [u8; 32], response: [u8; 32], challenge: [u8; 32]) -> bool {
+                        return false;
+                    }
+                }
+                "#,
+                &mut crate::NullImportResolver,
+            )?;
+            asg.set_core_mapping("schnorr");
+            Ok(Some(asg))
+        }
         _ => Ok(None),
     }
 }
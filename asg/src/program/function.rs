@@ -107,6 +107,7 @@ impl<'a> Function<'a> {
                             declaration: crate::VariableDeclaration::Parameter,
                             references: vec![],
                             assignments: vec![],
+                            referenced_in_loop: false,
                         }));
                         arguments.insert(identifier.name.to_string(), Cell::new(&*variable));
                     }
@@ -145,6 +146,7 @@ impl<'a> Function<'a> {
                 declaration: crate::VariableDeclaration::Parameter,
                 references: vec![],
                 assignments: vec![],
+                referenced_in_loop: false,
             }));
             self.scope
                 .variables
@@ -24,7 +24,7 @@ pub use circuit::*;
 mod function;
 pub use function::*;
 
-use crate::{ArenaNode, AsgContext, AsgConvertError, ImportResolver, Input, Scope};
+use crate::{ArenaNode, AsgContext, AsgConvertError, ImportResolver, Input, RecursionChecker, Scope};
 use leo_ast::{Identifier, PackageAccess, PackageOrPackages, Span};
 
 use indexmap::IndexMap;
@@ -121,6 +121,29 @@ fn resolve_import_package_access(
     }
 }
 
+fn imported_symbols(program: &leo_ast::Program) -> Vec<(Vec<String>, ImportSymbol, Span)> {
+    let mut imported_symbols: Vec<(Vec<String>, ImportSymbol, Span)> = vec![];
+    for import in program.imports.iter() {
+        resolve_import_package(&mut imported_symbols, vec![], &import.package_or_packages);
+    }
+
+    imported_symbols
+}
+
+fn deduplicate_packages(imported_symbols: &[(Vec<String>, ImportSymbol, Span)]) -> IndexMap<Vec<String>, Span> {
+    let mut deduplicated_imports: IndexMap<Vec<String>, Span> = IndexMap::new();
+    for (package, _symbol, span) in imported_symbols.iter() {
+        deduplicated_imports.insert(package.clone(), span.clone());
+    }
+
+    deduplicated_imports
+}
+
+/// Returns the packages imported by the given program AST in import order, each listed once.
+pub fn imported_packages(program: &leo_ast::Program) -> IndexMap<Vec<String>, Span> {
+    deduplicate_packages(&imported_symbols(program))
+}
+
 impl<'a> Program<'a> {
     /// Returns a new Leo program ASG from the given Leo program AST and its imports.
     ///
@@ -136,16 +159,10 @@ impl<'a> Program<'a> {
         import_resolver: &mut T,
     ) -> Result<Program<'a>, AsgConvertError> {
         // Recursively extract imported symbols.
-        let mut imported_symbols: Vec<(Vec<String>, ImportSymbol, Span)> = vec![];
-        for import in program.imports.iter() {
-            resolve_import_package(&mut imported_symbols, vec![], &import.package_or_packages);
-        }
+        let imported_symbols = imported_symbols(program);
 
         // Create package list.
-        let mut deduplicated_imports: IndexMap<Vec<String>, Span> = IndexMap::new();
-        for (package, _symbol, span) in imported_symbols.iter() {
-            deduplicated_imports.insert(package.clone(), span.clone());
-        }
+        let deduplicated_imports = deduplicate_packages(&imported_symbols);
 
         let mut wrapped_resolver = crate::CoreImportResolver::new(import_resolver);
 
@@ -218,6 +235,7 @@ impl<'a> Program<'a> {
             circuits: RefCell::new(imported_circuits),
             function: Cell::new(None),
             input: Cell::new(None),
+            in_loop: Cell::new(false),
         })) {
             ArenaNode::Scope(c) => c,
             _ => unimplemented!(),
@@ -233,6 +251,7 @@ impl<'a> Program<'a> {
             functions: RefCell::new(IndexMap::new()),
             circuits: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
+            in_loop: Cell::new(false),
         });
 
         // Prepare header-like scope entries.
@@ -285,6 +304,16 @@ impl<'a> Program<'a> {
             circuits.insert(name.name.to_string(), asg_circuit);
         }
 
+        // Reject call cycles between the functions and circuit member functions of the program.
+        let mut all_functions: Vec<&'a Function<'a>> = functions.values().copied().collect();
+        for circuit in circuits.values() {
+            all_functions.extend(circuit.members.borrow().values().filter_map(|member| match member {
+                CircuitMember::Function(function) => Some(*function),
+                CircuitMember::Variable(_) => None,
+            }));
+        }
+        RecursionChecker::new().check(all_functions)?;
+
         Ok(Program {
             context,
             id: context.get_id(),
@@ -41,6 +41,12 @@ impl<T> Monoid for VecAppend<T> {
     }
 }
 
+impl<T> From<Vec<T>> for VecAppend<T> {
+    fn from(values: Vec<T>) -> Self {
+        VecAppend(values)
+    }
+}
+
 impl<T> Into<Vec<T>> for VecAppend<T> {
     fn into(self) -> Vec<T> {
         self.0
@@ -42,6 +42,8 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
             Expression::ArrayInit(e) => self.reduce_array_init(e),
             Expression::ArrayInline(e) => self.reduce_array_inline(e),
             Expression::ArrayRangeAccess(e) => self.reduce_array_range_access(e),
+            Expression::ArrayFold(e) => self.reduce_array_fold(e),
+            Expression::ArrayMap(e) => self.reduce_array_map(e),
             Expression::Binary(e) => self.reduce_binary(e),
             Expression::Call(e) => self.reduce_call(e),
             Expression::CircuitAccess(e) => self.reduce_circuit_access(e),
@@ -49,6 +51,7 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
             Expression::Ternary(e) => self.reduce_ternary_expression(e),
             Expression::Cast(e) => self.reduce_cast_expression(e),
             Expression::Constant(e) => self.reduce_constant(e),
+            Expression::GroupAccess(e) => self.reduce_group_access(e),
             Expression::TupleAccess(e) => self.reduce_tuple_access(e),
             Expression::TupleInit(e) => self.reduce_tuple_init(e),
             Expression::Unary(e) => self.reduce_unary(e),
@@ -89,6 +92,21 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
         self.reducer.reduce_array_range_access(input, array, left, right)
     }
 
+    pub fn reduce_array_fold(&mut self, input: &ArrayFoldExpression<'a>) -> T {
+        let array = self.reduce_expression(input.array.get());
+        let initial = self.reduce_expression(input.initial.get());
+        let body = self.reduce_expression(input.body.get());
+
+        self.reducer.reduce_array_fold(input, array, initial, body)
+    }
+
+    pub fn reduce_array_map(&mut self, input: &ArrayMapExpression<'a>) -> T {
+        let array = self.reduce_expression(input.array.get());
+        let body = self.reduce_expression(input.body.get());
+
+        self.reducer.reduce_array_map(input, array, body)
+    }
+
     pub fn reduce_binary(&mut self, input: &BinaryExpression<'a>) -> T {
         let left = self.reduce_expression(input.left.get());
         let right = self.reduce_expression(input.right.get());
@@ -114,13 +132,14 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
     }
 
     pub fn reduce_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> T {
+        let base = input.base.get().map(|e| self.reduce_expression(e));
         let values = input
             .values
             .iter()
             .map(|(_, e)| self.reduce_expression(e.get()))
             .collect();
 
-        self.reducer.reduce_circuit_init(input, values)
+        self.reducer.reduce_circuit_init(input, base, values)
     }
 
     pub fn reduce_ternary_expression(&mut self, input: &TernaryExpression<'a>) -> T {
@@ -142,6 +161,12 @@ impl<'a, T: Monoid, R: MonoidalReducerExpression<'a, T>> MonoidalDirector<'a, T,
         self.reducer.reduce_constant(input)
     }
 
+    pub fn reduce_group_access(&mut self, input: &GroupAccessExpression<'a>) -> T {
+        let target = self.reduce_expression(input.target.get());
+
+        self.reducer.reduce_group_access(input, target)
+    }
+
     pub fn reduce_tuple_access(&mut self, input: &TupleAccessExpression<'a>) -> T {
         let tuple_ref = self.reduce_expression(input.tuple_ref.get());
 
@@ -172,11 +197,13 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
             Statement::Block(s) => self.reduce_block(s),
             Statement::Conditional(s) => self.reduce_conditional_statement(s),
             Statement::Console(s) => self.reduce_console(s),
+            Statement::Require(s) => self.reduce_require(s),
+            Statement::ConstAssert(s) => self.reduce_const_assert(s),
             Statement::Definition(s) => self.reduce_definition(s),
             Statement::Expression(s) => self.reduce_expression_statement(s),
             Statement::Iteration(s) => self.reduce_iteration(s),
             Statement::Return(s) => self.reduce_return(s),
-            Statement::Empty(_) => T::default(),
+            Statement::Break(_) | Statement::Continue(_) | Statement::Empty(_) => T::default(),
         };
 
         self.reducer.reduce_statement(input, value)
@@ -238,6 +265,11 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
     pub fn reduce_console(&mut self, input: &ConsoleStatement<'a>) -> T {
         let argument = match &input.function {
             ConsoleFunction::Assert(e) => self.reduce_expression(e.get()),
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                let left = self.reduce_expression(left.get());
+                let right = self.reduce_expression(right.get());
+                left.append(right)
+            }
             ConsoleFunction::Debug(f) | ConsoleFunction::Error(f) | ConsoleFunction::Log(f) => {
                 self.reduce_formatted_string(f)
             }
@@ -246,6 +278,19 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
         self.reducer.reduce_console(input, argument)
     }
 
+    pub fn reduce_require(&mut self, input: &RequireStatement<'a>) -> T {
+        let condition = self.reduce_expression(input.condition.get());
+        let message = self.reduce_formatted_string(&input.message);
+
+        self.reducer.reduce_require(input, condition, message)
+    }
+
+    pub fn reduce_const_assert(&mut self, input: &ConstAssertStatement<'a>) -> T {
+        let condition = self.reduce_expression(input.condition.get());
+
+        self.reducer.reduce_const_assert(input, condition)
+    }
+
     pub fn reduce_definition(&mut self, input: &DefinitionStatement<'a>) -> T {
         let value = self.reduce_expression(input.value.get());
 
@@ -261,9 +306,10 @@ impl<'a, T: Monoid, R: MonoidalReducerStatement<'a, T>> MonoidalDirector<'a, T,
     pub fn reduce_iteration(&mut self, input: &IterationStatement<'a>) -> T {
         let start = self.reduce_expression(input.start.get());
         let stop = self.reduce_expression(input.stop.get());
+        let step = input.step.get().map(|e| self.reduce_expression(e));
         let body = self.reduce_statement(input.body.get());
 
-        self.reducer.reduce_iteration(input, start, stop, body)
+        self.reducer.reduce_iteration(input, start, stop, step, body)
     }
 
     pub fn reduce_return(&mut self, input: &ReturnStatement<'a>) -> T {
@@ -44,6 +44,14 @@ pub trait MonoidalReducerExpression<'a, T: Monoid> {
         array.append_option(left).append_option(right)
     }
 
+    fn reduce_array_fold(&mut self, input: &ArrayFoldExpression<'a>, array: T, initial: T, body: T) -> T {
+        array.append(initial).append(body)
+    }
+
+    fn reduce_array_map(&mut self, input: &ArrayMapExpression<'a>, array: T, body: T) -> T {
+        array.append(body)
+    }
+
     fn reduce_binary(&mut self, input: &BinaryExpression<'a>, left: T, right: T) -> T {
         left.append(right)
     }
@@ -56,8 +64,8 @@ pub trait MonoidalReducerExpression<'a, T: Monoid> {
         target.unwrap_or_default()
     }
 
-    fn reduce_circuit_init(&mut self, input: &CircuitInitExpression<'a>, values: Vec<T>) -> T {
-        T::default().append_all(values.into_iter())
+    fn reduce_circuit_init(&mut self, input: &CircuitInitExpression<'a>, base: Option<T>, values: Vec<T>) -> T {
+        base.unwrap_or_default().append_all(values.into_iter())
     }
 
     fn reduce_ternary_expression(&mut self, input: &TernaryExpression<'a>, condition: T, if_true: T, if_false: T) -> T {
@@ -72,6 +80,10 @@ pub trait MonoidalReducerExpression<'a, T: Monoid> {
         T::default()
     }
 
+    fn reduce_group_access(&mut self, input: &GroupAccessExpression<'a>, target: T) -> T {
+        target
+    }
+
     fn reduce_tuple_access(&mut self, input: &TupleAccessExpression<'a>, tuple_ref: T) -> T {
         tuple_ref
     }
@@ -126,6 +138,14 @@ pub trait MonoidalReducerStatement<'a, T: Monoid>: MonoidalReducerExpression<'a,
         argument
     }
 
+    fn reduce_require(&mut self, input: &RequireStatement<'a>, condition: T, message: T) -> T {
+        condition.append(message)
+    }
+
+    fn reduce_const_assert(&mut self, input: &ConstAssertStatement<'a>, condition: T) -> T {
+        condition
+    }
+
     fn reduce_definition(&mut self, input: &DefinitionStatement<'a>, value: T) -> T {
         value
     }
@@ -134,8 +154,8 @@ pub trait MonoidalReducerStatement<'a, T: Monoid>: MonoidalReducerExpression<'a,
         expression
     }
 
-    fn reduce_iteration(&mut self, input: &IterationStatement<'a>, start: T, stop: T, body: T) -> T {
-        start.append(stop).append(body)
+    fn reduce_iteration(&mut self, input: &IterationStatement<'a>, start: T, stop: T, step: Option<T>, body: T) -> T {
+        start.append(stop).append_option(step).append(body)
     }
 
     fn reduce_return(&mut self, input: &ReturnStatement<'a>, value: T) -> T {
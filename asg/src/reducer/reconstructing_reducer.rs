@@ -73,11 +73,46 @@ pub trait ReconstructingReducerExpression<'a> {
             array: Cell::new(array),
             left: Cell::new(left),
             right: Cell::new(right),
+            inclusive: input.inclusive,
             span: input.span,
             length: input.length,
         })
     }
 
+    fn reduce_array_fold(
+        &mut self,
+        input: ArrayFoldExpression<'a>,
+        array: &'a Expression<'a>,
+        initial: &'a Expression<'a>,
+        body: &'a Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::ArrayFold(ArrayFoldExpression {
+            parent: input.parent,
+            span: input.span,
+            array: Cell::new(array),
+            initial: Cell::new(initial),
+            accumulator: input.accumulator,
+            element: input.element,
+            body: Cell::new(body),
+        })
+    }
+
+    fn reduce_array_map(
+        &mut self,
+        input: ArrayMapExpression<'a>,
+        array: &'a Expression<'a>,
+        body: &'a Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::ArrayMap(ArrayMapExpression {
+            parent: input.parent,
+            span: input.span,
+            array: Cell::new(array),
+            element: input.element,
+            body: Cell::new(body),
+            len: input.len,
+        })
+    }
+
     fn reduce_binary(
         &mut self,
         input: BinaryExpression<'a>,
@@ -125,11 +160,13 @@ pub trait ReconstructingReducerExpression<'a> {
     fn reduce_circuit_init(
         &mut self,
         input: CircuitInitExpression<'a>,
+        base: Option<&'a Expression<'a>>,
         values: Vec<(Identifier, &'a Expression<'a>)>,
     ) -> Expression<'a> {
         Expression::CircuitInit(CircuitInitExpression {
             parent: input.parent,
             circuit: input.circuit,
+            base: Cell::new(base),
             values: values.into_iter().map(|x| (x.0, Cell::new(x.1))).collect(),
             span: input.span,
         })
@@ -164,6 +201,15 @@ pub trait ReconstructingReducerExpression<'a> {
         Expression::Constant(input)
     }
 
+    fn reduce_group_access(&mut self, input: GroupAccessExpression<'a>, target: &'a Expression<'a>) -> Expression<'a> {
+        Expression::GroupAccess(GroupAccessExpression {
+            parent: input.parent,
+            target: Cell::new(target),
+            coordinate: input.coordinate,
+            span: input.span,
+        })
+    }
+
     fn reduce_tuple_access(
         &mut self,
         input: TupleAccessExpression<'a>,
@@ -293,20 +339,62 @@ pub trait ReconstructingReducerStatement<'a>: ReconstructingReducerExpression<'a
         })
     }
 
+    fn reduce_console_assert_eq(
+        &mut self,
+        input: ConsoleStatement<'a>,
+        left: &'a Expression<'a>,
+        right: &'a Expression<'a>,
+    ) -> Statement<'a> {
+        Statement::Console(ConsoleStatement {
+            parent: input.parent,
+            span: input.span,
+            function: match input.function {
+                ConsoleFunction::AssertEq(_, _) => ConsoleFunction::AssertEq(Cell::new(left), Cell::new(right)),
+                ConsoleFunction::AssertNeq(_, _) => ConsoleFunction::AssertNeq(Cell::new(left), Cell::new(right)),
+                _ => unimplemented!(),
+            },
+        })
+    }
+
     fn reduce_console_log(&mut self, input: ConsoleStatement<'a>, argument: FormatString<'a>) -> Statement<'a> {
-        assert!(!matches!(input.function, ConsoleFunction::Assert(_)));
+        assert!(matches!(
+            input.function,
+            ConsoleFunction::Debug(_) | ConsoleFunction::Error(_) | ConsoleFunction::Log(_)
+        ));
         Statement::Console(ConsoleStatement {
             parent: input.parent,
             span: input.span,
             function: match input.function {
-                ConsoleFunction::Assert(_) => unimplemented!(),
                 ConsoleFunction::Debug(_) => ConsoleFunction::Debug(argument),
                 ConsoleFunction::Error(_) => ConsoleFunction::Error(argument),
                 ConsoleFunction::Log(_) => ConsoleFunction::Log(argument),
+                _ => unimplemented!(),
             },
         })
     }
 
+    fn reduce_require(
+        &mut self,
+        input: RequireStatement<'a>,
+        condition: &'a Expression<'a>,
+        message: FormatString<'a>,
+    ) -> Statement<'a> {
+        Statement::Require(RequireStatement {
+            parent: input.parent,
+            span: input.span,
+            condition: Cell::new(condition),
+            message,
+        })
+    }
+
+    fn reduce_const_assert(&mut self, input: ConstAssertStatement<'a>, condition: &'a Expression<'a>) -> Statement<'a> {
+        Statement::ConstAssert(ConstAssertStatement {
+            parent: input.parent,
+            span: input.span,
+            condition: Cell::new(condition),
+        })
+    }
+
     fn reduce_definition(&mut self, input: DefinitionStatement<'a>, value: &'a Expression<'a>) -> Statement<'a> {
         Statement::Definition(DefinitionStatement {
             parent: input.parent,
@@ -333,6 +421,7 @@ pub trait ReconstructingReducerStatement<'a>: ReconstructingReducerExpression<'a
         input: IterationStatement<'a>,
         start: &'a Expression<'a>,
         stop: &'a Expression<'a>,
+        step: Option<&'a Expression<'a>>,
         body: &'a Statement<'a>,
     ) -> Statement<'a> {
         Statement::Iteration(IterationStatement {
@@ -341,6 +430,8 @@ pub trait ReconstructingReducerStatement<'a>: ReconstructingReducerExpression<'a
             variable: input.variable,
             start: Cell::new(start),
             stop: Cell::new(stop),
+            inclusive: input.inclusive,
+            step: Cell::new(step),
             body: Cell::new(body),
         })
     }
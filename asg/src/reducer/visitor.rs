@@ -52,6 +52,14 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_array_fold(&mut self, input: &ArrayFoldExpression<'a>) -> VisitResult {
+        Default::default()
+    }
+
+    fn visit_array_map(&mut self, input: &ArrayMapExpression<'a>) -> VisitResult {
+        Default::default()
+    }
+
     fn visit_binary(&mut self, input: &BinaryExpression<'a>) -> VisitResult {
         Default::default()
     }
@@ -80,6 +88,10 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_group_access(&mut self, input: &GroupAccessExpression<'a>) -> VisitResult {
+        Default::default()
+    }
+
     fn visit_tuple_access(&mut self, input: &TupleAccessExpression<'a>) -> VisitResult {
         Default::default()
     }
@@ -128,6 +140,14 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         Default::default()
     }
 
+    fn visit_require(&mut self, input: &RequireStatement<'a>) -> VisitResult {
+        Default::default()
+    }
+
+    fn visit_const_assert(&mut self, input: &ConstAssertStatement<'a>) -> VisitResult {
+        Default::default()
+    }
+
     fn visit_definition(&mut self, input: &DefinitionStatement<'a>) -> VisitResult {
         Default::default()
     }
@@ -55,6 +55,8 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
                 Expression::ArrayInit(e) => self.visit_array_init(e),
                 Expression::ArrayInline(e) => self.visit_array_inline(e),
                 Expression::ArrayRangeAccess(e) => self.visit_array_range_access(e),
+                Expression::ArrayFold(e) => self.visit_array_fold(e),
+                Expression::ArrayMap(e) => self.visit_array_map(e),
                 Expression::Binary(e) => self.visit_binary(e),
                 Expression::Call(e) => self.visit_call(e),
                 Expression::CircuitAccess(e) => self.visit_circuit_access(e),
@@ -62,6 +64,7 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
                 Expression::Ternary(e) => self.visit_ternary_expression(e),
                 Expression::Cast(e) => self.visit_cast_expression(e),
                 Expression::Constant(e) => self.visit_constant(e),
+                Expression::GroupAccess(e) => self.visit_group_access(e),
                 Expression::TupleAccess(e) => self.visit_tuple_access(e),
                 Expression::TupleInit(e) => self.visit_tuple_init(e),
                 Expression::Unary(e) => self.visit_unary(e),
@@ -127,6 +130,29 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
+    pub fn visit_array_fold(&mut self, input: &ArrayFoldExpression<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_array_fold(input) {
+            VisitResult::VisitChildren => {
+                self.visit_expression(&input.array)?;
+                self.visit_expression(&input.initial)?;
+                self.visit_expression(&input.body)?;
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
+    pub fn visit_array_map(&mut self, input: &ArrayMapExpression<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_array_map(input) {
+            VisitResult::VisitChildren => {
+                self.visit_expression(&input.array)?;
+                self.visit_expression(&input.body)?;
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
     pub fn visit_binary(&mut self, input: &BinaryExpression<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_binary(input) {
             VisitResult::VisitChildren => {
@@ -164,6 +190,7 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
     pub fn visit_circuit_init(&mut self, input: &CircuitInitExpression<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_circuit_init(input) {
             VisitResult::VisitChildren => {
+                self.visit_opt_expression(&input.base)?;
                 for (_, argument) in input.values.iter() {
                     self.visit_expression(argument)?;
                 }
@@ -199,6 +226,16 @@ impl<'a, R: ExpressionVisitor<'a>> VisitorDirector<'a, R> {
         self.visitor.visit_constant(input).into()
     }
 
+    pub fn visit_group_access(&mut self, input: &GroupAccessExpression<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_group_access(input) {
+            VisitResult::VisitChildren => {
+                self.visit_expression(&input.target)?;
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
     pub fn visit_tuple_access(&mut self, input: &TupleAccessExpression<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_tuple_access(input) {
             VisitResult::VisitChildren => {
@@ -244,11 +281,13 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
                 Statement::Block(s) => self.visit_block(s),
                 Statement::Conditional(s) => self.visit_conditional_statement(s),
                 Statement::Console(s) => self.visit_console(s),
+                Statement::Require(s) => self.visit_require(s),
+                Statement::ConstAssert(s) => self.visit_const_assert(s),
                 Statement::Definition(s) => self.visit_definition(s),
                 Statement::Expression(s) => self.visit_expression_statement(s),
                 Statement::Iteration(s) => self.visit_iteration(s),
                 Statement::Return(s) => self.visit_return(s),
-                Statement::Empty(_) => Ok(()),
+                Statement::Break(_) | Statement::Continue(_) | Statement::Empty(_) => Ok(()),
             },
             x => x.into(),
         }
@@ -331,11 +370,36 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
         }
     }
 
+    pub fn visit_require(&mut self, input: &RequireStatement<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_require(input) {
+            VisitResult::VisitChildren => {
+                self.visit_expression(&input.condition)?;
+                self.visit_formatted_string(&input.message)?;
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
+    pub fn visit_const_assert(&mut self, input: &ConstAssertStatement<'a>) -> ConcreteVisitResult {
+        match self.visitor.visit_const_assert(input) {
+            VisitResult::VisitChildren => {
+                self.visit_expression(&input.condition)?;
+                Ok(())
+            }
+            x => x.into(),
+        }
+    }
+
     pub fn visit_console(&mut self, input: &ConsoleStatement<'a>) -> ConcreteVisitResult {
         match self.visitor.visit_console(input) {
             VisitResult::VisitChildren => {
                 match &input.function {
                     ConsoleFunction::Assert(e) => self.visit_expression(e)?,
+                    ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                        self.visit_expression(left)?;
+                        self.visit_expression(right)?;
+                    }
                     ConsoleFunction::Debug(f) | ConsoleFunction::Error(f) | ConsoleFunction::Log(f) => {
                         self.visit_formatted_string(f)?
                     }
@@ -371,6 +435,7 @@ impl<'a, R: StatementVisitor<'a>> VisitorDirector<'a, R> {
             VisitResult::VisitChildren => {
                 self.visit_expression(&input.start)?;
                 self.visit_expression(&input.stop)?;
+                self.visit_opt_expression(&input.step)?;
                 self.visit_statement(&input.body)?;
                 Ok(())
             }
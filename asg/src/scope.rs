@@ -47,6 +47,9 @@ pub struct Scope<'a> {
 
     /// The main input to the program.
     pub input: Cell<Option<Input<'a>>>,
+
+    /// Whether this scope is the body of a loop.
+    pub in_loop: Cell<bool>,
 }
 
 #[allow(clippy::mut_from_ref)]
@@ -83,6 +86,39 @@ impl<'a> Scope<'a> {
         }
     }
 
+    ///
+    /// Returns `true` if the current scope is inside the body of a loop.
+    ///
+    /// Loop bodies do not extend into the bodies of functions, so the search stops at the current function.
+    ///
+    pub fn resolve_in_loop(&self) -> bool {
+        if self.in_loop.get() {
+            true
+        } else if self.function.get().is_some() {
+            false
+        } else if let Some(scope) = self.parent_scope.get() {
+            scope.resolve_in_loop()
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Returns `true` if the current scope is inside the body of a loop that does not enclose the declaration of
+    /// the variable with the given name.
+    ///
+    pub fn resolve_in_loop_below(&self, name: &str) -> bool {
+        if self.variables.borrow().contains_key(name) || self.function.get().is_some() {
+            false
+        } else if self.in_loop.get() {
+            true
+        } else if let Some(scope) = self.parent_scope.get() {
+            scope.resolve_in_loop_below(name)
+        } else {
+            false
+        }
+    }
+
     ///
     /// Returns a reference to the current input.
     ///
@@ -163,6 +199,7 @@ impl<'a> Scope<'a> {
             circuits: RefCell::new(IndexMap::new()),
             function: Cell::new(None),
             input: Cell::new(None),
+            in_loop: Cell::new(false),
         })
     }
 
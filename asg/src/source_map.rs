@@ -0,0 +1,294 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Maps AST expressions to the ASG expressions resolved from them.
+
+use crate::{
+    Expression,
+    ExpressionNode,
+    ExpressionVisitor,
+    Node,
+    Program,
+    ProgramVisitor,
+    Span,
+    StatementVisitor,
+    VisitResult,
+    VisitorDirector,
+};
+
+use indexmap::IndexMap;
+use serde_json::Value;
+use std::cell::Cell;
+
+///
+/// A lookup from the span of an AST expression to the ASG expression built from it.
+///
+/// Every ASG expression keeps the span of the AST expression it was converted from, so the
+/// resolved node, including its inferred type and any variable it refers to, can be found
+/// from an AST node by its span. When several ASG expressions share a span, the innermost
+/// one is kept.
+///
+pub struct SourceMap<'a> {
+    expressions: IndexMap<Span, &'a Expression<'a>>,
+}
+
+impl<'a> SourceMap<'a> {
+    ///
+    /// Returns a new source map of every expression in the given program.
+    ///
+    pub fn new(program: &Program<'a>) -> Self {
+        let mut director = VisitorDirector::new(SourceMap {
+            expressions: IndexMap::new(),
+        });
+        director.visit_program(program).ok();
+        director.visitor()
+    }
+
+    ///
+    /// Returns the ASG expression built from the AST expression at the given span.
+    ///
+    pub fn get(&self, span: &Span) -> Option<&'a Expression<'a>> {
+        self.expressions.get(span).copied()
+    }
+
+    ///
+    /// Returns the ASG expression built from the given AST expression.
+    ///
+    pub fn get_expression(&self, expression: &leo_ast::Expression) -> Option<&'a Expression<'a>> {
+        self.get(leo_ast::Node::span(expression))
+    }
+
+    ///
+    /// Serializes the given AST to JSON with the inferred type of each expression added as a
+    /// `ty` field next to the expression. Expressions without a resolved type have a `null` type.
+    ///
+    /// The AST is walked alongside its serialized form, so each expression is annotated exactly
+    /// once.
+    ///
+    pub fn to_typed_json_string(&self, ast: &leo_ast::Program) -> Result<String, serde_json::Error> {
+        let mut json = serde_json::to_value(ast)?;
+
+        for (identifier, circuit) in ast.circuits.iter() {
+            let key = serde_json::to_value(identifier)?;
+            let members = &mut json["circuits"][key.as_str().unwrap_or_default()]["members"];
+            for (index, member) in circuit.members.iter().enumerate() {
+                if let leo_ast::CircuitMember::CircuitFunction(function) = member {
+                    self.annotate_function(function, &mut members[index]["CircuitFunction"]);
+                }
+            }
+        }
+        for (identifier, function) in ast.functions.iter() {
+            let key = serde_json::to_value(identifier)?;
+            self.annotate_function(function, &mut json["functions"][key.as_str().unwrap_or_default()]);
+        }
+
+        serde_json::to_string_pretty(&json)
+    }
+
+    fn annotate_function(&self, function: &leo_ast::Function, json: &mut Value) {
+        self.annotate_block(&function.block, &mut json["block"]);
+    }
+
+    fn annotate_block(&self, block: &leo_ast::Block, json: &mut Value) {
+        let statements = &mut json["statements"];
+        for (index, statement) in block.statements.iter().enumerate() {
+            self.annotate_statement(statement, &mut statements[index]);
+        }
+    }
+
+    fn annotate_statement(&self, statement: &leo_ast::Statement, json: &mut Value) {
+        use leo_ast::Statement::*;
+
+        match statement {
+            Return(statement) => self.annotate_expression(&statement.expression, &mut json["Return"]["expression"]),
+            Break(_) | Continue(_) => (),
+            Definition(statement) => self.annotate_expression(&statement.value, &mut json["Definition"]["value"]),
+            Assign(statement) => {
+                let json = &mut json["Assign"];
+                for (index, access) in statement.assignee.accesses.iter().enumerate() {
+                    let json = &mut json["assignee"]["accesses"][index];
+                    match access {
+                        leo_ast::AssigneeAccess::ArrayRange(left, right) => {
+                            self.annotate_optional(left.as_ref(), &mut json["ArrayRange"][0]);
+                            self.annotate_optional(right.as_ref(), &mut json["ArrayRange"][1]);
+                        }
+                        leo_ast::AssigneeAccess::ArrayIndex(index) => {
+                            self.annotate_expression(index, &mut json["ArrayIndex"])
+                        }
+                        leo_ast::AssigneeAccess::Tuple(..) | leo_ast::AssigneeAccess::Member(_) => (),
+                    }
+                }
+                self.annotate_expression(&statement.value, &mut json["value"]);
+            }
+            Conditional(statement) => {
+                let json = &mut json["Conditional"];
+                self.annotate_expression(&statement.condition, &mut json["condition"]);
+                self.annotate_block(&statement.block, &mut json["block"]);
+                if let Some(next) = &statement.next {
+                    self.annotate_statement(next, &mut json["next"]);
+                }
+            }
+            Iteration(statement) => {
+                let json = &mut json["Iteration"];
+                self.annotate_expression(&statement.start, &mut json["start"]);
+                self.annotate_expression(&statement.stop, &mut json["stop"]);
+                self.annotate_optional(statement.step.as_ref(), &mut json["step"]);
+                self.annotate_block(&statement.block, &mut json["block"]);
+            }
+            Console(statement) => {
+                use leo_ast::ConsoleFunction::*;
+
+                let json = &mut json["Console"]["function"];
+                match &statement.function {
+                    Assert(expression) => self.annotate_expression(expression, &mut json["Assert"]),
+                    AssertEq(left, right) => {
+                        self.annotate_expression(left, &mut json["AssertEq"][0]);
+                        self.annotate_expression(right, &mut json["AssertEq"][1]);
+                    }
+                    AssertNeq(left, right) => {
+                        self.annotate_expression(left, &mut json["AssertNeq"][0]);
+                        self.annotate_expression(right, &mut json["AssertNeq"][1]);
+                    }
+                    Debug(string) => self.annotate_expressions(&string.parameters, &mut json["Debug"]["parameters"]),
+                    Error(string) => self.annotate_expressions(&string.parameters, &mut json["Error"]["parameters"]),
+                    Log(string) => self.annotate_expressions(&string.parameters, &mut json["Log"]["parameters"]),
+                }
+            }
+            Require(statement) => {
+                let json = &mut json["Require"];
+                self.annotate_expression(&statement.condition, &mut json["condition"]);
+                self.annotate_expressions(&statement.message.parameters, &mut json["message"]["parameters"]);
+            }
+            ConstAssert(statement) => {
+                self.annotate_expression(&statement.condition, &mut json["ConstAssert"]["condition"])
+            }
+            Expression(statement) => {
+                self.annotate_expression(&statement.expression, &mut json["Expression"]["expression"])
+            }
+            Block(block) => self.annotate_block(block, &mut json["Block"]),
+        }
+    }
+
+    fn annotate_expressions(&self, expressions: &[leo_ast::Expression], json: &mut Value) {
+        for (index, expression) in expressions.iter().enumerate() {
+            self.annotate_expression(expression, &mut json[index]);
+        }
+    }
+
+    fn annotate_optional(&self, expression: Option<&leo_ast::Expression>, json: &mut Value) {
+        if let Some(expression) = expression {
+            self.annotate_expression(expression, json);
+        }
+    }
+
+    fn expression_type(&self, expression: &leo_ast::Expression) -> Value {
+        self.get_expression(expression)
+            .and_then(|expression| expression.get_type())
+            .map(|type_| Value::String(type_.to_string()))
+            .unwrap_or(Value::Null)
+    }
+
+    fn annotate_expression(&self, expression: &leo_ast::Expression, json: &mut Value) {
+        use leo_ast::Expression::*;
+
+        json["ty"] = self.expression_type(expression);
+
+        match expression {
+            Identifier(_) | Value(_) => (),
+            Binary(expression) => {
+                let json = &mut json["Binary"];
+                self.annotate_expression(&expression.left, &mut json["left"]);
+                self.annotate_expression(&expression.right, &mut json["right"]);
+            }
+            Unary(expression) => self.annotate_expression(&expression.inner, &mut json["Unary"]["inner"]),
+            Ternary(expression) => {
+                let json = &mut json["Ternary"];
+                self.annotate_expression(&expression.condition, &mut json["condition"]);
+                self.annotate_expression(&expression.if_true, &mut json["if_true"]);
+                self.annotate_expression(&expression.if_false, &mut json["if_false"]);
+            }
+            Cast(expression) => self.annotate_expression(&expression.inner, &mut json["Cast"]["inner"]),
+            ArrayInline(expression) => {
+                let json = &mut json["ArrayInline"]["elements"];
+                for (index, element) in expression.elements.iter().enumerate() {
+                    match element {
+                        leo_ast::SpreadOrExpression::Spread(element) => {
+                            self.annotate_expression(element, &mut json[index]["Spread"])
+                        }
+                        leo_ast::SpreadOrExpression::Expression(element) => {
+                            self.annotate_expression(element, &mut json[index]["Expression"])
+                        }
+                    }
+                }
+            }
+            ArrayInit(expression) => self.annotate_expression(&expression.element, &mut json["ArrayInit"]["element"]),
+            ArrayComprehension(expression) => {
+                let json = &mut json["ArrayComprehension"];
+                self.annotate_expression(&expression.element, &mut json["element"]);
+                self.annotate_expression(&expression.start, &mut json["start"]);
+                self.annotate_expression(&expression.stop, &mut json["stop"]);
+            }
+            ArrayAccess(expression) => {
+                let json = &mut json["ArrayAccess"];
+                self.annotate_expression(&expression.array, &mut json["array"]);
+                self.annotate_expression(&expression.index, &mut json["index"]);
+            }
+            ArrayRangeAccess(expression) => {
+                let json = &mut json["ArrayRangeAccess"];
+                self.annotate_expression(&expression.array, &mut json["array"]);
+                self.annotate_optional(expression.left.as_deref(), &mut json["left"]);
+                self.annotate_optional(expression.right.as_deref(), &mut json["right"]);
+            }
+            TupleInit(expression) => {
+                self.annotate_expressions(&expression.elements, &mut json["TupleInit"]["elements"])
+            }
+            TupleAccess(expression) => self.annotate_expression(&expression.tuple, &mut json["TupleAccess"]["tuple"]),
+            CircuitInit(expression) => {
+                let json = &mut json["CircuitInit"];
+                self.annotate_optional(expression.base.as_deref(), &mut json["base"]);
+                for (index, member) in expression.members.iter().enumerate() {
+                    self.annotate_optional(member.expression.as_ref(), &mut json["members"][index]["expression"]);
+                }
+            }
+            CircuitMemberAccess(expression) => {
+                self.annotate_expression(&expression.circuit, &mut json["CircuitMemberAccess"]["circuit"])
+            }
+            CircuitStaticFunctionAccess(expression) => {
+                self.annotate_expression(&expression.circuit, &mut json["CircuitStaticFunctionAccess"]["circuit"])
+            }
+            Call(expression) => {
+                let json = &mut json["Call"];
+                self.annotate_expression(&expression.function, &mut json["function"]);
+                self.annotate_expressions(&expression.arguments, &mut json["arguments"]);
+            }
+            Lambda(expression) => self.annotate_expression(&expression.body, &mut json["Lambda"]["body"]),
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for SourceMap<'a> {
+    fn visit_expression(&mut self, input: &Cell<&'a Expression<'a>>) -> VisitResult {
+        let expression = input.get();
+        if let Some(span) = expression.span() {
+            self.expressions.insert(span.clone(), expression);
+        }
+        VisitResult::VisitChildren
+    }
+}
+
+impl<'a> StatementVisitor<'a> for SourceMap<'a> {}
+
+impl<'a> ProgramVisitor<'a> for SourceMap<'a> {}
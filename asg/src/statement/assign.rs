@@ -143,6 +143,9 @@ impl<'a> FromAst<'a, leo_ast::AssignStatement> for &'a Statement<'a> {
                                         &statement.span,
                                     ));
                                 }
+                            } else {
+                                // The length of a range with runtime bounds is checked once the bounds are known.
+                                target_type = Some(PartialType::Array(item.clone(), None))
                             }
                         }
                         _ => return Err(AsgConvertError::index_into_non_array(&name, &statement.span)),
@@ -151,15 +154,26 @@ impl<'a> FromAst<'a, leo_ast::AssignStatement> for &'a Statement<'a> {
                     AssignAccess::ArrayRange(Cell::new(left), Cell::new(right))
                 }
                 AstAssigneeAccess::ArrayIndex(index) => {
-                    target_type = match target_type.clone() {
-                        Some(PartialType::Array(item, _)) => item.map(|x| *x),
-                        _ => return Err(AsgConvertError::index_into_non_array(&name, &statement.span)),
-                    };
-                    AssignAccess::ArrayIndex(Cell::new(<&Expression<'a>>::from_ast(
+                    let index = <&Expression<'a>>::from_ast(
                         scope,
                         index,
                         Some(PartialType::Integer(None, Some(IntegerType::U32))),
-                    )?))
+                    )?;
+                    target_type = match target_type.clone() {
+                        Some(PartialType::Array(item, len)) => {
+                            if let (Some(len), Some(ConstValue::Int(value))) = (len, index.const_value()) {
+                                let value = value.to_usize().ok_or_else(|| {
+                                    AsgConvertError::invalid_assign_index(&name, &value.to_string(), &statement.span)
+                                })?;
+                                if value >= len {
+                                    return Err(AsgConvertError::array_index_out_of_bounds(value, &statement.span));
+                                }
+                            }
+                            item.map(|x| *x)
+                        }
+                        _ => return Err(AsgConvertError::index_into_non_array(&name, &statement.span)),
+                    };
+                    AssignAccess::ArrayIndex(Cell::new(index))
                 }
                 AstAssigneeAccess::Tuple(index, _) => {
                     let index = index
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, Expression, FromAst, Node, PartialType, Scope, Span, Statement, Type};
+use crate::{AsgConvertError, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Statement, Type};
 use leo_ast::{ConsoleFunction as AstConsoleFunction, FormatStringPart};
 
 use std::cell::Cell;
@@ -30,6 +30,8 @@ pub struct FormatString<'a> {
 #[derive(Clone)]
 pub enum ConsoleFunction<'a> {
     Assert(Cell<&'a Expression<'a>>),
+    AssertEq(Cell<&'a Expression<'a>>, Cell<&'a Expression<'a>>),
+    AssertNeq(Cell<&'a Expression<'a>>, Cell<&'a Expression<'a>>),
     Debug(FormatString<'a>),
     Error(FormatString<'a>),
     Log(FormatString<'a>),
@@ -89,6 +91,51 @@ impl<'a> Into<leo_ast::FormatString> for &FormatString<'a> {
     }
 }
 
+/// Converts both operands of an `assert_eq` or `assert_neq`, inferring the type of one side from the other.
+fn operands_from_ast<'a>(
+    scope: &'a Scope<'a>,
+    left: &leo_ast::Expression,
+    right: &leo_ast::Expression,
+    span: &Span,
+) -> Result<(Cell<&'a Expression<'a>>, Cell<&'a Expression<'a>>), AsgConvertError> {
+    let (left_ast, right_ast) = (left, right);
+    let (left, right) = match <&Expression<'a>>::from_ast(scope, left_ast, None) {
+        Ok(left) => {
+            let right = <&Expression<'a>>::from_ast(scope, right_ast, left.get_type().map(Type::partial))?;
+            (left, right)
+        }
+        Err(e) => {
+            let right = <&Expression<'a>>::from_ast(scope, right_ast, None)?;
+            if let Some(right_type) = right.get_type() {
+                (
+                    <&Expression<'a>>::from_ast(scope, left_ast, Some(right_type.partial()))?,
+                    right,
+                )
+            } else {
+                return Err(e);
+            }
+        }
+    };
+
+    match (left.get_type(), right.get_type()) {
+        (Some(left_type), Some(right_type)) => {
+            if !left_type.is_assignable_from(&right_type) {
+                return Err(AsgConvertError::unexpected_type(
+                    &left_type.to_string(),
+                    Some(&*right_type.to_string()),
+                    span,
+                ));
+            }
+        }
+        (None, None) => {
+            return Err(AsgConvertError::unexpected_type("any type", Some("unknown type"), span));
+        }
+        (_, _) => (),
+    }
+
+    Ok((Cell::new(left), Cell::new(right)))
+}
+
 impl<'a> FromAst<'a, leo_ast::ConsoleStatement> for ConsoleStatement<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
@@ -102,6 +149,14 @@ impl<'a> FromAst<'a, leo_ast::ConsoleStatement> for ConsoleStatement<'a> {
                 AstConsoleFunction::Assert(expression) => ConsoleFunction::Assert(Cell::new(
                     <&Expression<'a>>::from_ast(scope, expression, Some(Type::Boolean.into()))?,
                 )),
+                AstConsoleFunction::AssertEq(left, right) => {
+                    let (left, right) = operands_from_ast(scope, left, right, &statement.span)?;
+                    ConsoleFunction::AssertEq(left, right)
+                }
+                AstConsoleFunction::AssertNeq(left, right) => {
+                    let (left, right) = operands_from_ast(scope, left, right, &statement.span)?;
+                    ConsoleFunction::AssertNeq(left, right)
+                }
                 AstConsoleFunction::Debug(formatted_string) => {
                     ConsoleFunction::Debug(FormatString::from_ast(scope, formatted_string, None)?)
                 }
@@ -122,6 +177,8 @@ impl<'a> Into<leo_ast::ConsoleStatement> for &ConsoleStatement<'a> {
         leo_ast::ConsoleStatement {
             function: match &self.function {
                 Assert(e) => AstConsoleFunction::Assert(e.get().into()),
+                AssertEq(left, right) => AstConsoleFunction::AssertEq(left.get().into(), right.get().into()),
+                AssertNeq(left, right) => AstConsoleFunction::AssertNeq(left.get().into(), right.get().into()),
                 Debug(formatted_string) => AstConsoleFunction::Debug(formatted_string.into()),
                 Error(formatted_string) => AstConsoleFunction::Error(formatted_string.into()),
                 Log(formatted_string) => AstConsoleFunction::Log(formatted_string.into()),
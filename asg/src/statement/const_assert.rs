@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgConvertError,
+    ConstValue,
+    Expression,
+    ExpressionNode,
+    FromAst,
+    Node,
+    PartialType,
+    Scope,
+    Span,
+    Statement,
+    Type,
+};
+
+use std::cell::Cell;
+
+#[derive(Clone)]
+pub struct ConstAssertStatement<'a> {
+    pub parent: Cell<Option<&'a Statement<'a>>>,
+    pub span: Option<Span>,
+    pub condition: Cell<&'a Expression<'a>>,
+}
+
+impl<'a> Node for ConstAssertStatement<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> FromAst<'a, leo_ast::ConstAssertStatement> for ConstAssertStatement<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        statement: &leo_ast::ConstAssertStatement,
+        _expected_type: Option<PartialType<'a>>,
+    ) -> Result<Self, AsgConvertError> {
+        let condition = <&Expression<'a>>::from_ast(scope, &statement.condition, Some(Type::Boolean.into()))?;
+
+        if !condition.is_consty() {
+            return Err(AsgConvertError::const_assert_nonconst(&statement.span));
+        }
+
+        // Conditions depending on loop variables are only known once the loop is unrolled.
+        if let Some(ConstValue::Boolean(false)) = condition.const_value() {
+            return Err(AsgConvertError::const_assert_failed(
+                &statement.condition.to_string(),
+                &statement.span,
+            ));
+        }
+
+        Ok(ConstAssertStatement {
+            parent: Cell::new(None),
+            span: Some(statement.span.clone()),
+            condition: Cell::new(condition),
+        })
+    }
+}
+
+impl<'a> Into<leo_ast::ConstAssertStatement> for &ConstAssertStatement<'a> {
+    fn into(self) -> leo_ast::ConstAssertStatement {
+        leo_ast::ConstAssertStatement {
+            condition: self.condition.get().into(),
+            span: self.span.clone().unwrap_or_default(),
+        }
+    }
+}
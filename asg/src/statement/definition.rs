@@ -45,6 +45,39 @@ impl<'a> Node for DefinitionStatement<'a> {
     }
 }
 
+///
+/// Describes the type of a definition value for an error message. Each element of a tuple is
+/// converted against the annotated element type where there is one, so that implicitly typed
+/// integers resolve.
+///
+fn describe_value_type<'a>(scope: &'a Scope<'a>, value: &leo_ast::Expression, annotation: &Type<'a>) -> Option<String> {
+    match (value, annotation) {
+        (leo_ast::Expression::TupleInit(tuple), Type::Tuple(sub_types)) => {
+            let elements = tuple
+                .elements
+                .iter()
+                .enumerate()
+                .map(|(i, element)| {
+                    match sub_types.get(i) {
+                        Some(sub_type) => describe_value_type(scope, element, sub_type),
+                        None => <&Expression<'a>>::from_ast(scope, element, None)
+                            .ok()
+                            .and_then(|element| element.get_type())
+                            .map(|type_| type_.to_string()),
+                    }
+                    .unwrap_or_else(|| "unknown".to_string())
+                })
+                .collect::<Vec<_>>();
+            Some(format!("({})", elements.join(", ")))
+        }
+        _ => <&Expression<'a>>::from_ast(scope, value, Some(annotation.clone().into()))
+            .or_else(|_| <&Expression<'a>>::from_ast(scope, value, None))
+            .ok()?
+            .get_type()
+            .map(|type_| type_.to_string()),
+    }
+}
+
 impl<'a> FromAst<'a, leo_ast::DefinitionStatement> for &'a Statement<'a> {
     fn from_ast(
         scope: &'a Scope<'a>,
@@ -57,7 +90,37 @@ impl<'a> FromAst<'a, leo_ast::DefinitionStatement> for &'a Statement<'a> {
             .map(|x| scope.resolve_ast_type(&x))
             .transpose()?;
 
-        let value = <&Expression<'a>>::from_ast(scope, &statement.value, type_.clone().map(Into::into))?;
+        let value = match <&Expression<'a>>::from_ast(scope, &statement.value, type_.clone().map(Into::into)) {
+            Ok(value) => value,
+            // Report both types when a tuple annotation does not match its value.
+            Err(error) => {
+                return Err(match &type_ {
+                    Some(annotation @ Type::Tuple(_)) => {
+                        match describe_value_type(scope, &statement.value, annotation) {
+                            Some(received) if received != annotation.to_string() => {
+                                AsgConvertError::unexpected_definition_type(
+                                    &annotation.to_string(),
+                                    &received,
+                                    &statement.span,
+                                )
+                            }
+                            _ => error,
+                        }
+                    }
+                    _ => error,
+                });
+            }
+        };
+
+        if let (Some(annotation @ Type::Tuple(_)), Some(received)) = (&type_, value.get_type()) {
+            if !annotation.is_assignable_from(&received) {
+                return Err(AsgConvertError::unexpected_definition_type(
+                    &annotation.to_string(),
+                    &received.to_string(),
+                    &statement.span,
+                ));
+            }
+        }
 
         let type_ = type_.or_else(|| value.get_type());
 
@@ -99,6 +162,7 @@ impl<'a> FromAst<'a, leo_ast::DefinitionStatement> for &'a Statement<'a> {
                 declaration: crate::VariableDeclaration::Definition,
                 references: vec![],
                 assignments: vec![],
+                referenced_in_loop: false,
             })));
         }
 
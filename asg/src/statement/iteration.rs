@@ -39,6 +39,8 @@ pub struct IterationStatement<'a> {
     pub variable: &'a Variable<'a>,
     pub start: Cell<&'a Expression<'a>>,
     pub stop: Cell<&'a Expression<'a>>,
+    pub inclusive: bool,
+    pub step: Cell<Option<&'a Expression<'a>>>,
     pub body: Cell<&'a Statement<'a>>,
 }
 
@@ -56,20 +58,40 @@ impl<'a> FromAst<'a, leo_ast::IterationStatement> for &'a Statement<'a> {
     ) -> Result<Self, AsgConvertError> {
         let expected_index_type = Some(PartialType::Integer(Some(IntegerType::U32), None));
         let start = <&Expression<'a>>::from_ast(scope, &statement.start, expected_index_type.clone())?;
-        let stop = <&Expression<'a>>::from_ast(scope, &statement.stop, expected_index_type)?;
+        let stop = <&Expression<'a>>::from_ast(scope, &statement.stop, expected_index_type.clone())?;
+        let step = statement
+            .step
+            .as_ref()
+            .map(|step| <&Expression<'a>>::from_ast(scope, step, expected_index_type))
+            .transpose()?;
 
         // Return an error if start or stop is not constant.
         if !start.is_consty() {
-            return Err(AsgConvertError::unexpected_nonconst(
+            return Err(AsgConvertError::loop_bound_nonconst(
+                "start",
                 &start.span().cloned().unwrap_or_default(),
             ));
         }
         if !stop.is_consty() {
-            return Err(AsgConvertError::unexpected_nonconst(
+            return Err(AsgConvertError::loop_bound_nonconst(
+                "stop",
                 &stop.span().cloned().unwrap_or_default(),
             ));
         }
 
+        // Return an error if the step is not a positive constant.
+        if let Some(step) = step {
+            let step_span = step.span().cloned().unwrap_or_default();
+            if !step.is_consty() {
+                return Err(AsgConvertError::loop_bound_nonconst("step", &step_span));
+            }
+            if let Some(step_int) = step.const_value().as_ref().and_then(|value| value.int()) {
+                if step_int.to_usize() == Some(0) {
+                    return Err(AsgConvertError::invalid_loop_step(&step_int.raw_value(), &step_span));
+                }
+            }
+        }
+
         let variable = scope.context.alloc_variable(RefCell::new(InnerVariable {
             id: scope.context.get_id(),
             name: statement.variable.clone(),
@@ -81,23 +103,29 @@ impl<'a> FromAst<'a, leo_ast::IterationStatement> for &'a Statement<'a> {
             declaration: crate::VariableDeclaration::IterationDefinition,
             references: vec![],
             assignments: vec![],
+            referenced_in_loop: false,
         }));
         scope
             .variables
             .borrow_mut()
             .insert(statement.variable.name.to_string(), variable);
 
+        let body_scope = scope.make_subscope();
+        body_scope.in_loop.set(true);
+
         let statement = scope.context.alloc_statement(Statement::Iteration(IterationStatement {
             parent: Cell::new(None),
             span: Some(statement.span.clone()),
             variable,
             stop: Cell::new(stop),
             start: Cell::new(start),
+            inclusive: statement.inclusive,
+            step: Cell::new(step),
             body: Cell::new(
                 scope
                     .context
                     .alloc_statement(Statement::Block(crate::BlockStatement::from_ast(
-                        scope,
+                        body_scope,
                         &statement.block,
                         None,
                     )?)),
@@ -114,6 +142,8 @@ impl<'a> Into<leo_ast::IterationStatement> for &IterationStatement<'a> {
             variable: self.variable.borrow().name.clone(),
             start: self.start.get().into(),
             stop: self.stop.get().into(),
+            inclusive: self.inclusive,
+            step: self.step.get().map(|step| step.into()),
             block: match self.body.get() {
                 Statement::Block(block) => block.into(),
                 _ => unimplemented!(),
@@ -24,12 +24,21 @@ pub use assign::*;
 mod block;
 pub use block::*;
 
+mod break_;
+pub use break_::*;
+
 mod conditional;
 pub use conditional::*;
 
+mod const_assert;
+pub use const_assert::*;
+
 mod console;
 pub use console::*;
 
+mod continue_;
+pub use continue_::*;
+
 mod definition;
 pub use definition::*;
 
@@ -39,6 +48,9 @@ pub use expression::*;
 mod iteration;
 pub use iteration::*;
 
+mod require;
+pub use require::*;
+
 mod return_;
 pub use return_::*;
 
@@ -52,8 +64,12 @@ pub enum Statement<'a> {
     Conditional(ConditionalStatement<'a>),
     Iteration(IterationStatement<'a>),
     Console(ConsoleStatement<'a>),
+    Require(RequireStatement<'a>),
+    ConstAssert(ConstAssertStatement<'a>),
     Expression(ExpressionStatement<'a>),
     Block(BlockStatement<'a>),
+    Break(BreakStatement<'a>),
+    Continue(ContinueStatement<'a>),
     Empty(Option<Span>),
 }
 
@@ -67,8 +83,12 @@ impl<'a> Node for Statement<'a> {
             Conditional(s) => s.span(),
             Iteration(s) => s.span(),
             Console(s) => s.span(),
+            Require(s) => s.span(),
+            ConstAssert(s) => s.span(),
             Expression(s) => s.span(),
             Block(s) => s.span(),
+            Break(s) => s.span(),
+            Continue(s) => s.span(),
             Empty(s) => s.as_ref(),
         }
     }
@@ -98,6 +118,16 @@ impl<'a> FromAst<'a, leo_ast::Statement> for &'a Statement<'a> {
             Console(statement) => scope
                 .context
                 .alloc_statement(Statement::Console(ConsoleStatement::from_ast(scope, statement, None)?)),
+            Require(statement) => scope
+                .context
+                .alloc_statement(Statement::Require(RequireStatement::from_ast(scope, statement, None)?)),
+            ConstAssert(statement) => {
+                scope
+                    .context
+                    .alloc_statement(Statement::ConstAssert(ConstAssertStatement::from_ast(
+                        scope, statement, None,
+                    )?))
+            }
             Expression(statement) => {
                 scope
                     .context
@@ -108,6 +138,14 @@ impl<'a> FromAst<'a, leo_ast::Statement> for &'a Statement<'a> {
             Block(statement) => scope
                 .context
                 .alloc_statement(Statement::Block(BlockStatement::from_ast(scope, statement, None)?)),
+            Break(statement) => scope
+                .context
+                .alloc_statement(Statement::Break(BreakStatement::from_ast(scope, statement, None)?)),
+            Continue(statement) => scope
+                .context
+                .alloc_statement(Statement::Continue(ContinueStatement::from_ast(
+                    scope, statement, None,
+                )?)),
         })
     }
 }
@@ -122,8 +160,12 @@ impl<'a> Into<leo_ast::Statement> for &Statement<'a> {
             Conditional(statement) => leo_ast::Statement::Conditional(statement.into()),
             Iteration(statement) => leo_ast::Statement::Iteration(statement.into()),
             Console(statement) => leo_ast::Statement::Console(statement.into()),
+            Require(statement) => leo_ast::Statement::Require(statement.into()),
+            ConstAssert(statement) => leo_ast::Statement::ConstAssert(statement.into()),
             Expression(statement) => leo_ast::Statement::Expression(statement.into()),
             Block(statement) => leo_ast::Statement::Block(statement.into()),
+            Break(statement) => leo_ast::Statement::Break(statement.into()),
+            Continue(statement) => leo_ast::Statement::Continue(statement.into()),
             Empty(_) => unimplemented!(),
         }
     }
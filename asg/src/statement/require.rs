@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AsgConvertError, Expression, FormatString, FromAst, Node, PartialType, Scope, Span, Statement, Type};
+
+use std::cell::Cell;
+
+#[derive(Clone)]
+pub struct RequireStatement<'a> {
+    pub parent: Cell<Option<&'a Statement<'a>>>,
+    pub span: Option<Span>,
+    pub condition: Cell<&'a Expression<'a>>,
+    pub message: FormatString<'a>,
+}
+
+impl<'a> Node for RequireStatement<'a> {
+    fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl<'a> FromAst<'a, leo_ast::RequireStatement> for RequireStatement<'a> {
+    fn from_ast(
+        scope: &'a Scope<'a>,
+        statement: &leo_ast::RequireStatement,
+        _expected_type: Option<PartialType<'a>>,
+    ) -> Result<Self, AsgConvertError> {
+        Ok(RequireStatement {
+            parent: Cell::new(None),
+            span: Some(statement.span.clone()),
+            condition: Cell::new(<&Expression<'a>>::from_ast(
+                scope,
+                &statement.condition,
+                Some(Type::Boolean.into()),
+            )?),
+            message: FormatString::from_ast(scope, &statement.message, None)?,
+        })
+    }
+}
+
+impl<'a> Into<leo_ast::RequireStatement> for &RequireStatement<'a> {
+    fn into(self) -> leo_ast::RequireStatement {
+        leo_ast::RequireStatement {
+            condition: self.condition.get().into(),
+            message: (&self.message).into(),
+            span: self.span.clone().unwrap_or_default(),
+        }
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AsgConvertError, Expression, FromAst, Node, PartialType, Scope, Span, Statement, Type};
+use crate::{AsgConvertError, Expression, ExpressionNode, FromAst, Node, PartialType, Scope, Span, Statement, Type};
 
 use std::cell::Cell;
 #[derive(Clone)]
@@ -40,14 +40,28 @@ impl<'a> FromAst<'a, leo_ast::ReturnStatement> for ReturnStatement<'a> {
             .resolve_current_function()
             .map(|x| x.output.clone())
             .map(Into::into);
+        let expression = <&Expression<'a>>::from_ast(scope, &statement.expression, return_type.clone().map(Into::into))
+            .map_err(|e| {
+                // Report a branch returning a value of another type against the declared output.
+                let found = <&Expression<'a>>::from_ast(scope, &statement.expression, None)
+                    .ok()
+                    .and_then(|expression| expression.get_type());
+                match (&return_type, found) {
+                    (Some(return_type), Some(found)) if !return_type.is_assignable_from(&found) => {
+                        AsgConvertError::return_type_mismatch(
+                            &return_type.to_string(),
+                            &found.to_string(),
+                            &statement.span,
+                        )
+                    }
+                    _ => e,
+                }
+            })?;
+
         Ok(ReturnStatement {
             parent: Cell::new(None),
             span: Some(statement.span.clone()),
-            expression: Cell::new(<&Expression<'a>>::from_ast(
-                scope,
-                &statement.expression,
-                return_type.map(Into::into),
-            )?),
+            expression: Cell::new(expression),
         })
     }
 }
@@ -39,6 +39,7 @@ pub struct InnerVariable<'a> {
     pub declaration: VariableDeclaration,
     pub references: Vec<&'a Expression<'a>>, // all Expression::VariableRef or panic
     pub assignments: Vec<&'a Statement<'a>>, // all Statement::Assign or panic -- must be 1 if not mutable, or 0 if declaration == input | parameter
+    pub referenced_in_loop: bool, // referenced inside a loop that does not enclose the declaration, so later reassignments may reach the reference
 }
 
 pub type Variable<'a> = RefCell<InnerVariable<'a>>;
@@ -34,6 +34,16 @@ fn test_multiple_returns_fail_conditional() {
     load_asg(program_string).err().unwrap();
 }
 
+#[test]
+fn test_multiple_returns_type_mismatch() {
+    let program_string = include_str!("multiple_returns_type_mismatch.leo");
+    let error = load_asg(program_string).err().unwrap();
+
+    assert!(error
+        .to_string()
+        .contains("function is declared to return 'u8', but this branch returns 'bool'"));
+}
+
 #[test]
 fn test_scope_fail() {
     let program_string = include_str!("scope_fail.leo");
@@ -65,3 +75,29 @@ fn test_return_array_tuple_fail() {
     let program_string = include_str!("return_array_tuple_fail.leo");
     load_asg(program_string).err().unwrap();
 }
+
+// Recursion
+
+#[test]
+fn test_recursion() {
+    let program_string = include_str!("recursion.leo");
+    let error = load_asg(program_string).err().unwrap();
+
+    assert!(error.to_string().contains("'factorial -> factorial'"));
+}
+
+#[test]
+fn test_mutual_recursion() {
+    let program_string = include_str!("mutual_recursion.leo");
+    let error = load_asg(program_string).err().unwrap();
+
+    assert!(error.to_string().contains("'even -> odd -> even'"));
+}
+
+#[test]
+fn test_circuit_recursion() {
+    let program_string = include_str!("circuit_recursion.leo");
+    let error = load_asg(program_string).err().unwrap();
+
+    assert!(error.to_string().contains("'Counter::count -> Counter::count'"));
+}
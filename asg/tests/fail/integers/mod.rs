@@ -35,3 +35,91 @@ pub mod i16;
 pub mod i32;
 pub mod i64;
 pub mod i8;
+
+use crate::load_asg;
+
+#[test]
+fn test_const_overflow() {
+    let program_string = include_str!("const_overflow.leo");
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_const_division_by_zero() {
+    let program_string = include_str!("const_division_by_zero.leo");
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_unknown_integer_constant() {
+    let program_string = include_str!("unknown_bound.leo");
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_integer_constant_type_mismatch() {
+    let program_string = include_str!("bound_type_mismatch.leo");
+    load_asg(program_string).err().unwrap();
+}
+
+#[test]
+fn test_u8_literal_out_of_range() {
+    let program_string = include_str!("u8_literal_out_of_range.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("integer literal '256' is out of range for type 'u8'"));
+    assert!(error.contains("between 0 and 255"));
+}
+
+#[test]
+fn test_i16_literal_out_of_range() {
+    let program_string = include_str!("i16_literal_out_of_range.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("integer literal '-32769' is out of range for type 'i16'"));
+    assert!(error.contains("between -32768 and 32767"));
+}
+
+#[test]
+fn test_u64_literal_out_of_range() {
+    let program_string = include_str!("u64_literal_out_of_range.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("out of range for type 'u64'"));
+    assert!(error.contains("between 0 and 18446744073709551615"));
+}
+
+#[test]
+fn test_i128_literal_out_of_range() {
+    let program_string = include_str!("i128_literal_out_of_range.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("out of range for type 'i128'"));
+    assert!(
+        error.contains("between -170141183460469231731687303715884105728 and 170141183460469231731687303715884105727")
+    );
+}
+
+#[test]
+fn test_implicit_literal_out_of_range() {
+    let program_string = include_str!("implicit_literal_out_of_range.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("integer literal '4294967296' is out of range for type 'u32'"));
+}
+
+#[test]
+fn test_mixed_signedness_comparison_literal() {
+    let program_string = include_str!("mixed_signedness_literal.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("cannot compare 'u8' < 'i8'"));
+}
+
+#[test]
+fn test_mixed_signedness_comparison_variable() {
+    let program_string = include_str!("mixed_signedness_variable.leo");
+    let error = load_asg(program_string).err().unwrap().to_string();
+
+    assert!(error.contains("cannot compare 'u32' >= 'i32'"));
+}
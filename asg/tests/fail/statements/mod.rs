@@ -27,3 +27,10 @@ fn test_let_mut_declaration_fail() {
     let program_string = include_str!("let_mut_declaration_fail.leo");
     load_asg(program_string).err().unwrap();
 }
+
+#[test]
+fn test_iteration_nonconst_bound_fail() {
+    let program_string = include_str!("iteration_nonconst_bound_fail.leo");
+    let error = load_asg(program_string).err().unwrap();
+    assert!(error.to_string().contains("non-constant stop bound"));
+}
@@ -13,3 +13,21 @@
 
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+
+#[test]
+fn test_annotated_arity_fail() {
+    let program_string = include_str!("annotated_arity_fail.leo");
+    let error = load_asg(program_string).err().unwrap();
+    assert!(error.to_string().contains("'(u8, bool)'"));
+    assert!(error.to_string().contains("'(u8, bool, u8)'"));
+}
+
+#[test]
+fn test_annotated_type_fail() {
+    let program_string = include_str!("annotated_type_fail.leo");
+    let error = load_asg(program_string).err().unwrap();
+    assert!(error.to_string().contains("'(u8, bool)'"));
+    assert!(error.to_string().contains("'(u8, u8)'"));
+}
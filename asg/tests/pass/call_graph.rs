@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::load_asg;
+use leo_asg::*;
+
+#[test]
+fn test_call_graph_edges() {
+    let program_string = r#"
+    circuit Foo {
+        x: u32,
+
+        function double(self) -> u32 {
+            return add(self.x, self.x);
+        }
+    }
+
+    function add(a: u32, b: u32) -> u32 {
+        return a + b;
+    }
+
+    function main() {
+        let foo = Foo { x: 1u32 };
+        console.assert(foo.double() == add(1u32, 1u32));
+    }
+    "#;
+    let asg = load_asg(program_string).unwrap();
+    let graph = CallGraph::new(&asg);
+
+    let edges = graph
+        .edges()
+        .iter()
+        .map(|edge| (edge.caller.as_str(), edge.callee.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(edges, vec![
+        ("main", "Foo::double"),
+        ("Foo::double", "add"),
+        ("main", "add")
+    ]);
+    assert!(graph.edges().iter().all(|edge| !edge.recursive));
+
+    let dot = graph.to_dot();
+    assert!(dot.contains("\"main\" -> \"Foo::double\";"));
+    assert!(dot.contains("\"Foo::double\" -> \"add\";"));
+}
@@ -133,3 +133,9 @@ fn test_circuit_explicit_define() {
     "#;
     load_asg(program_string).unwrap();
 }
+
+#[test]
+fn test_operator_overloading_chain() {
+    let program_string = include_str!("operator_overloading_chain.leo");
+    load_asg(program_string).unwrap();
+}
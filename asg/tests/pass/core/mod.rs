@@ -33,3 +33,27 @@ fn test_blake2s_random() {
     let program_string = include_str!("blake2s_random.leo");
     load_asg(program_string).unwrap();
 }
+
+#[test]
+fn test_blake2s_digest() {
+    let program_string = include_str!("blake2s_digest.leo");
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_unstable_pedersen() {
+    let program_string = include_str!("unstable_pedersen.leo");
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_unstable_schnorr() {
+    let program_string = include_str!("unstable_schnorr.leo");
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_unstable_merkle() {
+    let program_string = include_str!("unstable_merkle.leo");
+    load_asg(program_string).unwrap();
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{make_test_context, TESTING_FILEPATH};
+use leo_asg::*;
+use leo_parser::parse_ast;
+
+#[test]
+fn test_declarations_in_declaration_order() {
+    let program_string = r#"
+    circuit Point {
+        x: u8,
+        y: u8,
+
+        function origin() -> Point {
+            return Point { x: 0, y: 0 };
+        }
+    }
+
+    circuit Line {
+        start: Point,
+        end: Point,
+    }
+
+    function length(line: Line) -> u8 {
+        return line.end.x - line.start.x;
+    }
+
+    function main() {
+        let line = Line { start: Point::origin(), end: Point { x: 2, y: 0 } };
+        let x = length(line);
+    }
+    "#;
+    let ast = parse_ast(&TESTING_FILEPATH, program_string).unwrap();
+    let asg = Program::new(make_test_context(), ast.as_repr(), &mut NullImportResolver).unwrap();
+
+    let declarations: Vec<_> = DeclarationCollector::collect(&asg)
+        .into_iter()
+        .map(|declaration| (declaration.kind, declaration.identifier.name.to_string()))
+        .collect();
+
+    assert_eq!(declarations, vec![
+        (DeclarationKind::Circuit, "Point".to_string()),
+        (DeclarationKind::Circuit, "Line".to_string()),
+        (DeclarationKind::Function, "length".to_string()),
+        (DeclarationKind::Function, "main".to_string()),
+    ]);
+}
+
+#[test]
+fn test_declaration_spans() {
+    let program_string = r#"
+    function main() {}
+    "#;
+    let ast = parse_ast(&TESTING_FILEPATH, program_string).unwrap();
+    let asg = Program::new(make_test_context(), ast.as_repr(), &mut NullImportResolver).unwrap();
+
+    let declarations = DeclarationCollector::collect(&asg);
+
+    assert_eq!(declarations.len(), 1);
+    assert_eq!(declarations[0].identifier.span.line_start, 2);
+}
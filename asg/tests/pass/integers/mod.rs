@@ -35,3 +35,17 @@ pub mod i16;
 pub mod i32;
 pub mod i64;
 pub mod i8;
+
+use crate::load_asg;
+
+#[test]
+fn test_const_arithmetic() {
+    let program_string = include_str!("const_arithmetic.leo");
+    load_asg(program_string).unwrap();
+}
+
+#[test]
+fn test_const_bounds() {
+    let program_string = include_str!("const_bounds.leo");
+    load_asg(program_string).unwrap();
+}
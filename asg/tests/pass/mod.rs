@@ -17,9 +17,11 @@
 pub mod address;
 pub mod array;
 pub mod boolean;
+pub mod call_graph;
 pub mod circuits;
 pub mod console;
 pub mod core;
+pub mod declarations;
 pub mod definition;
 pub mod field;
 pub mod form_ast;
@@ -29,5 +31,6 @@ pub mod import;
 pub mod input_files;
 pub mod integers;
 pub mod mutability;
+pub mod source_map;
 pub mod statements;
 pub mod tuples;
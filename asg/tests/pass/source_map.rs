@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{make_test_context, TESTING_FILEPATH};
+use leo_asg::*;
+use leo_parser::parse_ast;
+
+#[test]
+fn test_identifier_maps_to_inferred_type() {
+    let program_string = r#"
+    function main() {
+        let x = 1u8;
+        let y = x;
+    }
+    "#;
+    let ast = parse_ast(&TESTING_FILEPATH, program_string).unwrap();
+    let asg = Program::new(make_test_context(), ast.as_repr(), &mut NullImportResolver).unwrap();
+    let source_map = SourceMap::new(&asg);
+
+    let main = ast.as_repr().functions.values().next().unwrap();
+    let value = match &main.block.statements[1] {
+        leo_ast::Statement::Definition(definition) => &definition.value,
+        _ => panic!("expected a definition statement"),
+    };
+
+    let expression = source_map.get_expression(value).unwrap();
+    match expression {
+        Expression::VariableRef(variable_ref) => assert_eq!(&*variable_ref.variable.borrow().name.name, "x"),
+        _ => panic!("expected a variable reference"),
+    }
+    assert!(expression.get_type() == Some(Type::Integer(IntegerType::U8)));
+}
+
+#[test]
+fn test_typed_json_contains_literal_type() {
+    let program_string = r#"
+    function main() {
+        let x = 1u8;
+    }
+    "#;
+    let ast = parse_ast(&TESTING_FILEPATH, program_string).unwrap();
+    let asg = Program::new(make_test_context(), ast.as_repr(), &mut NullImportResolver).unwrap();
+
+    let json = SourceMap::new(&asg).to_typed_json_string(ast.as_repr()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let main = json["functions"].as_object().unwrap().values().next().unwrap();
+    let value = &main["block"]["statements"][0]["Definition"]["value"];
+
+    assert!(value.get("Value").is_some());
+    assert_eq!(value["ty"], "u8");
+}
+
+#[test]
+fn test_typed_json_annotates_nested_expressions() {
+    let program_string = r#"
+    function main(a: u8) {
+        if a > 1u8 {
+            console.assert(a + 1u8 == 3u8);
+        }
+    }
+    "#;
+    let ast = parse_ast(&TESTING_FILEPATH, program_string).unwrap();
+    let asg = Program::new(make_test_context(), ast.as_repr(), &mut NullImportResolver).unwrap();
+
+    let json = SourceMap::new(&asg).to_typed_json_string(ast.as_repr()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let main = json["functions"].as_object().unwrap().values().next().unwrap();
+    let conditional = &main["block"]["statements"][0]["Conditional"];
+    assert_eq!(conditional["condition"]["ty"], "bool");
+    assert_eq!(conditional["condition"]["Binary"]["left"]["ty"], "u8");
+
+    let assertion = &conditional["block"]["statements"][0]["Console"]["function"]["Assert"];
+    assert_eq!(assertion["ty"], "bool");
+    assert_eq!(assertion["Binary"]["left"]["ty"], "u8");
+    assert_eq!(assertion["Binary"]["left"]["Binary"]["right"]["ty"], "u8");
+
+    // Only expressions are annotated.
+    assert!(conditional.get("ty").is_none());
+    assert!(conditional["block"].get("ty").is_none());
+}
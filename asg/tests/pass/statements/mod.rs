@@ -39,3 +39,9 @@ fn test_block() {
     let program_string = include_str!("block.leo");
     load_asg(program_string).unwrap();
 }
+
+#[test]
+fn test_let_reassigned_in_loop() {
+    let program_string = include_str!("let_reassigned_in_loop.leo");
+    load_asg(program_string).unwrap();
+}
@@ -81,3 +81,9 @@ fn test_nested_typed() {
     let program_string = include_str!("nested_typed.leo");
     load_asg(program_string).unwrap();
 }
+
+#[test]
+fn test_annotated() {
+    let program_string = include_str!("annotated.leo");
+    load_asg(program_string).unwrap();
+}
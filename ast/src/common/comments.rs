@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexMap;
+use tendril::StrTendril;
+
+/// The comments of a Leo program, which are discarded from its AST.
+///
+/// Each comment is associated with the first token that follows it, keyed by the line that token
+/// starts on, so that it can be emitted ahead of the node beginning on that line.
+/// Comments at the end of a file that no token follows are kept as trailing comments.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Comments {
+    leading: IndexMap<usize, Vec<StrTendril>>,
+    trailing: Vec<StrTendril>,
+}
+
+impl Comments {
+    /// Adds a comment preceding the token that starts on the given line.
+    pub fn push_leading(&mut self, line: usize, comment: StrTendril) {
+        self.leading.entry(line).or_default().push(comment);
+    }
+
+    /// Adds a comment that no token follows.
+    pub fn push_trailing(&mut self, comment: StrTendril) {
+        self.trailing.push(comment);
+    }
+
+    /// Returns the comments preceding the node that starts on the given line.
+    pub fn leading(&self, line: usize) -> &[StrTendril] {
+        self.leading.get(&line).map(|comments| &comments[..]).unwrap_or(&[])
+    }
+
+    /// Returns the comments at the end of the file.
+    pub fn trailing(&self) -> &[StrTendril] {
+        &self.trailing
+    }
+
+    /// Returns the number of comments.
+    pub fn len(&self) -> usize {
+        self.leading.values().map(|comments| comments.len()).sum::<usize>() + self.trailing.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -17,6 +17,9 @@
 pub mod array_dimensions;
 pub use array_dimensions::*;
 
+pub mod comments;
+pub use comments::*;
+
 pub mod const_self_keyword;
 pub use const_self_keyword::*;
 
@@ -14,12 +14,59 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{fmt, sync::Arc};
+use std::{
+    cell::RefCell,
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use tendril::StrTendril;
 
-#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+/// How spans are written when an AST is serialized.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpanSerialization {
+    /// Spans are written as parsed, including the path of their file.
+    Full,
+    /// Paths under the given directory are written relative to it, other absolute paths as their file name.
+    Relative(PathBuf),
+    /// Every span is written as the default span, without position, path, or content.
+    Omitted,
+}
+
+impl Default for SpanSerialization {
+    fn default() -> Self {
+        SpanSerialization::Full
+    }
+}
+
+thread_local! {
+    static SPAN_SERIALIZATION: RefCell<SpanSerialization> = RefCell::new(SpanSerialization::Full);
+}
+
+///
+/// Runs `f` with spans serialized according to `mode` on the current thread.
+///
+pub fn with_span_serialization<T>(mode: SpanSerialization, f: impl FnOnce() -> T) -> T {
+    let previous = SPAN_SERIALIZATION.with(|current| current.replace(mode));
+    let result = f();
+    SPAN_SERIALIZATION.with(|current| current.replace(previous));
+    result
+}
+
+fn relative_path(path: &str, base: &Path) -> String {
+    let path = Path::new(path);
+    if let Ok(relative) = path.strip_prefix(base) {
+        relative.to_string_lossy().into_owned()
+    } else if path.is_absolute() {
+        path.file_name().unwrap_or_default().to_string_lossy().into_owned()
+    } else {
+        path.to_string_lossy().into_owned()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Hash)]
 pub struct Span {
     pub line_start: usize,
     pub line_stop: usize,
@@ -30,6 +77,47 @@ pub struct Span {
     pub content: StrTendril,
 }
 
+#[derive(Serialize)]
+struct SerializedSpan<'a> {
+    line_start: usize,
+    line_stop: usize,
+    col_start: usize,
+    col_stop: usize,
+    path: &'a str,
+    content: &'a str,
+}
+
+impl Serialize for Span {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let path = SPAN_SERIALIZATION.with(|mode| match &*mode.borrow() {
+            SpanSerialization::Full => Some(self.path.to_string()),
+            SpanSerialization::Relative(base) => Some(relative_path(&self.path, base)),
+            SpanSerialization::Omitted => None,
+        });
+
+        let span = match &path {
+            Some(path) => SerializedSpan {
+                line_start: self.line_start,
+                line_stop: self.line_stop,
+                col_start: self.col_start,
+                col_stop: self.col_stop,
+                path,
+                content: &self.content[..],
+            },
+            None => SerializedSpan {
+                line_start: 0,
+                line_stop: 0,
+                col_start: 0,
+                col_stop: 0,
+                path: "",
+                content: "",
+            },
+        };
+
+        span.serialize(serializer)
+    }
+}
+
 impl fmt::Display for Span {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.line_start == self.line_stop {
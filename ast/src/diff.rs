@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structural differences between two abstract syntax trees.
+
+use crate::Program;
+
+use serde_json::Value;
+use std::fmt;
+
+/// A subtree that differs between two abstract syntax trees.
+///
+/// A value of `None` means the subtree is absent from that tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AstDiff {
+    /// The path to the subtree from the root of the program, e.g. `functions.main.block.statements.0`.
+    pub path: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl fmt::Display for AstDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.path)?;
+        if let Some(before) = &self.before {
+            writeln!(f, "  - {}", before)?;
+        }
+        if let Some(after) = &self.after {
+            write!(f, "  + {}", after)?;
+        }
+        Ok(())
+    }
+}
+
+impl AstDiff {
+    ///
+    /// Returns the subtrees that differ between the `before` and `after` programs.
+    ///
+    /// Spans are ignored, so only changes to the structure of the program are reported.
+    ///
+    pub fn between(before: &Program, after: &Program) -> Result<Vec<AstDiff>, serde_json::Error> {
+        let mut before = serde_json::to_value(before)?;
+        let mut after = serde_json::to_value(after)?;
+        remove_spans(&mut before);
+        remove_spans(&mut after);
+
+        let mut diffs = vec![];
+        diff_values(String::new(), Some(&before), Some(&after), &mut diffs);
+
+        Ok(diffs)
+    }
+}
+
+fn remove_spans(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("span");
+            map.values_mut().for_each(remove_spans);
+        }
+        Value::Array(values) => values.iter_mut().for_each(remove_spans),
+        _ => (),
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    // Circuits and functions are keyed by their serialized identifier, use the identifier name instead.
+    let name = match serde_json::from_str::<Value>(key) {
+        Ok(Value::Object(identifier)) => identifier.get("name").and_then(Value::as_str).map(str::to_string),
+        _ => None,
+    };
+    let key = name.as_deref().unwrap_or(key);
+
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn diff_values(path: String, before: Option<&Value>, after: Option<&Value>, diffs: &mut Vec<AstDiff>) {
+    match (before, after) {
+        // Objects with the same fields or variant are compared field by field.
+        (Some(Value::Object(before)), Some(Value::Object(after)))
+            if before.len() == after.len() && before.keys().all(|key| after.contains_key(key)) =>
+        {
+            for (key, value) in before.iter() {
+                diff_values(child_path(&path, key), Some(value), after.get(key), diffs);
+            }
+        }
+        (Some(Value::Array(before)), Some(Value::Array(after))) => {
+            for i in 0..before.len().max(after.len()) {
+                diff_values(child_path(&path, &i.to_string()), before.get(i), after.get(i), diffs);
+            }
+        }
+        (before, after) if before != after => diffs.push(AstDiff {
+            path,
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => (),
+    }
+}
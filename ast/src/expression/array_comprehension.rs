@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An array built from an element expression evaluated at each index of a range, `[i * 2 | i in 0..4]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrayComprehensionExpression {
+    pub element: Box<Expression>,
+    pub variable: Identifier,
+    pub start: Box<Expression>,
+    pub stop: Box<Expression>,
+    pub span: Span,
+}
+
+impl fmt::Display for ArrayComprehensionExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{} | {} in {}..{}]",
+            self.element, self.variable, self.start, self.stop
+        )
+    }
+}
+
+impl Node for ArrayComprehensionExpression {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
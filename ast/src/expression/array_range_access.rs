@@ -21,6 +21,7 @@ pub struct ArrayRangeAccessExpression {
     pub array: Box<Expression>,
     pub left: Option<Box<Expression>>,
     pub right: Option<Box<Expression>>,
+    pub inclusive: bool,
     pub span: Span,
 }
 
@@ -28,9 +29,10 @@ impl fmt::Display for ArrayRangeAccessExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{}[{}..{}]",
+            "{}[{}{}{}]",
             self.array,
             self.left.as_ref().map(|e| e.to_string()).unwrap_or_default(),
+            if self.inclusive { "..=" } else { ".." },
             self.right.as_ref().map(|e| e.to_string()).unwrap_or_default()
         )
     }
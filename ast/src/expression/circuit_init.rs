@@ -19,6 +19,9 @@ use super::*;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CircuitInitExpression {
     pub name: Identifier,
+    /// The value of `..base`, which supplies the members that are not given explicitly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Box<Expression>>,
     pub members: Vec<CircuitImpliedVariableDefinition>,
     pub span: Span,
 }
@@ -26,6 +29,12 @@ pub struct CircuitInitExpression {
 impl fmt::Display for CircuitInitExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} {{", self.name)?;
+        if let Some(base) = &self.base {
+            write!(f, "..{}", base)?;
+            if !self.members.is_empty() {
+                write!(f, ", ")?;
+            }
+        }
         for (i, member) in self.members.iter().enumerate() {
             if let Some(expression) = &member.expression {
                 write!(f, "{}: {}", member.identifier, expression)?;
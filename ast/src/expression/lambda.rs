@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A lambda expression, `|acc, x| acc + x`, accepted only as the argument of an array builtin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LambdaExpression {
+    pub parameters: Vec<Identifier>,
+    pub body: Box<Expression>,
+    pub span: Span,
+}
+
+impl fmt::Display for LambdaExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "|")?;
+        for (i, parameter) in self.parameters.iter().enumerate() {
+            write!(f, "{}", parameter)?;
+            if i < self.parameters.len() - 1 {
+                write!(f, ", ")?;
+            }
+        }
+        write!(f, "| {}", self.body)
+    }
+}
+
+impl Node for LambdaExpression {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+}
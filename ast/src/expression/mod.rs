@@ -44,6 +44,8 @@ mod array_inline;
 pub use array_inline::*;
 mod array_init;
 pub use array_init::*;
+mod array_comprehension;
+pub use array_comprehension::*;
 mod tuple_access;
 pub use tuple_access::*;
 mod tuple_init;
@@ -60,6 +62,8 @@ mod call;
 pub use call::*;
 mod cast;
 pub use cast::*;
+mod lambda;
+pub use lambda::*;
 
 /// Expression that evaluates to a value
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -73,6 +77,7 @@ pub enum Expression {
 
     ArrayInline(ArrayInlineExpression),
     ArrayInit(ArrayInitExpression),
+    ArrayComprehension(ArrayComprehensionExpression),
     ArrayAccess(ArrayAccessExpression),
     ArrayRangeAccess(ArrayRangeAccessExpression),
 
@@ -84,6 +89,7 @@ pub enum Expression {
     CircuitStaticFunctionAccess(CircuitStaticFunctionAccessExpression),
 
     Call(CallExpression),
+    Lambda(LambdaExpression),
 }
 
 impl Node for Expression {
@@ -97,6 +103,7 @@ impl Node for Expression {
             Ternary(n) => n.span(),
             ArrayInline(n) => n.span(),
             ArrayInit(n) => n.span(),
+            ArrayComprehension(n) => n.span(),
             ArrayAccess(n) => n.span(),
             ArrayRangeAccess(n) => n.span(),
             TupleInit(n) => n.span(),
@@ -106,6 +113,7 @@ impl Node for Expression {
             CircuitStaticFunctionAccess(n) => n.span(),
             Call(n) => n.span(),
             Cast(n) => n.span(),
+            Lambda(n) => n.span(),
         }
     }
 
@@ -119,6 +127,7 @@ impl Node for Expression {
             Ternary(n) => n.set_span(span),
             ArrayInline(n) => n.set_span(span),
             ArrayInit(n) => n.set_span(span),
+            ArrayComprehension(n) => n.set_span(span),
             ArrayAccess(n) => n.set_span(span),
             ArrayRangeAccess(n) => n.set_span(span),
             TupleInit(n) => n.set_span(span),
@@ -128,6 +137,7 @@ impl Node for Expression {
             CircuitStaticFunctionAccess(n) => n.set_span(span),
             Call(n) => n.set_span(span),
             Cast(n) => n.set_span(span),
+            Lambda(n) => n.set_span(span),
         }
     }
 }
@@ -143,6 +153,7 @@ impl fmt::Display for Expression {
             Ternary(n) => n.fmt(f),
             ArrayInline(n) => n.fmt(f),
             ArrayInit(n) => n.fmt(f),
+            ArrayComprehension(n) => n.fmt(f),
             ArrayAccess(n) => n.fmt(f),
             ArrayRangeAccess(n) => n.fmt(f),
             TupleInit(n) => n.fmt(f),
@@ -152,6 +163,7 @@ impl fmt::Display for Expression {
             CircuitStaticFunctionAccess(n) => n.fmt(f),
             Call(n) => n.fmt(f),
             Cast(n) => n.fmt(f),
+            Lambda(n) => n.fmt(f),
         }
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{InputValue, MainInput, ProgramInput, ProgramState, Record, Registers, State, StateLeaf};
+use crate::{InputValue, MainInput, Parameter, ProgramInput, ProgramState, Record, Registers, State, StateLeaf};
 use leo_input::{
     files::{File, TableOrSection},
     InputParserError,
@@ -106,6 +106,16 @@ impl Input {
         self.program_input.get_constant(name)
     }
 
+    /// Returns the names of the main function input values.
+    pub fn main_names(&self) -> Vec<&str> {
+        self.program_input.main.names()
+    }
+
+    /// Returns the names of the constant input values.
+    pub fn constant_names(&self) -> Vec<&str> {
+        self.program_input.constants.names()
+    }
+
     /// Returns the runtime register input values
     pub fn get_registers(&self) -> &Registers {
         self.program_input.get_registers()
@@ -125,4 +135,34 @@ impl Input {
     pub fn get_state_leaf(&self) -> &StateLeaf {
         self.program_state.get_state_leaf()
     }
+
+    /// Returns `true` if the main function input `name` is declared `public`.
+    pub fn is_public(&self, name: &str) -> bool {
+        self.program_input.main.is_public(name)
+    }
+
+    /// Returns the parameters of the main function inputs declared `public`.
+    pub fn public_main_parameters(&self) -> &[Parameter] {
+        self.program_input.main.public_parameters()
+    }
+
+    /// Returns the input section values allocated as public variables, in allocation order.
+    /// These are the `[[public]]` state values and any section definition declared `public`.
+    pub fn public_section_inputs(&self) -> Vec<(Parameter, Option<InputValue>)> {
+        let sections = vec![
+            (self.get_registers().values(), false),
+            (self.get_record().values(), false),
+            (self.get_state().values(), true),
+            (self.get_state_leaf().values(), false),
+        ];
+
+        sections
+            .into_iter()
+            .flat_map(|(values, public)| {
+                values
+                    .into_iter()
+                    .filter(move |(parameter, _value)| public || parameter.public)
+            })
+            .collect()
+    }
 }
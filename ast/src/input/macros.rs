@@ -83,6 +83,7 @@ macro_rules! main_input_section {
         #[derive(Clone, PartialEq, Eq, Default)]
         pub struct $name {
             input: IndexMap<String, Option<InputValue>>,
+            public: Vec<Parameter>,
         }
 
         #[allow(clippy::len_without_is_empty)]
@@ -100,7 +101,10 @@ macro_rules! main_input_section {
                     *value = None;
                 });
 
-                Self { input }
+                Self {
+                    input,
+                    public: self.public.clone(),
+                }
             }
 
             pub fn len(&self) -> usize {
@@ -114,9 +118,13 @@ macro_rules! main_input_section {
             /// Parses main input definitions and stores them in `self`.
             pub fn parse(&mut self, definitions: Vec<Definition>) -> Result<(), InputParserError> {
                 for definition in definitions {
-                    let name = definition.parameter.variable.value;
-                    let value = InputValue::from_expression(definition.parameter.type_, definition.expression)?;
+                    let value = InputValue::from_expression(definition.parameter.type_.clone(), definition.expression)?;
+                    let parameter = Parameter::from(definition.parameter);
+                    let name = parameter.variable.name.to_string();
 
+                    if parameter.public {
+                        self.public.push(parameter);
+                    }
                     self.insert(name, Some(value));
                 }
 
@@ -127,6 +135,21 @@ macro_rules! main_input_section {
             pub fn get(&self, name: &str) -> Option<Option<InputValue>> {
                 self.input.get(name).cloned()
             }
+
+            /// Returns the names of the inputs in declaration order.
+            pub fn names(&self) -> Vec<&str> {
+                self.input.keys().map(|name| name.as_str()).collect()
+            }
+
+            /// Returns `true` if the input `name` is declared `public`.
+            pub fn is_public(&self, name: &str) -> bool {
+                self.public.iter().any(|parameter| parameter.variable.name.as_ref() == name)
+            }
+
+            /// Returns the parameters of the inputs declared `public`, in declaration order.
+            pub fn public_parameters(&self) -> &[Parameter] {
+                &self.public
+            }
         }
     )*)
 }
@@ -34,3 +34,9 @@ pub use program_input::*;
 
 pub mod program_state;
 pub use program_state::*;
+
+pub mod template;
+pub use template::*;
+
+pub mod validator;
+pub use validator::*;
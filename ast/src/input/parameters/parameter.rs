@@ -19,6 +19,7 @@ use leo_input::parameters::Parameter as GrammarParameter;
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Parameter {
+    pub public: bool,
     pub variable: Identifier,
     pub type_: Type,
     pub span: Span,
@@ -27,6 +28,7 @@ pub struct Parameter {
 impl<'ast> From<GrammarParameter<'ast>> for Parameter {
     fn from(parameter: GrammarParameter<'ast>) -> Self {
         Self {
+            public: parameter.public.is_some(),
             variable: Identifier::from(parameter.variable),
             type_: Type::from(parameter.type_),
             span: Span::from(parameter.span),
@@ -65,7 +65,12 @@ impl ProgramInput {
     /// Parse each input included in a file and store them in `self`.
     pub fn parse(&mut self, section: Section) -> Result<(), InputParserError> {
         match section.header {
-            Header::Constants(_constants) => self.constants.parse(section.definitions),
+            Header::Constants(_constants) => {
+                if let Some(definition) = section.definitions.iter().find(|d| d.parameter.public.is_some()) {
+                    return Err(InputParserError::public_constant(&definition.parameter));
+                }
+                self.constants.parse(section.definitions)
+            }
             Header::Main(_main) => self.main.parse(section.definitions),
             Header::Registers(_registers) => self.registers.parse(section.definitions),
             header => Err(InputParserError::input_section_header(header)),
@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates a program input file from the signature of `main`.
+
+use crate::{CircuitMember, FunctionInput, FunctionInputVariable, Program, Type};
+
+/// Placeholder used for `address` parameters.
+const ADDRESS_PLACEHOLDER: &str = "aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8";
+
+/// A skeleton program input file for the `main` function of a program.
+pub struct InputTemplate<'a> {
+    program: &'a Program,
+}
+
+impl<'a> InputTemplate<'a> {
+    pub fn new(program: &'a Program) -> Self {
+        Self { program }
+    }
+
+    ///
+    /// Returns the contents of an input file declaring every `main` parameter with a
+    /// placeholder value, or `None` if the program has no `main` function.
+    ///
+    /// Constant parameters are placed in the `[constants]` section and a register is declared
+    /// for each value returned by `main`. Circuit parameters cannot be read from input files,
+    /// so they are written as comments showing the expected members.
+    ///
+    pub fn generate(&self) -> Option<String> {
+        let main = self
+            .program
+            .functions
+            .values()
+            .find(|function| function.get_name() == "main")?;
+
+        let variables = main
+            .input
+            .iter()
+            .filter_map(|input| match input {
+                FunctionInput::Variable(variable) => Some(variable),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut template = format!("// The program input for {}/src/main.leo\n", self.program.name);
+
+        template.push_str("[main]\n");
+        for variable in variables.iter().filter(|variable| !variable.const_) {
+            self.write_parameter(&mut template, variable);
+        }
+
+        let constants = variables.iter().filter(|variable| variable.const_).collect::<Vec<_>>();
+        if !constants.is_empty() {
+            template.push_str("\n[constants]\n");
+            for variable in constants {
+                self.write_parameter(&mut template, variable);
+            }
+        }
+
+        template.push_str("\n[registers]\n");
+        let outputs = match &main.output {
+            Some(Type::Tuple(types)) => types.clone(),
+            Some(type_) => vec![type_.clone()],
+            None => vec![],
+        };
+        for (index, type_) in outputs.iter().enumerate() {
+            self.write_definition(&mut template, &format!("r{}", index), type_);
+        }
+
+        Some(template)
+    }
+
+    fn write_parameter(&self, template: &mut String, variable: &FunctionInputVariable) {
+        self.write_definition(template, &variable.identifier.name, &variable.type_);
+    }
+
+    fn write_definition(&self, template: &mut String, name: &str, type_: &Type) {
+        let prefix = if Self::contains_circuit(type_) { "// " } else { "" };
+
+        template.push_str(&format!(
+            "{}{}: {} = {};\n",
+            prefix,
+            name,
+            Self::type_string(type_),
+            self.placeholder(type_)
+        ));
+    }
+
+    ///
+    /// Returns the input file spelling of the given type.
+    ///
+    fn type_string(type_: &Type) -> String {
+        match type_ {
+            Type::Circuit(identifier) => identifier.name.to_string(),
+            Type::Array(element, dimensions) => format!("[{}; {}]", Self::type_string(element), dimensions),
+            Type::Tuple(types) => format!(
+                "({})",
+                types.iter().map(Self::type_string).collect::<Vec<_>>().join(", ")
+            ),
+            type_ => type_.to_string(),
+        }
+    }
+
+    ///
+    /// Returns `true` if the given type is, or contains, a circuit type.
+    ///
+    fn contains_circuit(type_: &Type) -> bool {
+        match type_ {
+            Type::Circuit(_) | Type::SelfType => true,
+            Type::Array(element, _) => Self::contains_circuit(element),
+            Type::Tuple(types) => types.iter().any(Self::contains_circuit),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Returns a placeholder value of the given type.
+    ///
+    fn placeholder(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Address => ADDRESS_PLACEHOLDER.to_string(),
+            Type::Boolean => "false".to_string(),
+            Type::Field => "0".to_string(),
+            Type::Group => "0group".to_string(),
+            Type::IntegerType(_) => "0".to_string(),
+            Type::Array(element, dimensions) => format!("[{}; {}]", self.placeholder(element), dimensions),
+            Type::Tuple(types) => format!(
+                "({})",
+                types
+                    .iter()
+                    .map(|type_| self.placeholder(type_))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Circuit(identifier) => {
+                let circuit = self
+                    .program
+                    .circuits
+                    .values()
+                    .find(|circuit| circuit.circuit_name.name == identifier.name);
+
+                let members = match circuit {
+                    Some(circuit) => circuit
+                        .members
+                        .iter()
+                        .filter_map(|member| match member {
+                            CircuitMember::CircuitVariable(name, type_) => {
+                                Some(format!("{}: {}", name.name, self.placeholder(type_)))
+                            }
+                            CircuitMember::CircuitFunction(_) => None,
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    None => "..".to_string(),
+                };
+
+                format!("{} {{ {} }}", identifier.name, members)
+            }
+            Type::SelfType => "Self".to_string(),
+        }
+    }
+}
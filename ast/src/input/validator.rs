@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks a program input file against the signature of `main`.
+
+use crate::{FunctionInput, Input, InputValue, IntegerType, Program, Type};
+
+use std::fmt;
+
+/// A difference between a program input file and the parameters of `main`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputMismatch {
+    /// A parameter of `main` has no value in the section for it.
+    Missing { name: String, section: &'static str },
+    /// A value in the input file is not a parameter of `main`.
+    Extra { name: String, section: &'static str },
+    /// A value does not have the type of its parameter.
+    Type { name: String, expected: String },
+}
+
+impl fmt::Display for InputMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputMismatch::Missing { name, section } => write!(f, "missing `{}` in the [{}] section", name, section),
+            InputMismatch::Extra { name, section } => write!(
+                f,
+                "unexpected `{}` in the [{}] section, `main` has no such parameter",
+                name, section
+            ),
+            InputMismatch::Type { name, expected } => write!(f, "`{}` does not have the type `{}`", name, expected),
+        }
+    }
+}
+
+/// Validates a program input file against the parameters of the `main` function of a program.
+pub struct InputValidator<'a> {
+    program: &'a Program,
+}
+
+impl<'a> InputValidator<'a> {
+    pub fn new(program: &'a Program) -> Self {
+        Self { program }
+    }
+
+    ///
+    /// Returns every missing, extra, or mistyped value of the given input, or `None` if the
+    /// program has no `main` function.
+    ///
+    /// Constant parameters are expected in the `[constants]` section, all others in `[main]`.
+    ///
+    pub fn validate(&self, input: &Input) -> Option<Vec<InputMismatch>> {
+        let main = self
+            .program
+            .functions
+            .values()
+            .find(|function| function.get_name() == "main")?;
+
+        let variables = main
+            .input
+            .iter()
+            .filter_map(|input| match input {
+                FunctionInput::Variable(variable) => Some(variable),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut mismatches = vec![];
+        for variable in variables.iter() {
+            let name = variable.identifier.name.to_string();
+            let (section, value) = if variable.const_ {
+                ("constants", input.get_constant(&name))
+            } else {
+                ("main", input.get(&name))
+            };
+
+            match value {
+                None => mismatches.push(InputMismatch::Missing { name, section }),
+                Some(Some(value)) if !Self::value_matches(&value, &variable.type_) => {
+                    mismatches.push(InputMismatch::Type {
+                        name,
+                        expected: variable.type_.to_string(),
+                    })
+                }
+                Some(_) => (),
+            }
+        }
+
+        let sections = [
+            ("main", input.main_names(), false),
+            ("constants", input.constant_names(), true),
+        ];
+        for (section, names, const_) in sections.iter() {
+            for name in names.iter() {
+                let declared = variables
+                    .iter()
+                    .any(|variable| variable.const_ == *const_ && variable.identifier.name.as_ref() == *name);
+                if !declared {
+                    mismatches.push(InputMismatch::Extra {
+                        name: name.to_string(),
+                        section: *section,
+                    });
+                }
+            }
+        }
+
+        Some(mismatches)
+    }
+
+    ///
+    /// Returns `true` if the given input value has the given type.
+    ///
+    fn value_matches(value: &InputValue, type_: &Type) -> bool {
+        match (value, type_) {
+            (InputValue::Address(_), Type::Address)
+            | (InputValue::Boolean(_), Type::Boolean)
+            | (InputValue::Field(_), Type::Field)
+            | (InputValue::Group(_), Type::Group) => true,
+            (InputValue::Integer(integer_type, _), Type::IntegerType(expected)) => {
+                &IntegerType::from(integer_type.clone()) == expected
+            }
+            (InputValue::Array(values), Type::Array(element, dimensions)) => {
+                let mut dimensions = dimensions.clone();
+                let length = dimensions.remove_first();
+                let element = if dimensions.is_empty() {
+                    *element.clone()
+                } else {
+                    Type::Array(element.clone(), dimensions)
+                };
+
+                length.map_or(false, |length| length.value.as_ref() == values.len().to_string())
+                    && values.iter().all(|value| Self::value_matches(value, &element))
+            }
+            (InputValue::Tuple(values), Type::Tuple(types)) => {
+                values.len() == types.len()
+                    && values
+                        .iter()
+                        .zip(types.iter())
+                        .all(|(value, type_)| Self::value_matches(value, type_))
+            }
+            _ => false,
+        }
+    }
+}
@@ -32,6 +32,9 @@ pub use self::circuits::*;
 pub mod common;
 pub use self::common::*;
 
+pub mod diff;
+pub use self::diff::*;
+
 pub mod errors;
 pub use self::errors::*;
 
@@ -88,6 +91,20 @@ impl Ast {
         Ok(())
     }
 
+    /// Mutates the program ast by rewriting nested ternary expressions into chains nested in their false branches.
+    pub fn normalize_ternary_chains(&mut self) -> Result<(), CanonicalizeError> {
+        self.ast = ReconstructingDirector::new(TernaryChainNormalizer).reduce_program(self.as_repr())?;
+        Ok(())
+    }
+
+    /// Returns the changes that canonicalization makes to the program ast.
+    pub fn canonicalization_diff(&self) -> Result<Vec<AstDiff>, CanonicalizeError> {
+        let mut canonicalized = Ast::new(self.ast.clone());
+        canonicalized.canonicalize()?;
+
+        Ok(AstDiff::between(&self.ast, &canonicalized.ast)?)
+    }
+
     /// Returns a reference to the inner program AST representation.
     pub fn as_repr(&self) -> &Program {
         &self.ast
@@ -98,10 +115,19 @@ impl Ast {
     }
 
     /// Serializes the ast into a JSON string.
+    ///
+    /// The output is deterministic, circuits and functions are written in declaration order.
     pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(&self.ast)
     }
 
+    /// Serializes the ast into a JSON string, writing spans according to `spans`.
+    ///
+    /// Relative or omitted spans keep serialized asts stable across machines.
+    pub fn to_json_string_with_spans(&self, spans: SpanSerialization) -> Result<String, serde_json::Error> {
+        with_span_serialization(spans, || self.to_json_string())
+    }
+
     /// Deserializes the JSON string into a ast.
     pub fn from_json_string(json: &str) -> Result<Self, serde_json::Error> {
         let ast: Program = serde_json::from_str(json)?;
@@ -17,11 +17,22 @@
 //! A Leo program consists of import, circuit, and function definitions.
 //! Each defined type consists of ast statements and expressions.
 
-use crate::{Circuit, Function, FunctionInput, Identifier, ImportStatement};
+use crate::{
+    Block,
+    Circuit,
+    CircuitMember,
+    Comments,
+    Function,
+    FunctionInput,
+    Identifier,
+    ImportStatement,
+    Node,
+    Statement,
+};
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{collections::HashSet, fmt};
 
 /// Stores the Leo program abstract syntax tree.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -29,7 +40,9 @@ pub struct Program {
     pub name: String,
     pub expected_input: Vec<FunctionInput>,
     pub imports: Vec<ImportStatement>,
+    /// The circuits in declaration order, which is preserved when serialized.
     pub circuits: IndexMap<Identifier, Circuit>,
+    /// The functions in declaration order, which is preserved when serialized.
     pub functions: IndexMap<Identifier, Function>,
 }
 
@@ -60,6 +73,36 @@ impl fmt::Display for Program {
 }
 
 impl Program {
+    ///
+    /// Returns the source of the program with the given comments written ahead of the imports,
+    /// circuits, circuit members, functions, and statements that they precede.
+    /// Comments within a single statement or member, or ahead of a closing brace, are not written.
+    ///
+    pub fn to_string_with_comments(&self, comments: &Comments) -> String {
+        let mut writer = CommentWriter::new(comments);
+
+        for import in self.imports.iter() {
+            writer.write_leading(import.span.line_start, "");
+            writer.output.push_str(&format!("{}\n", import));
+        }
+        writer.output.push('\n');
+        for (name, circuit) in self.circuits.iter() {
+            writer.write_leading(name.span.line_start, "");
+            writer.write_circuit(circuit);
+            writer.output.push('\n');
+        }
+        writer.output.push('\n');
+        for function in self.functions.values() {
+            writer.write_function(function, "");
+            writer.output.push('\n');
+        }
+        for comment in comments.trailing() {
+            writer.output.push_str(comment.trim_end());
+            writer.output.push('\n');
+        }
+        writer.output
+    }
+
     pub fn new(name: String) -> Self {
         Self {
             name,
@@ -79,3 +122,115 @@ impl Program {
         self
     }
 }
+
+/// Writes program nodes in their `Display` form, each preceded by the comments on its first line.
+struct CommentWriter<'a> {
+    comments: &'a Comments,
+    /// Lines whose comments were written, as several nodes may start on the same line.
+    written: HashSet<usize>,
+    output: String,
+}
+
+impl<'a> CommentWriter<'a> {
+    fn new(comments: &'a Comments) -> Self {
+        Self {
+            comments,
+            written: HashSet::new(),
+            output: String::new(),
+        }
+    }
+
+    fn write_leading(&mut self, line: usize, indent: &str) {
+        if !self.written.insert(line) {
+            return;
+        }
+        for comment in self.comments.leading(line) {
+            self.output.push_str(indent);
+            self.output.push_str(comment.trim_end());
+            self.output.push('\n');
+        }
+    }
+
+    fn write_circuit(&mut self, circuit: &Circuit) {
+        self.output.push_str(&format!("circuit {} {{ \n", circuit.circuit_name));
+        for member in circuit.members.iter() {
+            match member {
+                CircuitMember::CircuitVariable(identifier, _) => {
+                    self.write_leading(identifier.span.line_start, "    ");
+                    self.output.push_str(&format!("    {}\n", member));
+                }
+                CircuitMember::CircuitFunction(function) => {
+                    self.write_function(function, "    ");
+                    self.output.push('\n');
+                }
+            }
+        }
+        self.output.push('}');
+    }
+
+    fn write_function(&mut self, function: &Function, indent: &str) {
+        let line = function
+            .annotations
+            .first()
+            .map(|annotation| annotation.span.line_start)
+            .unwrap_or(function.span.line_start);
+        self.write_leading(line, indent);
+
+        let parameters = function
+            .input
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.output
+            .push_str(&format!("{}function {}({})", indent, function.identifier, parameters));
+        if let Some(output) = &function.output {
+            self.output.push_str(&format!(" -> {}", output));
+        }
+        self.output.push(' ');
+        self.write_block(&function.block);
+    }
+
+    fn write_block(&mut self, block: &Block) {
+        self.output.push_str("{\n");
+        if block.statements.is_empty() {
+            self.output.push_str("\t\n");
+        }
+        for statement in block.statements.iter() {
+            self.write_leading(statement.span().line_start, "\t");
+            self.output.push('\t');
+            self.write_statement(statement);
+            self.output.push('\n');
+        }
+        self.output.push('}');
+    }
+
+    fn write_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Conditional(conditional) => {
+                self.output.push_str(&format!("if ({}) ", conditional.condition));
+                self.write_block(&conditional.block);
+                if let Some(next) = &conditional.next {
+                    self.output.push(' ');
+                    self.write_statement(next);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                self.output.push_str(&format!(
+                    "for {} in {}{}{}",
+                    iteration.variable,
+                    iteration.start,
+                    if iteration.inclusive { "..=" } else { ".." },
+                    iteration.stop
+                ));
+                if let Some(step) = &iteration.step {
+                    self.output.push_str(&format!(" step {}", step));
+                }
+                self.output.push(' ');
+                self.write_block(&iteration.block);
+            }
+            Statement::Block(block) => self.write_block(block),
+            statement => self.output.push_str(&statement.to_string()),
+        }
+    }
+}
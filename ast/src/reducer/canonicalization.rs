@@ -116,6 +116,20 @@ impl Canonicalizer {
                 });
             }
 
+            Expression::ArrayComprehension(array_comprehension) => {
+                let element = Box::new(self.canonicalize_expression(&array_comprehension.element));
+                let start = Box::new(self.canonicalize_expression(&array_comprehension.start));
+                let stop = Box::new(self.canonicalize_expression(&array_comprehension.stop));
+
+                return Expression::ArrayComprehension(ArrayComprehensionExpression {
+                    element,
+                    variable: array_comprehension.variable.clone(),
+                    start,
+                    stop,
+                    span: array_comprehension.span.clone(),
+                });
+            }
+
             Expression::ArrayAccess(array_access) => {
                 let array = Box::new(self.canonicalize_expression(&array_access.array));
                 let index = Box::new(self.canonicalize_expression(&array_access.index));
@@ -142,6 +156,7 @@ impl Canonicalizer {
                     array,
                     left,
                     right,
+                    inclusive: array_range_access.inclusive,
                     span: array_range_access.span.clone(),
                 });
             }
@@ -177,6 +192,10 @@ impl Canonicalizer {
 
                 return Expression::CircuitInit(CircuitInitExpression {
                     name,
+                    base: circuit_init
+                        .base
+                        .as_ref()
+                        .map(|base| Box::new(self.canonicalize_expression(base))),
                     members: circuit_init.members.clone(),
                     span: circuit_init.span.clone(),
                 });
@@ -302,12 +321,15 @@ impl Canonicalizer {
             Statement::Iteration(iteration) => {
                 let start = self.canonicalize_expression(&iteration.start);
                 let stop = self.canonicalize_expression(&iteration.stop);
+                let step = iteration.step.as_ref().map(|step| self.canonicalize_expression(step));
                 let block = self.canonicalize_block(&iteration.block);
 
                 Statement::Iteration(IterationStatement {
                     variable: iteration.variable.clone(),
                     start,
                     stop,
+                    inclusive: iteration.inclusive,
+                    step,
                     block,
                     span: iteration.span.clone(),
                 })
@@ -317,6 +339,14 @@ impl Canonicalizer {
                     ConsoleFunction::Assert(expression) => {
                         ConsoleFunction::Assert(self.canonicalize_expression(expression))
                     }
+                    ConsoleFunction::AssertEq(left, right) => ConsoleFunction::AssertEq(
+                        self.canonicalize_expression(left),
+                        self.canonicalize_expression(right),
+                    ),
+                    ConsoleFunction::AssertNeq(left, right) => ConsoleFunction::AssertNeq(
+                        self.canonicalize_expression(left),
+                        self.canonicalize_expression(right),
+                    ),
                     ConsoleFunction::Debug(format) | ConsoleFunction::Error(format) | ConsoleFunction::Log(format) => {
                         let parameters = format
                             .parameters
@@ -344,11 +374,35 @@ impl Canonicalizer {
                     span: console_function_call.span.clone(),
                 })
             }
+            Statement::Require(require) => {
+                let condition = self.canonicalize_expression(&require.condition);
+                let parameters = require
+                    .message
+                    .parameters
+                    .iter()
+                    .map(|parameter| self.canonicalize_expression(parameter))
+                    .collect();
+
+                Statement::Require(RequireStatement {
+                    condition,
+                    message: FormatString {
+                        parts: require.message.parts.clone(),
+                        parameters,
+                        span: require.message.span.clone(),
+                    },
+                    span: require.span.clone(),
+                })
+            }
+            Statement::ConstAssert(const_assert) => Statement::ConstAssert(ConstAssertStatement {
+                condition: self.canonicalize_expression(&const_assert.condition),
+                span: const_assert.span.clone(),
+            }),
             Statement::Expression(expression) => Statement::Expression(ExpressionStatement {
                 expression: self.canonicalize_expression(&expression.expression),
                 span: expression.span.clone(),
             }),
             Statement::Block(block) => Statement::Block(self.canonicalize_block(block)),
+            Statement::Break(_) | Statement::Continue(_) => statement.clone(),
         }
     }
 
@@ -468,73 +522,70 @@ impl ReconstructingReducer for Canonicalizer {
         value: Expression,
         _in_circuit: bool,
     ) -> Result<AssignStatement, CanonicalizeError> {
-        match value {
-            Expression::Value(value_expr) if assign.operation != AssignOperation::Assign => {
-                let mut left = Box::new(Expression::Identifier(assignee.identifier.clone()));
-
-                for access in assignee.accesses.iter() {
-                    match self.canonicalize_assignee_access(&access) {
-                        AssigneeAccess::ArrayIndex(index) => {
-                            left = Box::new(Expression::ArrayAccess(ArrayAccessExpression {
-                                array: left,
-                                index: Box::new(index),
-                                span: assign.span.clone(),
-                            }));
-                        }
-                        AssigneeAccess::Tuple(positive_number, _) => {
-                            left = Box::new(Expression::TupleAccess(TupleAccessExpression {
-                                tuple: left,
-                                index: positive_number,
-                                span: assign.span.clone(),
-                            }));
-                        }
-                        AssigneeAccess::Member(identifier) => {
-                            left = Box::new(Expression::CircuitMemberAccess(CircuitMemberAccessExpression {
-                                circuit: left,
-                                name: identifier,
-                                span: assign.span.clone(),
-                            }));
-                        }
-                        _ => unimplemented!(), // No reason for someone to compute ArrayRanges.
-                    }
-                }
-
-                let right = Box::new(Expression::Value(value_expr));
-
-                let op = match assign.operation {
-                    AssignOperation::Assign => unimplemented!(), // Imposible
-                    AssignOperation::Add => BinaryOperation::Add,
-                    AssignOperation::Sub => BinaryOperation::Sub,
-                    AssignOperation::Mul => BinaryOperation::Mul,
-                    AssignOperation::Div => BinaryOperation::Div,
-                    AssignOperation::Pow => BinaryOperation::Pow,
-                    AssignOperation::Or => BinaryOperation::Or,
-                    AssignOperation::And => BinaryOperation::And,
-                    AssignOperation::BitOr => BinaryOperation::BitOr,
-                    AssignOperation::BitAnd => BinaryOperation::BitAnd,
-                    AssignOperation::BitXor => BinaryOperation::BitXor,
-                    AssignOperation::Shr => BinaryOperation::Shr,
-                    AssignOperation::ShrSigned => BinaryOperation::ShrSigned,
-                    AssignOperation::Shl => BinaryOperation::Shl,
-                    AssignOperation::Mod => BinaryOperation::Mod,
-                };
+        // Range assignees are left untouched, the compiler applies the operation element-wise.
+        // So are indices other than identifiers and literals, which would be evaluated twice.
+        let keep_compound = assignee.accesses.iter().any(|access| match access {
+            AssigneeAccess::ArrayRange(_, _) => true,
+            AssigneeAccess::ArrayIndex(index) => !matches!(index, Expression::Identifier(_) | Expression::Value(_)),
+            AssigneeAccess::Tuple(_, _) | AssigneeAccess::Member(_) => false,
+        });
 
-                let new_value = Expression::Binary(BinaryExpression {
-                    left,
-                    right,
-                    op,
+        let op = match assign.operation.binary_operation() {
+            Some(op) if !keep_compound => op,
+            _ => {
+                return Ok(AssignStatement {
+                    operation: assign.operation.clone(),
+                    assignee,
+                    value,
                     span: assign.span.clone(),
                 });
+            }
+        };
 
-                Ok(AssignStatement {
-                    operation: AssignOperation::Assign,
-                    assignee,
-                    value: new_value,
-                    span: assign.span.clone(),
-                })
+        let mut left = Box::new(Expression::Identifier(assignee.identifier.clone()));
+
+        for access in assignee.accesses.iter() {
+            match self.canonicalize_assignee_access(&access) {
+                AssigneeAccess::ArrayIndex(index) => {
+                    left = Box::new(Expression::ArrayAccess(ArrayAccessExpression {
+                        array: left,
+                        index: Box::new(index),
+                        span: assign.span.clone(),
+                    }));
+                }
+                AssigneeAccess::Tuple(positive_number, _) => {
+                    left = Box::new(Expression::TupleAccess(TupleAccessExpression {
+                        tuple: left,
+                        index: positive_number,
+                        span: assign.span.clone(),
+                    }));
+                }
+                AssigneeAccess::Member(identifier) => {
+                    left = Box::new(Expression::CircuitMemberAccess(CircuitMemberAccessExpression {
+                        circuit: left,
+                        name: identifier,
+                        span: assign.span.clone(),
+                    }));
+                }
+                AssigneeAccess::ArrayRange(_, _) => unreachable!(), // Handled above.
             }
-            _ => Ok(assign.clone()),
         }
+
+        let right = Box::new(value);
+
+        let new_value = Expression::Binary(BinaryExpression {
+            left,
+            right,
+            op,
+            span: assign.span.clone(),
+        });
+
+        Ok(AssignStatement {
+            operation: AssignOperation::Assign,
+            assignee,
+            value: new_value,
+            span: assign.span.clone(),
+        })
     }
 
     fn reduce_function(
@@ -20,6 +20,9 @@ use crate::{FormattedError, Span};
 pub enum CanonicalizeError {
     #[error("{}", _0)]
     Error(#[from] FormattedError),
+
+    #[error("{}", _0)]
+    SerdeJsonError(#[from] serde_json::Error),
 }
 
 impl CanonicalizeError {
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+/// Replaces references to the index variable of an array comprehension with a constant index.
+struct IndexSubstitution {
+    variable: Identifier,
+    index: usize,
+}
+
+impl IndexSubstitution {
+    fn index(&self, span: &Span) -> Expression {
+        Expression::Value(ValueExpression::Integer(
+            IntegerType::U32,
+            self.index.to_string().into(),
+            span.clone(),
+        ))
+    }
+}
+
+impl ReconstructingReducer for IndexSubstitution {
+    fn reduce_expression(
+        &mut self,
+        _expression: &Expression,
+        new: Expression,
+        _in_circuit: bool,
+    ) -> Result<Expression, CanonicalizeError> {
+        match new {
+            Expression::Identifier(identifier) if identifier.name == self.variable.name => {
+                Ok(self.index(&identifier.span))
+            }
+            new => Ok(new),
+        }
+    }
+
+    fn reduce_circuit_implied_variable_definition(
+        &mut self,
+        _variable: &CircuitImpliedVariableDefinition,
+        identifier: Identifier,
+        expression: Option<Expression>,
+        _in_circuit: bool,
+    ) -> Result<CircuitImpliedVariableDefinition, CanonicalizeError> {
+        // `Circuit { i }` is shorthand for `Circuit { i: i }`.
+        let expression = match expression {
+            None if identifier.name == self.variable.name => Some(self.index(&identifier.span)),
+            expression => expression,
+        };
+
+        Ok(CircuitImpliedVariableDefinition { identifier, expression })
+    }
+
+    fn reduce_array_comprehension(
+        &mut self,
+        array_comprehension: &ArrayComprehensionExpression,
+        element: Expression,
+        variable: Identifier,
+        start: Expression,
+        stop: Expression,
+        _in_circuit: bool,
+    ) -> Result<ArrayComprehensionExpression, CanonicalizeError> {
+        // A nested comprehension over the same name shadows the index in its element, but not in its range.
+        let element = if variable.name == self.variable.name {
+            (*array_comprehension.element).clone()
+        } else {
+            element
+        };
+
+        Ok(ArrayComprehensionExpression {
+            element: Box::new(element),
+            variable,
+            start: Box::new(start),
+            stop: Box::new(stop),
+            span: array_comprehension.span.clone(),
+        })
+    }
+
+    fn reduce_lambda(
+        &mut self,
+        lambda: &LambdaExpression,
+        parameters: Vec<Identifier>,
+        body: Expression,
+        _in_circuit: bool,
+    ) -> Result<LambdaExpression, CanonicalizeError> {
+        // A lambda parameter with the same name shadows the index in the lambda body.
+        let body = if parameters.iter().any(|parameter| parameter.name == self.variable.name) {
+            (*lambda.body).clone()
+        } else {
+            body
+        };
+
+        Ok(LambdaExpression {
+            parameters,
+            body: Box::new(body),
+            span: lambda.span.clone(),
+        })
+    }
+}
+
+impl ArrayComprehensionExpression {
+    ///
+    /// Returns the element expression at `index`, with the index variable replaced by the `u32` constant `index`.
+    ///
+    pub fn element_at(&self, index: usize) -> Result<Expression, CanonicalizeError> {
+        let mut director = ReconstructingDirector::new(IndexSubstitution {
+            variable: self.variable.clone(),
+            index,
+        });
+
+        director.reduce_expression(&self.element)
+    }
+}
@@ -20,8 +20,13 @@ pub use canonicalization::*;
 mod errors;
 pub use errors::*;
 
+mod index_substitution;
+
 mod reconstructing_reducer;
 pub use reconstructing_reducer::*;
 
 mod reconstructing_director;
 pub use reconstructing_director::*;
+
+mod ternary_chain;
+pub use ternary_chain::*;
@@ -63,6 +63,9 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
 
             Expression::ArrayInline(array_inline) => Expression::ArrayInline(self.reduce_array_inline(&array_inline)?),
             Expression::ArrayInit(array_init) => Expression::ArrayInit(self.reduce_array_init(&array_init)?),
+            Expression::ArrayComprehension(array_comprehension) => {
+                Expression::ArrayComprehension(self.reduce_array_comprehension(&array_comprehension)?)
+            }
             Expression::ArrayAccess(array_access) => Expression::ArrayAccess(self.reduce_array_access(&array_access)?),
             Expression::ArrayRangeAccess(array_range_access) => {
                 Expression::ArrayRangeAccess(self.reduce_array_range_access(&array_range_access)?)
@@ -82,6 +85,7 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
             }
 
             Expression::Call(call) => Expression::Call(self.reduce_call(&call)?),
+            Expression::Lambda(lambda) => Expression::Lambda(self.reduce_lambda(&lambda)?),
         };
 
         self.reducer.reduce_expression(expression, new, self.in_circuit)
@@ -177,6 +181,19 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         self.reducer.reduce_array_init(array_init, element, self.in_circuit)
     }
 
+    pub fn reduce_array_comprehension(
+        &mut self,
+        array_comprehension: &ArrayComprehensionExpression,
+    ) -> Result<ArrayComprehensionExpression, CanonicalizeError> {
+        let element = self.reduce_expression(&array_comprehension.element)?;
+        let variable = self.reduce_identifier(&array_comprehension.variable)?;
+        let start = self.reduce_expression(&array_comprehension.start)?;
+        let stop = self.reduce_expression(&array_comprehension.stop)?;
+
+        self.reducer
+            .reduce_array_comprehension(array_comprehension, element, variable, start, stop, self.in_circuit)
+    }
+
     pub fn reduce_array_access(
         &mut self,
         array_access: &ArrayAccessExpression,
@@ -249,6 +266,11 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         circuit_init: &CircuitInitExpression,
     ) -> Result<CircuitInitExpression, CanonicalizeError> {
         let name = self.reduce_identifier(&circuit_init.name)?;
+        let base = circuit_init
+            .base
+            .as_ref()
+            .map(|base| self.reduce_expression(base))
+            .transpose()?;
 
         let mut members = vec![];
         for member in circuit_init.members.iter() {
@@ -256,7 +278,7 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         }
 
         self.reducer
-            .reduce_circuit_init(circuit_init, name, members, self.in_circuit)
+            .reduce_circuit_init(circuit_init, name, base, members, self.in_circuit)
     }
 
     pub fn reduce_circuit_member_access(
@@ -292,15 +314,30 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         self.reducer.reduce_call(call, function, arguments, self.in_circuit)
     }
 
+    pub fn reduce_lambda(&mut self, lambda: &LambdaExpression) -> Result<LambdaExpression, CanonicalizeError> {
+        let mut parameters = vec![];
+        for parameter in lambda.parameters.iter() {
+            parameters.push(self.reduce_identifier(parameter)?);
+        }
+
+        let body = self.reduce_expression(&lambda.body)?;
+
+        self.reducer.reduce_lambda(lambda, parameters, body, self.in_circuit)
+    }
+
     // Statements
     pub fn reduce_statement(&mut self, statement: &Statement) -> Result<Statement, CanonicalizeError> {
         let new = match statement {
             Statement::Return(return_statement) => Statement::Return(self.reduce_return(&return_statement)?),
+            Statement::Break(break_statement) => Statement::Break(break_statement.clone()),
+            Statement::Continue(continue_statement) => Statement::Continue(continue_statement.clone()),
             Statement::Definition(definition) => Statement::Definition(self.reduce_definition(&definition)?),
             Statement::Assign(assign) => Statement::Assign(self.reduce_assign(&assign)?),
             Statement::Conditional(conditional) => Statement::Conditional(self.reduce_conditional(&conditional)?),
             Statement::Iteration(iteration) => Statement::Iteration(self.reduce_iteration(&iteration)?),
             Statement::Console(console) => Statement::Console(self.reduce_console(&console)?),
+            Statement::Require(require) => Statement::Require(self.reduce_require(&require)?),
+            Statement::ConstAssert(const_assert) => Statement::ConstAssert(self.reduce_const_assert(&const_assert)?),
             Statement::Expression(expression) => Statement::Expression(self.reduce_expression_statement(&expression)?),
             Statement::Block(block) => Statement::Block(self.reduce_block(&block)?),
         };
@@ -400,10 +437,15 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         let variable = self.reduce_identifier(&iteration.variable)?;
         let start = self.reduce_expression(&iteration.start)?;
         let stop = self.reduce_expression(&iteration.stop)?;
+        let step = iteration
+            .step
+            .as_ref()
+            .map(|step| self.reduce_expression(step))
+            .transpose()?;
         let block = self.reduce_block(&iteration.block)?;
 
         self.reducer
-            .reduce_iteration(iteration, variable, start, stop, block, self.in_circuit)
+            .reduce_iteration(iteration, variable, start, stop, step, block, self.in_circuit)
     }
 
     pub fn reduce_console(
@@ -412,6 +454,12 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
     ) -> Result<ConsoleStatement, CanonicalizeError> {
         let function = match &console_function_call.function {
             ConsoleFunction::Assert(expression) => ConsoleFunction::Assert(self.reduce_expression(expression)?),
+            ConsoleFunction::AssertEq(left, right) => {
+                ConsoleFunction::AssertEq(self.reduce_expression(left)?, self.reduce_expression(right)?)
+            }
+            ConsoleFunction::AssertNeq(left, right) => {
+                ConsoleFunction::AssertNeq(self.reduce_expression(left)?, self.reduce_expression(right)?)
+            }
             ConsoleFunction::Debug(format) | ConsoleFunction::Error(format) | ConsoleFunction::Log(format) => {
                 let mut parameters = vec![];
                 for parameter in format.parameters.iter() {
@@ -437,6 +485,34 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
             .reduce_console(console_function_call, function, self.in_circuit)
     }
 
+    pub fn reduce_require(&mut self, require: &RequireStatement) -> Result<RequireStatement, CanonicalizeError> {
+        let condition = self.reduce_expression(&require.condition)?;
+
+        let mut parameters = vec![];
+        for parameter in require.message.parameters.iter() {
+            parameters.push(self.reduce_expression(parameter)?);
+        }
+
+        let message = FormatString {
+            parts: require.message.parts.clone(),
+            parameters,
+            span: require.message.span.clone(),
+        };
+
+        self.reducer
+            .reduce_require(require, condition, message, self.in_circuit)
+    }
+
+    pub fn reduce_const_assert(
+        &mut self,
+        const_assert: &ConstAssertStatement,
+    ) -> Result<ConstAssertStatement, CanonicalizeError> {
+        let condition = self.reduce_expression(&const_assert.condition)?;
+
+        self.reducer
+            .reduce_const_assert(const_assert, condition, self.in_circuit)
+    }
+
     pub fn reduce_expression_statement(
         &mut self,
         expression: &ExpressionStatement,
@@ -156,6 +156,24 @@ pub trait ReconstructingReducer {
         })
     }
 
+    fn reduce_array_comprehension(
+        &mut self,
+        array_comprehension: &ArrayComprehensionExpression,
+        element: Expression,
+        variable: Identifier,
+        start: Expression,
+        stop: Expression,
+        _in_circuit: bool,
+    ) -> Result<ArrayComprehensionExpression, CanonicalizeError> {
+        Ok(ArrayComprehensionExpression {
+            element: Box::new(element),
+            variable,
+            start: Box::new(start),
+            stop: Box::new(stop),
+            span: array_comprehension.span.clone(),
+        })
+    }
+
     fn reduce_array_access(
         &mut self,
         array_access: &ArrayAccessExpression,
@@ -182,6 +200,7 @@ pub trait ReconstructingReducer {
             array: Box::new(array),
             left: left.map(|expr| Box::new(expr)),
             right: right.map(|expr| Box::new(expr)),
+            inclusive: array_rage_access.inclusive,
             span: array_rage_access.span.clone(),
         })
     }
@@ -225,11 +244,13 @@ pub trait ReconstructingReducer {
         &mut self,
         circuit_init: &CircuitInitExpression,
         name: Identifier,
+        base: Option<Expression>,
         members: Vec<CircuitImpliedVariableDefinition>,
         _in_circuit: bool,
     ) -> Result<CircuitInitExpression, CanonicalizeError> {
         Ok(CircuitInitExpression {
             name,
+            base: base.map(Box::new),
             members,
             span: circuit_init.span.clone(),
         })
@@ -277,6 +298,20 @@ pub trait ReconstructingReducer {
         })
     }
 
+    fn reduce_lambda(
+        &mut self,
+        lambda: &LambdaExpression,
+        parameters: Vec<Identifier>,
+        body: Expression,
+        _in_circuit: bool,
+    ) -> Result<LambdaExpression, CanonicalizeError> {
+        Ok(LambdaExpression {
+            parameters,
+            body: Box::new(body),
+            span: lambda.span.clone(),
+        })
+    }
+
     // Statements
     fn reduce_statement(
         &mut self,
@@ -382,12 +417,14 @@ pub trait ReconstructingReducer {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn reduce_iteration(
         &mut self,
         iteration: &IterationStatement,
         variable: Identifier,
         start: Expression,
         stop: Expression,
+        step: Option<Expression>,
         block: Block,
         _in_circuit: bool,
     ) -> Result<IterationStatement, CanonicalizeError> {
@@ -395,6 +432,8 @@ pub trait ReconstructingReducer {
             variable,
             start,
             stop,
+            inclusive: iteration.inclusive,
+            step,
             block,
             span: iteration.span.clone(),
         })
@@ -412,6 +451,32 @@ pub trait ReconstructingReducer {
         })
     }
 
+    fn reduce_require(
+        &mut self,
+        require: &RequireStatement,
+        condition: Expression,
+        message: FormatString,
+        _in_circuit: bool,
+    ) -> Result<RequireStatement, CanonicalizeError> {
+        Ok(RequireStatement {
+            condition,
+            message,
+            span: require.span.clone(),
+        })
+    }
+
+    fn reduce_const_assert(
+        &mut self,
+        const_assert: &ConstAssertStatement,
+        condition: Expression,
+        _in_circuit: bool,
+    ) -> Result<ConstAssertStatement, CanonicalizeError> {
+        Ok(ConstAssertStatement {
+            condition,
+            span: const_assert.span.clone(),
+        })
+    }
+
     fn reduce_expression_statement(
         &mut self,
         expression_statement: &ExpressionStatement,
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+/// A chain of ternary expressions nested in their false branches.
+///
+/// `a ? x : b ? y : z` is the chain with arms `(a, x)`, `(b, y)` and the default `z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TernaryChain {
+    pub arms: Vec<(Expression, Expression)>,
+    pub default: Expression,
+}
+
+impl TernaryChain {
+    ///
+    /// Returns the chain starting at `ternary`.
+    ///
+    /// Returns `None` if the false branch of `ternary` is not another ternary expression.
+    ///
+    pub fn from_ternary(ternary: &TernaryExpression) -> Option<Self> {
+        let mut arms = vec![((*ternary.condition).clone(), (*ternary.if_true).clone())];
+        let mut default = &*ternary.if_false;
+
+        while let Expression::Ternary(next) = default {
+            arms.push(((*next.condition).clone(), (*next.if_true).clone()));
+            default = &*next.if_false;
+        }
+
+        if arms.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            arms,
+            default: default.clone(),
+        })
+    }
+}
+
+/// Rewrites nested ternary expressions into chains nested in their false branches.
+/// `a ? (b ? x : y) : z` becomes `!a ? z : b ? x : y`.
+/// Ternaries nesting in both branches are left unchanged.
+#[derive(Default)]
+pub struct TernaryChainNormalizer;
+
+impl TernaryChainNormalizer {
+    fn negate(condition: Expression) -> Expression {
+        match condition {
+            Expression::Unary(UnaryExpression {
+                op: UnaryOperation::Not,
+                inner,
+                ..
+            }) => *inner,
+            condition => Expression::Unary(UnaryExpression {
+                span: condition.span().clone(),
+                inner: Box::new(condition),
+                op: UnaryOperation::Not,
+            }),
+        }
+    }
+}
+
+impl ReconstructingReducer for TernaryChainNormalizer {
+    fn reduce_ternary(
+        &mut self,
+        ternary: &TernaryExpression,
+        condition: Expression,
+        if_true: Expression,
+        if_false: Expression,
+        _in_circuit: bool,
+    ) -> Result<TernaryExpression, CanonicalizeError> {
+        let (condition, if_true, if_false) = match (if_true, if_false) {
+            (if_true @ Expression::Ternary(_), if_false) if !matches!(if_false, Expression::Ternary(_)) => {
+                (Self::negate(condition), if_false, if_true)
+            }
+            (if_true, if_false) => (condition, if_true, if_false),
+        };
+
+        Ok(TernaryExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            span: ternary.span.clone(),
+        })
+    }
+}
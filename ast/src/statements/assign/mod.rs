@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node, Span};
+use crate::{BinaryOperation, Expression, Node, Span};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -41,6 +41,31 @@ pub enum AssignOperation {
     Mod,
 }
 
+impl AssignOperation {
+    ///
+    /// Returns the binary operation applied by a compound assignment, or `None` for `=`.
+    ///
+    pub fn binary_operation(&self) -> Option<BinaryOperation> {
+        Some(match self {
+            AssignOperation::Assign => return None,
+            AssignOperation::Add => BinaryOperation::Add,
+            AssignOperation::Sub => BinaryOperation::Sub,
+            AssignOperation::Mul => BinaryOperation::Mul,
+            AssignOperation::Div => BinaryOperation::Div,
+            AssignOperation::Pow => BinaryOperation::Pow,
+            AssignOperation::Or => BinaryOperation::Or,
+            AssignOperation::And => BinaryOperation::And,
+            AssignOperation::BitOr => BinaryOperation::BitOr,
+            AssignOperation::BitAnd => BinaryOperation::BitAnd,
+            AssignOperation::BitXor => BinaryOperation::BitXor,
+            AssignOperation::Shr => BinaryOperation::Shr,
+            AssignOperation::ShrSigned => BinaryOperation::ShrSigned,
+            AssignOperation::Shl => BinaryOperation::Shl,
+            AssignOperation::Mod => BinaryOperation::Mod,
+        })
+    }
+}
+
 impl AsRef<str> for AssignOperation {
     fn as_ref(&self) -> &'static str {
         match self {
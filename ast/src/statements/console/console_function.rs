@@ -22,6 +22,8 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum ConsoleFunction {
     Assert(Expression),
+    AssertEq(Expression, Expression),
+    AssertNeq(Expression, Expression),
     Debug(FormatString),
     Error(FormatString),
     Log(FormatString),
@@ -31,6 +33,8 @@ impl fmt::Display for ConsoleFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ConsoleFunction::Assert(assert) => write!(f, "assert({})", assert),
+            ConsoleFunction::AssertEq(left, right) => write!(f, "assert_eq({}, {})", left, right),
+            ConsoleFunction::AssertNeq(left, right) => write!(f, "assert_neq({}, {})", left, right),
             ConsoleFunction::Debug(debug) => write!(f, "debug({})", debug),
             ConsoleFunction::Error(error) => write!(f, "error{})", error),
             ConsoleFunction::Log(log) => write!(f, "log({})", log),
@@ -42,6 +46,7 @@ impl Node for ConsoleFunction {
     fn span(&self) -> &Span {
         match self {
             ConsoleFunction::Assert(assert) => assert.span(),
+            ConsoleFunction::AssertEq(left, _) | ConsoleFunction::AssertNeq(left, _) => left.span(),
             ConsoleFunction::Debug(formatted) | ConsoleFunction::Error(formatted) | ConsoleFunction::Log(formatted) => {
                 &formatted.span
             }
@@ -51,6 +56,7 @@ impl Node for ConsoleFunction {
     fn set_span(&mut self, span: Span) {
         match self {
             ConsoleFunction::Assert(assert) => assert.set_span(span),
+            ConsoleFunction::AssertEq(left, _) | ConsoleFunction::AssertNeq(left, _) => left.set_span(span),
             ConsoleFunction::Debug(formatted) | ConsoleFunction::Error(formatted) | ConsoleFunction::Log(formatted) => {
                 formatted.set_span(span)
             }
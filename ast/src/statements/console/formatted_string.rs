@@ -41,8 +41,8 @@ impl fmt::Display for FormatString {
             self.parts
                 .iter()
                 .map(|x| match x {
-                    FormatStringPart::Const(x) => x,
-                    FormatStringPart::Container => "{}",
+                    FormatStringPart::Const(x) => x.replace('{', "{{").replace('}', "}}"),
+                    FormatStringPart::Container => "{}".to_string(),
                 })
                 .collect::<Vec<_>>()
                 .join("")
@@ -24,6 +24,8 @@ pub struct IterationStatement {
     pub variable: Identifier,
     pub start: Expression,
     pub stop: Expression,
+    pub inclusive: bool,
+    pub step: Option<Expression>,
     pub block: Block,
     pub span: Span,
 }
@@ -32,9 +34,16 @@ impl fmt::Display for IterationStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "for {} in {}..{} {}",
-            self.variable, self.start, self.stop, self.block
-        )
+            "for {} in {}{}{}",
+            self.variable,
+            self.start,
+            if self.inclusive { "..=" } else { ".." },
+            self.stop
+        )?;
+        if let Some(step) = &self.step {
+            write!(f, " step {}", step)?;
+        }
+        write!(f, " {}", self.block)
     }
 }
 
@@ -26,6 +26,18 @@ pub use block::*;
 pub mod return_statement;
 pub use return_statement::*;
 
+pub mod break_statement;
+pub use break_statement::*;
+
+pub mod continue_statement;
+pub use continue_statement::*;
+
+pub mod require_statement;
+pub use require_statement::*;
+
+pub mod const_assert_statement;
+pub use const_assert_statement::*;
+
 pub mod iteration;
 pub use iteration::*;
 
@@ -24,11 +24,15 @@ use std::fmt;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Statement {
     Return(ReturnStatement),
+    Break(BreakStatement),
+    Continue(ContinueStatement),
     Definition(DefinitionStatement),
     Assign(AssignStatement),
     Conditional(ConditionalStatement),
     Iteration(IterationStatement),
     Console(ConsoleStatement),
+    Require(RequireStatement),
+    ConstAssert(ConstAssertStatement),
     Expression(ExpressionStatement),
     Block(Block),
 }
@@ -37,11 +41,15 @@ impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Statement::Return(x) => x.fmt(f),
+            Statement::Break(x) => x.fmt(f),
+            Statement::Continue(x) => x.fmt(f),
             Statement::Definition(x) => x.fmt(f),
             Statement::Assign(x) => x.fmt(f),
             Statement::Conditional(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
             Statement::Console(x) => x.fmt(f),
+            Statement::Require(x) => x.fmt(f),
+            Statement::ConstAssert(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Block(x) => x.fmt(f),
         }
@@ -53,11 +61,15 @@ impl Node for Statement {
         use Statement::*;
         match &self {
             Return(n) => n.span(),
+            Break(n) => n.span(),
+            Continue(n) => n.span(),
             Definition(n) => n.span(),
             Assign(n) => n.span(),
             Conditional(n) => n.span(),
             Iteration(n) => n.span(),
             Console(n) => n.span(),
+            Require(n) => n.span(),
+            ConstAssert(n) => n.span(),
             Expression(n) => n.span(),
             Block(n) => n.span(),
         }
@@ -67,11 +79,15 @@ impl Node for Statement {
         use Statement::*;
         match self {
             Return(n) => n.set_span(span),
+            Break(n) => n.set_span(span),
+            Continue(n) => n.set_span(span),
             Definition(n) => n.set_span(span),
             Assign(n) => n.set_span(span),
             Conditional(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
             Console(n) => n.set_span(span),
+            Require(n) => n.set_span(span),
+            ConstAssert(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Block(n) => n.set_span(span),
         }
@@ -25,8 +25,19 @@ use crate::{
     OutputFile,
 };
 pub use leo_asg::{new_context, AsgContext as Context, AsgContext};
-use leo_asg::{Asg, AsgPass, FormattedError, Program as AsgProgram};
-use leo_ast::{Input, MainInput, Program as AstProgram};
+use leo_asg::{Asg, AsgPass, CallGraph, FormattedError, Program as AsgProgram};
+use leo_ast::{
+    AstDiff,
+    CircuitMember,
+    FunctionInput,
+    Input,
+    InputMismatch,
+    InputValidator,
+    InputValue,
+    MainInput,
+    Parameter,
+    Program as AstProgram,
+};
 use leo_input::LeoInputParser;
 use leo_package::inputs::InputPairs;
 use leo_parser::parse_ast;
@@ -60,12 +71,15 @@ pub fn thread_leaked_context() -> AsgContext<'static> {
 pub struct Compiler<'a, F: PrimeField, G: GroupType<F>> {
     program_name: String,
     main_file_path: PathBuf,
+    main_source: Option<String>,
     output_directory: PathBuf,
     program: AstProgram,
     program_input: Input,
     context: AsgContext<'a>,
     asg: Option<AsgProgram<'a>>,
     options: CompilerOptions,
+    warnings: Vec<FormattedError>,
+    call_graph: CallGraph,
     _engine: PhantomData<F>,
     _group: PhantomData<G>,
 }
@@ -83,12 +97,15 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         Self {
             program_name: package_name.clone(),
             main_file_path,
+            main_source: None,
             output_directory,
             program: AstProgram::new(package_name),
             program_input: Input::new(),
             asg: None,
             context,
             options: CompilerOptions::default(),
+            warnings: vec![],
+            call_graph: CallGraph::default(),
             _engine: PhantomData,
             _group: PhantomData,
         }
@@ -114,6 +131,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         Ok(compiler)
     }
 
+    ///
+    /// Returns the abstract syntax tree of the parsed main program.
+    ///
+    pub fn ast(&self) -> &AstProgram {
+        &self.program
+    }
+
     pub fn set_options(&mut self, options: CompilerOptions) {
         self.options = options;
     }
@@ -208,6 +232,17 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         self.parse_program_from_string(&content)
     }
 
+    ///
+    /// Returns the changes that canonicalization makes to the syntax tree of the main program file.
+    ///
+    pub fn canonicalization_diff(&self) -> Result<Vec<AstDiff>, CompilerError> {
+        let content = self.main_source()?;
+
+        let ast = parse_ast(self.main_file_path.to_str().unwrap_or_default(), &content)?;
+
+        Ok(ast.canonicalization_diff()?)
+    }
+
     ///
     /// Equivalent to parse_and_check_program but uses the given program_string instead of a main
     /// file path.
@@ -216,6 +251,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
         // Use the parser to construct the abstract syntax tree (ast).
 
         let mut ast = parse_ast(self.main_file_path.to_str().unwrap_or_default(), program_string)?;
+        self.main_source = Some(program_string.to_string());
+
         // Preform compiler optimization via canonicalizing AST if its enabled.
         if self.options.canonicalization_enabled {
             ast.canonicalize()?;
@@ -227,31 +264,141 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
 
         tracing::debug!("Program parsing complete\n{:#?}", self.program);
 
+        // Read the imported packages in parallel before resolving them.
+        let mut import_parser = leo_imports::ImportParser::new(self.options.offline);
+        import_parser.prefetch(&self.program);
+
         // Create a new symbol table from the program, imported_programs, and program_input.
-        let asg = Asg::new(self.context, &self.program, &mut leo_imports::ImportParser::default())?;
+        let asg = Asg::new(self.context, &self.program, &mut import_parser)?;
 
         tracing::debug!("ASG generation complete");
 
         // Store the ASG.
         self.asg = Some(asg.into_repr());
 
+        self.collect_warnings()?;
+
+        // Build the call graph before function inlining removes calls.
+        self.call_graph = CallGraph::new(self.asg.as_ref().unwrap());
+
         self.do_asg_passes().map_err(CompilerError::AsgPassError)?;
 
         Ok(())
     }
 
+    ///
+    /// Returns the warnings emitted while compiling the program.
+    ///
+    pub fn warnings(&self) -> &[FormattedError] {
+        &self.warnings
+    }
+
+    ///
+    /// Returns the differences between the parsed program input and the parameters of `main`,
+    /// or `None` if the program has no `main` function.
+    ///
+    pub fn verify_input(&self) -> Option<Vec<InputMismatch>> {
+        InputValidator::new(&self.program).validate(&self.program_input)
+    }
+
+    ///
+    /// Returns the public inputs a verifier needs, with their values, in the order they are allocated.
+    ///
+    /// The public input section values come first, followed by the `public` parameters of `main`.
+    ///
+    pub fn public_inputs(&self) -> Vec<(Parameter, Option<InputValue>)> {
+        let mut public_inputs = self.program_input.public_section_inputs();
+
+        let main = self
+            .program
+            .functions
+            .values()
+            .find(|function| function.get_name() == "main");
+
+        if let Some(main) = main {
+            for input in main.input.iter() {
+                let variable = match input {
+                    FunctionInput::Variable(variable) if !variable.const_ => variable,
+                    _ => continue,
+                };
+                let name = variable.identifier.name.as_ref();
+                let parameter = self
+                    .program_input
+                    .public_main_parameters()
+                    .iter()
+                    .find(|parameter| parameter.variable.name.as_ref() == name);
+
+                if let Some(parameter) = parameter {
+                    public_inputs.push((parameter.clone(), self.program_input.get(name).flatten()));
+                }
+            }
+        }
+
+        public_inputs
+    }
+
+    ///
+    /// Returns the calls between the functions and circuit methods of the main program.
+    ///
+    pub fn call_graph(&self) -> &CallGraph {
+        &self.call_graph
+    }
+
+    ///
+    /// Runs the lints on the program in asg format, before any pass rewrites it.
+    ///
+    /// Returns an error if any warning is emitted while warnings are denied.
+    ///
+    fn collect_warnings(&mut self) -> Result<(), CompilerError> {
+        self.warnings = leo_asg::UnusedVariables::check(self.asg.as_ref().unwrap());
+
+        for warning in self.warnings.iter() {
+            tracing::warn!("{}", warning);
+        }
+
+        if self.options.deny_warnings && !self.warnings.is_empty() {
+            return Err(CompilerError::DeniedWarnings(self.warnings.len()));
+        }
+
+        Ok(())
+    }
+
     ///
     /// Run compiler optimization passes on the program in asg format.
     ///
     fn do_asg_passes(&mut self) -> Result<(), FormattedError> {
         assert!(self.asg.is_some());
 
+        // Reject lossy implicit conversions before they are folded away.
+        if self.options.strict {
+            let asg = self.asg.take().unwrap();
+            self.asg = Some(leo_asg_passes::StrictConversions::do_pass(asg)?);
+        }
+
+        // Strip debug statements in release mode, before any pass can evaluate them.
+        if self.options.release {
+            let asg = self.asg.take().unwrap();
+            self.asg = Some(leo_asg_passes::DebugElimination::do_pass(asg)?);
+        }
+
+        // Inline small functions, so that constant folding can see through the calls.
+        if self.options.function_inlining_enabled {
+            let asg = self.asg.take().unwrap();
+            self.asg = Some(leo_asg_passes::FunctionInlining::do_pass(asg)?);
+        }
+
         // Do constant folding.
         if self.options.constant_folding_enabled {
             let asg = self.asg.take().unwrap();
             self.asg = Some(leo_asg_passes::ConstantFolding::do_pass(asg)?);
         }
 
+        // Hoist loop-invariant expressions, after constant folding has resolved the loop bounds.
+        if self.options.loop_optimization_enabled {
+            let asg = self.asg.take().unwrap();
+            self.asg = Some(leo_asg_passes::LoopInvariantHoisting::do_pass(asg)?);
+        }
+
         // Do dead code elimination.
         if self.options.dead_code_elimination_enabled {
             let asg = self.asg.take().unwrap();
@@ -265,23 +412,91 @@ impl<'a, F: PrimeField, G: GroupType<F>> Compiler<'a, F, G> {
     /// Synthesizes the circuit with program input to verify correctness.
     ///
     pub fn compile_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<OutputBytes, CompilerError> {
-        generate_constraints::<F, G, CS>(cs, &self.asg.as_ref().unwrap(), &self.program_input)
+        generate_constraints::<F, G, CS>(
+            cs,
+            &self.asg.as_ref().unwrap(),
+            &self.program_input,
+            self.options.overflow_behavior(),
+        )
+    }
+
+    ///
+    /// Returns an error if a function annotated with `@max_constraints(n)` has more than `n`
+    /// constraints attributed to it, given the number of constraints attributed to each function.
+    ///
+    pub fn check_constraint_budgets(&self, constraints_per_function: &[(String, usize)]) -> Result<(), CompilerError> {
+        let methods = self.program.circuits.values().flat_map(|circuit| {
+            circuit.members.iter().filter_map(|member| match member {
+                CircuitMember::CircuitFunction(function) => Some(function),
+                CircuitMember::CircuitVariable(..) => None,
+            })
+        });
+
+        for function in self.program.functions.values().chain(methods) {
+            let name = function.identifier.name.as_ref();
+            for annotation in function.annotations.iter() {
+                if annotation.name.name.as_ref() != "max_constraints" {
+                    continue;
+                }
+                let budget = match &annotation.arguments[..] {
+                    [budget] => budget.parse::<usize>().ok(),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    CompilerError::ConstraintBudgetError(FormattedError::new_from_span(
+                        "expected a single constraint count, e.g. `@max_constraints(1000)`".to_string(),
+                        &annotation.span,
+                    ))
+                })?;
+
+                let count = constraints_per_function
+                    .iter()
+                    .find(|(function, _)| function == name)
+                    .map(|(_, count)| *count)
+                    .unwrap_or(0);
+                if count > budget {
+                    return Err(CompilerError::ConstraintBudgetError(FormattedError::new_from_span(
+                        format!(
+                            "function `{}` has {} constraints, exceeding its budget of {}",
+                            name, count, budget
+                        ),
+                        &annotation.span,
+                    )));
+                }
+            }
+        }
+
+        Ok(())
     }
 
     ///
     /// Synthesizes the circuit for test functions with program input.
     ///
     pub fn compile_test_constraints(self, input_pairs: InputPairs) -> Result<(u32, u32), CompilerError> {
-        generate_test_constraints::<F, G>(&self.asg.as_ref().unwrap(), input_pairs, &self.output_directory)
+        generate_test_constraints::<F, G>(
+            &self.asg.as_ref().unwrap(),
+            input_pairs,
+            &self.output_directory,
+            self.options.overflow_behavior(),
+        )
+    }
+
+    ///
+    /// Returns the source of the main program, which may not have been read from the main file path.
+    ///
+    fn main_source(&self) -> Result<String, CompilerError> {
+        match &self.main_source {
+            Some(source) => Ok(source.clone()),
+            None => fs::read_to_string(&self.main_file_path)
+                .map_err(|e| CompilerError::FileReadError(self.main_file_path.clone(), e)),
+        }
     }
 
     ///
     /// Returns a SHA256 checksum of the program file.
     ///
     pub fn checksum(&self) -> Result<String, CompilerError> {
-        // Read in the main file as string
-        let unparsed_file = fs::read_to_string(&self.main_file_path)
-            .map_err(|e| CompilerError::FileReadError(self.main_file_path.clone(), e))?;
+        let unparsed_file = self.main_source()?;
 
         // Hash the file contents
         let mut hasher = Sha256::new();
@@ -18,6 +18,7 @@
 
 use crate::{
     errors::ConsoleError,
+    evaluate_eq,
     get_indicator_value,
     program::ConstrainedProgram,
     value::ConstrainedValue,
@@ -61,4 +62,44 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
         Ok(())
     }
+
+    ///
+    /// Enforces a `console.assert_eq` (`equal == true`) or `console.assert_neq` (`equal == false`)
+    /// statement, reporting both values when the assertion fails.
+    ///
+    pub fn evaluate_console_assert_eq<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        left: &'a Expression<'a>,
+        right: &'a Expression<'a>,
+        equal: bool,
+        span: &Span,
+    ) -> Result<(), ConsoleError> {
+        // Evaluate both sides of the assertion
+        let left = self.enforce_expression(cs, left)?;
+        let right = self.enforce_expression(cs, right)?;
+
+        // If the indicator bit is false, do not evaluate the assertion
+        // This is okay since we are not enforcing any constraints
+        if !get_indicator_value(indicator) {
+            return Ok(()); // Continue execution.
+        }
+
+        let (left_string, right_string) = (left.to_string(), right.to_string());
+
+        let result_option = match evaluate_eq(cs, left, right, span)? {
+            ConstrainedValue::Boolean(boolean) => boolean.get_value(),
+            _ => {
+                return Err(ConsoleError::assertion_must_be_boolean(span));
+            }
+        };
+        let result_bool = result_option.ok_or_else(|| ConsoleError::assertion_depends_on_input(span))?;
+
+        match (equal, result_bool) {
+            (true, false) => Err(ConsoleError::assert_eq_failed(left_string, right_string, span)),
+            (false, true) => Err(ConsoleError::assert_neq_failed(left_string, right_string, span)),
+            _ => Ok(()),
+        }
+    }
 }
@@ -39,6 +39,26 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     &console.span.clone().unwrap_or_default(),
                 )?;
             }
+            ConsoleFunction::AssertEq(left, right) => {
+                self.evaluate_console_assert_eq(
+                    cs,
+                    indicator,
+                    left.get(),
+                    right.get(),
+                    true,
+                    &console.span.clone().unwrap_or_default(),
+                )?;
+            }
+            ConsoleFunction::AssertNeq(left, right) => {
+                self.evaluate_console_assert_eq(
+                    cs,
+                    indicator,
+                    left.get(),
+                    right.get(),
+                    false,
+                    &console.span.clone().unwrap_or_default(),
+                )?;
+            }
             ConsoleFunction::Debug(string) => {
                 let string = self.format(cs, string)?;
 
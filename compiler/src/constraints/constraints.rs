@@ -16,7 +16,15 @@
 
 //! Generates R1CS constraints for a compiled Leo program.
 
-use crate::{errors::CompilerError, ConstrainedProgram, GroupType, OutputBytes, OutputFile};
+use crate::{
+    errors::CompilerError,
+    reset_namespace_names,
+    ConstrainedProgram,
+    GroupType,
+    OutputBytes,
+    OutputFile,
+    OverflowBehavior,
+};
 use leo_asg::Program;
 use leo_ast::Input;
 use leo_input::LeoInputParser;
@@ -30,8 +38,11 @@ pub fn generate_constraints<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSy
     cs: &mut CS,
     program: &Program<'a>,
     input: &Input,
+    overflow: OverflowBehavior,
 ) -> Result<OutputBytes, CompilerError> {
+    reset_namespace_names();
     let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone());
+    resolved_program.overflow = overflow;
 
     let main = {
         let program = program;
@@ -51,8 +62,11 @@ pub fn generate_test_constraints<'a, F: PrimeField, G: GroupType<F>>(
     program: &Program<'a>,
     input: InputPairs,
     output_directory: &Path,
+    overflow: OverflowBehavior,
 ) -> Result<(u32, u32), CompilerError> {
+    reset_namespace_names();
     let mut resolved_program = ConstrainedProgram::<F, G>::new(program.clone());
+    resolved_program.overflow = overflow;
     let program_name = program.name.clone();
 
     // Get default input
@@ -57,6 +57,12 @@ pub enum CompilerError {
 
     #[error("{}", _0)]
     CanonicalizeError(#[from] CanonicalizeError),
+
+    #[error("Aborting due to {} warning(s), warnings are denied", _0)]
+    DeniedWarnings(usize),
+
+    #[error("{}", _0)]
+    ConstraintBudgetError(FormattedError),
 }
 
 impl LeoError for CompilerError {}
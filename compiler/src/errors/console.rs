@@ -56,6 +56,18 @@ impl ConsoleError {
         Self::new_from_span(message, span)
     }
 
+    pub fn assert_eq_failed(left: String, right: String, span: &Span) -> Self {
+        let message = format!("Assertion failed: `{}` does not equal `{}`", left, right);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn assert_neq_failed(left: String, right: String, span: &Span) -> Self {
+        let message = format!("Assertion failed: `{}` equals `{}`", left, right);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn assertion_must_be_boolean(span: &Span) -> Self {
         let message = "Assertion expression must evaluate to a boolean value".to_string();
 
@@ -67,6 +67,18 @@ impl ExpressionError {
         Self::new_from_span(message, span)
     }
 
+    pub fn invalid_bool_cast(value: String, span: &Span) -> Self {
+        let message = format!("cannot cast `{}` to bool, expected `0` or `1`", value);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn unsupported_cast(value: String, target_type: String, span: &Span) -> Self {
+        let message = format!("casting `{}` to `{}` is not supported", value, target_type);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn array_length_out_of_bounds(span: &Span) -> Self {
         let message = "array length cannot be >= 2^32".to_string();
 
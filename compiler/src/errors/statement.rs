@@ -82,6 +82,15 @@ impl StatementError {
         Self::new_from_span(message, span)
     }
 
+    pub fn array_assign_range_length(expected: usize, actual: usize, span: &Span) -> Self {
+        let message = format!(
+            "Cannot assign array of length `{}` to array range of length `{}`",
+            actual, expected
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn array_assign_range_order(start: usize, stop: usize, length: usize, span: &Span) -> Self {
         let message = format!(
             "Array assign range `{}`..`{}` out of range for array of length `{}`",
@@ -146,6 +155,33 @@ impl StatementError {
         Self::new_from_span(message, span)
     }
 
+    pub fn const_assert_failed(condition: String, span: &Span) -> Self {
+        let message = format!("const assertion failed: `{}`", condition);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn const_assert_nonconst(actual: String, span: &Span) -> Self {
+        let message = format!(
+            "const_assert condition must resolve to a constant boolean, found `{}`",
+            actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn require_boolean(actual: String, span: &Span) -> Self {
+        let message = format!("require condition must resolve to a boolean, found `{}`", actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn require_failed(message: String, span: &Span) -> Self {
+        let message = format!("Requirement failed: {}", message);
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn tuple_assign_index(span: &Span) -> Self {
         let message = "Cannot assign single index to tuple of values".to_string();
 
@@ -190,4 +226,10 @@ impl StatementError {
 
         Self::new_from_span(message, span)
     }
+
+    pub fn loop_step_zero(span: &Span) -> Self {
+        let message = "iteration step must be a positive constant".to_string();
+
+        Self::new_from_span(message, span)
+    }
 }
@@ -36,6 +36,15 @@ impl FieldError {
         Self::new_from_span(message, span)
     }
 
+    pub fn unary_operation(operation: String, error: SynthesisError, span: &Span) -> Self {
+        let message = format!(
+            "the field operation `{}` failed due to synthesis error `{:?}`",
+            operation, error,
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn binary_operation(operation: String, error: SynthesisError, span: &Span) -> Self {
         let message = format!(
             "the field binary operation `{}` failed due to synthesis error `{:?}`",
@@ -62,4 +71,16 @@ impl FieldError {
 
         Self::new_from_span(message, span)
     }
+
+    pub fn invalid_bit_length(expected: usize, actual: usize, span: &Span) -> Self {
+        let message = format!("expected {} bits for a field element, found {}", expected, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn no_square_root(field: String, span: &Span) -> Self {
+        let message = format!("no square root found for field `{}`", field);
+
+        Self::new_from_span(message, span)
+    }
 }
@@ -98,4 +98,13 @@ impl GroupError {
 
         Self::new_from_span(message, span)
     }
+
+    pub fn hash_input_too_long(max_bytes: usize, actual: usize, span: &Span) -> Self {
+        let message = format!(
+            "hash input of {} bytes exceeds the maximum of {} bytes",
+            actual, max_bytes
+        );
+
+        Self::new_from_span(message, span)
+    }
 }
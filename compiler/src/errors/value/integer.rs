@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{FormattedError, LeoError, Span};
+use leo_ast::{FormattedError, IntegerType, LeoError, Span};
 
 use snarkvm_gadgets::errors::{SignedIntegerError, UnsignedIntegerError};
 use snarkvm_r1cs::SynthesisError;
@@ -74,6 +74,36 @@ impl IntegerError {
         Self::new_from_span(message, span)
     }
 
+    pub fn invalid_integer_input(parameter: &str, actual: String, span: &Span) -> Self {
+        let message = format!("failed to parse `{}` as integer input `{}`", actual, parameter);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_integer_type(expected: &IntegerType, actual: &IntegerType, span: &Span) -> Self {
+        let message = format!("expected integer input of type `{}`, found `{}`", expected, actual);
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn invalid_byte_length(integer_type: &IntegerType, expected: usize, actual: usize, span: &Span) -> Self {
+        let message = format!(
+            "expected {} bytes to construct an integer of type `{}`, found {}",
+            expected, integer_type, actual
+        );
+
+        Self::new_from_span(message, span)
+    }
+
+    pub fn inverted_clamp_bounds(min: String, max: String, span: &Span) -> Self {
+        let message = format!(
+            "clamp bounds are inverted, the minimum `{}` is greater than the maximum `{}`",
+            min, max
+        );
+
+        Self::new_from_span(message, span)
+    }
+
     pub fn missing_integer(expected: String, span: &Span) -> Self {
         let message = format!("expected integer input `{}` not found", expected);
 
@@ -85,4 +115,13 @@ impl IntegerError {
 
         Self::new_from_span(message, span)
     }
+
+    pub fn constant_overflow(left: String, operation: &str, right: String, span: &Span) -> Self {
+        let message = format!(
+            "constant operation `{} {} {}` overflows its integer type",
+            left, operation, right
+        );
+
+        Self::new_from_span(message, span)
+    }
 }
@@ -44,3 +44,20 @@ pub fn enforce_add<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
         )),
     }
 }
+
+///
+/// Enforces `left + right`, wrapping integer overflow around instead of failing.
+///
+pub fn enforce_wrapping_add<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    left: ConstrainedValue<'a, F, G>,
+    right: ConstrainedValue<'a, F, G>,
+    span: &Span,
+) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+    match (left, right) {
+        (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
+            Ok(ConstrainedValue::Integer(num_1.wrapping_add(cs, num_2, span)?))
+        }
+        (left, right) => enforce_add(cs, left, right, span),
+    }
+}
@@ -16,7 +16,7 @@
 
 //! Enforces an arithmetic `/` operator in a resolved Leo program.
 
-use crate::{errors::ExpressionError, value::ConstrainedValue, GroupType};
+use crate::{errors::ExpressionError, value::ConstrainedValue, AllocatedConstants, GroupType};
 use leo_ast::Span;
 
 use snarkvm_fields::PrimeField;
@@ -27,10 +27,21 @@ pub fn enforce_div<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     left: ConstrainedValue<'a, F, G>,
     right: ConstrainedValue<'a, F, G>,
     span: &Span,
+) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+    enforce_div_with_constants(cs, left, right, &AllocatedConstants::default(), span)
+}
+
+/// Enforces `/`, taking the allocated constants of integer division from `constants`.
+pub(crate) fn enforce_div_with_constants<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    left: ConstrainedValue<'a, F, G>,
+    right: ConstrainedValue<'a, F, G>,
+    constants: &AllocatedConstants,
+    span: &Span,
 ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
     match (left, right) {
         (ConstrainedValue::Integer(num_1), ConstrainedValue::Integer(num_2)) => {
-            Ok(ConstrainedValue::Integer(num_1.div(cs, num_2, span)?))
+            Ok(ConstrainedValue::Integer(num_1.div_with_constants(cs, num_2, constants, span)?))
         }
         (ConstrainedValue::Field(field_1), ConstrainedValue::Field(field_2)) => {
             Ok(ConstrainedValue::Field(field_1.div(cs, &field_2, span)?))
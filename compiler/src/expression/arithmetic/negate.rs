@@ -34,3 +34,17 @@ pub fn enforce_negate<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F
         value => Err(ExpressionError::incompatible_types(format!("-{}", value), span)),
     }
 }
+
+///
+/// Enforces `-value`, wrapping integer overflow around instead of failing.
+///
+pub fn enforce_wrapping_negate<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    value: ConstrainedValue<'a, F, G>,
+    span: &Span,
+) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+    match value {
+        ConstrainedValue::Integer(integer) => Ok(ConstrainedValue::Integer(integer.wrapping_negate(cs, span)?)),
+        value => enforce_negate(cs, value, span),
+    }
+}
@@ -23,17 +23,13 @@ use crate::{
     errors::ExpressionError,
     program::ConstrainedProgram,
     relational::*,
-    value::{ConstrainedValue, Integer},
+    value::{ArrayValue, ConstrainedValue, Integer},
     GroupType,
 };
 use leo_asg::{ConstInt, Expression, Span};
 
 use snarkvm_fields::PrimeField;
-use snarkvm_gadgets::utilities::{
-    boolean::Boolean,
-    eq::{EqGadget, EvaluateEqGadget},
-    select::CondSelectGadget,
-};
+use snarkvm_gadgets::utilities::{eq::EqGadget, select::CondSelectGadget};
 use snarkvm_r1cs::ConstraintSystem;
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
@@ -45,65 +41,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         index: &'a Expression<'a>,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
-        let mut array = match self.enforce_expression(cs, array)? {
-            ConstrainedValue::Array(array) => array,
+        let array = match self.enforce_expression(cs, array)? {
+            ConstrainedValue::Array(array) => ArrayValue::new(array),
             value => return Err(ExpressionError::undefined_array(value.to_string(), span)),
         };
 
         let index_resolved = self.enforce_index(cs, index, span)?;
-        if let Some(resolved) = index_resolved.to_usize() {
-            if resolved >= array.len() {
-                return Err(ExpressionError::array_index_out_of_bounds(resolved, span));
-            }
-            Ok(array[resolved].to_owned())
-        } else {
-            if array.is_empty() {
-                return Err(ExpressionError::array_index_out_of_bounds(0, span));
-            }
-            {
-                let array_len: u32 = array
-                    .len()
-                    .try_into()
-                    .map_err(|_| ExpressionError::array_length_out_of_bounds(span))?;
-                let bounds_check = evaluate_lt::<F, G, CS>(
-                    cs,
-                    ConstrainedValue::Integer(index_resolved.clone()),
-                    ConstrainedValue::Integer(Integer::new(&ConstInt::U32(array_len))),
-                    span,
-                )?;
-                let bounds_check = match bounds_check {
-                    ConstrainedValue::Boolean(b) => b,
-                    _ => unimplemented!("illegal non-Integer returned from lt"),
-                };
-                let namespace_string = format!("evaluate array access bounds {}:{}", span.line_start, span.col_start);
-                let mut unique_namespace = cs.ns(|| namespace_string);
-                bounds_check
-                    .enforce_equal(&mut unique_namespace, &Boolean::Constant(true))
-                    .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
-            }
-
-            let mut current_value = array.pop().unwrap();
-            for (i, item) in array.into_iter().enumerate() {
-                let namespace_string = format!("evaluate array access eq {} {}:{}", i, span.line_start, span.col_start);
-                let eq_namespace = cs.ns(|| namespace_string);
-
-                let index_bounded = i
-                    .try_into()
-                    .map_err(|_| ExpressionError::array_index_out_of_legal_bounds(span))?;
-                let const_index = ConstInt::U32(index_bounded).cast_to(&index_resolved.get_type());
-                let index_comparison = index_resolved
-                    .evaluate_equal(eq_namespace, &Integer::new(&const_index))
-                    .map_err(|_| ExpressionError::cannot_evaluate("==".to_string(), span))?;
-
-                let unique_namespace =
-                    cs.ns(|| format!("select array access {} {}:{}", i, span.line_start, span.col_start));
-                let value =
-                    ConstrainedValue::conditionally_select(unique_namespace, &index_comparison, &item, &current_value)
-                        .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span))?;
-                current_value = value;
-            }
-            Ok(current_value)
-        }
+        array.get(cs, &index_resolved, span)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -113,11 +57,12 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         array: &'a Expression<'a>,
         left: Option<&'a Expression<'a>>,
         right: Option<&'a Expression<'a>>,
+        inclusive: bool,
         length: usize,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
         let array = match self.enforce_expression(cs, array)? {
-            ConstrainedValue::Array(array) => array,
+            ConstrainedValue::Array(array) => ArrayValue::new(array),
             value => return Err(ExpressionError::undefined_array(value.to_string(), span)),
         };
 
@@ -126,6 +71,19 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             None => Integer::new(&ConstInt::U32(0)), // Array slice starts at index 0
         };
         let to_resolved = match right {
+            Some(to_index) if inclusive => {
+                // Inclusive slices end one past the given index.
+                let to_index = self.enforce_index(cs, to_index, span)?;
+                match enforce_add::<F, G, _>(
+                    cs,
+                    ConstrainedValue::Integer(to_index),
+                    ConstrainedValue::Integer(Integer::new(&ConstInt::U32(1))),
+                    span,
+                )? {
+                    ConstrainedValue::Integer(i) => i,
+                    _ => unimplemented!("illegal non-Integer returned from add"),
+                }
+            }
             Some(to_index) => self.enforce_index(cs, to_index, span)?,
             None => {
                 let index_bounded: u32 = array
@@ -148,7 +106,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             if right > array.len() {
                 return Err(ExpressionError::array_index_out_of_bounds(right, span));
             }
-            ConstrainedValue::Array(array[left..right].to_owned())
+            ConstrainedValue::Array(array.values()[left..right].to_owned())
         } else {
             {
                 let calc_len = enforce_sub::<F, G, _>(
@@ -170,27 +128,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     .enforce_equal(&mut unique_namespace, &Integer::new(&ConstInt::U32(length as u32)))
                     .map_err(|e| ExpressionError::cannot_enforce("array length check".to_string(), e, span))?;
             }
-            {
-                let bounds_check = evaluate_le::<F, G, _>(
-                    cs,
-                    ConstrainedValue::Integer(to_resolved),
-                    ConstrainedValue::Integer(Integer::new(&ConstInt::U32(array.len() as u32))),
-                    span,
-                )?;
-                let bounds_check = match bounds_check {
-                    ConstrainedValue::Boolean(b) => b,
-                    _ => unimplemented!("illegal non-Integer returned from le"),
-                };
-                let namespace_string = format!(
-                    "evaluate array range access bounds {}:{}",
-                    span.line_start, span.col_start
-                );
-                let mut unique_namespace = cs.ns(|| namespace_string);
-                bounds_check
-                    .enforce_equal(&mut unique_namespace, &Boolean::Constant(true))
-                    .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
-            }
-            let mut windows = array.windows(length);
+            array.enforce_bound(cs, &to_resolved, true, span)?;
+            let mut windows = array.values().windows(length);
             let mut result = ConstrainedValue::Array(vec![]);
 
             for i in 0..length {
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an array fold in a compiled Leo program.
+
+use crate::{errors::ExpressionError, namespace_name, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use leo_asg::{ArrayFoldExpression, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Returns the accumulator after applying the body of the fold to each element of the array in turn.
+    ///
+    /// The array and initial value are evaluated once, and the body once per element.
+    ///
+    pub fn enforce_array_fold<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        fold: &'a ArrayFoldExpression<'a>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let elements = match self.enforce_expression(cs, fold.array.get())? {
+            ConstrainedValue::Array(elements) => elements,
+            value => return Err(ExpressionError::undefined_array(value.to_string(), span)),
+        };
+
+        let mut accumulator = self.enforce_expression(cs, fold.initial.get())?;
+        for (i, element) in elements.into_iter().enumerate() {
+            self.store(fold.accumulator.borrow().id, accumulator);
+            self.store(fold.element.borrow().id, element);
+
+            accumulator = self.enforce_expression(
+                &mut cs.ns(|| namespace_name(format!("fold element {}", i), span)),
+                fold.body.get(),
+            )?;
+        }
+
+        Ok(accumulator)
+    }
+}
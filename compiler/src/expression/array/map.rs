@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an array map in a compiled Leo program.
+
+use crate::{errors::ExpressionError, namespace_name, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use leo_asg::{ArrayMapExpression, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Returns the array of the results of applying the body of the map to each element of the array.
+    ///
+    /// The array is evaluated once, and the body once per element.
+    ///
+    pub fn enforce_array_map<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        map: &'a ArrayMapExpression<'a>,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let elements = match self.enforce_expression(cs, map.array.get())? {
+            ConstrainedValue::Array(elements) => elements,
+            value => return Err(ExpressionError::undefined_array(value.to_string(), span)),
+        };
+
+        let mut result = Vec::with_capacity(elements.len());
+        for (i, element) in elements.into_iter().enumerate() {
+            self.store(map.element.borrow().id, element);
+
+            result.push(self.enforce_expression(
+                &mut cs.ns(|| namespace_name(format!("map element {}", i), span)),
+                map.body.get(),
+            )?);
+        }
+
+        Ok(ConstrainedValue::Array(result))
+    }
+}
@@ -22,5 +22,11 @@ pub use self::array::*;
 pub mod access;
 pub use self::access::*;
 
+pub mod fold;
+pub use self::fold::*;
+
+pub mod map;
+pub use self::map::*;
+
 pub mod index;
 pub use self::index::*;
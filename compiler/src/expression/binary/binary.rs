@@ -16,8 +16,18 @@
 
 //! Enforces a binary expression in a compiled Leo program.
 
-use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
-use leo_asg::Expression;
+use crate::{
+    arithmetic::*,
+    errors::ExpressionError,
+    logical::*,
+    program::ConstrainedProgram,
+    relational::*,
+    value::ConstrainedValue,
+    AllocatedConstants,
+    GroupType,
+    OverflowBehavior,
+};
+use leo_asg::{BinaryOperation, Expression, Node, Span};
 
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::ConstraintSystem;
@@ -32,16 +42,67 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         left: &'a Expression<'a>,
         right: &'a Expression<'a>,
     ) -> Result<ConstrainedValuePair<'a, F, G>, ExpressionError> {
+        // Sibling binary expressions share a parent namespace, so the operands are named by position.
         let resolved_left = {
-            let mut left_namespace = cs.ns(|| "left".to_string());
+            let span = left.span().cloned().unwrap_or_default();
+            let mut left_namespace = cs.ns(|| format!("left {}:{}", span.line_start, span.col_start));
             self.enforce_expression(&mut left_namespace, left)?
         };
 
         let resolved_right = {
-            let mut right_namespace = cs.ns(|| "right".to_string());
+            let span = right.span().cloned().unwrap_or_default();
+            let mut right_namespace = cs.ns(|| format!("right {}:{}", span.line_start, span.col_start));
             self.enforce_expression(&mut right_namespace, right)?
         };
 
         Ok((resolved_left, resolved_right))
     }
+
+    ///
+    /// Enforces `operation` on resolved operands.
+    ///
+    /// Takes the program state it needs as arguments, so assignments can apply a compound
+    /// operation while they hold references into the program.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn enforce_binary_operation<CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        operation: &BinaryOperation,
+        left: ConstrainedValue<'a, F, G>,
+        right: ConstrainedValue<'a, F, G>,
+        overflow: OverflowBehavior,
+        constants: &AllocatedConstants,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        match operation {
+            BinaryOperation::Add => match overflow {
+                OverflowBehavior::Checked => enforce_add(cs, left, right, span),
+                OverflowBehavior::Wrapping => enforce_wrapping_add(cs, left, right, span),
+            },
+            BinaryOperation::Sub => match overflow {
+                OverflowBehavior::Checked => enforce_sub(cs, left, right, span),
+                OverflowBehavior::Wrapping => enforce_wrapping_sub(cs, left, right, span),
+            },
+            BinaryOperation::Mul => match overflow {
+                OverflowBehavior::Checked => enforce_mul(cs, left, right, span),
+                OverflowBehavior::Wrapping => enforce_wrapping_mul(cs, left, right, span),
+            },
+            BinaryOperation::Div => enforce_div_with_constants(cs, left, right, constants, span),
+            BinaryOperation::Pow => match overflow {
+                OverflowBehavior::Checked => enforce_pow(cs, left, right, span),
+                OverflowBehavior::Wrapping => enforce_wrapping_pow(cs, left, right, span),
+            },
+            BinaryOperation::Or => enforce_or(cs, left, right, span).map_err(ExpressionError::BooleanError),
+            BinaryOperation::And => enforce_and(cs, left, right, span).map_err(ExpressionError::BooleanError),
+            BinaryOperation::Eq => evaluate_eq(cs, left, right, span),
+            BinaryOperation::Ne => {
+                evaluate_not(evaluate_eq(cs, left, right, span)?, span).map_err(ExpressionError::BooleanError)
+            }
+            BinaryOperation::Ge => evaluate_ge(cs, left, right, span),
+            BinaryOperation::Gt => evaluate_gt(cs, left, right, span),
+            BinaryOperation::Le => evaluate_le(cs, left, right, span),
+            BinaryOperation::Lt => evaluate_lt(cs, left, right, span),
+            _ => unimplemented!("unimplemented binary operator"),
+        }
+    }
 }
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces an `as` cast in a resolved Leo program.
+
+use crate::{
+    errors::ExpressionError,
+    value::{ConstrainedValue, Integer},
+    GroupType,
+};
+use leo_asg::{Span, Type};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::traits::utilities::{boolean::Boolean, eq::EqGadget};
+use snarkvm_r1cs::ConstraintSystem;
+
+///
+/// Casts a boolean to an integer, with `true` as `1` and `false` as `0`, or an integer to a
+/// boolean, which is only satisfied if the integer is `0` or `1`.
+///
+pub fn enforce_cast<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    value: ConstrainedValue<'a, F, G>,
+    target_type: &Type<'a>,
+    span: &Span,
+) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+    match (value, target_type) {
+        (ConstrainedValue::Boolean(boolean), Type::Integer(integer_type)) => {
+            let mut bits = vec![Boolean::constant(false); Integer::bit_width(integer_type)];
+            bits[0] = boolean;
+
+            Ok(ConstrainedValue::Integer(Integer::from_bits_typed(integer_type, &bits)))
+        }
+        (ConstrainedValue::Integer(integer), Type::Boolean) => {
+            let bits = integer.get_bits();
+
+            if bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))) {
+                return match integer.to_usize() {
+                    Some(0) | Some(1) => Ok(ConstrainedValue::Boolean(bits[0])),
+                    _ => Err(ExpressionError::invalid_bool_cast(integer.to_string(), span)),
+                };
+            }
+
+            let name = format!("{} as bool {}:{}", integer, span.line_start, span.col_start);
+            for (i, bit) in bits.iter().enumerate().skip(1) {
+                bit.enforce_equal(cs.ns(|| format!("{} bit {}", name, i)), &Boolean::constant(false))
+                    .map_err(|e| ExpressionError::cannot_enforce("as bool".to_string(), e, span))?;
+            }
+
+            Ok(ConstrainedValue::Boolean(bits[0]))
+        }
+        (value, target_type) => Err(ExpressionError::unsupported_cast(
+            value.to_string(),
+            target_type.to_string(),
+            span,
+        )),
+    }
+}
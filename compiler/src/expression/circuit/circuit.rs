@@ -37,20 +37,40 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         let circuit = expr.circuit.get();
         let members = circuit.members.borrow();
 
+        // Members that are not given explicitly are taken from the base value.
+        let mut base_members = match expr.base.get() {
+            Some(base) => match self.enforce_expression(cs, base)? {
+                ConstrainedValue::CircuitExpression(_, base_members) => base_members,
+                value => return Err(ExpressionError::undefined_circuit(value.to_string(), span)),
+            },
+            None => vec![],
+        };
+
         let mut resolved_members = Vec::with_capacity(members.len());
 
         // type checking is already done in asg
-        for (name, inner) in expr.values.iter() {
-            let target = members
-                .get(name.name.as_ref())
-                .expect("illegal name in asg circuit init expression");
-            match target {
-                CircuitMember::Variable(_type_) => {
+        for (name, member) in members.iter() {
+            if let CircuitMember::Function(_) = member {
+                continue;
+            }
+            let explicit = expr
+                .values
+                .iter()
+                .find(|(identifier, _)| identifier.name.as_ref() == name.as_str());
+            let resolved = match explicit {
+                Some((identifier, inner)) => {
                     let variable_value = self.enforce_expression(cs, inner.get())?;
-                    resolved_members.push(ConstrainedCircuitMember(name.clone(), variable_value));
+                    ConstrainedCircuitMember(identifier.clone(), variable_value)
                 }
-                _ => return Err(ExpressionError::expected_circuit_member(name.to_string(), span)),
-            }
+                None => {
+                    let index = base_members
+                        .iter()
+                        .position(|member| member.0.name.as_ref() == name.as_str())
+                        .ok_or_else(|| ExpressionError::expected_circuit_member(name.to_string(), span))?;
+                    base_members.swap_remove(index)
+                }
+            };
+            resolved_members.push(resolved);
         }
 
         let value = ConstrainedValue::CircuitExpression(circuit, resolved_members);
@@ -18,6 +18,7 @@
 
 use crate::{
     arithmetic::*,
+    enforce_cast,
     errors::ExpressionError,
     logical::*,
     program::ConstrainedProgram,
@@ -26,6 +27,7 @@ use crate::{
     value::{Address, ConstrainedValue, Integer},
     FieldType,
     GroupType,
+    OverflowBehavior,
 };
 use leo_asg::{expression::*, ConstValue, Expression, Node, Span};
 
@@ -69,7 +71,10 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         let span = &expression.span().cloned().unwrap_or_default();
         match expression {
             // Cast
-            Expression::Cast(_) => unimplemented!("casts not implemented"),
+            Expression::Cast(CastExpression { inner, target_type, .. }) => {
+                let resolved_inner = self.enforce_expression(cs, inner.get())?;
+                enforce_cast(cs, resolved_inner, target_type, span)
+            }
 
             // Variables
             Expression::VariableRef(variable_ref) => self.evaluate_ref(variable_ref),
@@ -83,34 +88,25 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             }) => {
                 let (resolved_left, resolved_right) = self.enforce_binary_expression(cs, left.get(), right.get())?;
 
-                match operation {
-                    BinaryOperation::Add => enforce_add(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Sub => enforce_sub(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Mul => enforce_mul(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Div => enforce_div(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Pow => enforce_pow(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Or => {
-                        enforce_or(cs, resolved_left, resolved_right, span).map_err(ExpressionError::BooleanError)
-                    }
-                    BinaryOperation::And => {
-                        enforce_and(cs, resolved_left, resolved_right, span).map_err(ExpressionError::BooleanError)
-                    }
-                    BinaryOperation::Eq => evaluate_eq(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Ne => evaluate_not(evaluate_eq(cs, resolved_left, resolved_right, span)?, span)
-                        .map_err(ExpressionError::BooleanError),
-                    BinaryOperation::Ge => evaluate_ge(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Gt => evaluate_gt(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Le => evaluate_le(cs, resolved_left, resolved_right, span),
-                    BinaryOperation::Lt => evaluate_lt(cs, resolved_left, resolved_right, span),
-                    _ => unimplemented!("unimplemented binary operator"),
-                }
+                Self::enforce_binary_operation(
+                    cs,
+                    operation,
+                    resolved_left,
+                    resolved_right,
+                    self.overflow,
+                    &self.constants,
+                    span,
+                )
             }
 
             // Unary operations
             Expression::Unary(UnaryExpression { inner, operation, .. }) => match operation {
                 UnaryOperation::Negate => {
                     let resolved_inner = self.enforce_expression(cs, inner.get())?;
-                    enforce_negate(cs, resolved_inner, span)
+                    match self.overflow {
+                        OverflowBehavior::Checked => enforce_negate(cs, resolved_inner, span),
+                        OverflowBehavior::Wrapping => enforce_wrapping_negate(cs, resolved_inner, span),
+                    }
                 }
                 UnaryOperation::Not => Ok(evaluate_not(self.enforce_expression(cs, inner.get())?, span)?),
                 _ => unimplemented!("unimplemented unary operator"),
@@ -137,9 +133,12 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 array,
                 left,
                 right,
+                inclusive,
                 length,
                 ..
-            }) => self.enforce_array_range_access(cs, array.get(), left.get(), right.get(), *length, span),
+            }) => self.enforce_array_range_access(cs, array.get(), left.get(), right.get(), *inclusive, *length, span),
+            Expression::ArrayFold(fold) => self.enforce_array_fold(cs, fold, span),
+            Expression::ArrayMap(map) => self.enforce_array_map(cs, map, span),
 
             // Tuples
             Expression::TupleInit(TupleInitExpression { elements, .. }) => self.enforce_tuple(cs, &elements[..]),
@@ -151,6 +150,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             Expression::CircuitInit(expr) => self.enforce_circuit(cs, expr, span),
             Expression::CircuitAccess(expr) => self.enforce_circuit_access(cs, expr),
 
+            // Groups
+            Expression::GroupAccess(GroupAccessExpression { target, coordinate, .. }) => {
+                self.enforce_group_access(cs, target.get(), *coordinate, span)
+            }
+
             // Functions
             Expression::Call(CallExpression {
                 function,
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces group coordinate access in a compiled Leo program.
+
+use crate::{errors::ExpressionError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use leo_asg::{AffineCoordinate, Expression, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Returns the affine `coordinate` of the group element `group` as a field element.
+    ///
+    /// The coordinates of the identity element are `(0, 1)`.
+    ///
+    pub fn enforce_group_access<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        group: &'a Expression<'a>,
+        coordinate: AffineCoordinate,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        let group = match self.enforce_expression(cs, group)? {
+            ConstrainedValue::Group(group) => group,
+            value => {
+                return Err(ExpressionError::incompatible_types(
+                    format!("{}.{}", value, coordinate),
+                    span,
+                ))
+            }
+        };
+
+        let (x, y) = group.coordinates();
+
+        Ok(ConstrainedValue::Field(match coordinate {
+            AffineCoordinate::X => x,
+            AffineCoordinate::Y => y,
+        }))
+    }
+}
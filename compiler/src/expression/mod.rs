@@ -25,6 +25,9 @@ pub use self::array::*;
 pub mod binary;
 pub use self::binary::*;
 
+pub mod cast;
+pub use self::cast::*;
+
 pub mod circuit;
 pub use self::circuit::*;
 
@@ -37,6 +40,9 @@ pub use self::expression::*;
 pub mod function;
 pub use self::function::*;
 
+pub mod group_access;
+pub use self::group_access::*;
+
 pub mod variable_ref;
 pub use self::variable_ref::*;
 
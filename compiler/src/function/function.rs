@@ -61,6 +61,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             self.store(variable.id, input_value);
         }
 
+        // Loops in the caller do not guard the statements of the called function.
+        let loop_guards = std::mem::take(&mut self.loop_guards);
+
         // Evaluate every statement in the function and save all potential results
         let mut results = vec![];
         let indicator = Boolean::constant(true);
@@ -75,6 +78,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
         results.append(&mut result);
 
+        self.loop_guards = loop_guards;
+
         if function.qualifier == FunctionQualifier::MutSelfRef {
             if let (Some(self_var), Some(target)) = (self_var, target) {
                 let new_self = self
@@ -32,6 +32,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         array_type: &Type,
         array_len: usize,
         input_value: Option<InputValue>,
+        public: bool,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
         // Build the array value using the expected types.
@@ -56,6 +57,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                         array_type,
                         &value_name,
                         Some(value),
+                        public,
                         span,
                     )?)
                 }
@@ -65,7 +67,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 for i in 0..array_len {
                     let value_name = format!("{}_{}", &name, &i.to_string());
 
-                    array_value.push(self.allocate_main_function_input(cs, array_type, &value_name, None, span)?);
+                    array_value.push(self.allocate_main_function_input(
+                        cs,
+                        array_type,
+                        &value_name,
+                        None,
+                        public,
+                        span,
+                    )?);
                 }
             }
             _ => {
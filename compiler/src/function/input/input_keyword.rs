@@ -63,23 +63,25 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
         // Allocate each input variable as a circuit expression
 
+        // Only the state is declared in the `[[public]]` table of the state file
+
         let mut sections = Vec::with_capacity(4);
 
-        sections.push((registers_name, registers_values));
-        sections.push((record_name, record_values));
-        sections.push((state_name, state_values));
-        sections.push((state_leaf_name, state_leaf_values));
+        sections.push((registers_name, registers_values, false));
+        sections.push((record_name, record_values, false));
+        sections.push((state_name, state_values, true));
+        sections.push((state_leaf_name, state_leaf_values, false));
 
         let mut members = Vec::with_capacity(sections.len());
 
-        for (name, values) in sections {
+        for (name, values, public) in sections {
             let sub_circuit = match expected_type.members.borrow().get(name.name.as_ref()) {
                 Some(CircuitMember::Variable(Type::Circuit(circuit))) => *circuit,
                 _ => panic!("illegal input type definition from asg"),
             };
 
             let member_name = name.clone();
-            let member_value = self.allocate_input_section(cs, name, sub_circuit, values)?;
+            let member_value = self.allocate_input_section(cs, name, sub_circuit, values, public)?;
 
             let member = ConstrainedCircuitMember(member_name, member_value);
 
@@ -30,6 +30,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         identifier: Identifier,
         expected_type: &'a Circuit<'a>,
         section: IndexMap<Parameter, Option<InputValue>>,
+        public: bool,
     ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
         let mut members = Vec::with_capacity(section.len());
 
@@ -55,6 +56,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 &declared_type,
                 &parameter.variable.name,
                 option,
+                public || parameter.public,
                 &parameter.span,
             )?;
             let member = ConstrainedCircuitMember(member_name, member_value);
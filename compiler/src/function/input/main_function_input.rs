@@ -44,22 +44,24 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         type_: &Type,
         name: &str,
         input_option: Option<InputValue>,
+        public: bool,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
         match type_ {
-            Type::Address => Ok(Address::from_input(cs, name, input_option, span)?),
-            Type::Boolean => Ok(bool_from_input(cs, name, input_option, span)?),
-            Type::Field => Ok(field_from_input(cs, name, input_option, span)?),
-            Type::Group => Ok(group_from_input(cs, name, input_option, span)?),
+            Type::Address => Ok(Address::from_input(cs, name, input_option, public, span)?),
+            Type::Boolean => Ok(bool_from_input(cs, name, input_option, public, span)?),
+            Type::Field => Ok(field_from_input(cs, name, input_option, public, span)?),
+            Type::Group => Ok(group_from_input(cs, name, input_option, public, span)?),
             Type::Integer(integer_type) => Ok(ConstrainedValue::Integer(Integer::from_input(
                 cs,
                 integer_type,
                 name,
                 input_option,
+                public,
                 span,
             )?)),
-            Type::Array(type_, len) => self.allocate_array(cs, name, &*type_, *len, input_option, span),
-            Type::Tuple(types) => self.allocate_tuple(cs, &name, types, input_option, span),
+            Type::Array(type_, len) => self.allocate_array(cs, name, &*type_, *len, input_option, public, span),
+            Type::Tuple(types) => self.allocate_tuple(cs, &name, types, input_option, public, span),
             _ => unimplemented!("main function input not implemented for type {}", type_), // Should not happen.
         }
     }
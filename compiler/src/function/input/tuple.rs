@@ -31,6 +31,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         name: &str,
         types: &[Type],
         input_value: Option<InputValue>,
+        public: bool,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, FunctionError> {
         let mut tuple_values = vec![];
@@ -45,7 +46,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 for (i, (value, type_)) in values.into_iter().zip(types.iter()).enumerate() {
                     let value_name = format!("{}_{}", &name, &i.to_string());
 
-                    tuple_values.push(self.allocate_main_function_input(cs, type_, &value_name, Some(value), span)?)
+                    tuple_values.push(self.allocate_main_function_input(
+                        cs,
+                        type_,
+                        &value_name,
+                        Some(value),
+                        public,
+                        span,
+                    )?)
                 }
             }
             None => {
@@ -53,7 +61,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 for (i, type_) in types.iter().enumerate() {
                     let value_name = format!("{}_{}", &name, &i.to_string());
 
-                    tuple_values.push(self.allocate_main_function_input(cs, type_, &value_name, None, span)?);
+                    tuple_values.push(self.allocate_main_function_input(cs, type_, &value_name, None, public, span)?);
                 }
             }
             _ => {
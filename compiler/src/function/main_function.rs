@@ -76,6 +76,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                         &input_variable.type_.clone(),
                         &name,
                         input_option,
+                        input.is_public(&name),
                         &function.span.clone().unwrap_or_default(),
                     )?,
                     // If input option is found in [constants] section and function argument is const.
@@ -46,7 +46,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         output: &mut Vec<ResolvedAssigneeAccess>,
     ) -> Result<Option<Variable<'a>>, StatementError> {
         match expr {
-            Expression::ArrayRangeAccess(ArrayRangeAccessExpression { array, left, right, .. }) => {
+            Expression::ArrayRangeAccess(ArrayRangeAccessExpression {
+                array,
+                left,
+                right,
+                inclusive,
+                ..
+            }) => {
                 let inner = self.prepare_mut_access(cs, array.get(), span, output)?;
                 let start_index = left
                     .get()
@@ -65,7 +71,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                         x.to_usize()
                             .ok_or_else(|| StatementError::array_assign_index_const(span))
                     })
-                    .transpose()?;
+                    .transpose()?
+                    .map(|stop| if *inclusive { stop + 1 } else { stop });
 
                 output.push(ResolvedAssigneeAccess::ArrayRange(start_index, stop_index));
                 Ok(inner)
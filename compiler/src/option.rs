@@ -14,6 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+///
+/// The behavior of integer `+`, `-`, `*`, `**` and unary `-` on overflow.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowBehavior {
+    /// Fail synthesis when the result does not fit in the integer type.
+    Checked,
+    /// Wrap the result around at the bounds of the integer type.
+    Wrapping,
+}
+
 ///
 /// Toggles compiler optimizations on the program.
 ///
@@ -22,17 +33,54 @@ pub struct CompilerOptions {
     pub canonicalization_enabled: bool,
     pub constant_folding_enabled: bool,
     pub dead_code_elimination_enabled: bool,
+    /// Inline small functions at their call sites. Disabled by default, as inlined functions no
+    /// longer have their constraints attributed to them.
+    pub function_inlining_enabled: bool,
+    /// Hoist loop-invariant expressions out of loops, so that they are not synthesized again on
+    /// every unrolled iteration. Disabled by default.
+    pub loop_optimization_enabled: bool,
+    /// Resolve imports from local directories only.
+    pub offline: bool,
+    /// Reject implicit conversions that may lose information, requiring an explicit cast.
+    pub strict: bool,
+    /// Build in release mode, stripping `console.debug` statements from the program.
+    pub release: bool,
+    /// Treat warnings as errors, failing compilation if any warning is emitted.
+    pub deny_warnings: bool,
+    /// The overflow behavior of integer arithmetic in release mode. Checked by
+    /// default, debug builds always check for overflow.
+    pub release_overflow: OverflowBehavior,
+}
+
+impl CompilerOptions {
+    ///
+    /// Returns the overflow behavior of integer arithmetic in the selected build mode.
+    ///
+    pub fn overflow_behavior(&self) -> OverflowBehavior {
+        if self.release {
+            self.release_overflow
+        } else {
+            OverflowBehavior::Checked
+        }
+    }
 }
 
 impl Default for CompilerOptions {
     ///
-    /// All compiler optimizations are enabled by default.
+    /// All compiler optimizations except function inlining and loop optimization are enabled by default.
     ///
     fn default() -> Self {
         CompilerOptions {
             canonicalization_enabled: true,
             constant_folding_enabled: true,
             dead_code_elimination_enabled: true,
+            function_inlining_enabled: false,
+            loop_optimization_enabled: false,
+            offline: false,
+            strict: false,
+            release: false,
+            deny_warnings: false,
+            release_overflow: OverflowBehavior::Checked,
         }
     }
 }
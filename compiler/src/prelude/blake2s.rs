@@ -15,17 +15,17 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::CoreCircuit;
-use crate::{errors::ExpressionError, ConstrainedValue, GroupType, Integer};
+use crate::{errors::ExpressionError, ConstrainedValue, GroupType, Integer, IntegerTrait};
 use leo_asg::{Function, Span};
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
-    algorithms::prf::Blake2sGadget,
+    algorithms::prf::{blake2s_gadget, Blake2sGadget},
     traits::{
         algorithms::PRFGadget,
-        utilities::{uint::UInt8, ToBytesGadget},
+        utilities::{boolean::Boolean, uint::UInt8, ToBytesGadget},
     },
 };
-use snarkvm_r1cs::ConstraintSystem;
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 
 pub struct Blake2s;
 
@@ -46,6 +46,29 @@ fn unwrap_argument<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>)
     }
 }
 
+///
+/// Returns the unkeyed 32-byte BLAKE2s digest of `input`.
+///
+pub fn blake2s_digest<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: CS,
+    input: &[UInt8],
+) -> Result<Vec<UInt8>, SynthesisError> {
+    let bits = input
+        .iter()
+        .flat_map(|byte| byte.to_bits_le())
+        .collect::<Vec<Boolean>>();
+
+    let words = blake2s_gadget(cs, &bits)?;
+
+    Ok(words
+        .iter()
+        .flat_map(|word| word.to_bits_le())
+        .collect::<Vec<Boolean>>()
+        .chunks(8)
+        .map(UInt8::from_bits_le)
+        .collect())
+}
+
 impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Blake2s {
     fn call_function<CS: ConstraintSystem<F>>(
         &self,
@@ -55,19 +78,35 @@ impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Blake2s {
         target: Option<ConstrainedValue<'a, F, G>>,
         mut arguments: Vec<ConstrainedValue<'a, F, G>>,
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
-        assert_eq!(arguments.len(), 2); // asg enforced
-        assert!(function.name.borrow().name.as_ref() == "hash"); // asg enforced
         assert!(target.is_none()); // asg enforced
-        let input = unwrap_argument(arguments.remove(1));
-        let seed = unwrap_argument(arguments.remove(0));
 
-        let digest = Blake2sGadget::check_evaluation_gadget(cs.ns(|| "blake2s hash"), &seed[..], &input[..])
-            .map_err(|e| ExpressionError::cannot_enforce("Blake2s check evaluation gadget".to_owned(), e, span))?;
+        let digest = match &*function.name.borrow().name {
+            "hash" => {
+                assert_eq!(arguments.len(), 2); // asg enforced
+                let input = unwrap_argument(arguments.remove(1));
+                let seed = unwrap_argument(arguments.remove(0));
+
+                let digest = Blake2sGadget::check_evaluation_gadget(cs.ns(|| "blake2s hash"), &seed[..], &input[..])
+                    .map_err(|e| {
+                        ExpressionError::cannot_enforce("Blake2s check evaluation gadget".to_owned(), e, span)
+                    })?;
+
+                digest
+                    .to_bytes(cs)
+                    .map_err(|e| ExpressionError::cannot_enforce("Vec<UInt8> ToBytes".to_owned(), e, span))?
+            }
+            "digest" => {
+                assert_eq!(arguments.len(), 1); // asg enforced
+                let input = unwrap_argument(arguments.remove(0));
+
+                blake2s_digest(cs.ns(|| "blake2s digest"), &input)
+                    .map_err(|e| ExpressionError::cannot_enforce("Blake2s digest gadget".to_owned(), e, span))?
+            }
+            name => unreachable!("illegal blake2s function `{}`", name), // asg enforced
+        };
 
         Ok(ConstrainedValue::Array(
             digest
-                .to_bytes(cs)
-                .map_err(|e| ExpressionError::cannot_enforce("Vec<UInt8> ToBytes".to_owned(), e, span))?
                 .into_iter()
                 .map(Integer::U8)
                 .map(ConstrainedValue::Integer)
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{pedersen_hash, CoreCircuit};
+use crate::{errors::ExpressionError, ConstrainedValue, FieldType, GroupType};
+use leo_asg::{Function, Span};
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    fields::FpGadget,
+    traits::utilities::{boolean::Boolean, select::CondSelectGadget},
+};
+use snarkvm_r1cs::ConstraintSystem;
+
+pub struct MerklePath;
+
+fn unwrap_field<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> FieldType<F> {
+    if let ConstrainedValue::Field(field) = arg {
+        field
+    } else {
+        panic!("illegal non-field type in merkle path call");
+    }
+}
+
+fn unwrap_array<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> Vec<ConstrainedValue<F, G>> {
+    if let ConstrainedValue::Array(items) = arg {
+        items
+    } else {
+        panic!("illegal non-array type in merkle path call");
+    }
+}
+
+fn unwrap_boolean<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> Boolean {
+    if let ConstrainedValue::Boolean(boolean) = arg {
+        boolean
+    } else {
+        panic!("illegal non-bool type in merkle path call");
+    }
+}
+
+///
+/// Returns `true` if `path` leads from `leaf` to `root`.
+///
+/// `path` holds the sibling at each level from the leaf upwards, and `index` holds one bit per
+/// level that is set when the running node is the right child. Each parent is the Pedersen hash
+/// of its left and right children.
+///
+pub fn verify_merkle_path<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    leaf: &FieldType<F>,
+    path: &[FieldType<F>],
+    index: &[Boolean],
+    root: &FieldType<F>,
+    span: &Span,
+) -> Result<Boolean, ExpressionError> {
+    if path.len() != index.len() {
+        return Err(ExpressionError::invalid_length(path.len(), index.len(), span));
+    }
+
+    let mut node = leaf.clone();
+    for (i, (sibling, is_right)) in path.iter().zip(index.iter()).enumerate() {
+        let mut cs = cs.ns(|| format!("level {}", i));
+
+        let node_gadget = node.to_gadget(cs.ns(|| "node"));
+        let sibling_gadget = sibling.to_gadget(cs.ns(|| "sibling"));
+
+        let left = FpGadget::conditionally_select(cs.ns(|| "left"), is_right, &sibling_gadget, &node_gadget)
+            .map_err(|e| ExpressionError::cannot_enforce("merkle path select".to_owned(), e, span))?;
+        let right = FpGadget::conditionally_select(cs.ns(|| "right"), is_right, &node_gadget, &sibling_gadget)
+            .map_err(|e| ExpressionError::cannot_enforce("merkle path select".to_owned(), e, span))?;
+
+        let children: [ConstrainedValue<F, G>; 2] = [
+            ConstrainedValue::Field(FieldType::Allocated(left)),
+            ConstrainedValue::Field(FieldType::Allocated(right)),
+        ];
+        node = pedersen_hash(cs.ns(|| "hash"), &children, span)?;
+    }
+
+    Ok(node.is_eq(cs.ns(|| "node == root"), root, span)?)
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for MerklePath {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert_eq!(arguments.len(), 4); // asg enforced
+        assert!(function.name.borrow().name.as_ref() == "verify"); // asg enforced
+        assert!(target.is_none()); // asg enforced
+
+        let root = unwrap_field(arguments.remove(3));
+        let index = unwrap_array(arguments.remove(2))
+            .into_iter()
+            .map(unwrap_boolean)
+            .collect::<Vec<_>>();
+        let path = unwrap_array(arguments.remove(1))
+            .into_iter()
+            .map(unwrap_field)
+            .collect::<Vec<_>>();
+        let leaf = unwrap_field(arguments.remove(0));
+
+        let valid = verify_merkle_path::<F, G, _>(cs, &leaf, &path, &index, &root, span)?;
+
+        Ok(ConstrainedValue::Boolean(valid))
+    }
+}
@@ -17,6 +17,15 @@
 pub mod blake2s;
 pub use blake2s::*;
 
+pub mod merkle;
+pub use merkle::*;
+
+pub mod pedersen;
+pub use pedersen::*;
+
+pub mod schnorr;
+pub use schnorr::*;
+
 use crate::{errors::ExpressionError, ConstrainedValue, GroupType};
 use leo_asg::{Function, Span};
 use snarkvm_fields::PrimeField;
@@ -33,9 +42,37 @@ pub trait CoreCircuit<'a, F: PrimeField, G: GroupType<F>>: Send + Sync {
     ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError>;
 }
 
+pub enum ResolvedCoreCircuit {
+    Blake2s(Blake2s),
+    MerklePath(MerklePath),
+    Pedersen(Pedersen),
+    Schnorr(Schnorr),
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for ResolvedCoreCircuit {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        match self {
+            ResolvedCoreCircuit::Blake2s(circuit) => circuit.call_function(cs, function, span, target, arguments),
+            ResolvedCoreCircuit::MerklePath(circuit) => circuit.call_function(cs, function, span, target, arguments),
+            ResolvedCoreCircuit::Pedersen(circuit) => circuit.call_function(cs, function, span, target, arguments),
+            ResolvedCoreCircuit::Schnorr(circuit) => circuit.call_function(cs, function, span, target, arguments),
+        }
+    }
+}
+
 pub fn resolve_core_circuit<'a, F: PrimeField, G: GroupType<F>>(name: &str) -> impl CoreCircuit<'a, F, G> {
     match name {
-        "blake2s" => Blake2s,
+        "blake2s" => ResolvedCoreCircuit::Blake2s(Blake2s),
+        "merkle" => ResolvedCoreCircuit::MerklePath(MerklePath),
+        "pedersen" => ResolvedCoreCircuit::Pedersen(Pedersen),
+        "schnorr" => ResolvedCoreCircuit::Schnorr(Schnorr),
         _ => unimplemented!("invalid core circuit: {}", name),
     }
 }
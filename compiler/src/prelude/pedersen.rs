@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, FieldType, GroupType};
+use leo_asg::{Function, Span};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+pub struct Pedersen;
+
+///
+/// Returns the Pedersen hash of a sequence of field and integer values.
+///
+/// Field elements contribute their canonical 32-byte little-endian encoding and integers
+/// their little-endian bytes, concatenated in order.
+///
+pub fn pedersen_hash<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    input: &[ConstrainedValue<'a, F, G>],
+    span: &Span,
+) -> Result<FieldType<F>, ExpressionError> {
+    let mut bytes = Vec::new();
+    for (i, value) in input.iter().enumerate() {
+        match value {
+            ConstrainedValue::Field(field) => {
+                bytes.extend(field.to_bytes_le(cs.ns(|| format!("field {} to bytes", i)), span)?)
+            }
+            ConstrainedValue::Integer(integer) => bytes.extend(integer.to_bytes_le()),
+            _ => panic!("illegal non-field, non-integer type in pedersen call"),
+        }
+    }
+
+    Ok(G::pedersen_hash(cs.ns(|| "pedersen hash"), &bytes, span)?)
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Pedersen {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert_eq!(arguments.len(), 1); // asg enforced
+        assert!(matches!(&*function.name.borrow().name, "hash" | "hash_bytes")); // asg enforced
+        assert!(target.is_none()); // asg enforced
+
+        let input = match arguments.remove(0) {
+            ConstrainedValue::Array(items) => items,
+            _ => panic!("illegal non-array type in pedersen call"),
+        };
+
+        Ok(ConstrainedValue::Field(pedersen_hash(cs, &input, span)?))
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CoreCircuit;
+use crate::{errors::ExpressionError, ConstrainedValue, GroupType, Integer, IntegerTrait};
+use leo_asg::{Function, GroupValue, Span};
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    algorithms::prf::Blake2sGadget,
+    traits::{
+        algorithms::PRFGadget,
+        utilities::{boolean::Boolean, eq::EvaluateEqGadget, uint::UInt8, ToBytesGadget},
+    },
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+/// Byte length of the signed message and of the signature scalars.
+const SCHNORR_BYTES: usize = 32;
+
+/// The salt of the challenge hash, to build snarkVM `SchnorrParameters` matching the circuit.
+pub const SCHNORR_SALT: [u8; 32] = *b"LeoSchnorrSignatureVerification!";
+
+pub struct Schnorr;
+
+fn unwrap_bytes<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> Vec<UInt8> {
+    if let ConstrainedValue::Array(args) = arg {
+        args.into_iter()
+            .map(|item| {
+                if let ConstrainedValue::Integer(Integer::U8(item)) = item {
+                    item
+                } else {
+                    panic!("illegal non-u8 type in schnorr call");
+                }
+            })
+            .collect()
+    } else {
+        panic!("illegal non-array type in schnorr call");
+    }
+}
+
+fn unwrap_group<F: PrimeField, G: GroupType<F>>(arg: ConstrainedValue<F, G>) -> G {
+    if let ConstrainedValue::Group(group) = arg {
+        group
+    } else {
+        panic!("illegal non-group type in schnorr call");
+    }
+}
+
+fn blake2s_prf<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    seed: &[UInt8],
+    input: &[UInt8],
+) -> Result<Vec<UInt8>, SynthesisError> {
+    let digest = Blake2sGadget::check_evaluation_gadget(cs.ns(|| "blake2s"), seed, input)?;
+
+    digest.to_bytes(cs.ns(|| "to_bytes"))
+}
+
+fn to_bits_le(bytes: &[UInt8]) -> Vec<Boolean> {
+    bytes.iter().flat_map(|byte| byte.to_bits_le()).collect()
+}
+
+///
+/// Returns `true` if the little-endian `bits` are less than the constant little-endian `bound`.
+///
+fn is_less_than_constant<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+    bound: &[bool],
+) -> Result<Boolean, SynthesisError> {
+    let mut less = Boolean::constant(false);
+    for (i, (bit, bound_bit)) in bits.iter().zip(bound).enumerate() {
+        // A differing bit decides the comparison, an equal bit keeps the result of the lower bits.
+        less = if *bound_bit {
+            Boolean::or(cs.ns(|| format!("bit {}", i)), &bit.not(), &less)?
+        } else {
+            Boolean::and(cs.ns(|| format!("bit {}", i)), &bit.not(), &less)?
+        };
+    }
+
+    Ok(less)
+}
+
+fn is_equal<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    first: &G,
+    second: &G,
+    span: &Span,
+) -> Result<Boolean, ExpressionError> {
+    let (first_x, first_y) = first.coordinates();
+    let (second_x, second_y) = second.coordinates();
+    let x_equal = first_x.is_eq(cs.ns(|| "x == x"), &second_x, span)?;
+    let y_equal = first_y.is_eq(cs.ns(|| "y == y"), &second_y, span)?;
+
+    Boolean::and(cs.ns(|| "x and y equal"), &x_equal, &y_equal)
+        .map_err(|e| ExpressionError::cannot_enforce("group equality".to_owned(), e, span))
+}
+
+///
+/// Returns `true` if (`response`, `challenge`) is a snarkVM Schnorr signature of `message`
+/// under `public_key`, with the group generator and [`SCHNORR_SALT`] as parameters.
+///
+/// The commitment `response * generator + challenge * public_key` is recomputed, and the
+/// challenge must equal the Blake2s hash of the salt, the commitment coordinates and the message,
+/// truncated to the bit length of the subgroup order. Both scalars are read little-endian and
+/// must be less than the subgroup order, and the public key must lie in the subgroup.
+///
+pub fn schnorr_verify<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    public_key: &G,
+    message: &[UInt8],
+    response: &[UInt8],
+    challenge: &[UInt8],
+    span: &Span,
+) -> Result<Boolean, ExpressionError> {
+    let order = G::subgroup_order();
+    for bytes in [message, response, challenge].iter() {
+        if bytes.len() != SCHNORR_BYTES {
+            return Err(ExpressionError::invalid_length(SCHNORR_BYTES, bytes.len(), span));
+        }
+    }
+    if order.len() != SCHNORR_BYTES * 8 {
+        return Err(ExpressionError::invalid_length(order.len() / 8, SCHNORR_BYTES, span));
+    }
+
+    let response_bits = to_bits_le(response);
+    let challenge_bits = to_bits_le(challenge);
+    let cannot_enforce = |e| ExpressionError::cannot_enforce("Schnorr verification".to_owned(), e, span);
+
+    let response_in_range =
+        is_less_than_constant(cs.ns(|| "response < order"), &response_bits, &order).map_err(cannot_enforce)?;
+    let challenge_in_range =
+        is_less_than_constant(cs.ns(|| "challenge < order"), &challenge_bits, &order).map_err(cannot_enforce)?;
+
+    let order_bits = order
+        .iter()
+        .map(|bit| Boolean::constant(*bit))
+        .collect::<Vec<Boolean>>();
+    let zero = G::constant(&GroupValue::Single("0".into()), span)?;
+    let order_key = public_key.mul(cs.ns(|| "order * public key"), &order_bits, span)?;
+    let key_in_subgroup = is_equal(cs.ns(|| "order * public key == 0"), &order_key, &zero, span)?;
+
+    let response_generator = G::one().mul(cs.ns(|| "response * generator"), &response_bits, span)?;
+    let challenge_key = public_key.mul(cs.ns(|| "challenge * public key"), &challenge_bits, span)?;
+    let commitment = response_generator.add(cs.ns(|| "commitment"), &challenge_key, span)?;
+
+    let (commitment_x, commitment_y) = commitment.coordinates();
+    let mut hash_input = commitment_x.to_bytes_le(cs.ns(|| "commitment x bytes"), span)?;
+    hash_input.extend(commitment_y.to_bytes_le(cs.ns(|| "commitment y bytes"), span)?);
+    hash_input.extend_from_slice(message);
+
+    let salt = UInt8::constant_vec(&SCHNORR_SALT);
+    let digest = blake2s_prf(cs.ns(|| "challenge hash"), &salt, &hash_input).map_err(cannot_enforce)?;
+
+    // The digest is truncated to the bit length of the order, as by `PrimeField::from_random_bytes`.
+    let order_length = order.iter().rposition(|bit| *bit).map_or(0, |position| position + 1);
+    let mut checks = vec![response_in_range, challenge_in_range, key_in_subgroup];
+    for (i, (digest_bit, challenge_bit)) in to_bits_le(&digest).iter().zip(&challenge_bits).enumerate() {
+        let expected_bit = if i < order_length {
+            *digest_bit
+        } else {
+            Boolean::constant(false)
+        };
+        checks.push(
+            expected_bit
+                .evaluate_equal(cs.ns(|| format!("challenge bit {}", i)), challenge_bit)
+                .map_err(cannot_enforce)?,
+        );
+    }
+
+    Boolean::kary_and(cs.ns(|| "signature valid"), &checks).map_err(cannot_enforce)
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> CoreCircuit<'a, F, G> for Schnorr {
+    fn call_function<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        function: &'a Function<'a>,
+        span: &Span,
+        target: Option<ConstrainedValue<'a, F, G>>,
+        mut arguments: Vec<ConstrainedValue<'a, F, G>>,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        assert_eq!(arguments.len(), 4); // asg enforced
+        assert!(function.name.borrow().name.as_ref() == "verify"); // asg enforced
+        assert!(target.is_none()); // asg enforced
+
+        let challenge = unwrap_bytes(arguments.remove(3));
+        let response = unwrap_bytes(arguments.remove(2));
+        let message = unwrap_bytes(arguments.remove(1));
+        let public_key = unwrap_group(arguments.remove(0));
+
+        let valid = schnorr_verify(cs, &public_key, &message, &response, &challenge, span)?;
+
+        Ok(ConstrainedValue::Boolean(valid))
+    }
+}
@@ -16,7 +16,7 @@
 
 //! An in memory store to keep track of defined names when constraining a Leo program.
 
-use crate::{value::ConstrainedValue, GroupType};
+use crate::{value::ConstrainedValue, AllocatedConstants, GroupType, LoopGuard, OverflowBehavior};
 
 use leo_asg::Program;
 use snarkvm_fields::PrimeField;
@@ -26,6 +26,12 @@ use indexmap::IndexMap;
 pub struct ConstrainedProgram<'a, F: PrimeField, G: GroupType<F>> {
     pub asg: Program<'a>,
     identifiers: IndexMap<u32, ConstrainedValue<'a, F, G>>,
+    /// The guards of the loops being unrolled, innermost last.
+    pub(crate) loop_guards: Vec<LoopGuard>,
+    /// The overflow behavior of integer arithmetic.
+    pub(crate) overflow: OverflowBehavior,
+    /// The constants allocated by the integer gadgets, shared across the whole program.
+    pub(crate) constants: AllocatedConstants,
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
@@ -33,6 +39,9 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         Self {
             asg,
             identifiers: IndexMap::new(),
+            loop_guards: vec![],
+            overflow: OverflowBehavior::Checked,
+            constants: AllocatedConstants::default(),
         }
     }
 
@@ -16,11 +16,23 @@
 
 //! Enforces an assign statement in a compiled Leo program.
 
-use crate::{arithmetic::*, errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
-use leo_asg::{AssignOperation, AssignStatement, Span};
+use crate::{
+    errors::StatementError,
+    program::ConstrainedProgram,
+    statement::assign::ResolvedAssigneeAccess,
+    value::{ArrayValue, ConstrainedValue, Integer},
+    AllocatedConstants,
+    GroupType,
+    OverflowBehavior,
+};
+use leo_asg::{AssignOperation, AssignStatement, ConstInt, Span};
 
 use snarkvm_fields::PrimeField;
-use snarkvm_gadgets::traits::utilities::{boolean::Boolean, select::CondSelectGadget};
+use snarkvm_gadgets::traits::utilities::{
+    boolean::Boolean,
+    eq::EvaluateEqGadget,
+    select::CondSelectGadget,
+};
 use snarkvm_r1cs::ConstraintSystem;
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
@@ -33,18 +45,166 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     ) -> Result<(), StatementError> {
         // Get the name of the variable we are assigning to
         let new_value = self.enforce_expression(cs, statement.value.get())?;
-        let mut resolved_assignee = self.resolve_assign(cs, statement)?;
+        let resolved_accesses = self.resolve_assign_accesses(cs, statement)?;
 
-        if resolved_assignee.len() == 1 {
+        self.enforce_assign_accesses(
+            cs,
+            indicator,
+            statement,
+            &statement.operation,
+            resolved_accesses,
+            new_value,
+        )
+    }
+
+    ///
+    /// Assigns `new_value` to the target of `resolved_accesses` with the given operation.
+    ///
+    /// An array index that is unknown at compile time becomes a conditional write to every
+    /// position of the array, selected by comparing the index against each position.
+    /// A compound operation is enforced once on the selected element before it is written.
+    ///
+    fn enforce_assign_accesses<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        statement: &AssignStatement<'a>,
+        operation: &AssignOperation,
+        mut resolved_accesses: Vec<ResolvedAssigneeAccess>,
+        new_value: ConstrainedValue<'a, F, G>,
+    ) -> Result<(), StatementError> {
+        let dynamic_position = resolved_accesses
+            .iter()
+            .position(|access| matches!(access, ResolvedAssigneeAccess::ArrayDynamicIndex(_)));
+        let is_range = resolved_accesses
+            .iter()
+            .any(|access| matches!(access, ResolvedAssigneeAccess::ArrayRange(_, _)));
+
+        if let Some(position) = dynamic_position {
+            let span = statement.span.clone().unwrap_or_default();
+
+            if let Some(binary_operation) = operation.binary_operation() {
+                let overflow = self.overflow;
+                let constants = self.constants.clone();
+                let target = self.read_assign_target(cs, statement, &resolved_accesses, &span)?;
+
+                let new_value = match (target, new_value) {
+                    // A range applies the operation to each of its elements.
+                    (ConstrainedValue::Array(targets), ConstrainedValue::Array(new_values)) if is_range => {
+                        if new_values.len() != targets.len() {
+                            return Err(StatementError::array_assign_range_length(
+                                targets.len(),
+                                new_values.len(),
+                                &span,
+                            ));
+                        }
+
+                        let mut results = Vec::with_capacity(targets.len());
+                        for (i, (target, new_value)) in targets.into_iter().zip(new_values).enumerate() {
+                            let namespace_string =
+                                format!("assign operation {} {}:{}", i, span.line_start, span.col_start);
+                            results.push(Self::enforce_binary_operation(
+                                &mut cs.ns(|| namespace_string),
+                                &binary_operation,
+                                target,
+                                new_value,
+                                overflow,
+                                &constants,
+                                &span,
+                            )?);
+                        }
+                        ConstrainedValue::Array(results)
+                    }
+                    (target, new_value) => {
+                        let namespace_string = format!("assign operation {}:{}", span.line_start, span.col_start);
+                        Self::enforce_binary_operation(
+                            &mut cs.ns(|| namespace_string),
+                            &binary_operation,
+                            target,
+                            new_value,
+                            overflow,
+                            &constants,
+                            &span,
+                        )?
+                    }
+                };
+
+                return self.enforce_assign_accesses(
+                    cs,
+                    indicator,
+                    statement,
+                    &AssignOperation::Assign,
+                    resolved_accesses,
+                    new_value,
+                );
+            }
+
+            let index = match &resolved_accesses[position] {
+                ResolvedAssigneeAccess::ArrayDynamicIndex(index) => index.clone(),
+                _ => unreachable!(),
+            };
+
+            // The index only has to be in bounds when the assignment is reached.
+            let length = match self
+                .resolve_assign_targets(statement, resolved_accesses[..position].to_vec())?
+                .as_slice()
+            {
+                [ConstrainedValue::Array(array)] => {
+                    let array = ArrayValue::new(array.clone());
+                    let namespace_string = format!("array assign bounds {}:{}", span.line_start, span.col_start);
+                    array.enforce_bound_when(&mut cs.ns(|| namespace_string), indicator, &index, false, &span)?;
+                    array.len()
+                }
+                _ => return Err(StatementError::array_assign_index(&span)),
+            };
+
+            for i in 0..length {
+                // A position the index type cannot represent is never written.
+                let const_index = ConstInt::U32(i as u32).cast_to(&index.get_type());
+                if const_index.to_usize() != Some(i) {
+                    continue;
+                }
+
+                let namespace_string = format!("array assign index {} {}:{}", i, span.line_start, span.col_start);
+                let mut cs = cs.ns(|| namespace_string);
+
+                let is_index = index
+                    .evaluate_equal(cs.ns(|| "index eq"), &Integer::new(&const_index))
+                    .map_err(|_| StatementError::indicator_calculation(format!("index == {}", i), &span))?;
+                let condition = Boolean::and(cs.ns(|| "indicator and index eq"), indicator, &is_index)
+                    .map_err(|_| StatementError::indicator_calculation(format!("index == {}", i), &span))?;
+
+                resolved_accesses[position] = ResolvedAssigneeAccess::ArrayIndex(i);
+
+                self.enforce_assign_accesses(
+                    &mut cs,
+                    &condition,
+                    statement,
+                    operation,
+                    resolved_accesses.clone(),
+                    new_value.clone(),
+                )?;
+            }
+
+            return Ok(());
+        }
+
+        let overflow = self.overflow;
+        let constants = self.constants.clone();
+        let mut resolved_assignee = self.resolve_assign_targets(statement, resolved_accesses)?;
+
+        if !is_range {
             let span = statement.span.clone().unwrap_or_default();
 
             Self::enforce_assign_operation(
                 cs,
                 indicator,
                 format!("select {} {}:{}", new_value, &span.line_start, &span.col_start),
-                &statement.operation,
+                operation,
                 resolved_assignee[0],
                 new_value,
+                overflow,
+                &constants,
                 &span,
             )?;
         } else {
@@ -52,6 +212,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 ConstrainedValue::Array(new_values) => {
                     let span = statement.span.clone().unwrap_or_default();
 
+                    if new_values.len() != resolved_assignee.len() {
+                        return Err(StatementError::array_assign_range_length(
+                            resolved_assignee.len(),
+                            new_values.len(),
+                            &span,
+                        ));
+                    }
+
                     for (i, (old_ref, new_value)) in
                         resolved_assignee.into_iter().zip(new_values.into_iter()).enumerate()
                     {
@@ -62,9 +230,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                                 "select-splice {} {} {}:{}",
                                 i, new_value, &span.line_start, &span.col_start
                             ),
-                            &statement.operation,
+                            operation,
                             old_ref,
                             new_value,
+                            overflow,
+                            &constants,
                             &span,
                         )?;
                     }
@@ -80,6 +250,44 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         Ok(())
     }
 
+    ///
+    /// Returns the value targeted by `resolved_accesses`, selecting the elements at array indices
+    /// that are unknown at compile time. Their bounds are checked where the target is written.
+    ///
+    fn read_assign_target<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        statement: &AssignStatement<'a>,
+        resolved_accesses: &[ResolvedAssigneeAccess],
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, StatementError> {
+        let variable = statement.target_variable.get().borrow();
+        let mut value = self
+            .get(variable.id)
+            .cloned()
+            .ok_or_else(|| StatementError::undefined_variable(variable.name.to_string(), span))?;
+
+        for (i, access) in resolved_accesses.iter().enumerate() {
+            value = match (access, value) {
+                (ResolvedAssigneeAccess::ArrayDynamicIndex(index), ConstrainedValue::Array(values)) => {
+                    let namespace_string = format!("select assign target {} {}:{}", i, span.line_start, span.col_start);
+                    ArrayValue::new(values).select(&mut cs.ns(|| namespace_string), index, span)?
+                }
+                (ResolvedAssigneeAccess::ArrayRange(..), mut value) => {
+                    let targets = Self::resolve_assignee_access(access.clone(), span, vec![&mut value])?;
+                    ConstrainedValue::Array(targets.into_iter().map(|target| target.clone()).collect())
+                }
+                (access, mut value) => {
+                    let mut targets = Self::resolve_assignee_access(access.clone(), span, vec![&mut value])?;
+                    targets.remove(0).clone()
+                }
+            };
+        }
+
+        Ok(value)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn enforce_assign_operation<CS: ConstraintSystem<F>>(
         cs: &mut CS,
         condition: &Boolean,
@@ -87,16 +295,15 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         operation: &AssignOperation,
         target: &mut ConstrainedValue<'a, F, G>,
         new_value: ConstrainedValue<'a, F, G>,
+        overflow: OverflowBehavior,
+        constants: &AllocatedConstants,
         span: &Span,
     ) -> Result<(), StatementError> {
-        let new_value = match operation {
-            AssignOperation::Assign => new_value,
-            AssignOperation::Add => enforce_add(cs, target.clone(), new_value, span)?,
-            AssignOperation::Sub => enforce_sub(cs, target.clone(), new_value, span)?,
-            AssignOperation::Mul => enforce_mul(cs, target.clone(), new_value, span)?,
-            AssignOperation::Div => enforce_div(cs, target.clone(), new_value, span)?,
-            AssignOperation::Pow => enforce_pow(cs, target.clone(), new_value, span)?,
-            _ => unimplemented!("unimplemented assign operator"),
+        let new_value = match operation.binary_operation() {
+            None => new_value,
+            Some(operation) => {
+                Self::enforce_binary_operation(cs, &operation, target.clone(), new_value, overflow, constants, span)?
+            }
         };
         let selected_value = ConstrainedValue::conditionally_select(cs.ns(|| scope), condition, &new_value, target)
             .map_err(|_| StatementError::select_fail(new_value.to_string(), target.to_string(), span))?;
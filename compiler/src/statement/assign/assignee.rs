@@ -16,28 +16,40 @@
 
 //! Resolves assignees in a compiled Leo program.
 
-use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use crate::{
+    errors::StatementError,
+    program::ConstrainedProgram,
+    value::{ConstrainedValue, Integer},
+    GroupType,
+};
 use leo_asg::{AssignAccess, AssignStatement, Identifier, Span};
 
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::ConstraintSystem;
 
+#[derive(Clone)]
 pub(crate) enum ResolvedAssigneeAccess {
     ArrayRange(Option<usize>, Option<usize>),
     ArrayIndex(usize),
+    ArrayDynamicIndex(Integer),
     Tuple(usize, Span),
     Member(Identifier),
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
-    pub fn resolve_assign<CS: ConstraintSystem<F>>(
+    ///
+    /// Evaluates the accesses of an assignee.
+    ///
+    /// Array indices that are not known at compile time resolve to `ArrayDynamicIndex`.
+    ///
+    pub(crate) fn resolve_assign_accesses<CS: ConstraintSystem<F>>(
         &mut self,
         cs: &mut CS,
         assignee: &AssignStatement<'a>,
-    ) -> Result<Vec<&mut ConstrainedValue<'a, F, G>>, StatementError> {
+    ) -> Result<Vec<ResolvedAssigneeAccess>, StatementError> {
         let span = assignee.span.clone().unwrap_or_default();
 
-        let resolved_accesses = assignee
+        assignee
             .target_accesses
             .iter()
             .map(|access| match access {
@@ -63,17 +75,28 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     Ok(ResolvedAssigneeAccess::ArrayRange(start_index, stop_index))
                 }
                 AssignAccess::ArrayIndex(index) => {
-                    let index = self
-                        .enforce_index(cs, index.get(), &span)?
-                        .to_usize()
-                        .ok_or_else(|| StatementError::array_assign_index_const(&span))?;
+                    let index = self.enforce_index(cs, index.get(), &span)?;
 
-                    Ok(ResolvedAssigneeAccess::ArrayIndex(index))
+                    Ok(match index.to_usize() {
+                        Some(index) => ResolvedAssigneeAccess::ArrayIndex(index),
+                        None => ResolvedAssigneeAccess::ArrayDynamicIndex(index),
+                    })
                 }
                 AssignAccess::Tuple(index) => Ok(ResolvedAssigneeAccess::Tuple(*index, span.clone())),
                 AssignAccess::Member(identifier) => Ok(ResolvedAssigneeAccess::Member(identifier.clone())),
             })
-            .collect::<Result<Vec<_>, StatementError>>()?;
+            .collect::<Result<Vec<_>, StatementError>>()
+    }
+
+    ///
+    /// Returns mutable references to the values targeted by the given assignee accesses.
+    ///
+    pub(crate) fn resolve_assign_targets(
+        &mut self,
+        assignee: &AssignStatement<'a>,
+        resolved_accesses: Vec<ResolvedAssigneeAccess>,
+    ) -> Result<Vec<&mut ConstrainedValue<'a, F, G>>, StatementError> {
+        let span = assignee.span.clone().unwrap_or_default();
 
         let variable = assignee.target_variable.get().borrow();
 
@@ -118,7 +141,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 }
                 match value.remove(0) {
                     ConstrainedValue::Array(old) => {
-                        if index > old.len() {
+                        if index >= old.len() {
                             Err(StatementError::array_assign_index_bounds(index, old.len(), span))
                         } else {
                             Ok(vec![old.get_mut(index).unwrap()])
@@ -127,6 +150,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                     _ => Err(StatementError::array_assign_index(span)),
                 }
             }
+            ResolvedAssigneeAccess::ArrayDynamicIndex(_) => Err(StatementError::array_assign_index_const(span)),
             ResolvedAssigneeAccess::ArrayRange(start_index, stop_index) => {
                 let start_index = start_index.unwrap_or(0);
 
@@ -155,7 +179,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
                 }
                 match value.remove(0) {
                     ConstrainedValue::Tuple(old) => {
-                        if index > old.len() {
+                        if index >= old.len() {
                             Err(StatementError::tuple_assign_index_bounds(index, old.len(), &span))
                         } else {
                             Ok(vec![&mut old[index]])
@@ -17,7 +17,7 @@
 //! Enforces a branch of a conditional or iteration statement in a compiled Leo program.
 
 use crate::{program::ConstrainedProgram, GroupType, IndicatorAndConstrainedValue, StatementResult};
-use leo_asg::BlockStatement;
+use leo_asg::{BlockStatement, Node};
 
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::traits::utilities::boolean::Boolean;
@@ -36,7 +36,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
         let mut results = Vec::with_capacity(block.statements.len());
         // Evaluate statements. Only allow a single return argument to be returned.
         for statement in block.statements.iter() {
-            let value = self.enforce_statement(cs, indicator, statement.get())?;
+            // Statements after a `break` or `continue` that is always taken are never executed.
+            if self.loop_exited() {
+                break;
+            }
+
+            let span = statement.get().span().cloned().unwrap_or_default();
+            let indicator = self.guard_indicator(cs, indicator, &span)?;
+            let value = self.enforce_statement(cs, &indicator, statement.get())?;
 
             results.extend(value);
         }
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks a const assertion in a compiled Leo program.
+
+use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType, StatementResult};
+use leo_asg::ConstAssertStatement;
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::traits::utilities::boolean::Boolean;
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Checks that the condition of a `const_assert` statement is a constant `true`.
+    ///
+    /// The condition is evaluated at compile time and no constraints are emitted. Assertions in
+    /// branches that are known to be unreachable are skipped.
+    ///
+    pub fn enforce_const_assert_statement<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        statement: &ConstAssertStatement<'a>,
+    ) -> StatementResult<()> {
+        if let Boolean::Constant(false) = indicator {
+            return Ok(());
+        }
+
+        let span = statement.span.clone().unwrap_or_default();
+
+        match self.enforce_expression(cs, statement.condition.get())? {
+            ConstrainedValue::Boolean(Boolean::Constant(true)) => Ok(()),
+            ConstrainedValue::Boolean(Boolean::Constant(false)) => {
+                let condition: leo_ast::Expression = statement.condition.get().into();
+
+                Err(StatementError::const_assert_failed(condition.to_string(), &span))
+            }
+            value => Err(StatementError::const_assert_nonconst(value.to_string(), &span)),
+        }
+    }
+}
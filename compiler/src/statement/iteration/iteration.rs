@@ -24,6 +24,7 @@ use crate::{
     IndicatorAndConstrainedValue,
     Integer,
     IntegerTrait,
+    LoopGuard,
     StatementResult,
 };
 use leo_asg::IterationStatement;
@@ -52,8 +53,36 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             .enforce_index(cs, statement.stop.get(), &span)?
             .to_usize()
             .ok_or_else(|| StatementError::loop_index_const(&span))?;
+        let step = match statement.step.get() {
+            Some(step) => self
+                .enforce_index(cs, step, &span)?
+                .to_usize()
+                .ok_or_else(|| StatementError::loop_index_const(&span))?,
+            None => 1,
+        };
+
+        if step == 0 {
+            return Err(StatementError::loop_step_zero(&span));
+        }
+
+        // Descending ranges count down from `from` towards the `to` bound.
+        let indices: Vec<usize> = match (from <= to, statement.inclusive) {
+            (true, false) => (from..to).step_by(step).collect(),
+            (true, true) => (from..=to).step_by(step).collect(),
+            (false, false) => (to + 1..=from).rev().step_by(step).collect(),
+            (false, true) => (to..=from).rev().step_by(step).collect(),
+        };
+
+        self.loop_guards.push(LoopGuard::default());
+
+        for i in indices {
+            let guard = self.loop_guards.last_mut().expect("missing loop guard");
+            // Stop unrolling once a `break` is always taken.
+            if guard.is_broken() {
+                break;
+            }
+            guard.continued = Boolean::constant(false);
 
-        for i in from..to {
             // Store index in current function scope.
             // For loop scope is not implemented.
             let variable = statement.variable.borrow();
@@ -74,6 +103,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             results.extend(result);
         }
 
+        self.loop_guards.pop();
+
         Ok(results)
     }
 }
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces break and continue statements in a compiled Leo program.
+//!
+//! Loops are unrolled, so a `break` or `continue` cannot jump. Instead, each loop being unrolled
+//! keeps a guard that records whether a `break` or `continue` has been taken. Statements that
+//! follow are evaluated with an indicator that is disabled once the guard is set.
+
+use crate::{errors::StatementError, namespace_name, program::ConstrainedProgram, GroupType, StatementResult};
+use leo_asg::Span;
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::traits::utilities::boolean::Boolean;
+use snarkvm_r1cs::ConstraintSystem;
+
+/// Tracks the `break` and `continue` statements taken in a loop that is being unrolled.
+#[derive(Clone, Debug)]
+pub struct LoopGuard {
+    /// Set if a `break` statement has been taken in this or a previous iteration.
+    pub broken: Boolean,
+    /// Set if a `continue` statement has been taken in the current iteration.
+    pub continued: Boolean,
+}
+
+impl Default for LoopGuard {
+    fn default() -> Self {
+        Self {
+            broken: Boolean::constant(false),
+            continued: Boolean::constant(false),
+        }
+    }
+}
+
+impl LoopGuard {
+    /// Returns `true` if a `break` statement is always taken, so no further iterations run.
+    pub fn is_broken(&self) -> bool {
+        matches!(self.broken, Boolean::Constant(true))
+    }
+
+    /// Returns `true` if the rest of the current iteration is never executed.
+    pub fn is_exited(&self) -> bool {
+        self.is_broken() || matches!(self.continued, Boolean::Constant(true))
+    }
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    /// Returns `true` if the innermost loop is never executed past this point in the current iteration.
+    pub(crate) fn loop_exited(&self) -> bool {
+        self.loop_guards.last().map(LoopGuard::is_exited).unwrap_or(false)
+    }
+
+    /// Returns the given `indicator` disabled by any `break` or `continue` taken in the innermost loop.
+    pub(crate) fn guard_indicator<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        span: &Span,
+    ) -> StatementResult<Boolean> {
+        let guard = match self.loop_guards.last() {
+            Some(guard) => guard,
+            None => return Ok(*indicator),
+        };
+        if let (Boolean::Constant(false), Boolean::Constant(false)) = (&guard.broken, &guard.continued) {
+            return Ok(*indicator);
+        }
+
+        let name = namespace_name("loop guard".to_string(), span);
+        let exited = Boolean::or(cs.ns(|| format!("{} exited", name)), &guard.broken, &guard.continued)
+            .map_err(|_| StatementError::indicator_calculation(name.clone(), span))?;
+
+        Boolean::and(cs.ns(|| format!("{} indicator", name)), indicator, &exited.not())
+            .map_err(|_| StatementError::indicator_calculation(name, span))
+    }
+
+    /// Enforces a `break` statement, disabling the remaining iterations of the innermost loop.
+    pub fn enforce_break_statement<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        span: &Span,
+    ) -> StatementResult<()> {
+        let guard = self.loop_guards.last_mut().expect("break statement outside of a loop");
+        let name = format!("break {}:{}", span.line_start, span.col_start);
+        guard.broken = Boolean::or(cs.ns(|| name.clone()), &guard.broken, indicator)
+            .map_err(|_| StatementError::indicator_calculation(name, span))?;
+
+        Ok(())
+    }
+
+    /// Enforces a `continue` statement, disabling the rest of the current iteration of the innermost loop.
+    pub fn enforce_continue_statement<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        span: &Span,
+    ) -> StatementResult<()> {
+        let guard = self
+            .loop_guards
+            .last_mut()
+            .expect("continue statement outside of a loop");
+        let name = format!("continue {}:{}", span.line_start, span.col_start);
+        guard.continued = Boolean::or(cs.ns(|| name.clone()), &guard.continued, indicator)
+            .map_err(|_| StatementError::indicator_calculation(name, span))?;
+
+        Ok(())
+    }
+}
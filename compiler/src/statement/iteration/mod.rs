@@ -18,3 +18,6 @@
 
 pub mod iteration;
 pub use self::iteration::*;
+
+pub mod loop_control;
+pub use self::loop_control::*;
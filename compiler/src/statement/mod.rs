@@ -25,12 +25,18 @@ pub use self::block::*;
 pub mod conditional;
 pub use self::conditional::*;
 
+pub mod const_assert;
+pub use self::const_assert::*;
+
 pub mod definition;
 pub use self::definition::*;
 
 pub mod iteration;
 pub use self::iteration::*;
 
+pub mod require;
+pub use self::require::*;
+
 pub mod return_;
 pub use self::return_::*;
 
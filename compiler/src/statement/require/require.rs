@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Enforces a require statement in a compiled Leo program.
+
+use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType, StatementResult};
+use leo_asg::RequireStatement;
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::traits::utilities::{boolean::Boolean, eq::EqGadget};
+use snarkvm_r1cs::ConstraintSystem;
+
+impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
+    ///
+    /// Enforces that the condition of a `require` statement holds whenever the statement is reached.
+    ///
+    /// Unlike `console.assert`, the condition is enforced in the constraint system. If the condition
+    /// is known to fail, the formatted message is returned in the error.
+    ///
+    pub fn enforce_require_statement<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        indicator: &Boolean,
+        statement: &RequireStatement<'a>,
+    ) -> StatementResult<()> {
+        let span = statement.span.clone().unwrap_or_default();
+
+        let condition = match self.enforce_expression(cs, statement.condition.get())? {
+            ConstrainedValue::Boolean(condition) => condition,
+            value => return Err(StatementError::require_boolean(value.to_string(), &span)),
+        };
+
+        // The requirement is satisfied if the statement is unreached or the condition holds.
+        let satisfied = Boolean::or(cs.ns(|| "unreached or satisfied"), &indicator.not(), &condition)
+            .map_err(|_| StatementError::indicator_calculation("!indicator || condition".to_string(), &span))?;
+
+        if satisfied.get_value() == Some(false) {
+            let message = self.format(cs, &statement.message)?;
+
+            return Err(StatementError::require_failed(message, &span));
+        }
+
+        satisfied
+            .enforce_equal(cs.ns(|| "enforce requirement"), &Boolean::Constant(true))
+            .map_err(|_| StatementError::require_failed("constraint could not be enforced".to_string(), &span))?;
+
+        Ok(())
+    }
+}
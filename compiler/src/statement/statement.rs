@@ -16,7 +16,7 @@
 
 //! Enforces a statement in a compiled Leo program.
 
-use crate::{errors::StatementError, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
+use crate::{errors::StatementError, namespace_name, program::ConstrainedProgram, value::ConstrainedValue, GroupType};
 use leo_asg::{Node, Statement};
 
 use snarkvm_fields::PrimeField;
@@ -43,7 +43,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
     ) -> StatementResult<Vec<IndicatorAndConstrainedValue<'a, F, G>>> {
         let mut results = vec![];
         let span = statement.span().cloned().unwrap_or_default();
-        let mut cs = cs.ns(|| format!("statement {}:{}", span.line_start, span.col_start));
+        let mut cs = cs.ns(|| namespace_name("statement".to_string(), &span));
         let cs = &mut cs;
 
         match statement {
@@ -71,6 +71,12 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
             Statement::Console(statement) => {
                 self.evaluate_console_function_call(cs, indicator, statement)?;
             }
+            Statement::Require(statement) => {
+                self.enforce_require_statement(cs, indicator, statement)?;
+            }
+            Statement::ConstAssert(statement) => {
+                self.enforce_const_assert_statement(cs, indicator, statement)?;
+            }
             Statement::Expression(statement) => {
                 let value = self.enforce_expression(cs, statement.expression.get())?;
                 // handle empty return value cases
@@ -95,6 +101,12 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedProgram<'a, F, G> {
 
                 results.extend(result);
             }
+            Statement::Break(_) => {
+                self.enforce_break_statement(cs, indicator, &span)?;
+            }
+            Statement::Continue(_) => {
+                self.enforce_continue_statement(cs, indicator, &span)?;
+            }
             Statement::Empty(_) => (),
         };
 
@@ -60,6 +60,7 @@ impl Address {
         cs: &mut CS,
         name: &str,
         input_value: Option<InputValue>,
+        public: bool,
         span: &Span,
     ) -> Result<ConstrainedValue<'a, F, G>, AddressError> {
         // Check that the input value is the correct type
@@ -74,10 +75,14 @@ impl Address {
             None => None,
         };
 
-        let address = Address::alloc(
-            cs.ns(|| format!("`{}: address` {}:{}", name, span.line_start, span.col_start)),
-            || address_value.ok_or(SynthesisError::AssignmentMissing),
-        )
+        let namespace = format!("`{}: address` {}:{}", name, span.line_start, span.col_start);
+        let value = || address_value.ok_or(SynthesisError::AssignmentMissing);
+
+        let address = if public {
+            Address::alloc_input(cs.ns(|| namespace), value)
+        } else {
+            Address::alloc(cs.ns(|| namespace), value)
+        }
         .map_err(|_| AddressError::missing_address(span))?;
 
         Ok(ConstrainedValue::Address(address))
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An array value with bounds-checked indexing.
+
+use std::convert::TryInto;
+
+use crate::{
+    errors::ExpressionError,
+    relational::{evaluate_ge, evaluate_le, evaluate_lt},
+    value::{ConstrainedValue, Integer},
+    GroupType,
+};
+use leo_asg::{ConstInt, Span};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::utilities::{
+    boolean::Boolean,
+    eq::{EqGadget, EvaluateEqGadget},
+    select::CondSelectGadget,
+};
+use snarkvm_r1cs::ConstraintSystem;
+
+/// The elements of a constrained array.
+///
+/// Indices with a known value are checked against the array length at compile time,
+/// all other indices are checked in the constraint system.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ArrayValue<'a, F: PrimeField, G: GroupType<F>> {
+    values: Vec<ConstrainedValue<'a, F, G>>,
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> ArrayValue<'a, F, G> {
+    pub fn new(values: Vec<ConstrainedValue<'a, F, G>>) -> Self {
+        Self { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[ConstrainedValue<'a, F, G>] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<ConstrainedValue<'a, F, G>> {
+        self.values
+    }
+
+    ///
+    /// Enforces `index < len`, or `index <= len` if `inclusive` is set.
+    ///
+    /// The length is compared in the type of the index. Signed indices are also enforced to be
+    /// non-negative, and a length above the maximum of the index type bounds every index.
+    ///
+    pub fn enforce_bound<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        index: &Integer,
+        inclusive: bool,
+        span: &Span,
+    ) -> Result<(), ExpressionError> {
+        self.enforce_bound_when(cs, &Boolean::Constant(true), index, inclusive, span)
+    }
+
+    ///
+    /// Enforces the bounds of [`ArrayValue::enforce_bound`] only when `condition` is true.
+    ///
+    pub fn enforce_bound_when<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        condition: &Boolean,
+        index: &Integer,
+        inclusive: bool,
+        span: &Span,
+    ) -> Result<(), ExpressionError> {
+        let array_len: u32 = self
+            .len()
+            .try_into()
+            .map_err(|_| ExpressionError::array_length_out_of_bounds(span))?;
+        let index_type = index.get_type();
+        let array_len = ConstInt::U32(array_len).cast_to(&index_type);
+        let index = ConstrainedValue::Integer(index.clone());
+
+        let mut bounds_checks = vec![];
+        if index_type.is_signed() {
+            let zero = ConstrainedValue::Integer(Integer::new(&ConstInt::U32(0).cast_to(&index_type)));
+            bounds_checks.push(evaluate_ge::<F, G, CS>(cs, index.clone(), zero, span)?);
+        }
+        if array_len.to_usize() == Some(self.len()) {
+            let array_len = ConstrainedValue::Integer(Integer::new(&array_len));
+            bounds_checks.push(if inclusive {
+                evaluate_le::<F, G, CS>(cs, index, array_len, span)?
+            } else {
+                evaluate_lt::<F, G, CS>(cs, index, array_len, span)?
+            });
+        }
+
+        for (i, bounds_check) in bounds_checks.into_iter().enumerate() {
+            let bounds_check = match bounds_check {
+                ConstrainedValue::Boolean(b) => b,
+                _ => unimplemented!("illegal non-Boolean returned from array bounds check"),
+            };
+
+            let namespace_string = format!("evaluate array bounds {} {}:{}", i, span.line_start, span.col_start);
+            let mut unique_namespace = cs.ns(|| namespace_string);
+            let bounds_check = Boolean::or(
+                unique_namespace.ns(|| "unreached or in bounds"),
+                &condition.not(),
+                &bounds_check,
+            )
+            .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
+            bounds_check
+                .enforce_equal(&mut unique_namespace, &Boolean::Constant(true))
+                .map_err(|e| ExpressionError::cannot_enforce("array bounds check".to_string(), e, span))?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the element at `index`.
+    ///
+    /// An index with a known value, constant or allocated, is checked and resolved at compile time.
+    /// Any other index is bounds checked in the constraint system and the element is conditionally
+    /// selected.
+    ///
+    pub fn get<CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        index: &Integer,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        if let Some(resolved) = index.to_usize() {
+            if resolved >= self.len() {
+                return Err(ExpressionError::array_index_out_of_bounds(resolved, span));
+            }
+            let mut values = self.values;
+            return Ok(values.swap_remove(resolved));
+        }
+
+        if self.is_empty() {
+            return Err(ExpressionError::array_index_out_of_bounds(0, span));
+        }
+        self.enforce_bound(cs, index, false, span)?;
+        self.select(cs, index, span)
+    }
+
+    ///
+    /// Returns the element at an `index` that is not known at compile time, without checking its bounds.
+    ///
+    /// The caller must ensure the index is in bounds wherever the element is used.
+    ///
+    pub fn select<CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        index: &Integer,
+        span: &Span,
+    ) -> Result<ConstrainedValue<'a, F, G>, ExpressionError> {
+        if self.is_empty() {
+            return Err(ExpressionError::array_index_out_of_bounds(0, span));
+        }
+        let mut values = self.values;
+
+        // Unsigned indices select through a balanced tree keyed on the index bits.
+        if !index.get_type().is_signed() {
+            let namespace_string = format!("select array access {}:{}", span.line_start, span.col_start);
+            return ConstrainedValue::select_by_index_bits(cs.ns(|| namespace_string), &index.get_bits(), values)
+                .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span));
+        }
+
+        let mut current_value = values.pop().unwrap();
+        for (i, item) in values.into_iter().enumerate() {
+            let namespace_string = format!("evaluate array access eq {} {}:{}", i, span.line_start, span.col_start);
+            let eq_namespace = cs.ns(|| namespace_string);
+
+            let index_bounded = i
+                .try_into()
+                .map_err(|_| ExpressionError::array_index_out_of_legal_bounds(span))?;
+            let const_index = ConstInt::U32(index_bounded).cast_to(&index.get_type());
+            let index_comparison = index
+                .evaluate_equal(eq_namespace, &Integer::new(&const_index))
+                .map_err(|_| ExpressionError::cannot_evaluate("==".to_string(), span))?;
+
+            let unique_namespace =
+                cs.ns(|| format!("select array access {} {}:{}", i, span.line_start, span.col_start));
+            current_value =
+                ConstrainedValue::conditionally_select(unique_namespace, &index_comparison, &item, &current_value)
+                    .map_err(|e| ExpressionError::cannot_enforce("conditional select".to_string(), e, span))?;
+        }
+        Ok(current_value)
+    }
+}
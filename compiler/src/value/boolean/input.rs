@@ -27,19 +27,26 @@ pub(crate) fn allocate_bool<F: PrimeField, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: &str,
     option: Option<bool>,
+    public: bool,
     span: &Span,
 ) -> Result<Boolean, BooleanError> {
-    Boolean::alloc(
-        cs.ns(|| format!("`{}: bool` {}:{}", name, span.line_start, span.col_start)),
-        || option.ok_or(SynthesisError::AssignmentMissing),
-    )
-    .map_err(|_| BooleanError::missing_boolean(format!("{}: bool", name), span))
+    let namespace = format!("`{}: bool` {}:{}", name, span.line_start, span.col_start);
+    let value = || option.ok_or(SynthesisError::AssignmentMissing);
+
+    let result = if public {
+        Boolean::alloc_input(cs.ns(|| namespace), value)
+    } else {
+        Boolean::alloc(cs.ns(|| namespace), value)
+    };
+
+    result.map_err(|_| BooleanError::missing_boolean(format!("{}: bool", name), span))
 }
 
 pub(crate) fn bool_from_input<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: &str,
     input_value: Option<InputValue>,
+    public: bool,
     span: &Span,
 ) -> Result<ConstrainedValue<'a, F, G>, BooleanError> {
     // Check that the input value is the correct type
@@ -54,7 +61,7 @@ pub(crate) fn bool_from_input<'a, F: PrimeField, G: GroupType<F>, CS: Constraint
         None => None,
     };
 
-    let number = allocate_bool(cs, name, option, span)?;
+    let number = allocate_bool(cs, name, option, public, span)?;
 
     Ok(ConstrainedValue::Boolean(number))
 }
@@ -16,16 +16,17 @@
 
 //! A data type that represents a field value
 
-use crate::{errors::FieldError, number_string_typing};
+use crate::{errors::FieldError, number_string_typing, IntegerTrait};
 use leo_ast::Span;
 
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, SquareRootField};
 use snarkvm_gadgets::{
     fields::{AllocatedFp, FpGadget},
     traits::{
         fields::FieldGadget,
         utilities::{
             alloc::AllocGadget,
+            bits::Xor,
             boolean::Boolean,
             eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
             select::CondSelectGadget,
@@ -145,24 +146,191 @@ impl<F: PrimeField> FieldType<F> {
     }
 
     pub fn div<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self, span: &Span) -> Result<Self, FieldError> {
-        let inverse = match other {
+        let inverse = other.inv(&mut cs, span)?;
+
+        self.mul(cs, &inverse, span)
+    }
+
+    ///
+    /// Returns the multiplicative inverse, or an error if the field element is zero.
+    ///
+    pub fn inv<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<Self, FieldError> {
+        match self {
             FieldType::Constant(constant) => {
                 let constant_inverse = constant
                     .inverse()
                     .ok_or_else(|| FieldError::no_inverse(constant.to_string(), span))?;
 
-                FieldType::Constant(constant_inverse)
+                Ok(FieldType::Constant(constant_inverse))
             }
             FieldType::Allocated(allocated) => {
+                if let Some(true) = allocated.get_value().map(|value| value.is_zero()) {
+                    return Err(FieldError::no_inverse(F::zero().to_string(), span));
+                }
+
                 let allocated_inverse = allocated
                     .inverse(&mut cs)
-                    .map_err(|e| FieldError::binary_operation("+".to_string(), e, span))?;
+                    .map_err(|e| FieldError::unary_operation("inv".to_string(), e, span))?;
 
-                FieldType::Allocated(allocated_inverse)
+                Ok(FieldType::Allocated(allocated_inverse))
             }
-        };
+        }
+    }
 
-        self.mul(cs, &inverse, span)
+    ///
+    /// Returns a square root of the field element.
+    ///
+    /// A constant without a root is an error. An allocated value without a root
+    /// leaves the enforced `root * root == self` constraint unsatisfied.
+    ///
+    pub fn sqrt<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<Self, FieldError>
+    where
+        F: SquareRootField,
+    {
+        match self {
+            FieldType::Constant(constant) => {
+                let constant_root = constant
+                    .sqrt()
+                    .ok_or_else(|| FieldError::no_square_root(constant.to_string(), span))?;
+
+                Ok(FieldType::Constant(constant_root))
+            }
+            FieldType::Allocated(allocated) => {
+                let root = FpGadget::alloc(cs.ns(|| "root"), || {
+                    let value = allocated.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+
+                    Ok(value.sqrt().unwrap_or_else(F::zero))
+                })
+                .map_err(|e| FieldError::unary_operation("sqrt".to_string(), e, span))?;
+
+                root.mul_equals(cs.ns(|| "root * root == self"), &root, allocated)
+                    .map_err(|e| FieldError::unary_operation("sqrt".to_string(), e, span))?;
+
+                Ok(FieldType::Allocated(root))
+            }
+        }
+    }
+
+    ///
+    /// Returns the field element as a gadget, without allocating a new variable for allocated values.
+    ///
+    pub fn to_gadget<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> FpGadget<F> {
+        match self {
+            FieldType::Constant(constant) => FpGadget::from(AllocatedFp::from(&mut cs, constant)),
+            FieldType::Allocated(allocated) => allocated.clone(),
+        }
+    }
+
+    ///
+    /// Returns whether two field elements are equal by comparing their canonical bits.
+    ///
+    pub fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self, span: &Span) -> Result<Boolean, FieldError> {
+        if let (FieldType::Constant(first), FieldType::Constant(second)) = (self, other) {
+            return Ok(Boolean::constant(first == second));
+        }
+
+        let self_bits = self.to_bits(cs.ns(|| "self bits"), span)?;
+        let other_bits = other.to_bits(cs.ns(|| "other bits"), span)?;
+
+        let mut result = Boolean::constant(true);
+        for (i, (first, second)) in self_bits.iter().zip(other_bits.iter()).enumerate() {
+            let different = first
+                .xor(cs.ns(|| format!("xor {}", i)), second)
+                .map_err(|e| FieldError::binary_operation("==".to_string(), e, span))?;
+            result = Boolean::and(cs.ns(|| format!("and {}", i)), &result, &different.not())
+                .map_err(|e| FieldError::binary_operation("==".to_string(), e, span))?;
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Returns whether the canonical representative of `self` is less than that of `other`.
+    ///
+    /// Both elements are read as integers in `[0, p)` and compared by their canonical bits. This
+    /// order is not preserved by field arithmetic, e.g. `p - 1 > 0` but `(p - 1) + 1 < 0 + 1`.
+    ///
+    pub fn less_than<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        span: &Span,
+    ) -> Result<Boolean, FieldError> {
+        if let (FieldType::Constant(first), FieldType::Constant(second)) = (self, other) {
+            return Ok(Boolean::constant(first.into_repr() < second.into_repr()));
+        }
+
+        let self_bits = self.to_bits(cs.ns(|| "self bits"), span)?;
+        let other_bits = other.to_bits(cs.ns(|| "other bits"), span)?;
+
+        // The most significant differing bit decides, so later bits override earlier ones.
+        let mut result = Boolean::constant(false);
+        for (i, (first, second)) in self_bits.iter().zip(other_bits.iter()).enumerate() {
+            let different = first
+                .xor(cs.ns(|| format!("xor {}", i)), second)
+                .map_err(|e| FieldError::binary_operation("<".to_string(), e, span))?;
+            result = Boolean::conditionally_select(cs.ns(|| format!("select {}", i)), &different, second, &result)
+                .map_err(|e| FieldError::binary_operation("<".to_string(), e, span))?;
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Returns the canonical little-endian bit decomposition of the field element.
+    ///
+    pub fn to_bits<CS: ConstraintSystem<F>>(&self, mut cs: CS, span: &Span) -> Result<Vec<Boolean>, FieldError> {
+        let mut bits = self
+            .to_gadget(&mut cs)
+            .to_bits_be_strict(cs.ns(|| "to_bits"))
+            .map_err(|e| FieldError::unary_operation("to_bits".to_string(), e, span))?;
+        bits.reverse();
+
+        Ok(bits)
+    }
+
+    ///
+    /// Returns the canonical little-endian bytes of the field element, zero-padded to a whole byte.
+    ///
+    pub fn to_bytes_le<CS: ConstraintSystem<F>>(&self, cs: CS, span: &Span) -> Result<Vec<UInt8>, FieldError> {
+        let mut bits = self.to_bits(cs, span)?;
+        bits.resize((bits.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        Ok(bits.chunks(8).map(UInt8::from_bits_le).collect())
+    }
+
+    ///
+    /// Returns the field element with the given little-endian bits.
+    ///
+    /// The result is decomposed again with the canonical range check and compared bit by bit,
+    /// so a pattern encoding a value at or above the modulus is unsatisfiable.
+    ///
+    pub fn from_bits<CS: ConstraintSystem<F>>(mut cs: CS, bits: &[Boolean], span: &Span) -> Result<Self, FieldError> {
+        let expected = F::size_in_bits();
+        if bits.len() != expected {
+            return Err(FieldError::invalid_bit_length(expected, bits.len(), span));
+        }
+
+        let result = FpGadget::alloc(cs.ns(|| "from_bits"), || {
+            bits.iter().rev().try_fold(F::zero(), |value, bit| {
+                let value = value.double();
+                match bit.get_value() {
+                    Some(true) => Ok(value + &F::one()),
+                    Some(false) => Ok(value),
+                    None => Err(SynthesisError::AssignmentMissing),
+                }
+            })
+        })
+        .map_err(|e| FieldError::unary_operation("from_bits".to_string(), e, span))?;
+        let result = FieldType::Allocated(result);
+
+        let result_bits = result.to_bits(cs.ns(|| "to_bits"), span)?;
+        for (i, (bit, result_bit)) in bits.iter().zip(result_bits.iter()).enumerate() {
+            bit.enforce_equal(cs.ns(|| format!("bit {}", i)), result_bit)
+                .map_err(|e| FieldError::unary_operation("from_bits".to_string(), e, span))?;
+        }
+
+        Ok(result)
     }
 
     pub fn alloc_helper<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<String>>(
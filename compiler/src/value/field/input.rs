@@ -27,24 +27,25 @@ pub(crate) fn allocate_field<F: PrimeField, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: &str,
     option: Option<String>,
+    public: bool,
     span: &Span,
 ) -> Result<FieldType<F>, FieldError> {
     match option {
         Some(string) => {
-            let number_info = number_string_typing(&string);
+            let (number, neg) = number_string_typing(&string);
+            let namespace = format!("`{}: field` {}:{}", name, span.line_start, span.col_start);
+            let value = || Some(number).ok_or(SynthesisError::AssignmentMissing);
 
-            match number_info {
-                (number, neg) if neg => FieldType::alloc(
-                    cs.ns(|| format!("`{}: field` {}:{}", name, span.line_start, span.col_start)),
-                    || Some(number).ok_or(SynthesisError::AssignmentMissing),
-                )
-                .map(|value| value.negate(cs, span))
-                .map_err(|_| FieldError::missing_field(format!("{}: field", name), span))?,
-                (number, _) => FieldType::alloc(
-                    cs.ns(|| format!("`{}: field` {}:{}", name, span.line_start, span.col_start)),
-                    || Some(number).ok_or(SynthesisError::AssignmentMissing),
-                )
-                .map_err(|_| FieldError::missing_field(format!("{}: field", name), span)),
+            let field = if public {
+                FieldType::alloc_input(cs.ns(|| namespace), value)
+            } else {
+                FieldType::alloc(cs.ns(|| namespace), value)
+            }
+            .map_err(|_| FieldError::missing_field(format!("{}: field", name), span))?;
+
+            match neg {
+                true => field.negate(cs, span),
+                false => Ok(field),
             }
         }
         None => Err(FieldError::missing_field(format!("{}: field", name), span)),
@@ -55,6 +56,7 @@ pub(crate) fn field_from_input<'a, F: PrimeField, G: GroupType<F>, CS: Constrain
     cs: &mut CS,
     name: &str,
     input_value: Option<InputValue>,
+    public: bool,
     span: &Span,
 ) -> Result<ConstrainedValue<'a, F, G>, FieldError> {
     // Check that the parameter value is the correct type
@@ -69,7 +71,7 @@ pub(crate) fn field_from_input<'a, F: PrimeField, G: GroupType<F>, CS: Constrain
         None => None,
     };
 
-    let field = allocate_field(cs, name, option, span)?;
+    let field = allocate_field(cs, name, option, public, span)?;
 
     Ok(ConstrainedValue::Field(field))
 }
@@ -16,21 +16,23 @@
 
 //! A data type that represents members in the group formed by the set of affine points on a curve.
 
-use crate::errors::GroupError;
+use crate::{errors::GroupError, FieldType};
 use leo_asg::{GroupValue, Span};
 
-use snarkvm_fields::{Field, One};
+use snarkvm_fields::{One, PrimeField};
 use snarkvm_gadgets::traits::utilities::{
     alloc::AllocGadget,
+    boolean::Boolean,
     eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
     select::CondSelectGadget,
+    uint::UInt8,
     ToBitsBEGadget,
     ToBytesGadget,
 };
 use snarkvm_r1cs::ConstraintSystem;
 use std::fmt::{Debug, Display};
 
-pub trait GroupType<F: Field>:
+pub trait GroupType<F: PrimeField>:
     Sized
     + Clone
     + Debug
@@ -53,4 +55,18 @@ pub trait GroupType<F: Field>:
     fn add<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: &Span) -> Result<Self, GroupError>;
 
     fn sub<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self, span: &Span) -> Result<Self, GroupError>;
+
+    /// Multiplies by a scalar given as little-endian bits.
+    /// A scalar whose bits are all constant takes a cheaper fixed-scalar path.
+    fn mul<CS: ConstraintSystem<F>>(&self, cs: CS, scalar: &[Boolean], span: &Span) -> Result<Self, GroupError>;
+
+    /// Returns the order of the prime-order subgroup as little-endian bits, padded to whole limbs.
+    fn subgroup_order() -> Vec<bool>;
+
+    /// Returns the affine `(x, y)` coordinates as field elements.
+    fn coordinates(&self) -> (FieldType<F>, FieldType<F>);
+
+    /// Returns the compressed Pedersen hash of `input` over this curve.
+    fn pedersen_hash<CS: ConstraintSystem<F>>(cs: CS, input: &[UInt8], span: &Span)
+        -> Result<FieldType<F>, GroupError>;
 }
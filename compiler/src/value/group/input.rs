@@ -27,19 +27,26 @@ pub(crate) fn allocate_group<F: PrimeField, G: GroupType<F>, CS: ConstraintSyste
     cs: &mut CS,
     name: &str,
     option: Option<GroupValue>,
+    public: bool,
     span: &Span,
 ) -> Result<G, GroupError> {
-    G::alloc(
-        cs.ns(|| format!("`{}: group` {}:{}", name, span.line_start, span.col_start)),
-        || option.ok_or(SynthesisError::AssignmentMissing),
-    )
-    .map_err(|_| GroupError::missing_group(format!("{}: group", name), span))
+    let namespace = format!("`{}: group` {}:{}", name, span.line_start, span.col_start);
+    let value = || option.ok_or(SynthesisError::AssignmentMissing);
+
+    let result = if public {
+        G::alloc_input(cs.ns(|| namespace), value)
+    } else {
+        G::alloc(cs.ns(|| namespace), value)
+    };
+
+    result.map_err(|_| GroupError::missing_group(format!("{}: group", name), span))
 }
 
 pub(crate) fn group_from_input<'a, F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>>(
     cs: &mut CS,
     name: &str,
     input_value: Option<InputValue>,
+    public: bool,
     span: &Span,
 ) -> Result<ConstrainedValue<'a, F, G>, GroupError> {
     // Check that the parameter value is the correct type
@@ -61,6 +68,7 @@ pub(crate) fn group_from_input<'a, F: PrimeField, G: GroupType<F>, CS: Constrain
             leo_ast::GroupValue::Single(s, _) => GroupValue::Single(s),
             leo_ast::GroupValue::Tuple(leo_ast::GroupTuple { x, y, .. }) => GroupValue::Tuple((&x).into(), (&y).into()),
         }),
+        public,
         span,
     )?;
 
@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::targets::twisted_edwards::{self, EdwardsPedersenHash, TwistedEdwardsGroupType};
+
+use snarkvm_curves::edwards_sw6::EdwardsParameters;
+
+/// A group element of the Edwards curve embedded in BW6-761.
+pub type EdwardsSw6GroupType = TwistedEdwardsGroupType<EdwardsParameters>;
+
+///
+/// Returns the Pedersen hash instance of the Edwards SW6 curve.
+///
+pub fn pedersen_hash_parameters() -> EdwardsPedersenHash<EdwardsParameters> {
+    twisted_edwards::pedersen_hash_parameters()
+}
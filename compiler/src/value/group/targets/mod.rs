@@ -17,3 +17,7 @@
 //! implemented group targets
 
 pub mod edwards_bls12;
+
+pub mod edwards_sw6;
+
+pub mod twisted_edwards;
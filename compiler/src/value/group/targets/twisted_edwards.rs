@@ -0,0 +1,814 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::GroupError, number_string_typing, FieldType, GroupType};
+use leo_asg::{GroupCoordinate, GroupValue, Span};
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use snarkvm_algorithms::{
+    crh::{PedersenCompressedCRH, PedersenSize},
+    traits::CRH,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{GroupAffine, GroupProjective},
+    AffineCurve,
+    ModelParameters,
+    TEModelParameters,
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_gadgets::{
+    algorithms::crh::{PedersenCRHParametersGadget, PedersenCompressedCRHGadget},
+    curves::templates::twisted_edwards::AffineGadget,
+    fields::{AllocatedFp, FpGadget},
+    traits::{
+        algorithms::CRHGadget,
+        curves::GroupGadget,
+        fields::FieldGadget,
+        utilities::{
+            alloc::AllocGadget,
+            boolean::Boolean,
+            eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
+            select::CondSelectGadget,
+            uint::UInt8,
+            ToBitsBEGadget,
+            ToBytesGadget,
+        },
+    },
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+use std::{
+    borrow::Borrow,
+    ops::{Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// Number of scalar bits consumed per step of a constant-scalar multiplication.
+const MUL_WINDOW_SIZE: usize = 4;
+
+/// Seed for the fixed Pedersen hash generators.
+const PEDERSEN_HASH_SEED: u64 = 0x6c65_6f5f_7065_6465;
+
+/// Window layout of the Pedersen hash, accepting up to 64 bytes of input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PedersenHashWindow;
+
+impl PedersenSize for PedersenHashWindow {
+    const NUM_WINDOWS: usize = 4;
+    const WINDOW_SIZE: usize = 128;
+}
+
+pub type EdwardsPedersenHash<P> = PedersenCompressedCRH<GroupProjective<P>, PedersenHashWindow>;
+
+type EdwardsPedersenHashGadget<P> =
+    PedersenCompressedCRHGadget<GroupProjective<P>, <P as ModelParameters>::BaseField, EdwardsGadget<P>>;
+
+/// The allocated affine point gadget of the twisted Edwards curve `P`.
+pub type EdwardsGadget<P> =
+    AffineGadget<P, <P as ModelParameters>::BaseField, FpGadget<<P as ModelParameters>::BaseField>>;
+
+///
+/// Returns the Pedersen hash instance of the curve `P` whose generators are derived from `PEDERSEN_HASH_SEED`.
+///
+pub fn pedersen_hash_parameters<P: TEModelParameters>() -> EdwardsPedersenHash<P> {
+    EdwardsPedersenHash::setup(&mut XorShiftRng::seed_from_u64(PEDERSEN_HASH_SEED))
+}
+
+///
+/// A group element of the twisted Edwards curve `P`, over its base field.
+///
+pub enum TwistedEdwardsGroupType<P: TEModelParameters>
+where
+    P::BaseField: PrimeField,
+{
+    Constant(GroupAffine<P>),
+    Allocated(Box<EdwardsGadget<P>>),
+}
+
+impl<P: TEModelParameters> Clone for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Constant(constant) => Self::Constant(*constant),
+            Self::Allocated(allocated) => Self::Allocated(allocated.clone()),
+        }
+    }
+}
+
+impl<P: TEModelParameters> std::fmt::Debug for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Constant(constant) => f.debug_tuple("Constant").field(constant).finish(),
+            Self::Allocated(allocated) => f.debug_tuple("Allocated").field(allocated).finish(),
+        }
+    }
+}
+
+impl<P: TEModelParameters> GroupType<P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn constant(group: &GroupValue, span: &Span) -> Result<Self, GroupError> {
+        let value = Self::edwards_affine_from_value(group, span)?;
+
+        Ok(Self::Constant(value))
+    }
+
+    fn to_allocated<CS: ConstraintSystem<P::BaseField>>(&self, mut cs: CS, span: &Span) -> Result<Self, GroupError> {
+        self.allocated(cs.ns(|| format!("allocate affine point {}:{}", span.line_start, span.col_start)))
+            .map(|ebg| Self::Allocated(Box::new(ebg)))
+            .map_err(|error| GroupError::synthesis_error(error, span))
+    }
+
+    fn negate<CS: ConstraintSystem<P::BaseField>>(&self, cs: CS, span: &Span) -> Result<Self, GroupError> {
+        match self {
+            Self::Constant(group) => Ok(Self::Constant(group.neg())),
+            Self::Allocated(group) => {
+                let result = <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::negate(group, cs)
+                    .map_err(|e| GroupError::negate_operation(e, span))?;
+
+                Ok(Self::Allocated(Box::new(result)))
+            }
+        }
+    }
+
+    fn add<CS: ConstraintSystem<P::BaseField>>(&self, cs: CS, other: &Self, span: &Span) -> Result<Self, GroupError> {
+        match (self, other) {
+            (Self::Constant(self_value), Self::Constant(other_value)) => {
+                Ok(Self::Constant(self_value.add(other_value)))
+            }
+
+            (Self::Allocated(self_value), Self::Allocated(other_value)) => {
+                let result =
+                    <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::add(self_value, cs, other_value)
+                        .map_err(|e| GroupError::binary_operation("+".to_string(), e, span))?;
+
+                Ok(Self::Allocated(Box::new(result)))
+            }
+
+            (Self::Constant(constant_value), Self::Allocated(allocated_value))
+            | (Self::Allocated(allocated_value), Self::Constant(constant_value)) => Ok(Self::Allocated(Box::new(
+                allocated_value
+                    .add_constant(cs, constant_value)
+                    .map_err(|e| GroupError::binary_operation("+".to_string(), e, span))?,
+            ))),
+        }
+    }
+
+    fn sub<CS: ConstraintSystem<P::BaseField>>(&self, cs: CS, other: &Self, span: &Span) -> Result<Self, GroupError> {
+        match (self, other) {
+            (Self::Constant(self_value), Self::Constant(other_value)) => {
+                Ok(Self::Constant(self_value.sub(other_value)))
+            }
+
+            (Self::Allocated(self_value), Self::Allocated(other_value)) => {
+                let result =
+                    <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::sub(self_value, cs, other_value)
+                        .map_err(|e| GroupError::binary_operation("-".to_string(), e, span))?;
+
+                Ok(Self::Allocated(Box::new(result)))
+            }
+
+            (Self::Constant(constant_value), Self::Allocated(allocated_value))
+            | (Self::Allocated(allocated_value), Self::Constant(constant_value)) => Ok(Self::Allocated(Box::new(
+                allocated_value
+                    .sub_constant(cs, constant_value)
+                    .map_err(|e| GroupError::binary_operation("-".to_string(), e, span))?,
+            ))),
+        }
+    }
+
+    fn mul<CS: ConstraintSystem<P::BaseField>>(
+        &self,
+        mut cs: CS,
+        scalar: &[Boolean],
+        span: &Span,
+    ) -> Result<Self, GroupError> {
+        let constant_scalar = scalar
+            .iter()
+            .map(|bit| match bit {
+                Boolean::Constant(bit) => Some(*bit),
+                _ => None,
+            })
+            .collect::<Option<Vec<bool>>>();
+
+        if let Some(bits) = constant_scalar {
+            return self.mul_by_constant(cs, &bits, span);
+        }
+
+        let base = self
+            .allocated(cs.ns(|| "base"))
+            .map_err(|e| GroupError::binary_operation("*".to_string(), e, span))?;
+        let zero = <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::zero(cs.ns(|| "zero"))
+            .map_err(|e| GroupError::binary_operation("*".to_string(), e, span))?;
+
+        let result = <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::mul_bits(
+            &base,
+            cs.ns(|| "mul_bits"),
+            &zero,
+            scalar.iter().cloned(),
+        )
+        .map_err(|e| GroupError::binary_operation("*".to_string(), e, span))?;
+
+        Ok(Self::Allocated(Box::new(result)))
+    }
+
+    fn subgroup_order() -> Vec<bool> {
+        P::ScalarField::characteristic()
+            .iter()
+            .flat_map(|limb| (0..64).map(move |i| (limb >> i) & 1 == 1))
+            .collect()
+    }
+
+    fn coordinates(&self) -> (FieldType<P::BaseField>, FieldType<P::BaseField>) {
+        match self {
+            Self::Constant(constant) => (FieldType::Constant(constant.x), FieldType::Constant(constant.y)),
+            Self::Allocated(allocated) => (
+                FieldType::Allocated(allocated.x.clone()),
+                FieldType::Allocated(allocated.y.clone()),
+            ),
+        }
+    }
+
+    fn pedersen_hash<CS: ConstraintSystem<P::BaseField>>(
+        mut cs: CS,
+        input: &[UInt8],
+        span: &Span,
+    ) -> Result<FieldType<P::BaseField>, GroupError> {
+        let max_bytes = PedersenHashWindow::NUM_WINDOWS * PedersenHashWindow::WINDOW_SIZE / 8;
+        if input.len() > max_bytes {
+            return Err(GroupError::hash_input_too_long(max_bytes, input.len(), span));
+        }
+
+        let crh = pedersen_hash_parameters();
+        let parameters = PedersenCRHParametersGadget::alloc(cs.ns(|| "pedersen parameters"), || Ok(&crh.parameters))
+            .map_err(|e| GroupError::synthesis_error(e, span))?;
+
+        let digest =
+            <EdwardsPedersenHashGadget<P> as CRHGadget<EdwardsPedersenHash<P>, P::BaseField>>::check_evaluation_gadget(
+                cs.ns(|| "pedersen hash"),
+                &parameters,
+                input.to_vec(),
+            )
+            .map_err(|e| GroupError::synthesis_error(e, span))?;
+
+        Ok(FieldType::Allocated(digest))
+    }
+}
+
+impl<P: TEModelParameters> TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    ///
+    /// Multiplies by a scalar known at compile time, given as little-endian bits.
+    ///
+    /// The scalar is consumed `MUL_WINDOW_SIZE` bits at a time against a table of small
+    /// multiples of the base, so no conditional selects are needed and zero windows are free.
+    ///
+    fn mul_by_constant<CS: ConstraintSystem<P::BaseField>>(
+        &self,
+        mut cs: CS,
+        scalar: &[bool],
+        span: &Span,
+    ) -> Result<Self, GroupError> {
+        let base = match self {
+            Self::Constant(base) => {
+                let mut result = GroupAffine::<P>::zero();
+                for bit in scalar.iter().rev() {
+                    result = result.add(&result);
+                    if *bit {
+                        result = result.add(base);
+                    }
+                }
+
+                return Ok(Self::Constant(result));
+            }
+            Self::Allocated(base) => base,
+        };
+
+        let windows = scalar
+            .chunks(MUL_WINDOW_SIZE)
+            .map(|window| {
+                window
+                    .iter()
+                    .rev()
+                    .fold(0usize, |digit, bit| (digit << 1) | *bit as usize)
+            })
+            .collect::<Vec<usize>>();
+
+        // table[i] holds (i + 1) * base, built only as far as the largest digit.
+        let largest_digit = windows.iter().copied().max().unwrap_or(0);
+        let mut table: Vec<EdwardsGadget<P>> = vec![(**base).clone()];
+        for i in 1..largest_digit {
+            let next = <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::add(
+                &table[i - 1],
+                cs.ns(|| format!("table {}", i)),
+                base,
+            )
+            .map_err(|e| GroupError::binary_operation("*".to_string(), e, span))?;
+            table.push(next);
+        }
+
+        let mut result: Option<EdwardsGadget<P>> = None;
+        for (i, (window, digit)) in scalar.chunks(MUL_WINDOW_SIZE).zip(windows).enumerate().rev() {
+            if let Some(result) = result.as_mut() {
+                for j in 0..window.len() {
+                    <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::double_in_place(
+                        result,
+                        cs.ns(|| format!("window {} double {}", i, j)),
+                    )
+                    .map_err(|e| GroupError::binary_operation("*".to_string(), e, span))?;
+                }
+            }
+
+            if digit == 0 {
+                continue;
+            }
+
+            result = Some(match result {
+                Some(result) => <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::add(
+                    &result,
+                    cs.ns(|| format!("window {} add", i)),
+                    &table[digit - 1],
+                )
+                .map_err(|e| GroupError::binary_operation("*".to_string(), e, span))?,
+                None => table[digit - 1].clone(),
+            });
+        }
+
+        Ok(match result {
+            Some(result) => Self::Allocated(Box::new(result)),
+            None => Self::Constant(GroupAffine::<P>::zero()),
+        })
+    }
+
+    pub fn edwards_affine_from_value(value: &GroupValue, span: &Span) -> Result<GroupAffine<P>, GroupError> {
+        match value {
+            GroupValue::Single(number, ..) => Self::edwards_affine_from_single(number, span),
+            GroupValue::Tuple(x, y) => Self::edwards_affine_from_tuple(x, y, span),
+        }
+    }
+
+    pub fn edwards_affine_from_single(number: &str, span: &Span) -> Result<GroupAffine<P>, GroupError> {
+        let number_info = number_string_typing(number);
+
+        if number_info.0.eq("0") {
+            Ok(GroupAffine::<P>::zero())
+        } else {
+            let one = edwards_affine_one();
+            let number_value = match number_info {
+                (number, neg) if neg => {
+                    -P::ScalarField::from_str(&number).map_err(|_| GroupError::n_group(number, span))?
+                }
+                (number, _) => P::ScalarField::from_str(&number).map_err(|_| GroupError::n_group(number, span))?,
+            };
+
+            let result: GroupAffine<P> = one.mul(&number_value);
+
+            Ok(result)
+        }
+    }
+
+    pub fn edwards_affine_from_tuple(
+        x: &GroupCoordinate,
+        y: &GroupCoordinate,
+        span: &Span,
+    ) -> Result<GroupAffine<P>, GroupError> {
+        let x = x.clone();
+        let y = y.clone();
+
+        match (x, y) {
+            // (x, y)
+            (GroupCoordinate::Number(x_string), GroupCoordinate::Number(y_string)) => Self::edwards_affine_from_pair(
+                number_string_typing(&x_string),
+                number_string_typing(&y_string),
+                span,
+                span,
+                span,
+            ),
+            // (x, +)
+            (GroupCoordinate::Number(x_string), GroupCoordinate::SignHigh) => {
+                Self::edwards_affine_from_x_str(number_string_typing(&x_string), span, Some(true), span)
+            }
+            // (x, -)
+            (GroupCoordinate::Number(x_string), GroupCoordinate::SignLow) => {
+                Self::edwards_affine_from_x_str(number_string_typing(&x_string), span, Some(false), span)
+            }
+            // (x, _)
+            (GroupCoordinate::Number(x_string), GroupCoordinate::Inferred) => {
+                Self::edwards_affine_from_x_str(number_string_typing(&x_string), span, None, span)
+            }
+            // (+, y)
+            (GroupCoordinate::SignHigh, GroupCoordinate::Number(y_string)) => {
+                Self::edwards_affine_from_y_str(number_string_typing(&y_string), span, Some(true), span)
+            }
+            // (-, y)
+            (GroupCoordinate::SignLow, GroupCoordinate::Number(y_string)) => {
+                Self::edwards_affine_from_y_str(number_string_typing(&y_string), span, Some(false), span)
+            }
+            // (_, y)
+            (GroupCoordinate::Inferred, GroupCoordinate::Number(y_string)) => {
+                Self::edwards_affine_from_y_str(number_string_typing(&y_string), span, None, span)
+            }
+            // Invalid
+            (x, y) => Err(GroupError::invalid_group(format!("({}, {})", x, y), span)),
+        }
+    }
+
+    pub fn edwards_affine_from_x_str(
+        x_info: (String, bool),
+        x_span: &Span,
+        greatest: Option<bool>,
+        element_span: &Span,
+    ) -> Result<GroupAffine<P>, GroupError> {
+        let x = match x_info {
+            (x_str, neg) if neg => -P::BaseField::from_str(&x_str).map_err(|_| GroupError::x_invalid(x_str, x_span))?,
+            (x_str, _) => P::BaseField::from_str(&x_str).map_err(|_| GroupError::x_invalid(x_str, x_span))?,
+        };
+
+        match greatest {
+            // Sign provided
+            Some(greatest) => {
+                GroupAffine::<P>::from_x_coordinate(x, greatest).ok_or_else(|| GroupError::x_recover(element_span))
+            }
+            // Sign inferred
+            None => {
+                // Attempt to recover with a sign_low bit.
+                if let Some(element) = GroupAffine::<P>::from_x_coordinate(x, false) {
+                    return Ok(element);
+                }
+
+                // Attempt to recover with a sign_high bit.
+                if let Some(element) = GroupAffine::<P>::from_x_coordinate(x, true) {
+                    return Ok(element);
+                }
+
+                // Otherwise return error.
+                Err(GroupError::x_recover(element_span))
+            }
+        }
+    }
+
+    pub fn edwards_affine_from_y_str(
+        y_info: (String, bool),
+        y_span: &Span,
+        greatest: Option<bool>,
+        element_span: &Span,
+    ) -> Result<GroupAffine<P>, GroupError> {
+        let y = match y_info {
+            (y_str, neg) if neg => -P::BaseField::from_str(&y_str).map_err(|_| GroupError::y_invalid(y_str, y_span))?,
+            (y_str, _) => P::BaseField::from_str(&y_str).map_err(|_| GroupError::y_invalid(y_str, y_span))?,
+        };
+
+        match greatest {
+            // Sign provided
+            Some(greatest) => {
+                GroupAffine::<P>::from_y_coordinate(y, greatest).ok_or_else(|| GroupError::y_recover(element_span))
+            }
+            // Sign inferred
+            None => {
+                // Attempt to recover with a sign_low bit.
+                if let Some(element) = GroupAffine::<P>::from_y_coordinate(y, false) {
+                    return Ok(element);
+                }
+
+                // Attempt to recover with a sign_high bit.
+                if let Some(element) = GroupAffine::<P>::from_y_coordinate(y, true) {
+                    return Ok(element);
+                }
+
+                // Otherwise return error.
+                Err(GroupError::y_recover(element_span))
+            }
+        }
+    }
+
+    pub fn edwards_affine_from_pair(
+        x_info: (String, bool),
+        y_info: (String, bool),
+        x_span: &Span,
+        y_span: &Span,
+        element_span: &Span,
+    ) -> Result<GroupAffine<P>, GroupError> {
+        let x = match x_info {
+            (x_str, neg) if neg => {
+                -P::BaseField::from_str(&x_str).map_err(|_| GroupError::x_invalid(x_str.to_string(), x_span))?
+            }
+            (x_str, _) => {
+                P::BaseField::from_str(&x_str).map_err(|_| GroupError::x_invalid(x_str.to_string(), x_span))?
+            }
+        };
+
+        let y = match y_info {
+            (y_str, neg) if neg => {
+                -P::BaseField::from_str(&y_str).map_err(|_| GroupError::y_invalid(y_str.to_string(), y_span))?
+            }
+            (y_str, _) => {
+                P::BaseField::from_str(&y_str).map_err(|_| GroupError::y_invalid(y_str.to_string(), y_span))?
+            }
+        };
+
+        let element = GroupAffine::<P>::new(x, y);
+
+        if element.is_on_curve() {
+            Ok(element)
+        } else {
+            Err(GroupError::not_on_curve(element.to_string(), element_span))
+        }
+    }
+
+    pub fn alloc_helper<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<GroupValue>>(
+        value_gen: Fn,
+    ) -> Result<GroupAffine<P>, SynthesisError> {
+        let group_value = match value_gen() {
+            Ok(value) => {
+                let group_value = value.borrow().clone();
+                Ok(group_value)
+            }
+            _ => Err(SynthesisError::AssignmentMissing),
+        }?;
+
+        Self::edwards_affine_from_value(&group_value, &Span::default()).map_err(|_| SynthesisError::AssignmentMissing)
+    }
+
+    pub fn allocated<CS: ConstraintSystem<P::BaseField>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<EdwardsGadget<P>, SynthesisError> {
+        match self {
+            Self::Constant(constant) => <EdwardsGadget<P> as AllocGadget<GroupAffine<P>, P::BaseField>>::alloc(
+                &mut cs.ns(|| format!("{:?}", constant)),
+                || Ok(constant),
+            ),
+            Self::Allocated(allocated) => {
+                let x_value = allocated.x.get_value();
+                let y_value = allocated.y.get_value();
+
+                let x_allocated = FpGadget::alloc(cs.ns(|| "x"), || x_value.ok_or(SynthesisError::AssignmentMissing))?;
+                let y_allocated = FpGadget::alloc(cs.ns(|| "y"), || y_value.ok_or(SynthesisError::AssignmentMissing))?;
+
+                Ok(EdwardsGadget::<P>::new(x_allocated, y_allocated))
+            }
+        }
+    }
+}
+
+impl<P: TEModelParameters> AllocGadget<GroupValue, P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<GroupValue>, CS: ConstraintSystem<P::BaseField>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = <EdwardsGadget<P> as AllocGadget<GroupAffine<P>, P::BaseField>>::alloc(cs, || {
+            Self::alloc_helper(value_gen)
+        })?;
+
+        Ok(Self::Allocated(Box::new(value)))
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<GroupValue>,
+        CS: ConstraintSystem<P::BaseField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = <EdwardsGadget<P> as AllocGadget<GroupAffine<P>, P::BaseField>>::alloc_input(cs, || {
+            Self::alloc_helper(value_gen)
+        })?;
+
+        Ok(Self::Allocated(Box::new(value)))
+    }
+}
+
+impl<P: TEModelParameters> PartialEq for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Constant(self_value), Self::Constant(other_value)) => self_value == other_value,
+
+            (Self::Allocated(self_value), Self::Allocated(other_value)) => self_value.eq(other_value),
+
+            (Self::Constant(constant_value), Self::Allocated(allocated_value))
+            | (Self::Allocated(allocated_value), Self::Constant(constant_value)) => {
+                <EdwardsGadget<P> as GroupGadget<GroupAffine<P>, P::BaseField>>::get_value(allocated_value)
+                    .map(|allocated_value| allocated_value == *constant_value)
+                    .unwrap_or(false)
+            }
+        }
+    }
+}
+
+impl<P: TEModelParameters> Eq for TwistedEdwardsGroupType<P> where P::BaseField: PrimeField {}
+
+// fn compare_allocated_edwards_bls_gadgets<CS: ConstraintSystem<P::BaseField>>(
+//     mut cs: CS,
+//     first: &EdwardsGadget<P>,
+//     second: &EdwardsGadget<P>,
+// ) -> Result<Boolean, SynthesisError> {
+//     // compare x coordinates
+//     let x_first = &first.x;
+//     let x_second = &second.x;
+//
+//     let compare_x = x_first.evaluate_equal(&mut cs.ns(|| format!("compare x")), x_second)?;
+//
+//     // compare y coordinates
+//     let y_first = &first.y;
+//     let y_second = &second.y;
+//
+//     let compare_y = y_first.evaluate_equal(&mut cs.ns(|| format!("compare y")), y_second)?;
+//
+//     Boolean::and(
+//         &mut cs.ns(|| format!("compare x and y results")),
+//         &compare_x,
+//         &compare_y,
+//     )
+// }
+
+impl<P: TEModelParameters> EvaluateEqGadget<P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn evaluate_equal<CS: ConstraintSystem<P::BaseField>>(
+        &self,
+        mut _cs: CS,
+        other: &Self,
+    ) -> Result<Boolean, SynthesisError> {
+        match (self, other) {
+            (Self::Constant(self_value), Self::Constant(other_value)) => {
+                Ok(Boolean::constant(self_value.eq(other_value)))
+            }
+            _ => unimplemented!(),
+            // (Self::Allocated(first), Self::Allocated(second)) => {
+            //     compare_allocated_edwards_bls_gadgets(cs, first, second)
+            // }
+            // (Self::Constant(constant_value), Self::Allocated(allocated_value))
+            // | (Self::Allocated(allocated_value), Self::Constant(constant_value)) => {
+            //     let allocated_constant_value =
+            //         <EdwardsGadget<P> as AllocGadget<GroupAffine<P>, P::BaseField>>::alloc(
+            //             &mut cs.ns(|| format!("alloc constant for eq")),
+            //             || Ok(constant_value),
+            //         )?;
+            //     compare_allocated_edwards_bls_gadgets(cs, allocated_value, &allocated_constant_value)
+            // }
+        }
+    }
+}
+
+impl<P: TEModelParameters> EqGadget<P::BaseField> for TwistedEdwardsGroupType<P> where P::BaseField: PrimeField {}
+
+impl<P: TEModelParameters> ConditionalEqGadget<P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<P::BaseField>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        match (self, other) {
+            // c - c
+            (Self::Constant(self_value), Self::Constant(other_value)) => {
+                if self_value == other_value {
+                    return Ok(());
+                }
+                Err(SynthesisError::AssignmentMissing)
+            }
+            // a - a
+            (Self::Allocated(self_value), Self::Allocated(other_value)) => {
+                <EdwardsGadget<P>>::conditional_enforce_equal(self_value, cs, other_value, condition)
+            }
+            // c - a = a - c
+            (Self::Constant(constant_value), Self::Allocated(allocated_value))
+            | (Self::Allocated(allocated_value), Self::Constant(constant_value)) => {
+                let x = FpGadget::from(AllocatedFp::from(&mut cs, &constant_value.x));
+                let y = FpGadget::from(AllocatedFp::from(&mut cs, &constant_value.y));
+                let constant_gadget = EdwardsGadget::<P>::new(x, y);
+
+                constant_gadget.conditional_enforce_equal(cs, allocated_value, condition)
+            }
+        }
+    }
+
+    fn cost() -> usize {
+        2 * <EdwardsGadget<P> as ConditionalEqGadget<P::BaseField>>::cost() //upper bound
+    }
+}
+
+impl<P: TEModelParameters> CondSelectGadget<P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn conditionally_select<CS: ConstraintSystem<P::BaseField>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        if let Boolean::Constant(cond) = *cond {
+            if cond { Ok(first.clone()) } else { Ok(second.clone()) }
+        } else {
+            let first_gadget = first.allocated(cs.ns(|| "first"))?;
+            let second_gadget = second.allocated(cs.ns(|| "second"))?;
+            let result = EdwardsGadget::<P>::conditionally_select(cs, cond, &first_gadget, &second_gadget)?;
+
+            Ok(Self::Allocated(Box::new(result)))
+        }
+    }
+
+    fn cost() -> usize {
+        2 * <EdwardsGadget<P> as CondSelectGadget<P::BaseField>>::cost()
+    }
+}
+
+impl<P: TEModelParameters> ToBitsBEGadget<P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn to_bits_be<CS: ConstraintSystem<P::BaseField>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let self_gadget = self.allocated(&mut cs)?;
+        self_gadget.to_bits_be(cs)
+    }
+
+    fn to_bits_be_strict<CS: ConstraintSystem<P::BaseField>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let self_gadget = self.allocated(&mut cs)?;
+        self_gadget.to_bits_be_strict(cs)
+    }
+}
+
+impl<P: TEModelParameters> ToBytesGadget<P::BaseField> for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn to_bytes<CS: ConstraintSystem<P::BaseField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let self_gadget = self.allocated(&mut cs)?;
+        self_gadget.to_bytes(cs)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<P::BaseField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let self_gadget = self.allocated(&mut cs)?;
+        self_gadget.to_bytes_strict(cs)
+    }
+}
+
+fn edwards_affine_one<P: TEModelParameters>() -> GroupAffine<P> {
+    let (x, y) = P::AFFINE_GENERATOR_COEFFS;
+
+    GroupAffine::<P>::new(x, y)
+}
+
+impl<P: TEModelParameters> One for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn one() -> Self {
+        let one = edwards_affine_one();
+
+        Self::Constant(one)
+    }
+
+    fn is_one(&self) -> bool {
+        self.eq(&Self::one())
+    }
+}
+
+impl<P: TEModelParameters> std::fmt::Display for TwistedEdwardsGroupType<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Constant(constant) => write!(f, "{:?}", constant),
+            Self::Allocated(allocated) => write!(f, "{:?}", allocated),
+        }
+    }
+}
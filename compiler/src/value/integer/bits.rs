@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Bit-counting helpers over little-endian boolean vectors.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::traits::utilities::{alloc::AllocGadget, boolean::Boolean, integer::Integer, uint::UInt32};
+use snarkvm_r1cs::{ConstraintSystem, LinearCombination, SynthesisError};
+
+///
+/// Returns the number of set bits in `bits`.
+///
+/// The count is allocated as a `u32` and constrained so that the weighted sum of its bits
+/// equals the plain sum of the input bits. Allocating the count bit by bit range-constrains it.
+///
+pub fn count_ones<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+) -> Result<UInt32, SynthesisError> {
+    let count = bits
+        .iter()
+        .map(|bit| bit.get_value().map(|value| value as u32))
+        .sum::<Option<u32>>();
+
+    if let (true, Some(count)) = (bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))), count) {
+        return Ok(UInt32::constant(count));
+    }
+
+    let result = UInt32::alloc(cs.ns(|| "count"), || count.ok_or(SynthesisError::AssignmentMissing))?;
+
+    let one = CS::one();
+
+    let mut bits_sum = LinearCombination::zero();
+    for bit in bits {
+        bits_sum = bits_sum + &bit.lc(one, F::one());
+    }
+
+    let mut result_sum = LinearCombination::zero();
+    let mut coeff = F::one();
+    for bit in result.to_bits_le() {
+        result_sum = result_sum + &bit.lc(one, coeff);
+        coeff.double_in_place();
+    }
+
+    cs.enforce(
+        || "enforce count",
+        |lc| lc + &bits_sum,
+        |lc| lc + one,
+        |lc| lc + &result_sum,
+    );
+
+    Ok(result)
+}
+
+///
+/// Returns the number of unset bits in `bits`.
+///
+pub fn count_zeros<F: PrimeField, CS: ConstraintSystem<F>>(cs: CS, bits: &[Boolean]) -> Result<UInt32, SynthesisError> {
+    let inverted = bits.iter().map(|bit| bit.not()).collect::<Vec<_>>();
+
+    count_ones(cs, &inverted)
+}
+
+///
+/// Returns the number of consecutive unset bits at the start of `bits`.
+///
+/// Each position contributes one to the count when it and every bit before it are unset,
+/// so an all-zero input returns the full width.
+///
+pub fn count_leading_unset<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bits: &[Boolean],
+) -> Result<UInt32, SynthesisError> {
+    let mut prefix = Vec::with_capacity(bits.len());
+    let mut all_unset = Boolean::constant(true);
+
+    for (i, bit) in bits.iter().enumerate() {
+        all_unset = Boolean::and(cs.ns(|| format!("prefix {}", i)), &all_unset, &bit.not())?;
+        prefix.push(all_unset);
+    }
+
+    count_ones(cs.ns(|| "count prefix"), &prefix)
+}
+
+///
+/// Returns the number of unset bits above the most significant set bit.
+///
+pub fn leading_zeros<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: CS,
+    bits: &[Boolean],
+) -> Result<UInt32, SynthesisError> {
+    let reversed = bits.iter().rev().cloned().collect::<Vec<_>>();
+
+    count_leading_unset(cs, &reversed)
+}
+
+///
+/// Returns the number of unset bits below the least significant set bit.
+///
+pub fn trailing_zeros<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: CS,
+    bits: &[Boolean],
+) -> Result<UInt32, SynthesisError> {
+    count_leading_unset(cs, bits)
+}
+
+///
+/// Returns the little-endian bits of `left + right + carry`, discarding the carry out of the top bit.
+///
+/// The sum is allocated with one extra bit for the carry out and constrained by a single linear
+/// combination, so an overflow wraps around instead of failing.
+///
+pub fn wrapping_add<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    left: &[Boolean],
+    right: &[Boolean],
+    carry: bool,
+) -> Result<Vec<Boolean>, SynthesisError> {
+    // Ripple the carry through the known bits to compute the assignment.
+    let mut values = Vec::with_capacity(left.len() + 1);
+    let mut carry_value = Some(carry);
+    for (first, second) in left.iter().zip(right.iter()) {
+        let value = match (first.get_value(), second.get_value(), carry_value) {
+            (Some(first), Some(second), Some(carry)) => {
+                carry_value = Some((first && second) || (carry && (first ^ second)));
+                Some(first ^ second ^ carry)
+            }
+            _ => {
+                carry_value = None;
+                None
+            }
+        };
+        values.push(value);
+    }
+
+    let is_constant = |bit: &Boolean| matches!(bit, Boolean::Constant(_));
+    if left.iter().all(is_constant) && right.iter().all(is_constant) {
+        return Ok(values
+            .into_iter()
+            .map(|value| Boolean::constant(value.unwrap_or_default()))
+            .collect());
+    }
+    values.push(carry_value);
+
+    let mut bits = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            Boolean::alloc(cs.ns(|| format!("bit {}", i)), || {
+                value.ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let one = CS::one();
+
+    let mut operands_sum = LinearCombination::zero();
+    if carry {
+        operands_sum = operands_sum + (F::one(), one);
+    }
+    let mut coeff = F::one();
+    for (first, second) in left.iter().zip(right.iter()) {
+        operands_sum = operands_sum + &first.lc(one, coeff) + &second.lc(one, coeff);
+        coeff.double_in_place();
+    }
+
+    let mut result_sum = LinearCombination::zero();
+    let mut coeff = F::one();
+    for bit in bits.iter() {
+        result_sum = result_sum + &bit.lc(one, coeff);
+        coeff.double_in_place();
+    }
+
+    cs.enforce(
+        || "enforce sum",
+        |lc| lc + &operands_sum,
+        |lc| lc + one,
+        |lc| lc + &result_sum,
+    );
+
+    bits.truncate(left.len());
+
+    Ok(bits)
+}
+
+///
+/// Returns the little-endian bits of `left * right`, discarding the bits above the width of `left`.
+///
+/// Each partial product bit below the width takes one `AND`, and their weighted sum is constrained
+/// by a single linear combination against the result bits and the allocated carry above them.
+///
+pub fn wrapping_mul<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    left: &[Boolean],
+    right: &[Boolean],
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let width = left.len();
+
+    let mut products = Vec::new();
+    for (i, right_bit) in right.iter().enumerate().take(width) {
+        for (j, left_bit) in left.iter().enumerate().take(width - i) {
+            let product = Boolean::and(cs.ns(|| format!("product {} {}", i, j)), left_bit, right_bit)?;
+            products.push((i + j, product));
+        }
+    }
+
+    // Ripple the counts of set products at each position to compute the assignment.
+    let counts = products
+        .iter()
+        .try_fold(vec![0usize; width], |mut counts, (position, product)| {
+            counts[*position] += product.get_value()? as usize;
+            Some(counts)
+        });
+    let mut carry_value = Some(0usize);
+    let mut values = Vec::with_capacity(width);
+    for position in 0..width {
+        let total = counts
+            .as_ref()
+            .zip(carry_value)
+            .map(|(counts, carry)| counts[position] + carry);
+        values.push(total.map(|total| total & 1 == 1));
+        carry_value = total.map(|total| total >> 1);
+    }
+
+    let is_constant = |(_, product): &(usize, Boolean)| matches!(product, Boolean::Constant(_));
+    if products.iter().all(is_constant) {
+        return Ok(values
+            .into_iter()
+            .map(|value| Boolean::constant(value.unwrap_or_default()))
+            .collect());
+    }
+
+    // The carry is less than the width, as each position holds at most `width` products.
+    let carry_width = (64 - (width as u64).leading_zeros()) as usize;
+    values.extend((0..carry_width).map(|i| carry_value.map(|carry| (carry >> i) & 1 == 1)));
+
+    let mut bits = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            Boolean::alloc(cs.ns(|| format!("bit {}", i)), || {
+                value.ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let one = CS::one();
+
+    let mut powers = Vec::with_capacity(bits.len());
+    let mut coeff = F::one();
+    for _ in 0..bits.len() {
+        powers.push(coeff);
+        coeff.double_in_place();
+    }
+
+    let mut products_sum = LinearCombination::zero();
+    for (position, product) in products.iter() {
+        products_sum = products_sum + &product.lc(one, powers[*position]);
+    }
+
+    let mut result_sum = LinearCombination::zero();
+    for (bit, power) in bits.iter().zip(powers.iter()) {
+        result_sum = result_sum + &bit.lc(one, *power);
+    }
+
+    cs.enforce(
+        || "enforce product",
+        |lc| lc + &products_sum,
+        |lc| lc + one,
+        |lc| lc + &result_sum,
+    );
+
+    bits.truncate(width);
+
+    Ok(bits)
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Allocated constants shared by the integer gadgets of a constraint system.
+
+use crate::Integer;
+use leo_asg::IntegerType;
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::traits::utilities::{
+    alloc::AllocGadget,
+    boolean::{AllocatedBit, Boolean},
+    int::{Int128, Int16, Int32, Int64, Int8},
+    uint::{UInt128, UInt16, UInt32, UInt64, UInt8},
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+macro_rules! allocate_one_and_zero {
+    ($gadget:ty, $variant:path, $cs:expr) => {
+        (
+            $variant(<$gadget>::alloc($cs.ns(|| "one"), || Ok(1))?),
+            $variant(<$gadget>::alloc($cs.ns(|| "zero"), || Ok(0))?),
+        )
+    };
+}
+
+///
+/// The allocated `true` bit and integer `1` and `0` of a single constraint system.
+///
+/// Each constant is allocated the first time a gadget asks for it and reused afterwards, so
+/// repeated operations do not re-enforce the boolean constraints of their bits. Clones share
+/// the same allocations.
+///
+#[derive(Clone, Debug, Default)]
+pub struct AllocatedConstants(Rc<RefCell<Allocations>>);
+
+#[derive(Debug, Default)]
+struct Allocations {
+    true_bit: Option<Boolean>,
+    one_and_zero: HashMap<IntegerType, (Integer, Integer)>,
+}
+
+impl AllocatedConstants {
+    ///
+    /// Returns the allocated `true` bit, allocating it in `cs` if this is the first request.
+    ///
+    pub fn true_bit<F: PrimeField, CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Boolean, SynthesisError> {
+        let mut allocations = self.0.borrow_mut();
+        if let Some(bit) = allocations.true_bit {
+            return Ok(bit);
+        }
+
+        let bit = Boolean::from(AllocatedBit::alloc(cs.ns(|| "true"), || Ok(true))?);
+        allocations.true_bit = Some(bit);
+
+        Ok(bit)
+    }
+
+    ///
+    /// Returns the allocated `1` and `0` of `integer_type`, allocating them in `cs` if this is
+    /// the first request for that type.
+    ///
+    pub fn one_and_zero<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        integer_type: &IntegerType,
+    ) -> Result<(Integer, Integer), SynthesisError> {
+        let mut allocations = self.0.borrow_mut();
+        if let Some(constants) = allocations.one_and_zero.get(integer_type) {
+            return Ok(constants.clone());
+        }
+
+        let constants = match integer_type {
+            IntegerType::U8 => allocate_one_and_zero!(UInt8, Integer::U8, cs),
+            IntegerType::U16 => allocate_one_and_zero!(UInt16, Integer::U16, cs),
+            IntegerType::U32 => allocate_one_and_zero!(UInt32, Integer::U32, cs),
+            IntegerType::U64 => allocate_one_and_zero!(UInt64, Integer::U64, cs),
+            IntegerType::U128 => allocate_one_and_zero!(UInt128, Integer::U128, cs),
+
+            IntegerType::I8 => allocate_one_and_zero!(Int8, Integer::I8, cs),
+            IntegerType::I16 => allocate_one_and_zero!(Int16, Integer::I16, cs),
+            IntegerType::I32 => allocate_one_and_zero!(Int32, Integer::I32, cs),
+            IntegerType::I64 => allocate_one_and_zero!(Int64, Integer::I64, cs),
+            IntegerType::I128 => allocate_one_and_zero!(Int128, Integer::I128, cs),
+        };
+        allocations.one_and_zero.insert(integer_type.clone(), constants.clone());
+
+        Ok(constants)
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Long division of integer gadgets with shared allocated constants.
+
+use crate::{AllocatedConstants, Integer, IntegerTrait};
+use leo_asg::IntegerType;
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    errors::{SignedIntegerError, UnsignedIntegerError},
+    traits::utilities::{
+        arithmetic::{Add, Neg, Sub},
+        bits::ComparatorGadget,
+        boolean::Boolean,
+        eq::EvaluateEqGadget,
+        int::{Int128, Int16, Int32, Int64, Int8},
+        select::CondSelectGadget,
+        uint::{Sub as UIntSub, UInt, UInt128, UInt16, UInt32, UInt64, UInt8},
+    },
+};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+///
+/// Integer division that takes its allocated `true`, `1` and `0` from [`AllocatedConstants`].
+///
+/// Mirrors the division gadgets of `snarkvm-gadgets`, which allocate these constants again on
+/// every call.
+///
+pub trait DivWithConstants: Sized {
+    type ErrorType;
+
+    fn div_with_constants<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        constants: &AllocatedConstants,
+    ) -> Result<Self, Self::ErrorType>;
+}
+
+///
+/// Returns the allocated `true`, `1` and `0` of `integer_type` from `constants`.
+///
+macro_rules! allocated_constants {
+    ($cs:expr, $constants:expr, $integer_type:path, $variant:path) => {{
+        let true_bit = $constants.true_bit($cs.ns(|| "true"))?;
+        match $constants.one_and_zero($cs.ns(|| "one and zero"), &$integer_type)? {
+            ($variant(one), $variant(zero)) => (true_bit, one, zero),
+            _ => unreachable!("allocated constants are stored by integer type"),
+        }
+    }};
+}
+
+/// Performs long division of two `UInt` objects.
+/// Reference: https://en.wikipedia.org/wiki/Division_algorithm
+macro_rules! div_uint_impl {
+    ($($gadget:ident => $integer_type:path, $variant:path),*) => ($(
+        impl DivWithConstants for $gadget {
+            type ErrorType = UnsignedIntegerError;
+
+            fn div_with_constants<F: PrimeField, CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self,
+                constants: &AllocatedConstants,
+            ) -> Result<Self, Self::ErrorType> {
+                if other.eq(&Self::constant(0)) {
+                    return Err(SynthesisError::DivisionByZero.into());
+                }
+
+                let (true_bit, one, zero) = if Self::result_is_constant(self, other) {
+                    (Boolean::constant(true), Self::constant(1), Self::constant(0))
+                } else {
+                    allocated_constants!(cs, constants, $integer_type, $variant)
+                };
+
+                let self_is_zero = self.evaluate_equal(cs.ns(|| "self_is_zero"), &Self::constant(0))?;
+                let mut quotient = zero.clone();
+                let mut remainder = zero;
+
+                for (i, bit) in self.bits.iter().rev().enumerate() {
+                    // Left shift remainder by 1
+                    remainder = Self::addmany(&mut cs.ns(|| format!("shift_left_{}", i)), &[
+                        remainder.clone(),
+                        remainder.clone(),
+                    ])?;
+
+                    // Set the least-significant bit of remainder to bit i of the numerator
+                    let new_remainder = Self::addmany(&mut cs.ns(|| format!("set_remainder_bit_{}", i)), &[
+                        remainder.clone(),
+                        one.clone(),
+                    ])?;
+
+                    remainder = Self::conditionally_select(
+                        &mut cs.ns(|| format!("increment_or_remainder_{}", i)),
+                        bit,
+                        &new_remainder,
+                        &remainder,
+                    )?;
+
+                    // R >= D is evaluated as (R == D) || ((R != D) && ((R - D) != 0)), checking the
+                    // subtraction for overflow after it is evaluated.
+                    let no_remainder = remainder.evaluate_equal(cs.ns(|| format!("no_remainder_{}", i)), other)?;
+                    let subtraction = remainder.sub_unsafe(&mut cs.ns(|| format!("subtract_divisor_{}", i)), other)?;
+                    let sub_is_zero =
+                        subtraction.evaluate_equal(cs.ns(|| format!("sub_is_zero_{}", i)), &Self::constant(0))?;
+                    let cond1 = Boolean::and(
+                        &mut cs.ns(|| format!("cond_1_{}", i)),
+                        &no_remainder.not(),
+                        &sub_is_zero.not(),
+                    )?;
+                    let cond2 = Boolean::or(&mut cs.ns(|| format!("cond_2_{}", i)), &no_remainder, &cond1)?;
+
+                    remainder = Self::conditionally_select(
+                        &mut cs.ns(|| format!("subtract_or_same_{}", i)),
+                        &cond2,
+                        &subtraction,
+                        &remainder,
+                    )?;
+
+                    let index = <$gadget as IntegerTrait>::SIZE - 1 - i;
+                    let mut new_quotient = quotient.clone();
+                    new_quotient.bits[index] = true_bit;
+                    if let Some(ref mut value) = new_quotient.value {
+                        *value += 1 << index;
+                    }
+
+                    quotient = Self::conditionally_select(
+                        &mut cs.ns(|| format!("set_bit_or_same_{}", i)),
+                        &cond2,
+                        &new_quotient,
+                        &quotient,
+                    )?;
+                }
+
+                Ok(Self::conditionally_select(&mut cs.ns(|| "self_or_quotient"), &self_is_zero, self, &quotient)?)
+            }
+        }
+    )*)
+}
+
+div_uint_impl!(
+    UInt8 => IntegerType::U8, Integer::U8,
+    UInt16 => IntegerType::U16, Integer::U16,
+    UInt32 => IntegerType::U32, Integer::U32,
+    UInt64 => IntegerType::U64, Integer::U64,
+    UInt128 => IntegerType::U128, Integer::U128
+);
+
+/// Performs long division of the absolute values of two `Int` objects and restores the sign.
+macro_rules! div_int_impl {
+    ($($gadget:ident => $integer_type:path, $variant:path),*) => ($(
+        impl DivWithConstants for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn div_with_constants<F: PrimeField, CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self,
+                constants: &AllocatedConstants,
+            ) -> Result<Self, Self::ErrorType> {
+                if other.eq(&Self::constant(0)) {
+                    return Err(SignedIntegerError::DivisionByZero);
+                }
+
+                let (true_bit, one, zero) = if Self::result_is_constant(self, other) {
+                    (Boolean::constant(true), Self::constant(1), Self::constant(0))
+                } else {
+                    allocated_constants!(cs, constants, $integer_type, $variant)
+                };
+
+                // If the numerator is 0, return 0
+                let self_is_zero = self.evaluate_equal(cs.ns(|| "self_is_zero"), &Self::constant(0))?;
+
+                // If other is the minimum number, the result is zero or one:
+                //   -128 / -128 = 1
+                //   x / -128 = 0 since the fractional result rounds to 0
+                let min = Self::constant(<$gadget as IntegerTrait>::IntegerType::MIN);
+                let other_is_min = other.evaluate_equal(&mut cs.ns(|| "other_min_check"), &min)?;
+                let self_is_min = self.evaluate_equal(&mut cs.ns(|| "self_min_check"), &min)?;
+                let both_min = Boolean::and(&mut cs.ns(|| "both_min"), &other_is_min, &self_is_min)?;
+
+                // If other is the minimum, set other to -1 so the calculation does not fail
+                let negative_one = one.neg(&mut cs.ns(|| "allocated_one"))?;
+                let a_valid = min.add(&mut cs.ns(|| "a_valid"), &one)?;
+                let a_set = Self::conditionally_select(&mut cs.ns(|| "a_set"), &self_is_min, &a_valid, self)?;
+                let b_set = Self::conditionally_select(&mut cs.ns(|| "b_set"), &other_is_min, &negative_one, other)?;
+
+                // If the most significant bits of both numbers are equal, the quotient is positive
+                let b_msb = other.bits.last().unwrap();
+                let a_msb = self.bits.last().unwrap();
+                let positive = a_msb.evaluate_equal(cs.ns(|| "compare_msb"), b_msb)?;
+
+                // Get the absolute value of each number
+                let a_comp = a_set.neg(&mut cs.ns(|| "a_neg"))?;
+                let a = Self::conditionally_select(&mut cs.ns(|| "a_abs"), a_msb, &a_comp, self)?;
+
+                let b_comp = b_set.neg(&mut cs.ns(|| "b_neg"))?;
+                let b = Self::conditionally_select(&mut cs.ns(|| "b_abs"), b_msb, &b_comp, &b_set)?;
+
+                let mut q = zero.clone();
+                let mut r = zero.clone();
+
+                let mut index = <$gadget as IntegerTrait>::SIZE - 1;
+                let mut bit_value = (1 as <$gadget as IntegerTrait>::IntegerType) << (index - 1);
+
+                for (i, bit) in a.bits.iter().rev().enumerate().skip(1) {
+                    // Left shift remainder by 1
+                    r = r.add(&mut cs.ns(|| format!("shift_left_{}", i)), &r)?;
+
+                    // Set the least-significant bit of remainder to bit i of the numerator
+                    let r_new = r.add(&mut cs.ns(|| format!("set_remainder_bit_{}", i)), &one)?;
+                    r = Self::conditionally_select(&mut cs.ns(|| format!("increment_or_remainder_{}", i)), bit, &r_new, &r)?;
+
+                    let can_sub = r.greater_than_or_equal(&mut cs.ns(|| format!("compare_remainder_{}", i)), &b)?;
+                    let sub = r.sub(&mut cs.ns(|| format!("subtract_divisor_{}", i)), &b)?;
+                    r = Self::conditionally_select(&mut cs.ns(|| format!("subtract_or_same_{}", i)), &can_sub, &sub, &r)?;
+
+                    index -= 1;
+
+                    let mut q_new = q.clone();
+                    q_new.bits[index] = true_bit;
+                    if let Some(ref mut value) = q_new.value {
+                        *value += bit_value;
+                    }
+
+                    bit_value >>= 1;
+
+                    q = Self::conditionally_select(&mut cs.ns(|| format!("set_bit_or_same_{}", i)), &can_sub, &q_new, &q)?;
+                }
+
+                let q_neg = q.neg(&mut cs.ns(|| "negate"))?;
+                q = Self::conditionally_select(&mut cs.ns(|| "positive or negative"), &positive, &q, &q_neg)?;
+
+                // Set to zero if the result is known to be fractional
+                q = Self::conditionally_select(&mut cs.ns(|| "fraction"), &other_is_min, &zero, &q)?;
+
+                // Set to one if the result is the division of the minimum number by itself
+                q = Self::conditionally_select(&mut cs.ns(|| "one_result"), &both_min, &one, &q)?;
+
+                Ok(Self::conditionally_select(&mut cs.ns(|| "self_or_quotient"), &self_is_zero, self, &q)?)
+            }
+        }
+    )*)
+}
+
+div_int_impl!(
+    Int8 => IntegerType::I8, Integer::I8,
+    Int16 => IntegerType::I16, Integer::I16,
+    Int32 => IntegerType::I32, Integer::I32,
+    Int64 => IntegerType::I64, Integer::I64,
+    Int128 => IntegerType::I128, Integer::I128
+);
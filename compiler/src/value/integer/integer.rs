@@ -15,23 +15,55 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 //! Conversion of integer declarations to constraints in Leo.
-use crate::{errors::IntegerError, IntegerTrait};
+use crate::{bits, errors::IntegerError, AllocatedConstants, DivWithConstants, IntegerTrait};
 use leo_asg::{ConstInt, IntegerType, Span};
 use leo_ast::InputValue;
 
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_gadgets::traits::utilities::{
-    alloc::AllocGadget,
-    arithmetic::{Add, Div, Mul, Neg, Pow, Sub},
-    bits::comparator::{ComparatorGadget, EvaluateLtGadget},
-    boolean::Boolean,
-    eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
-    int::{Int128, Int16, Int32, Int64, Int8},
-    select::CondSelectGadget,
-    uint::{Sub as UIntSub, *},
+use snarkvm_gadgets::{
+    errors::UnsignedIntegerError,
+    traits::utilities::{
+        alloc::AllocGadget,
+        arithmetic::{Add, Mul, Neg, Pow, Sub},
+        bits::comparator::{ComparatorGadget, EvaluateLtGadget},
+        boolean::Boolean,
+        eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
+        int::{Int128, Int16, Int32, Int64, Int8},
+        select::CondSelectGadget,
+        uint::{Sub as UIntSub, *},
+    },
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
-use std::{convert::TryInto, fmt};
+use std::{cell::Cell, convert::TryInto, fmt};
+
+thread_local! {
+    static NAMESPACE_COUNTER: Cell<usize> = Cell::new(0);
+}
+
+///
+/// Restarts the numbering of integer gadget and statement namespaces.
+///
+/// Called before each synthesis, so the same program always produces the same namespace paths.
+///
+pub fn reset_namespace_names() {
+    NAMESPACE_COUNTER.with(|counter| counter.set(0));
+}
+
+///
+/// Returns a namespace name for `operation` at `span` that is unique within a synthesis.
+///
+/// Allocated operands display as `[input]`, and inlined statements keep the spans of the
+/// function they were copied from, so the operation and span alone can repeat.
+///
+pub(crate) fn namespace_name(operation: String, span: &Span) -> String {
+    let id = NAMESPACE_COUNTER.with(|counter| {
+        let id = counter.get();
+        counter.set(id + 1);
+        id
+    });
+
+    format!("{} {}:{} #{}", operation, span.line_start, span.col_start, id)
+}
 
 /// An integer type enum wrapping the integer value.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
@@ -49,11 +81,14 @@ pub enum Integer {
     I128(Int128),
 }
 
+/// Displays the value of the integer, or `[input]` and its type if the value is unknown.
+/// The alternate form `{:#}` suffixes known values with their type, e.g. `255u8`.
 impl fmt::Display for Integer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let integer = self;
         let option = match_integer!(integer => integer.get_value());
         match option {
+            Some(number) if f.alternate() => write!(f, "{}{}", number, self.get_type()),
             Some(number) => write!(f, "{}", number),
             None => write!(f, "[input]{}", self.get_type()),
         }
@@ -81,6 +116,33 @@ impl Integer {
         }
     }
 
+    ///
+    /// Returns a constant integer of the given type from a native Rust integer.
+    ///
+    /// Returns `None` if the value does not fit in the given type.
+    ///
+    pub fn constant_from<T: Into<ConstInt>>(value: T, type_: &IntegerType) -> Option<Self> {
+        let value = value.into();
+        let cast = value.cast_to(type_);
+
+        if cast.raw_value() != value.raw_value() {
+            return None;
+        }
+
+        Some(Self::new(&cast))
+    }
+
+    ///
+    /// Returns the value of `self` if all of its bits are constant.
+    ///
+    pub fn to_const(&self, span: &Span) -> Option<ConstInt> {
+        if !self.get_bits().iter().all(|bit| matches!(bit, Boolean::Constant(_))) {
+            return None;
+        }
+
+        ConstInt::parse(&self.get_type(), &self.get_value()?, span).ok()
+    }
+
     pub fn get_bits(&self) -> Vec<Boolean> {
         let integer = self;
         match_integer!(integer => integer.to_bits_le())
@@ -91,20 +153,79 @@ impl Integer {
     //     (match_integer!(integer => integer.to_bits_le()), self.get_type())
     // }
 
-    // pub fn from_bits_typed(type_: &IntegerType, bits: &[Boolean]) -> Integer {
-    //     match type_ {
-    //         IntegerType::U8 => Integer::U8(UInt8::from_bits_le(bits)),
-    //         IntegerType::U16 => Integer::U16(UInt16::from_bits_le(bits)),
-    //         IntegerType::U32 => Integer::U32(UInt32::from_bits_le(bits)),
-    //         IntegerType::U64 => Integer::U64(UInt64::from_bits_le(bits)),
-    //         IntegerType::U128 => Integer::U128(UInt128::from_bits_le(bits)),
-    //         IntegerType::I8 => Integer::I8(Int8::from_bits_le(bits)),
-    //         IntegerType::I16 => Integer::I16(Int16::from_bits_le(bits)),
-    //         IntegerType::I32 => Integer::I32(Int32::from_bits_le(bits)),
-    //         IntegerType::I64 => Integer::I64(Int64::from_bits_le(bits)),
-    //         IntegerType::I128 => Integer::I128(Int128::from_bits_le(bits)),
-    //     }
-    // }
+    ///
+    /// Returns an integer of the given type from its little-endian bits.
+    ///
+    pub fn from_bits_typed(type_: &IntegerType, bits: &[Boolean]) -> Integer {
+        match type_ {
+            IntegerType::U8 => Integer::U8(UInt8::from_bits_le(bits)),
+            IntegerType::U16 => Integer::U16(UInt16::from_bits_le(bits)),
+            IntegerType::U32 => Integer::U32(UInt32::from_bits_le(bits)),
+            IntegerType::U64 => Integer::U64(UInt64::from_bits_le(bits)),
+            IntegerType::U128 => Integer::U128(UInt128::from_bits_le(bits)),
+            IntegerType::I8 => Integer::I8(Int8::from_bits_le(bits)),
+            IntegerType::I16 => Integer::I16(Int16::from_bits_le(bits)),
+            IntegerType::I32 => Integer::I32(Int32::from_bits_le(bits)),
+            IntegerType::I64 => Integer::I64(Int64::from_bits_le(bits)),
+            IntegerType::I128 => Integer::I128(Int128::from_bits_le(bits)),
+        }
+    }
+
+    ///
+    /// Returns the number of bits in an integer of the given type.
+    ///
+    pub fn bit_width(type_: &IntegerType) -> usize {
+        match type_ {
+            IntegerType::U8 | IntegerType::I8 => 8,
+            IntegerType::U16 | IntegerType::I16 => 16,
+            IntegerType::U32 | IntegerType::I32 => 32,
+            IntegerType::U64 | IntegerType::I64 => 64,
+            IntegerType::U128 | IntegerType::I128 => 128,
+        }
+    }
+
+    ///
+    /// Returns the bytes of `self` in little-endian order.
+    ///
+    /// The bytes share the bits of `self`, so no constraints are added.
+    ///
+    pub fn to_bytes_le(&self) -> Vec<UInt8> {
+        self.get_bits().chunks(8).map(UInt8::from_bits_le).collect()
+    }
+
+    ///
+    /// Returns the bytes of `self` in big-endian order.
+    ///
+    /// The bytes share the bits of `self`, so no constraints are added.
+    ///
+    pub fn to_bytes_be(&self) -> Vec<UInt8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    ///
+    /// Returns an integer of the given type from its bytes in little-endian order.
+    ///
+    pub fn from_bytes_le(type_: &IntegerType, bytes: &[UInt8], span: &Span) -> Result<Self, IntegerError> {
+        let expected = Self::bit_width(type_) / 8;
+        if bytes.len() != expected {
+            return Err(IntegerError::invalid_byte_length(type_, expected, bytes.len(), span));
+        }
+
+        let bits = bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+
+        Ok(Self::from_bits_typed(type_, &bits))
+    }
+
+    ///
+    /// Returns an integer of the given type from its bytes in big-endian order.
+    ///
+    pub fn from_bytes_be(type_: &IntegerType, bytes: &[UInt8], span: &Span) -> Result<Self, IntegerError> {
+        let bytes = bytes.iter().rev().cloned().collect::<Vec<_>>();
+
+        Self::from_bytes_le(type_, &bytes, span)
+    }
 
     pub fn get_value(&self) -> Option<String> {
         let integer = self;
@@ -138,19 +259,31 @@ impl Integer {
         name: &str,
         option: Option<String>,
         span: &Span,
+    ) -> Result<Self, IntegerError> {
+        Self::allocate(cs, integer_type, name, option, false, span)
+    }
+
+    /// Allocates the integer bits as public variables if `public` is set, otherwise as private ones.
+    fn allocate<F: Field, CS: ConstraintSystem<F>>(
+        cs: &mut CS,
+        integer_type: &IntegerType,
+        name: &str,
+        option: Option<String>,
+        public: bool,
+        span: &Span,
     ) -> Result<Self, IntegerError> {
         Ok(match integer_type {
-            IntegerType::U8 => allocate_type!(u8, UInt8, Integer::U8, cs, name, option, span),
-            IntegerType::U16 => allocate_type!(u16, UInt16, Integer::U16, cs, name, option, span),
-            IntegerType::U32 => allocate_type!(u32, UInt32, Integer::U32, cs, name, option, span),
-            IntegerType::U64 => allocate_type!(u64, UInt64, Integer::U64, cs, name, option, span),
-            IntegerType::U128 => allocate_type!(u128, UInt128, Integer::U128, cs, name, option, span),
-
-            IntegerType::I8 => allocate_type!(i8, Int8, Integer::I8, cs, name, option, span),
-            IntegerType::I16 => allocate_type!(i16, Int16, Integer::I16, cs, name, option, span),
-            IntegerType::I32 => allocate_type!(i32, Int32, Integer::I32, cs, name, option, span),
-            IntegerType::I64 => allocate_type!(i64, Int64, Integer::I64, cs, name, option, span),
-            IntegerType::I128 => allocate_type!(i128, Int128, Integer::I128, cs, name, option, span),
+            IntegerType::U8 => allocate_type!(u8, UInt8, Integer::U8, cs, name, option, public, span),
+            IntegerType::U16 => allocate_type!(u16, UInt16, Integer::U16, cs, name, option, public, span),
+            IntegerType::U32 => allocate_type!(u32, UInt32, Integer::U32, cs, name, option, public, span),
+            IntegerType::U64 => allocate_type!(u64, UInt64, Integer::U64, cs, name, option, public, span),
+            IntegerType::U128 => allocate_type!(u128, UInt128, Integer::U128, cs, name, option, public, span),
+
+            IntegerType::I8 => allocate_type!(i8, Int8, Integer::I8, cs, name, option, public, span),
+            IntegerType::I16 => allocate_type!(i16, Int16, Integer::I16, cs, name, option, public, span),
+            IntegerType::I32 => allocate_type!(i32, Int32, Integer::I32, cs, name, option, public, span),
+            IntegerType::I64 => allocate_type!(i64, Int64, Integer::I64, cs, name, option, public, span),
+            IntegerType::I128 => allocate_type!(i128, Int128, Integer::I128, cs, name, option, public, span),
         })
     }
 
@@ -159,12 +292,18 @@ impl Integer {
         integer_type: &IntegerType,
         name: &str,
         integer_value: Option<InputValue>,
+        public: bool,
         span: &Span,
     ) -> Result<Self, IntegerError> {
         // Check that the input value is the correct type
         let option = match integer_value {
             Some(input) => {
-                if let InputValue::Integer(_type_, number) = input {
+                if let InputValue::Integer(type_, number) = input {
+                    let input_type = IntegerType::from(type_);
+                    if &input_type != integer_type {
+                        return Err(IntegerError::invalid_integer_type(integer_type, &input_type, span));
+                    }
+
                     Some(number)
                 } else {
                     return Err(IntegerError::invalid_integer(input.to_string(), span));
@@ -173,7 +312,7 @@ impl Integer {
             None => None,
         };
 
-        Self::allocate_type(cs, integer_type, name, option, span)
+        Self::allocate(cs, integer_type, name, option, public, span)
     }
 
     pub fn negate<F: PrimeField, CS: ConstraintSystem<F>>(
@@ -181,7 +320,7 @@ impl Integer {
         cs: &mut CS,
         span: &Span,
     ) -> Result<Self, IntegerError> {
-        let unique_namespace = format!("enforce -{} {}:{}", self, span.line_start, span.col_start);
+        let unique_namespace = namespace_name(format!("enforce -{}", self), span);
 
         let a = self;
 
@@ -190,13 +329,36 @@ impl Integer {
         result.ok_or_else(|| IntegerError::negate_operation(span))
     }
 
+    ///
+    /// Returns `-self`, wrapping around at the bounds of the integer type.
+    ///
+    /// The negation is computed as `!self + 1` in two's complement, so negating `MIN` returns `MIN`.
+    ///
+    pub fn wrapping_negate<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        if !self.get_type().is_signed() {
+            return Err(IntegerError::negate_operation(span));
+        }
+
+        let unique_namespace = namespace_name(format!("enforce wrapping -{}", self), span);
+        let inverted = self.get_bits().iter().map(|bit| bit.not()).collect::<Vec<_>>();
+        let zero = vec![Boolean::constant(false); inverted.len()];
+        let bits = bits::wrapping_add(cs.ns(|| unique_namespace), &zero, &inverted, true)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Self::from_bits_typed(&self.get_type(), &bits))
+    }
+
     pub fn add<F: PrimeField, CS: ConstraintSystem<F>>(
         self,
         cs: &mut CS,
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
-        let unique_namespace = format!("enforce {} + {} {}:{}", self, other, span.line_start, span.col_start);
+        let unique_namespace = namespace_name(format!("enforce {} + {}", self, other), span);
 
         let a = self;
         let b = other;
@@ -212,7 +374,16 @@ impl Integer {
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
-        let unique_namespace = format!("enforce {} - {} {}:{}", self, other, span.line_start, span.col_start);
+        // The unsigned gadgets subtract their values natively, which panics on underflow.
+        if !self.get_type().is_signed() {
+            if let (Some(a), Some(b)) = (self.get_value(), other.get_value()) {
+                if a.parse::<u128>().ok() < b.parse::<u128>().ok() {
+                    return Err(IntegerError::unsigned(UnsignedIntegerError::Overflow, span));
+                }
+            }
+        }
+
+        let unique_namespace = namespace_name(format!("enforce {} - {}", self, other), span);
 
         let a = self;
         let b = other;
@@ -222,13 +393,138 @@ impl Integer {
         result.ok_or_else(|| IntegerError::binary_operation("-".to_string(), span))
     }
 
+    ///
+    /// Returns `self + other`, wrapping around at the bounds of the integer type.
+    ///
+    pub fn wrapping_add<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation("+".to_string(), span));
+        }
+
+        let unique_namespace = namespace_name(format!("enforce wrapping {} + {}", self, other), span);
+        let bits = bits::wrapping_add(cs.ns(|| unique_namespace), &self.get_bits(), &other.get_bits(), false)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Self::from_bits_typed(&self.get_type(), &bits))
+    }
+
+    ///
+    /// Returns `self - other`, wrapping around at the bounds of the integer type.
+    ///
+    /// The difference is computed as `self + !other + 1` in two's complement.
+    ///
+    pub fn wrapping_sub<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation("-".to_string(), span));
+        }
+
+        let unique_namespace = namespace_name(format!("enforce wrapping {} - {}", self, other), span);
+        let inverted = other.get_bits().iter().map(|bit| bit.not()).collect::<Vec<_>>();
+        let bits = bits::wrapping_add(cs.ns(|| unique_namespace), &self.get_bits(), &inverted, true)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Self::from_bits_typed(&self.get_type(), &bits))
+    }
+
+    ///
+    /// Returns `self * other`, wrapping around at the bounds of the integer type.
+    ///
+    /// The low bits of a product are the same for signed and unsigned operands in two's complement.
+    ///
+    pub fn wrapping_mul<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation("*".to_string(), span));
+        }
+
+        let unique_namespace = namespace_name(format!("enforce wrapping {} * {}", self, other), span);
+        let bits = bits::wrapping_mul(cs.ns(|| unique_namespace), &self.get_bits(), &other.get_bits())
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Self::from_bits_typed(&self.get_type(), &bits))
+    }
+
+    ///
+    /// Returns `self ** other`, wrapping around at the bounds of the integer type.
+    ///
+    /// The base is squared for each exponent bit, and multiplied into the result where the bit is set.
+    /// A signed exponent is enforced to be non-negative.
+    ///
+    pub fn wrapping_pow<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation("**".to_string(), span));
+        }
+
+        let unique_namespace = namespace_name(format!("enforce wrapping {} ** {}", self, other), span);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let mut exponent = other.get_bits();
+        if other.get_type().is_signed() {
+            let sign = exponent.pop().unwrap();
+            sign.enforce_equal(cs.ns(|| "exponent sign"), &Boolean::constant(false))
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+        }
+        // Constant zero bits above the highest possibly set exponent bit need no squaring.
+        let length = exponent
+            .iter()
+            .rposition(|bit| !matches!(bit, Boolean::Constant(false)))
+            .map_or(0, |position| position + 1);
+
+        let mut base = self.get_bits();
+        let mut result = (0..base.len())
+            .map(|i| Boolean::constant(i == 0))
+            .collect::<Vec<Boolean>>();
+        for (i, bit) in exponent.iter().take(length).enumerate() {
+            if i > 0 {
+                base = bits::wrapping_mul(cs.ns(|| format!("square {}", i)), &base, &base)
+                    .map_err(|e| IntegerError::synthesis(e, span))?;
+            }
+            if let Boolean::Constant(false) = bit {
+                continue;
+            }
+
+            let product = bits::wrapping_mul(cs.ns(|| format!("multiply {}", i)), &result, &base)
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+            result = product
+                .iter()
+                .zip(result.iter())
+                .enumerate()
+                .map(|(j, (product, result))| {
+                    Boolean::conditionally_select(cs.ns(|| format!("select {} {}", i, j)), bit, product, result)
+                })
+                .collect::<Result<Vec<Boolean>, _>>()
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+        }
+
+        Ok(Self::from_bits_typed(&self.get_type(), &result))
+    }
+
     pub fn mul<F: PrimeField, CS: ConstraintSystem<F>>(
         self,
         cs: &mut CS,
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
-        let unique_namespace = format!("enforce {} * {} {}:{}", self, other, span.line_start, span.col_start);
+        let unique_namespace = namespace_name(format!("enforce {} * {}", self, other), span);
 
         let a = self;
         let b = other;
@@ -244,12 +540,29 @@ impl Integer {
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
-        let unique_namespace = format!("enforce {} ÷ {} {}:{}", self, other, span.line_start, span.col_start);
+        self.div_with_constants(cs, other, &AllocatedConstants::default(), span)
+    }
+
+    ///
+    /// Returns `self` divided by `other`, rounded towards zero.
+    ///
+    /// The allocated constants of the division are taken from `constants`, so every division
+    /// given the same `constants` shares them.
+    ///
+    pub(crate) fn div_with_constants<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        constants: &AllocatedConstants,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = namespace_name(format!("enforce {} ÷ {}", self, other), span);
 
         let a = self;
         let b = other;
 
-        let result = match_integers_span!((a, b), span => a.div(cs.ns(|| unique_namespace), &b));
+        let result =
+            match_integers_span!((a, b), span => a.div_with_constants(cs.ns(|| unique_namespace), &b, constants));
 
         result.ok_or_else(|| IntegerError::binary_operation("÷".to_string(), span))
     }
@@ -260,7 +573,27 @@ impl Integer {
         other: Self,
         span: &Span,
     ) -> Result<Self, IntegerError> {
-        let unique_namespace = format!("enforce {} ** {} {}:{}", self, other, span.line_start, span.col_start);
+        // A constant power is evaluated with checked arithmetic instead of being enforced.
+        if let (Some(base), Some(exponent)) = (self.to_const(span), other.to_const(span)) {
+            return match base.value_pow(&exponent) {
+                Some(value) => Ok(Self::new(&value)),
+                None if base.get_int_type() == exponent.get_int_type() => Err(IntegerError::constant_overflow(
+                    self.to_string(),
+                    "**",
+                    other.to_string(),
+                    span,
+                )),
+                None => Err(IntegerError::binary_operation("**".to_string(), span)),
+            };
+        }
+
+        // A square is a single multiplication, skipping the exponentiation loop.
+        let is_square = other.to_const(span).is_some() && other.get_value().as_deref() == Some("2");
+        if is_square && self.get_type() == other.get_type() {
+            return self.clone().mul(cs, self, span);
+        }
+
+        let unique_namespace = namespace_name(format!("enforce {} ** {}", self, other), span);
 
         let a = self;
         let b = other;
@@ -269,6 +602,322 @@ impl Integer {
 
         result.ok_or_else(|| IntegerError::binary_operation("**".to_string(), span))
     }
+
+    ///
+    /// Returns the quotient and remainder of the Euclidean division of `self` by `other`.
+    ///
+    /// The remainder is never negative, matching Rust's `div_euclid` and `rem_euclid`. For signed
+    /// integers, the truncated quotient and remainder are corrected by adding selected values, so
+    /// an unselected correction can never overflow. A negative divisor is subtracted from the
+    /// remainder as `divisor + 1` followed by adding one, since negating `MIN` would overflow.
+    ///
+    pub fn divmod_euclid<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        other: &Self,
+        span: &Span,
+    ) -> Result<(Self, Self), IntegerError> {
+        let unique_namespace = namespace_name(format!("enforce {} divmod_euclid {}", self, other), span);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let quotient = self.clone().div(&mut cs.ns(|| "quotient"), other.clone(), span)?;
+        let product = quotient.clone().mul(&mut cs.ns(|| "product"), other.clone(), span)?;
+        let remainder = self.clone().sub(&mut cs.ns(|| "remainder"), product, span)?;
+
+        let type_ = self.get_type();
+        if !type_.is_signed() {
+            return Ok((quotient, remainder));
+        }
+
+        let zero = Integer::constant_from(0i8, &type_).unwrap();
+        let one = Integer::constant_from(1i8, &type_).unwrap();
+        let negative_one = Integer::constant_from(-1i8, &type_).unwrap();
+
+        let remainder_negative = remainder
+            .less_than(cs.ns(|| "remainder < 0"), &zero)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+        let divisor_negative = other
+            .less_than(cs.ns(|| "divisor < 0"), &zero)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        // A negative remainder is lifted by the divisor's magnitude, moving the quotient one step.
+        let add_divisor = Boolean::and(
+            cs.ns(|| "remainder < 0 && divisor > 0"),
+            &remainder_negative,
+            &divisor_negative.not(),
+        )
+        .map_err(|e| IntegerError::synthesis(e, span))?;
+        let sub_divisor = Boolean::and(
+            cs.ns(|| "remainder < 0 && divisor < 0"),
+            &remainder_negative,
+            &divisor_negative,
+        )
+        .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        let decrement = Self::conditionally_select(cs.ns(|| "select decrement"), &add_divisor, &negative_one, &zero)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+        let increment = Self::conditionally_select(cs.ns(|| "select increment"), &sub_divisor, &one, &zero)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+        let addend = Self::conditionally_select(cs.ns(|| "select addend"), &add_divisor, other, &zero)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+        let negative_divisor = Self::conditionally_select(
+            cs.ns(|| "select negative divisor"),
+            &divisor_negative,
+            other,
+            &negative_one,
+        )
+        .map_err(|e| IntegerError::synthesis(e, span))?;
+        let negative_divisor = negative_divisor.add(&mut cs.ns(|| "negative divisor + 1"), one.clone(), span)?;
+        let subtrahend =
+            Self::conditionally_select(cs.ns(|| "select subtrahend"), &sub_divisor, &negative_divisor, &zero)
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        let quotient = quotient.add(&mut cs.ns(|| "decrement quotient"), decrement, span)?;
+        let quotient = quotient.add(&mut cs.ns(|| "increment quotient"), increment.clone(), span)?;
+
+        let remainder = remainder.add(&mut cs.ns(|| "add divisor"), addend, span)?;
+        let remainder = remainder.sub(&mut cs.ns(|| "subtract divisor + 1"), subtrahend, span)?;
+        let remainder = remainder.add(&mut cs.ns(|| "add one"), increment, span)?;
+
+        Ok((quotient, remainder))
+    }
+
+    ///
+    /// Returns the Euclidean quotient of `self` divided by `other`.
+    ///
+    pub fn div_euclid<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        other: &Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        Ok(self.divmod_euclid(cs, other, span)?.0)
+    }
+
+    ///
+    /// Returns the non-negative remainder of `self` divided by `other`.
+    ///
+    pub fn rem_euclid<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        other: &Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        Ok(self.divmod_euclid(cs, other, span)?.1)
+    }
+
+    ///
+    /// Returns `self` bounded to the inclusive range `[min, max]`.
+    ///
+    /// Constant bounds with `min > max` are an error. Otherwise `max` takes precedence when the
+    /// bounds are inverted at runtime.
+    ///
+    pub fn clamp<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        min: &Self,
+        max: &Self,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        if min.get_type() != self.get_type() || max.get_type() != self.get_type() {
+            return Err(IntegerError::binary_operation("clamp".to_string(), span));
+        }
+
+        let unique_namespace = namespace_name(format!("enforce clamp {} to {}..={}", self, min, max), span);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let inverted = max
+            .less_than(cs.ns(|| "max < min"), min)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+        if let Boolean::Constant(true) = inverted {
+            return Err(IntegerError::inverted_clamp_bounds(
+                min.to_string(),
+                max.to_string(),
+                span,
+            ));
+        }
+
+        let below = self
+            .less_than(cs.ns(|| "value < min"), min)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+        let bounded_below = Self::conditionally_select(cs.ns(|| "select min"), &below, min, self)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        let above = max
+            .less_than(cs.ns(|| "max < value"), &bounded_below)
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Self::conditionally_select(cs.ns(|| "select max"), &above, max, &bounded_below)
+            .map_err(|e| IntegerError::synthesis(e, span))
+    }
+
+    ///
+    /// Returns a `Boolean` that is true if `self` equals any of the given `constants`.
+    ///
+    /// Each membership check compares the bits of `self` directly against the known bits of the
+    /// constant, so no intermediate integers are allocated. An empty set is never satisfied.
+    ///
+    pub fn is_one_of<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        constants: &[ConstInt],
+        span: &Span,
+    ) -> Result<Boolean, IntegerError> {
+        let mut unique: Vec<&ConstInt> = Vec::with_capacity(constants.len());
+        for constant in constants {
+            if constant.get_int_type() != self.get_type() {
+                return Err(IntegerError::binary_operation("is_one_of".to_string(), span));
+            }
+            if !unique.contains(&constant) {
+                unique.push(constant);
+            }
+        }
+
+        if unique.is_empty() {
+            return Ok(Boolean::constant(false));
+        }
+
+        let bits = self.get_bits();
+        let mut not_equal = Vec::with_capacity(unique.len());
+
+        for (i, constant) in unique.into_iter().enumerate() {
+            let literals = bits
+                .iter()
+                .zip(Integer::new(constant).get_bits())
+                .map(|(bit, expected)| match expected.get_value() {
+                    Some(true) => *bit,
+                    _ => bit.not(),
+                })
+                .collect::<Vec<_>>();
+
+            let unique_namespace = namespace_name(format!("is {} == constant {}", self, i), span);
+            let equal = Boolean::kary_and(cs.ns(|| unique_namespace), &literals)
+                .map_err(|e| IntegerError::synthesis(e, span))?;
+
+            not_equal.push(equal.not());
+        }
+
+        // a or b or c == not (not a and not b and not c)
+        let unique_namespace = namespace_name(format!("is {} one of set", self), span);
+        let none_equal =
+            Boolean::kary_and(cs.ns(|| unique_namespace), &not_equal).map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(none_equal.not())
+    }
+
+    ///
+    /// Returns the number of set bits in `self` as a `u32`.
+    ///
+    pub fn count_ones<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = namespace_name(format!("count ones {}", self), span);
+
+        let count = bits::count_ones(cs.ns(|| unique_namespace), &self.get_bits())
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Integer::U32(count))
+    }
+
+    ///
+    /// Returns the number of unset bits in `self` as a `u32`.
+    ///
+    pub fn count_zeros<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = namespace_name(format!("count zeros {}", self), span);
+
+        let count = bits::count_zeros(cs.ns(|| unique_namespace), &self.get_bits())
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Integer::U32(count))
+    }
+
+    ///
+    /// Returns the number of zero bits above the most significant set bit of `self` as a `u32`.
+    ///
+    pub fn leading_zeros<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = namespace_name(format!("leading zeros {}", self), span);
+
+        let count = bits::leading_zeros(cs.ns(|| unique_namespace), &self.get_bits())
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Integer::U32(count))
+    }
+
+    ///
+    /// Returns the number of zero bits below the least significant set bit of `self` as a `u32`.
+    ///
+    pub fn trailing_zeros<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = namespace_name(format!("trailing zeros {}", self), span);
+
+        let count = bits::trailing_zeros(cs.ns(|| unique_namespace), &self.get_bits())
+            .map_err(|e| IntegerError::synthesis(e, span))?;
+
+        Ok(Integer::U32(count))
+    }
+
+    ///
+    /// Returns a `Boolean` that is true if exactly one bit of `self` is set.
+    ///
+    pub fn is_power_of_two<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        span: &Span,
+    ) -> Result<Boolean, IntegerError> {
+        let unique_namespace = namespace_name(format!("is power of two {}", self), span);
+        let mut cs = cs.ns(|| unique_namespace);
+
+        let count =
+            bits::count_ones(cs.ns(|| "count ones"), &self.get_bits()).map_err(|e| IntegerError::synthesis(e, span))?;
+
+        count
+            .evaluate_equal(cs.ns(|| "count == 1"), &UInt32::constant(1))
+            .map_err(|e| IntegerError::synthesis(e, span))
+    }
+
+    ///
+    /// Returns `self` with its bits rotated left by `n` places, wrapping the high bits around to the low end.
+    ///
+    /// The rotation amount is taken modulo the bit width. Rotation permutes the existing bits and
+    /// adds no constraints.
+    ///
+    pub fn rotate_left(&self, n: u32) -> Self {
+        let mut bits = self.get_bits();
+        let amount = n as usize % bits.len();
+
+        // Bits are little-endian, so moving bits towards the high end rotates the vector right.
+        bits.rotate_right(amount);
+
+        Self::from_bits_typed(&self.get_type(), &bits)
+    }
+
+    ///
+    /// Returns `self` with its bits rotated right by `n` places, wrapping the low bits around to the high end.
+    ///
+    /// The rotation amount is taken modulo the bit width. Rotation permutes the existing bits and
+    /// adds no constraints.
+    ///
+    pub fn rotate_right(&self, n: u32) -> Self {
+        let mut bits = self.get_bits();
+        let amount = n as usize % bits.len();
+
+        bits.rotate_left(amount);
+
+        Self::from_bits_typed(&self.get_type(), &bits)
+    }
 }
 
 impl<F: PrimeField> EvaluateEqGadget<F> for Integer {
@@ -127,26 +127,29 @@ macro_rules! match_integers_span {
 }
 
 macro_rules! allocate_type {
-    ($rust_ty:ty, $gadget_ty:ty, $leo_ty:path, $cs:expr, $name:expr, $option:expr, $span:expr) => {{
-        let option = $option.map(|s| {
-            s.parse::<$rust_ty>()
-                .map_err(|_| IntegerError::invalid_integer(s, $span))
-                .unwrap()
-        });
-
-        let result = <$gadget_ty>::alloc(
-            $cs.ns(|| {
-                format!(
-                    "`{}: {}` {}:{}",
-                    $name,
-                    stringify!($rust_ty),
-                    $span.line_start,
-                    $span.col_start
-                )
-            }),
-            || option.ok_or(SynthesisError::AssignmentMissing),
-        )
-        .map_err(|_| IntegerError::missing_integer(format!("{}: {}", $name, stringify!($rust_ty)), $span))?;
+    ($rust_ty:ty, $gadget_ty:ty, $leo_ty:path, $cs:expr, $name:expr, $option:expr, $public:expr, $span:expr) => {{
+        let parameter = format!("{}: {}", $name, stringify!($rust_ty));
+
+        let value = match $option {
+            Some(s) => Some(
+                s.parse::<$rust_ty>()
+                    .map_err(|_| IntegerError::invalid_integer_input(&parameter, s, $span))?,
+            ),
+            None => None,
+        };
+
+        let namespace = format!("`{}` {}:{}", parameter, $span.line_start, $span.col_start);
+        let value = || value.ok_or(SynthesisError::AssignmentMissing);
+
+        let result = if $public {
+            <$gadget_ty>::alloc_input($cs.ns(|| namespace), value)
+        } else {
+            <$gadget_ty>::alloc($cs.ns(|| namespace), value)
+        }
+        .map_err(|error| match error {
+            SynthesisError::AssignmentMissing => IntegerError::missing_integer(parameter, $span),
+            error => IntegerError::synthesis(error, $span),
+        })?;
 
         $leo_ty(result)
     }};
@@ -18,5 +18,13 @@
 pub mod macros;
 pub use self::macros::*;
 
+pub mod bits;
+
+pub mod constants;
+pub use self::constants::*;
+
+pub mod division;
+pub use self::division::*;
+
 pub mod integer;
 pub use self::integer::*;
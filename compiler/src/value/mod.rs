@@ -19,6 +19,9 @@
 pub mod address;
 pub use self::address::*;
 
+pub mod array;
+pub use self::array::*;
+
 pub mod boolean;
 
 pub mod field;
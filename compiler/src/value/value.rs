@@ -75,6 +75,45 @@ impl<'a, F: PrimeField, G: GroupType<F>> ConstrainedValue<'a, F, G> {
             ConstrainedValue::CircuitExpression(id, _members) => Type::Circuit(*id),
         })
     }
+
+    ///
+    /// Returns the value in `values` at the index given by the little-endian `index_bits`.
+    ///
+    /// Values are selected pairwise in a balanced tree with one level per index bit, so selecting
+    /// from `n` values takes `n - 1` selections and no index comparisons.
+    /// The caller must ensure that the index is less than the number of values.
+    ///
+    pub(crate) fn select_by_index_bits<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        index_bits: &[Boolean],
+        mut values: Vec<Self>,
+    ) -> Result<Self, SynthesisError> {
+        for (level, bit) in index_bits.iter().enumerate() {
+            if values.len() <= 1 {
+                break;
+            }
+
+            let mut selected = Vec::with_capacity((values.len() + 1) / 2);
+            let mut values_iter = values.into_iter();
+            while let Some(even) = values_iter.next() {
+                let pair = selected.len();
+                // An unpaired value can only be reached when this index bit is unset.
+                let value = match values_iter.next() {
+                    Some(odd) => Self::conditionally_select(
+                        cs.ns(|| format!("select level {} pair {}", level, pair)),
+                        bit,
+                        &odd,
+                        &even,
+                    )?,
+                    None => even,
+                };
+                selected.push(value);
+            }
+            values = selected;
+        }
+
+        values.into_iter().next().ok_or(SynthesisError::Unsatisfiable)
+    }
 }
 
 impl<'a, F: PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<'a, F, G> {
@@ -111,6 +150,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<'a, F
                 write!(f, "({})", values)
             }
             ConstrainedValue::CircuitExpression(ref circuit, ref members) => {
+                // Write members in declaration order regardless of how the value was built.
+                let declared = circuit.members.borrow();
+                let mut members = members.iter().collect::<Vec<_>>();
+                members.sort_by_key(|member| declared.get_index_of(&*member.0.name));
+
                 write!(f, "{} {{", circuit.name.borrow())?;
                 for (i, member) in members.iter().enumerate() {
                     write!(f, "{}: {}", member.0, member.1)?;
@@ -18,11 +18,25 @@ use crate::{
     assert_satisfied,
     expect_asg_error,
     expect_compiler_error,
+    generate_main_input,
     get_output,
     parse_program,
     parse_program_with_input,
+    parse_program_with_options,
+    EdwardsConstrainedValue,
     EdwardsTestCompiler,
 };
+use leo_asg::{ConstInt, Span};
+use leo_compiler::{ArrayValue, CompilerOptions, ConstrainedValue, Integer};
+use leo_synthesizer::CountingConstraintSystem;
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_gadgets::traits::utilities::{
+    alloc::AllocGadget,
+    int::Int32,
+    uint::UInt32,
+};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
 
 pub fn output_ones(program: EdwardsTestCompiler) {
     let expected = include_bytes!("output/registers_ones.out");
@@ -93,6 +107,108 @@ fn test_initializer() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_comprehension() {
+    let program_string = include_str!("comprehension.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_fold() {
+    let program_string = include_str!("fold.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_fold_shadowing() {
+    let program_string = include_str!("fold_shadowing.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_fold_accumulator_reuse() {
+    let program_string = include_str!("fold_accumulator_reuse.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_fold_chain() {
+    let program_string = include_str!("fold_chain.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_fold_input() {
+    let program_string = include_str!("fold_input.leo");
+    let input_string = include_str!("input/one_two_three.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_fold_input_fail() {
+    let program_string = include_str!("fold_input.leo");
+    let input_string = include_str!("input/three_ones.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let _err = expect_compiler_error(program);
+}
+
+#[test]
+fn test_map() {
+    let program_string = include_str!("map.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_map_input() {
+    let program_string = include_str!("map_input.leo");
+    let input_string = include_str!("input/map_input.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let expected = include_bytes!("output/map_input.out");
+    let actual = get_output(program);
+
+    assert!(expected.eq(actual.bytes().as_slice()));
+}
+
+#[test]
+fn test_map_shadowing() {
+    let program_string = include_str!("map_shadowing.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_map_chain() {
+    let program_string = include_str!("map_chain.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_lambda_fail() {
+    let program_string = include_str!("lambda_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 #[test]
 fn test_initializer_fail() {
     let program_string = include_str!("initializer_fail.leo");
@@ -246,6 +362,22 @@ fn test_slice_lower() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_slice_inclusive() {
+    let program_string = include_str!("slice_inclusive.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_slice_inclusive_fail() {
+    let program_string = include_str!("slice_inclusive_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 // Array type tests
 
 #[test]
@@ -559,6 +691,137 @@ fn test_array_index() {
     assert_satisfied(program);
 }
 
+const INDEX_16_PROGRAM: &str = r#"
+    function main(i: u32, expected: u32) {
+        let a = [0u32, 11, 22, 33, 44, 55, 66, 77, 88, 99, 110, 121, 132, 143, 154, 165];
+
+        console.assert(a[i] == expected);
+    }
+    "#;
+
+fn index_16_input(index_type: &str, i: u32) -> String {
+    format!("[main]\ni: {} = {};\nexpected: u32 = {};\n", index_type, i, i * 11)
+}
+
+#[test]
+fn test_array_index_16() {
+    for i in 0..16 {
+        let input_string = index_16_input("u32", i);
+        let program = parse_program_with_input(INDEX_16_PROGRAM, &input_string).unwrap();
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_array_index_16_signed() {
+    let program_string = INDEX_16_PROGRAM.replace("i: u32", "i: i32");
+    for i in 0..16 {
+        let input_string = index_16_input("i32", i);
+        let program = parse_program_with_input(&program_string, &input_string).unwrap();
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_array_index_odd_length() {
+    let program_string = r#"
+    function main(i: u32, expected: u8) {
+        let a = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+
+        console.assert(a[i] == expected);
+    }
+    "#;
+    for i in 0..13 {
+        let input_string = format!("[main]\ni: u32 = {};\nexpected: u8 = {};\n", i, i + 1);
+        let program = parse_program_with_input(program_string, &input_string).unwrap();
+
+        assert_satisfied(program);
+    }
+}
+
+///
+/// Returns the element of `[1u8, 2, ..]` with `length` elements at the index `i`, allocated as `i32`
+/// if `signed` is set and as `u32` otherwise.
+///
+/// Selecting directly skips the compile time resolution of an index with a known value,
+/// so the constraints of a runtime index are generated and checked.
+///
+fn select_allocated_index(length: u8, i: u8, signed: bool) -> String {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let index = if signed {
+        Integer::I32(Int32::alloc(cs.ns(|| "index"), || Ok(i as i32)).unwrap())
+    } else {
+        Integer::U32(UInt32::alloc(cs.ns(|| "index"), || Ok(i as u32)).unwrap())
+    };
+    let values = (1..=length)
+        .map(|value| ConstrainedValue::Integer(Integer::new(&ConstInt::U8(value))))
+        .collect::<Vec<EdwardsConstrainedValue>>();
+
+    let selected = ArrayValue::new(values)
+        .select(&mut cs.ns(|| "select"), &index, &Span::default())
+        .unwrap();
+    assert!(cs.is_satisfied());
+
+    match selected {
+        ConstrainedValue::Integer(integer) => integer.get_value().unwrap(),
+        _ => panic!("expected an integer element"),
+    }
+}
+
+#[test]
+fn test_array_select_allocated_index() {
+    for length in [1u8, 2, 5, 13, 16].iter().copied() {
+        for i in 0..length {
+            assert_eq!(select_allocated_index(length, i, false), (i + 1).to_string());
+        }
+    }
+}
+
+#[test]
+fn test_array_select_allocated_index_signed() {
+    for length in [1u8, 2, 5, 13, 16].iter().copied() {
+        for i in 0..length {
+            assert_eq!(select_allocated_index(length, i, true), (i + 1).to_string());
+        }
+    }
+}
+
+fn index_16_constraints(index_type: &str) -> usize {
+    let program_string = format!(
+        r#"
+    function main(i: {}) {{
+        let a = [0u32, 11, 22, 33, 44, 55, 66, 77, 88, 99, 110, 121, 132, 143, 154, 165];
+        let b = a[i];
+    }}
+    "#,
+        index_type
+    );
+    let mut program = parse_program(&program_string).unwrap();
+    program.set_main_input(generate_main_input(vec![("i", None)]));
+
+    // Without an index value, the access is selected in the constraint system.
+    let mut cs = CountingConstraintSystem::<Fq>::default();
+    program.compile_constraints(&mut cs).unwrap();
+
+    cs.num_constraints()
+}
+
+#[test]
+fn test_array_index_16_constraints() {
+    // Unsigned indices select through a tree of the index bits, signed indices compare against every position.
+    let tree = index_16_constraints("u32");
+    let linear = index_16_constraints("i32");
+
+    assert!(
+        tree < linear,
+        "tree selection used {} constraints, linear used {}",
+        tree,
+        linear
+    );
+}
+
 #[test]
 fn test_array_index_bounds_fail() {
     let program_string = r#"
@@ -714,3 +977,33 @@ fn test_array_range_index_full_dyn_bounds_fail() {
 
     expect_compiler_error(program);
 }
+
+// Strict mode
+
+fn strict_options() -> CompilerOptions {
+    CompilerOptions {
+        strict: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_strict_index_implicit_conversion() {
+    let program_string = include_str!("strict_index.leo");
+
+    let program = parse_program(program_string).unwrap();
+    assert_satisfied(program);
+
+    let error = parse_program_with_options(program_string, strict_options())
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("explicit cast"));
+}
+
+#[test]
+fn test_strict_index_explicit_cast() {
+    let program_string = include_str!("strict_index_cast.leo");
+    let program = parse_program_with_options(program_string, strict_options()).unwrap();
+
+    assert_satisfied(program);
+}
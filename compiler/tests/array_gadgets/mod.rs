@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::{ConstInt, IntegerType, Span};
+use leo_compiler::{targets::edwards_bls12::EdwardsGroupType, ArrayValue, ConstrainedValue, Integer};
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
+
+type Value<'a> = ConstrainedValue<'a, Fq, EdwardsGroupType>;
+
+fn u8_array<'a>(values: &[u8]) -> ArrayValue<'a, Fq, EdwardsGroupType> {
+    ArrayValue::new(
+        values
+            .iter()
+            .map(|value| Value::Integer(Integer::new(&ConstInt::U8(*value))))
+            .collect(),
+    )
+}
+
+fn allocate_u32(cs: &mut TestConstraintSystem<Fq>, value: u32) -> Integer {
+    Integer::allocate_type(cs, &IntegerType::U32, "i", Some(value.to_string()), &Span::default()).unwrap()
+}
+
+#[test]
+fn test_get_constant_index() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let array = u8_array(&[1, 2, 3]);
+
+    let index = Integer::new(&ConstInt::U32(1));
+    let value = array.get(&mut cs, &index, &Span::default()).unwrap();
+
+    assert_eq!(value.to_string(), "2");
+    assert_eq!(cs.num_constraints(), 0);
+}
+
+#[test]
+fn test_get_constant_index_out_of_bounds() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let array = u8_array(&[1, 2, 3]);
+
+    let index = Integer::new(&ConstInt::U32(3));
+    let error = array.get(&mut cs, &index, &Span::default()).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("cannot access index 3 of array out of bounds"));
+}
+
+#[test]
+fn test_get_allocated_index() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let array = u8_array(&[1, 2, 3]);
+
+    let index = allocate_u32(&mut cs, 2);
+    let value = array.get(&mut cs, &index, &Span::default()).unwrap();
+
+    assert_eq!(value.to_string(), "3");
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_get_allocated_index_out_of_bounds() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let array = u8_array(&[1, 2, 3]);
+
+    // The value of the allocated index is known, so it is checked at compile time.
+    let index = allocate_u32(&mut cs, 3);
+    let error = array.get(&mut cs, &index, &Span::default()).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("cannot access index 3 of array out of bounds"));
+}
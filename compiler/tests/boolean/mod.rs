@@ -24,6 +24,9 @@ use crate::{
     EdwardsTestCompiler,
 };
 
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
+
 pub fn output_true(program: EdwardsTestCompiler) {
     let expected = include_bytes!("output/registers_true.out");
     let actual = get_output(program);
@@ -185,6 +188,46 @@ fn test_true_and_u32() {
     expect_asg_error(error);
 }
 
+// Casts
+
+#[test]
+fn test_cast_to_integer() {
+    let program_string = include_str!("cast_to_integer.leo");
+    let input_string = include_str!("input/cast_true_false.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_cast_from_integer() {
+    let program_string = include_str!("cast_from_integer.leo");
+    let input_string = include_str!("input/cast_one.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_cast_from_integer_not_bit() {
+    let program_string = include_str!("cast_from_integer_input.leo");
+    let input_string = include_str!("input/cast_two.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    // The allocated `2u8` is cast without a compile time error, but cannot satisfy the circuit.
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.compile_constraints(&mut cs).unwrap();
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_cast_from_integer_fail() {
+    let program_string = include_str!("cast_from_integer_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 // All
 
 #[test]
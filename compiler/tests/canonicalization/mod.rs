@@ -14,21 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, parse_program, parse_program_with_input};
-use leo_ast::Ast;
+use crate::{assert_ast_snapshot, assert_satisfied, parse_program, parse_program_with_input};
+use leo_ast::{Ast, Expression, ReturnStatement, SpanSerialization, Statement, TernaryChain};
 use leo_parser::parser;
 
 pub fn parse_program_ast(file_string: &str) -> Ast {
+    let mut ast = parse_initial_ast(file_string);
+    ast.canonicalize().expect("Failed to canonicalize program.");
+
+    ast
+}
+
+pub fn parse_initial_ast(file_string: &str) -> Ast {
     const TEST_PROGRAM_PATH: &str = "";
     let test_program_file_path = std::path::PathBuf::from(TEST_PROGRAM_PATH);
 
-    let mut ast = Ast::new(
+    Ast::new(
         parser::parse(test_program_file_path.to_str().expect("unwrap fail"), &file_string)
             .expect("Failed to parse file."),
-    );
-    ast.canonicalize().expect("Failed to canonicalize program.");
-
-    ast
+    )
 }
 
 #[test]
@@ -46,6 +50,17 @@ fn test_big_self_in_circuit_replacement() {
     assert_eq!(expected_ast, ast);
 }
 
+#[test]
+fn test_big_self_in_circuit_replacement_snapshot() {
+    let program_string = include_str!("big_self_in_circuit_replacement.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_ast_snapshot(
+        "canonicalization/snapshots/big_self_in_circuit_replacement.json",
+        &program,
+    );
+}
+
 #[test]
 fn test_big_self_outside_circuit_fail() {
     // Check program is invalid.
@@ -87,3 +102,65 @@ fn test_compound_assignment() {
 
     assert_eq!(expected_ast, ast);
 }
+
+#[test]
+fn test_compound_assignment_operators() {
+    let program_string = include_str!("compound_assignment_operators.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_compound_assignment_index_evaluated_once() {
+    let program_string = include_str!("compound_assignment_index_once.leo");
+    let input_string = include_str!("input/compound_assignment_index_once.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_canonicalization_diff() {
+    let program_string = include_str!("compound_assignment.leo");
+    let diffs = parse_initial_ast(program_string)
+        .canonicalization_diff()
+        .expect("Failed to canonicalize program.");
+
+    assert!(!diffs.is_empty());
+    assert!(diffs
+        .iter()
+        .any(|diff| diff.path.starts_with("functions.main.block.statements")));
+}
+
+#[test]
+fn test_canonicalization_diff_empty() {
+    let program_string = include_str!("no_desugaring.leo");
+    let diffs = parse_initial_ast(program_string)
+        .canonicalization_diff()
+        .expect("Failed to canonicalize program.");
+
+    assert!(diffs.is_empty());
+}
+
+#[test]
+fn test_normalize_ternary_chain() {
+    let mut ast = parse_initial_ast(include_str!("ternary_chain.leo"));
+    ast.normalize_ternary_chains()
+        .expect("Failed to normalize ternary chains.");
+
+    let expected = parse_initial_ast(include_str!("ternary_chain_normalized.leo"));
+    let to_json = |ast: &Ast| ast.to_json_string_with_spans(SpanSerialization::Omitted).unwrap();
+    assert_eq!(to_json(&expected), to_json(&ast));
+
+    let main = ast.as_repr().functions.values().next().unwrap();
+    let chain = match &main.block.statements[0] {
+        Statement::Return(ReturnStatement {
+            expression: Expression::Ternary(ternary),
+            ..
+        }) => TernaryChain::from_ternary(ternary).unwrap(),
+        statement => panic!("expected a ternary return, found `{}`", statement),
+    };
+    assert_eq!(chain.arms.len(), 2);
+    assert_eq!(chain.default.to_string(), "2u8");
+}
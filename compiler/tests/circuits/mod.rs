@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_asg_error, parse_program};
+use crate::{assert_satisfied, expect_asg_error, make_test_context, parse_program, EdwardsConstrainedValue};
+use leo_asg::{ConstInt, Identifier, NullImportResolver, Program};
+use leo_compiler::{ConstrainedCircuitMember, ConstrainedValue, Integer};
+use leo_parser::parse_ast;
 
 // Expressions
 
@@ -42,6 +45,22 @@ fn test_inline_undefined() {
     expect_asg_error(error);
 }
 
+#[test]
+fn test_update() {
+    let program_string = include_str!("update.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_update_fail() {
+    let program_string = include_str!("update_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 // Members
 
 #[test]
@@ -132,6 +151,38 @@ fn test_member_static_function_undefined() {
     expect_asg_error(error)
 }
 
+#[test]
+fn test_impl_block() {
+    let program_string = include_str!("impl_block.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_impl_block_collision_fail() {
+    let program_string = include_str!("impl_block_collision_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_operator_overloading() {
+    let program_string = include_str!("operator_overloading.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_operator_overloading_undefined_fail() {
+    let program_string = include_str!("operator_overloading_undefined_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 // Constant
 #[test]
 fn test_const_self_variable() {
@@ -223,6 +274,14 @@ fn test_mutate_variable() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_mutate_nested_variable() {
+    let program_string = include_str!("mut_nested_variable.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
 #[test]
 fn test_mutate_variable_fail() {
     let program_string = include_str!("mut_variable_fail.leo");
@@ -316,3 +375,33 @@ fn test_mutable_call_immutable_context() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_member_display_order() {
+    let program_string = include_str!("member_order.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+
+    let ast = parse_ast("member_order.leo", program_string).unwrap();
+    let asg = Program::new(make_test_context(), ast.as_repr(), &mut NullImportResolver).unwrap();
+    let circuit = *asg.circuits.get("Foo").unwrap();
+
+    let member = |name: &str, value: u8| {
+        ConstrainedCircuitMember(
+            Identifier::new(name.into()),
+            ConstrainedValue::Integer(Integer::new(&ConstInt::U8(value))),
+        )
+    };
+
+    // Members are written in declaration order however the value was assembled.
+    for members in [
+        vec![member("a", 1), member("b", 2), member("c", 3)],
+        vec![member("c", 3), member("a", 1), member("b", 2)],
+        vec![member("b", 2), member("c", 3), member("a", 1)],
+    ] {
+        let value: EdwardsConstrainedValue = ConstrainedValue::CircuitExpression(circuit, members);
+
+        assert_eq!(value.to_string(), "Foo {a: 1, b: 2, c: 3}");
+    }
+}
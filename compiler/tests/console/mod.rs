@@ -21,8 +21,15 @@ use crate::{
     generate_main_input,
     parse_program,
     parse_program_with_input,
+    parse_program_with_options,
+    EdwardsTestCompiler,
 };
 use leo_ast::InputValue;
+use leo_compiler::CompilerOptions;
+use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
 
 #[test]
 fn test_log() {
@@ -39,6 +46,30 @@ fn test_log_fail() {
     assert!(parse_program(program_string).is_err());
 }
 
+#[test]
+fn test_log_escaped_braces() {
+    let program_string = include_str!("log_escaped_braces.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_log_fail_unbalanced_open() {
+    let program_string = include_str!("log_fail_unbalanced_open.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    assert!(error.to_string().contains("unbalanced brace"));
+}
+
+#[test]
+fn test_log_fail_unbalanced_close() {
+    let program_string = include_str!("log_fail_unbalanced_close.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    assert!(error.to_string().contains("unbalanced brace"));
+}
+
 #[test]
 fn test_log_parameter() {
     let program_string = include_str!("log_parameter.leo");
@@ -117,6 +148,55 @@ fn test_debug() {
     assert_satisfied(program);
 }
 
+fn u32_input(value: &str) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {})),
+        value.to_string(),
+    ))
+}
+
+fn release_options() -> CompilerOptions {
+    CompilerOptions {
+        release: true,
+        ..Default::default()
+    }
+}
+
+fn num_constraints(mut program: EdwardsTestCompiler) -> usize {
+    let main_input = generate_main_input(vec![
+        ("a", u32_input("3")),
+        ("b", u32_input("5")),
+    ]);
+    program.set_main_input(main_input);
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.compile_constraints(&mut cs).unwrap();
+    assert!(cs.is_satisfied());
+
+    cs.num_constraints()
+}
+
+#[test]
+fn test_debug_release() {
+    let program_string = include_str!("debug.leo");
+    let program = parse_program_with_options(program_string, release_options()).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_debug_input_release() {
+    let program_string = include_str!("debug_input.leo");
+    let empty_string = include_str!("debug_input_empty.leo");
+
+    let empty = num_constraints(parse_program(empty_string).unwrap());
+    let debug = num_constraints(parse_program(program_string).unwrap());
+    let release = num_constraints(parse_program_with_options(program_string, release_options()).unwrap());
+
+    assert!(debug > empty);
+    assert_eq!(release, empty);
+}
+
 // Error
 
 #[test]
@@ -167,3 +247,80 @@ fn test_conditional_assert() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_assert_eq() {
+    let program_string = include_str!("assert_eq.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", u32_input("7")),
+        ("b", u32_input("7")),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_assert_eq_fail() {
+    let program_string = include_str!("assert_eq.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", u32_input("7")),
+        ("b", u32_input("13")),
+    ]);
+
+    program.set_main_input(main_input);
+
+    let error = expect_compiler_error(program).to_string();
+
+    assert!(error.contains("`7`"));
+    assert!(error.contains("`13`"));
+}
+
+#[test]
+fn test_assert_neq() {
+    let program_string = include_str!("assert_neq.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", u32_input("7")),
+        ("b", u32_input("13")),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", u32_input("7")),
+        ("b", u32_input("7")),
+    ]);
+
+    program.set_main_input(main_input);
+
+    let error = expect_compiler_error(program).to_string();
+
+    assert!(error.contains("`7` equals `7`"));
+}
+
+#[test]
+fn test_assert_eq_array() {
+    let program_string = include_str!("assert_eq_array.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_assert_eq_fail_type() {
+    let program_string = include_str!("assert_eq_fail_type.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
@@ -21,14 +21,17 @@ use crate::{
     get_output,
     parse_program,
     parse_program_with_input,
+    test_rng,
 };
 
 use leo_ast::InputValue;
+use leo_compiler::blake2s_digest;
 use leo_input::types::{IntegerType, U8Type, UnsignedIntegerType};
 use rand::Rng;
-use rand_core::SeedableRng;
-use rand_xorshift::XorShiftRng;
 use snarkvm_algorithms::{prf::blake2s::Blake2s as B2SPRF, traits::PRF};
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_gadgets::traits::utilities::uint::UInt8;
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
 
 #[test]
 fn test_arguments_length_fail() {
@@ -62,7 +65,7 @@ fn test_blake2s_input() {
 
 #[test]
 fn test_blake2s_random() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     let mut seed = [0u8; 32];
     rng.fill(&mut seed);
@@ -95,6 +98,59 @@ fn test_blake2s_random() {
     assert_satisfied(program);
 }
 
+/// Returns the digest computed by the `Blake2s::digest` gadget over allocated `bytes`.
+fn blake2s_digest_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let input = UInt8::alloc_vec(cs.ns(|| "input"), bytes).unwrap();
+
+    let digest = blake2s_digest(cs.ns(|| "digest"), &input).unwrap();
+    assert!(cs.is_satisfied());
+
+    digest.iter().map(|byte| byte.value.unwrap()).collect()
+}
+
+#[test]
+fn test_digest_length_fail() {
+    let program_string = include_str!("digest_length_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_blake2s_digest_reference() {
+    // Reference digests from RFC 7693.
+    assert_eq!(
+        hex::encode(blake2s_digest_bytes(b"")),
+        "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+    );
+    assert_eq!(
+        hex::encode(blake2s_digest_bytes(b"abc")),
+        "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+    );
+}
+
+#[test]
+fn test_blake2s_digest_random() {
+    let mut rng = test_rng();
+
+    let mut message = [0u8; 32];
+    rng.fill(&mut message);
+
+    let expected = blake2s_digest_bytes(&message);
+
+    let program_string = include_str!("blake2s_digest.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("message", Some(bytes_gadget_to_input(message.to_vec()))),
+        ("expected", Some(bytes_gadget_to_input(expected))),
+    ]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
 fn bytes_gadget_to_input(bytes: Vec<u8>) -> InputValue {
     let u8_type = IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {}));
     let bytes = bytes
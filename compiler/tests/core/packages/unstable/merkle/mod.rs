@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_asg_error, parse_program, test_rng};
+
+use leo_asg::Span;
+use leo_compiler::{
+    targets::edwards_bls12::{pedersen_hash_parameters, EdwardsGroupType},
+    verify_merkle_path,
+    FieldType,
+};
+use rand::Rng;
+use snarkvm_algorithms::traits::CRH;
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_gadgets::{
+    fields::FpGadget,
+    traits::utilities::{
+        alloc::AllocGadget,
+        boolean::{AllocatedBit, Boolean},
+    },
+};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::bytes::ToBytes;
+
+const DEPTH: usize = 3;
+
+fn hash_children(left: Fq, right: Fq) -> Fq {
+    let mut input = Vec::new();
+    left.write(&mut input).unwrap();
+    right.write(&mut input).unwrap();
+
+    pedersen_hash_parameters().hash(&input).unwrap()
+}
+
+/// Returns a random leaf with its path and index bits, and the root they lead to.
+fn random_path(rng: &mut impl Rng) -> (Fq, Vec<Fq>, Vec<bool>, Fq) {
+    let leaf: Fq = rng.gen();
+    let path = (0..DEPTH).map(|_| rng.gen()).collect::<Vec<Fq>>();
+    let index = (0..DEPTH).map(|_| rng.gen()).collect::<Vec<bool>>();
+
+    let root = path.iter().zip(index.iter()).fold(leaf, |node, (sibling, is_right)| {
+        if *is_right {
+            hash_children(*sibling, node)
+        } else {
+            hash_children(node, *sibling)
+        }
+    });
+
+    (leaf, path, index, root)
+}
+
+fn allocate_field(cs: &mut TestConstraintSystem<Fq>, name: &str, value: Fq) -> FieldType<Fq> {
+    FieldType::Allocated(FpGadget::alloc(cs.ns(|| name), || Ok(value)).unwrap())
+}
+
+fn verify(leaf: Fq, path: &[Fq], index: &[bool], root: Fq) -> Option<bool> {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let leaf = allocate_field(&mut cs, "leaf", leaf);
+    let root = allocate_field(&mut cs, "root", root);
+    let path = path
+        .iter()
+        .enumerate()
+        .map(|(i, sibling)| allocate_field(&mut cs, &format!("sibling {}", i), *sibling))
+        .collect::<Vec<FieldType<Fq>>>();
+    let index = index
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| Boolean::from(AllocatedBit::alloc(cs.ns(|| format!("index {}", i)), || Ok(*bit)).unwrap()))
+        .collect::<Vec<Boolean>>();
+
+    let valid =
+        verify_merkle_path::<_, EdwardsGroupType, _>(cs.ns(|| "verify"), &leaf, &path, &index, &root, &Span::default())
+            .unwrap();
+    assert!(cs.is_satisfied());
+
+    valid.get_value()
+}
+
+#[test]
+fn test_depth_mismatch_fail() {
+    let program_string = include_str!("depth_mismatch_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_valid_path() {
+    let mut rng = test_rng();
+    let (leaf, path, index, root) = random_path(&mut rng);
+
+    assert_eq!(verify(leaf, &path, &index, root), Some(true));
+}
+
+#[test]
+fn test_tampered_path() {
+    let mut rng = test_rng();
+    let (leaf, mut path, index, root) = random_path(&mut rng);
+
+    path[1] = rng.gen();
+
+    assert_eq!(verify(leaf, &path, &index, root), Some(false));
+}
+
+#[test]
+fn test_wrong_index() {
+    let mut rng = test_rng();
+    let (leaf, path, mut index, root) = random_path(&mut rng);
+
+    index[0] = !index[0];
+
+    assert_eq!(verify(leaf, &path, &index, root), Some(false));
+}
@@ -15,3 +15,6 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod blake2s;
+pub mod merkle;
+pub mod pedersen;
+pub mod schnorr;
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_asg_error, generate_main_input, get_output, parse_program, test_rng, EdwardsConstrainedValue};
+
+use leo_asg::Span;
+use leo_ast::InputValue;
+use leo_compiler::{
+    pedersen_hash,
+    targets::edwards_bls12::pedersen_hash_parameters,
+    ConstrainedValue,
+    FieldType,
+    Integer,
+    IntegerTrait,
+};
+use rand::Rng;
+use snarkvm_algorithms::traits::CRH;
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_gadgets::traits::utilities::{alloc::AllocGadget, uint::UInt8};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::bytes::ToBytes;
+
+#[test]
+fn test_arguments_type_fail() {
+    let program_string = include_str!("arguments_type_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_pedersen_random() {
+    let mut rng = test_rng();
+    let a: u64 = rng.gen();
+    let b: u64 = rng.gen();
+
+    let program_string = include_str!("pedersen_random.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", Some(InputValue::Field(a.to_string()))),
+        ("b", Some(InputValue::Field(b.to_string()))),
+    ]);
+    program.set_main_input(main_input);
+
+    get_output(program);
+}
+
+#[test]
+fn test_pedersen_hash_fields() {
+    let mut rng = test_rng();
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let mut expected_input = Vec::new();
+    let mut input: Vec<EdwardsConstrainedValue> = Vec::new();
+    for i in 0..2 {
+        let value: u64 = rng.gen();
+        let field = FieldType::alloc(cs.ns(|| format!("field {}", i)), || Ok(value.to_string())).unwrap();
+
+        field.get_value().unwrap().write(&mut expected_input).unwrap();
+        input.push(ConstrainedValue::Field(field));
+    }
+
+    let digest = pedersen_hash(cs.ns(|| "hash"), &input, &Span::default()).unwrap();
+    let expected = pedersen_hash_parameters().hash(&expected_input).unwrap();
+
+    assert_eq!(digest.get_value(), Some(expected));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_pedersen_hash_bytes() {
+    let mut rng = test_rng();
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let bytes = (0..64).map(|_| rng.gen()).collect::<Vec<u8>>();
+    let input = UInt8::alloc_vec(cs.ns(|| "bytes"), &bytes)
+        .unwrap()
+        .into_iter()
+        .map(|byte| ConstrainedValue::Integer(Integer::U8(byte)))
+        .collect::<Vec<EdwardsConstrainedValue>>();
+
+    let digest = pedersen_hash(cs.ns(|| "hash"), &input, &Span::default()).unwrap();
+    let expected = pedersen_hash_parameters().hash(&bytes).unwrap();
+
+    assert_eq!(digest.get_value(), Some(expected));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_pedersen_hash_input_too_long() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let input = (0..65)
+        .map(|_| ConstrainedValue::Integer(Integer::U8(UInt8::constant(0))))
+        .collect::<Vec<EdwardsConstrainedValue>>();
+
+    assert!(pedersen_hash(cs.ns(|| "hash"), &input, &Span::default()).is_err());
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{expect_asg_error, parse_program, test_rng};
+
+use leo_asg::Span;
+use leo_compiler::{schnorr_verify, targets::edwards_bls12::EdwardsGroupType, GroupType, SCHNORR_SALT};
+use rand::Rng;
+use snarkvm_algorithms::{
+    signature::{SchnorrOutput, SchnorrParameters, SchnorrSignature},
+    traits::SignatureScheme,
+};
+use snarkvm_curves::{
+    edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq, Fr},
+    traits::{AffineCurve, Group},
+    TEModelParameters,
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_gadgets::traits::utilities::{boolean::Boolean, integer::Integer, uint::UInt8};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::bytes::ToBytes;
+
+use blake2::Blake2s;
+use std::marker::PhantomData;
+
+type Schnorr = SchnorrSignature<EdwardsAffine, Blake2s>;
+
+///
+/// Returns the snarkVM Schnorr scheme over the Leo group generator and salt.
+///
+fn schnorr() -> Schnorr {
+    let (x, y) = EdwardsParameters::AFFINE_GENERATOR_COEFFS;
+    let mut generator = EdwardsAffine::new(x, y);
+
+    let mut generator_powers = Vec::with_capacity(256);
+    for _ in 0..256 {
+        generator_powers.push(generator);
+        generator.double_in_place();
+    }
+
+    Schnorr::from(SchnorrParameters {
+        generator_powers,
+        salt: SCHNORR_SALT,
+        _hash: PhantomData,
+    })
+}
+
+fn to_bytes_le<T: ToBytes>(value: &T) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    value.write(&mut bytes[..]).unwrap();
+    bytes
+}
+
+fn verify(public_key: EdwardsAffine, message: &[u8], response: &[u8], challenge: &[u8]) -> Option<bool> {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let span = Span::default();
+
+    let public_key = EdwardsGroupType::Constant(public_key)
+        .to_allocated(cs.ns(|| "public key"), &span)
+        .unwrap();
+    let message = UInt8::alloc_vec(cs.ns(|| "message"), message).unwrap();
+    let response = UInt8::alloc_vec(cs.ns(|| "response"), response).unwrap();
+    let challenge = UInt8::alloc_vec(cs.ns(|| "challenge"), challenge).unwrap();
+
+    let valid: Boolean =
+        schnorr_verify(cs.ns(|| "verify"), &public_key, &message, &response, &challenge, &span).unwrap();
+    assert!(cs.is_satisfied());
+
+    valid.get_value()
+}
+
+fn verify_signature(
+    public_key: EdwardsAffine,
+    message: &[u8],
+    signature: &SchnorrOutput<EdwardsAffine>,
+) -> Option<bool> {
+    verify(
+        public_key,
+        message,
+        &to_bytes_le(&signature.prover_response),
+        &to_bytes_le(&signature.verifier_challenge),
+    )
+}
+
+#[test]
+fn test_arguments_length_fail() {
+    let program_string = include_str!("arguments_length_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_valid_signature() {
+    let mut rng = test_rng();
+    let schnorr = schnorr();
+
+    for _ in 0..4 {
+        let message: [u8; 32] = rng.gen();
+        let private_key = schnorr.generate_private_key(&mut rng).unwrap();
+        let public_key = schnorr.generate_public_key(&private_key).unwrap();
+        let signature = schnorr.sign(&private_key, &message, &mut rng).unwrap();
+        assert!(schnorr.verify(&public_key, &message, &signature).unwrap());
+
+        assert_eq!(verify_signature(public_key.0, &message, &signature), Some(true));
+    }
+}
+
+#[test]
+fn test_invalid_signature() {
+    let mut rng = test_rng();
+    let schnorr = schnorr();
+
+    let message: [u8; 32] = rng.gen();
+    let private_key = schnorr.generate_private_key(&mut rng).unwrap();
+    let public_key = schnorr.generate_public_key(&private_key).unwrap();
+    let signature = schnorr.sign(&private_key, &message, &mut rng).unwrap();
+
+    let mut other_message = message;
+    other_message[0] ^= 1;
+    assert!(!schnorr.verify(&public_key, &other_message, &signature).unwrap());
+    assert_eq!(verify_signature(public_key.0, &other_message, &signature), Some(false));
+
+    let other_private_key = schnorr.generate_private_key(&mut rng).unwrap();
+    let other_public_key = schnorr.generate_public_key(&other_private_key).unwrap();
+    assert_eq!(verify_signature(other_public_key.0, &message, &signature), Some(false));
+
+    let mut forged = signature.clone();
+    forged.prover_response += &Fr::from(1u64);
+    assert_eq!(verify_signature(public_key.0, &message, &forged), Some(false));
+}
+
+#[test]
+fn test_response_out_of_range() {
+    let mut rng = test_rng();
+    let schnorr = schnorr();
+
+    let message: [u8; 32] = rng.gen();
+    let private_key = schnorr.generate_private_key(&mut rng).unwrap();
+    let public_key = schnorr.generate_public_key(&private_key).unwrap();
+    let signature = schnorr.sign(&private_key, &message, &mut rng).unwrap();
+
+    // Adding the order to the response leaves the commitment unchanged.
+    let mut response = Fr::characteristic().to_vec();
+    let mut carry = 0u128;
+    for (limb, added) in response.iter_mut().zip(signature.prover_response.into_repr().0.iter()) {
+        let sum = *limb as u128 + *added as u128 + carry;
+        *limb = sum as u64;
+        carry = sum >> 64;
+    }
+    assert_eq!(carry, 0);
+    let response = response.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<u8>>();
+
+    assert_eq!(
+        verify(
+            public_key.0,
+            &message,
+            &response,
+            &to_bytes_le(&signature.verifier_challenge)
+        ),
+        Some(false)
+    );
+}
+
+#[test]
+fn test_public_key_outside_subgroup() {
+    let mut rng = test_rng();
+    let schnorr = schnorr();
+
+    let message: [u8; 32] = rng.gen();
+    let private_key = schnorr.generate_private_key(&mut rng).unwrap();
+    let public_key = schnorr.generate_public_key(&private_key).unwrap();
+    let signature = schnorr.sign(&private_key, &message, &mut rng).unwrap();
+
+    // The point of order two is on the curve but outside the prime-order subgroup.
+    let low_order = EdwardsAffine::new(Fq::zero(), -Fq::one());
+    assert!(!low_order.is_in_correct_subgroup_assuming_on_curve());
+
+    assert_eq!(
+        verify_signature(public_key.0 + &low_order, &message, &signature),
+        Some(false)
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let span = Span::default();
+
+    let public_key = EdwardsGroupType::one();
+    let message = vec![UInt8::constant(0); 31];
+    let scalar = vec![UInt8::constant(0); 32];
+
+    let result = schnorr_verify(cs.ns(|| "verify"), &public_key, &message, &scalar, &scalar, &span);
+
+    assert!(result.is_err());
+}
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, import::set_local_dir, parse_program};
+use crate::{assert_satisfied, import::set_local_dir, parse_program, parse_program_with_options};
+use leo_compiler::{errors::CompilerError, CompilerOptions};
 
 #[test]
 fn test_out_of_order() {
@@ -36,3 +37,29 @@ fn test_out_of_order_with_import() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_unused_variable_warning() {
+    let program_string = include_str!("unused_variable.leo");
+
+    let program = parse_program(program_string).unwrap();
+
+    let warnings = program.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "unused variable `a`");
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_unused_variable_denied_warnings() {
+    let program_string = include_str!("unused_variable.leo");
+    let options = CompilerOptions {
+        deny_warnings: true,
+        ..Default::default()
+    };
+
+    let error = parse_program_with_options(program_string, options).err().unwrap();
+
+    assert!(matches!(error, CompilerError::DeniedWarnings(1)));
+}
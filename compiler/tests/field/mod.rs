@@ -14,15 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program};
+use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program, test_rng};
 use leo_ast::InputValue;
 
 use snarkvm_curves::edwards_bls12::Fq;
 use snarkvm_utilities::bytes::ToBytes;
 
 use num_bigint::BigUint;
-use rand::{Rng, SeedableRng};
-use rand_xorshift::XorShiftRng;
+use rand::Rng;
 
 // Helper function to convert field element into decimal base 10 string
 pub fn field_to_decimal_string(f: Fq) -> String {
@@ -43,7 +42,7 @@ pub fn field_to_decimal_string(f: Fq) -> String {
 fn test_negate() {
     use std::ops::Neg;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -86,7 +85,7 @@ fn test_no_space_between_literal() {
 fn test_add() {
     use std::ops::Add;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -116,7 +115,7 @@ fn test_add() {
 fn test_sub() {
     use std::ops::Sub;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -145,7 +144,7 @@ fn test_sub() {
 fn test_div() {
     use std::ops::Div;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -174,7 +173,7 @@ fn test_div() {
 fn test_mul() {
     use std::ops::Mul;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -202,7 +201,7 @@ fn test_mul() {
 
 #[test]
 fn test_eq() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -244,9 +243,71 @@ fn test_eq() {
     }
 }
 
+#[test]
+fn test_lt() {
+    let mut rng = test_rng();
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+
+        let a_string = field_to_decimal_string(a);
+        let b_string = field_to_decimal_string(b);
+        let c = a_string.parse::<BigUint>().unwrap() < b_string.parse::<BigUint>().unwrap();
+
+        let program_string = include_str!("lt.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a_string))),
+            ("b", Some(InputValue::Field(b_string))),
+            ("c", Some(InputValue::Boolean(c))),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_gt() {
+    let mut rng = test_rng();
+
+    for _ in 0..10 {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+
+        let a_string = field_to_decimal_string(a);
+        let b_string = field_to_decimal_string(b);
+        let c = a_string.parse::<BigUint>().unwrap() > b_string.parse::<BigUint>().unwrap();
+
+        let program_string = include_str!("gt.leo");
+        let mut program = parse_program(program_string).unwrap();
+
+        let main_input = generate_main_input(vec![
+            ("a", Some(InputValue::Field(a_string))),
+            ("b", Some(InputValue::Field(b_string))),
+            ("c", Some(InputValue::Boolean(c))),
+        ]);
+
+        program.set_main_input(main_input);
+
+        assert_satisfied(program);
+    }
+}
+
+#[test]
+fn test_canonical_representative() {
+    let program_string = include_str!("representative.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
 #[test]
 fn test_console_assert_pass() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -269,7 +330,7 @@ fn test_console_assert_pass() {
 
 #[test]
 fn test_console_assert_fail() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: Fq = rng.gen();
@@ -298,7 +359,7 @@ fn test_console_assert_fail() {
 
 #[test]
 fn test_ternary() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     let a: Fq = rng.gen();
     let b: Fq = rng.gen();
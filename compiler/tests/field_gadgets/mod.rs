@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::Span;
+use leo_compiler::FieldType;
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField};
+use snarkvm_gadgets::traits::utilities::{
+    alloc::AllocGadget,
+    boolean::{AllocatedBit, Boolean},
+};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+fn allocate_field(cs: &mut TestConstraintSystem<Fq>, name: &str, value: &str) -> FieldType<Fq> {
+    FieldType::alloc(cs.ns(|| name), || Ok(value.to_string())).unwrap()
+}
+
+#[test]
+fn test_inv() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let a = allocate_field(&mut cs, "a", "7");
+
+    let inverse = a.inv(cs.ns(|| "inv"), &Span::default()).unwrap();
+    let product = inverse.mul(cs.ns(|| "mul"), &a, &Span::default()).unwrap();
+
+    assert_eq!(product.get_value(), Some(Fq::one()));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_inv_constant() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let a = FieldType::<Fq>::constant("-3".to_string(), &Span::default()).unwrap();
+
+    let inverse = a.inv(cs.ns(|| "inv"), &Span::default()).unwrap();
+    let product = inverse.mul(cs.ns(|| "mul"), &a, &Span::default()).unwrap();
+
+    assert_eq!(product.get_value(), Some(Fq::one()));
+    assert_eq!(cs.num_constraints(), 0);
+}
+
+#[test]
+fn test_inv_zero() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let zero = allocate_field(&mut cs, "zero", "0");
+    let constant_zero = FieldType::<Fq>::constant("0".to_string(), &Span::default()).unwrap();
+
+    assert!(zero.inv(cs.ns(|| "inv"), &Span::default()).is_err());
+    assert!(constant_zero.inv(cs.ns(|| "constant inv"), &Span::default()).is_err());
+}
+
+#[test]
+fn test_sqrt() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let a = allocate_field(&mut cs, "a", "9");
+
+    let root = a.sqrt(cs.ns(|| "sqrt"), &Span::default()).unwrap();
+
+    assert_eq!(root.get_value().map(|root| root.square()), a.get_value());
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_sqrt_non_residue() {
+    // Find a small value with no square root.
+    let value = (2..)
+        .map(|n: u32| n.to_string())
+        .find(|n| {
+            let field = FieldType::<Fq>::constant(n.clone(), &Span::default()).unwrap();
+            field.get_value().unwrap().sqrt().is_none()
+        })
+        .unwrap();
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let a = allocate_field(&mut cs, "a", &value);
+    let constant = FieldType::<Fq>::constant(value, &Span::default()).unwrap();
+
+    a.sqrt(cs.ns(|| "sqrt"), &Span::default()).unwrap();
+
+    assert!(!cs.is_satisfied());
+    assert!(constant.sqrt(cs.ns(|| "constant sqrt"), &Span::default()).is_err());
+}
+
+fn allocate_bits(cs: &mut TestConstraintSystem<Fq>, bits: &[Boolean]) -> Vec<Boolean> {
+    bits.iter()
+        .enumerate()
+        .map(|(i, bit)| {
+            let value = bit.get_value().unwrap();
+            Boolean::from(AllocatedBit::alloc(cs.ns(|| format!("bit {}", i)), || Ok(value)).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_bits_round_trip() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    // Allocate a value with the high bits set, which cannot be parsed from a negative input.
+    let constant = FieldType::<Fq>::constant("-12345".to_string(), &Span::default()).unwrap();
+    let a = FieldType::Allocated(constant.allocated(cs.ns(|| "a")).unwrap());
+
+    let bits = a.to_bits(cs.ns(|| "to_bits"), &Span::default()).unwrap();
+    assert_eq!(bits.len(), Fq::size_in_bits());
+
+    let result = FieldType::from_bits(cs.ns(|| "from_bits"), &bits, &Span::default()).unwrap();
+
+    assert_eq!(result.get_value(), a.get_value());
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_bits_little_endian() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let a = FieldType::<Fq>::constant("6".to_string(), &Span::default()).unwrap();
+
+    let bits = a.to_bits(cs.ns(|| "to_bits"), &Span::default()).unwrap();
+    let values = bits.iter().map(|bit| bit.get_value().unwrap()).collect::<Vec<bool>>();
+
+    assert_eq!(&values[..4], &[false, true, true, false]);
+    assert!(values[4..].iter().all(|bit| !bit));
+}
+
+#[test]
+fn test_from_bits_non_canonical() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let minus_one = FieldType::<Fq>::constant("-1".to_string(), &Span::default()).unwrap();
+
+    // The modulus is odd, so setting the lowest bit of `p - 1` encodes `p` itself.
+    let mut bits = minus_one.to_bits(cs.ns(|| "to_bits"), &Span::default()).unwrap();
+    assert_eq!(bits[0].get_value(), Some(false));
+    bits[0] = Boolean::constant(true);
+
+    let bits = allocate_bits(&mut cs, &bits);
+    assert!(cs.is_satisfied());
+
+    FieldType::from_bits(cs.ns(|| "from_bits"), &bits, &Span::default()).unwrap();
+
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_from_bits_invalid_length() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let bits = vec![Boolean::constant(false); 8];
+
+    assert!(FieldType::<Fq>::from_bits(cs.ns(|| "from_bits"), &bits, &Span::default()).is_err());
+}
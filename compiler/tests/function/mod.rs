@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_asg_error, get_output, parse_program, parse_program_with_input};
+use crate::{
+    assert_satisfied,
+    expect_asg_error,
+    get_output,
+    parse_program,
+    parse_program_with_input,
+    parse_program_with_input_and_options,
+};
+use leo_compiler::{errors::CompilerError, CompilerOptions};
+use leo_synthesizer::{CircuitSynthesizer, ConstraintLimit, CountingConstraintSystem};
+
+use snarkvm_curves::{bls12_377::Bls12_377, edwards_bls12::Fq};
+use snarkvm_r1cs::ConstraintSystem;
 
 #[test]
 fn test_conditional_return() {
@@ -29,6 +41,120 @@ fn test_conditional_return() {
     assert_eq!(expected_string, actual_string);
 }
 
+#[test]
+fn test_constraints_per_function() {
+    let input_string = include_str!("input/constraints_per_function.in");
+    let program_string = include_str!("constraints_per_function.leo");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::with_function_constraints();
+    program.compile_constraints(&mut cs).unwrap();
+
+    let counts = cs.constraints_per_function();
+    let count_of = |name: &str| {
+        counts
+            .iter()
+            .find(|(function, _)| function == name)
+            .map(|(_, count)| *count)
+            .unwrap_or(0)
+    };
+
+    assert!(count_of("double") > 0);
+    assert!(count_of("square") > 0);
+}
+
+#[test]
+fn test_max_constraints() {
+    let input_string = include_str!("input/constraints_per_function.in");
+    let program_string = include_str!("max_constraints.leo");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::with_function_constraints();
+    program.compile_constraints(&mut cs).unwrap();
+
+    program
+        .check_constraint_budgets(&cs.constraints_per_function())
+        .unwrap();
+}
+
+#[test]
+fn test_max_constraints_fail() {
+    let input_string = include_str!("input/constraints_per_function.in");
+    let program_string = include_str!("max_constraints_fail.leo");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::with_function_constraints();
+    program.compile_constraints(&mut cs).unwrap();
+
+    // `double` is under its budget, while a `u32` multiplication exceeds the budget of `square`.
+    let error = program
+        .check_constraint_budgets(&cs.constraints_per_function())
+        .unwrap_err();
+    assert!(matches!(error, CompilerError::ConstraintBudgetError(_)));
+    assert!(error.to_string().contains("function `square`"));
+}
+
+#[test]
+fn test_constraint_limit_fail() {
+    let input_string = include_str!("input/constraints_per_function.in");
+    let program_string = include_str!("constraint_limit_fail.leo");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = ConstraintLimit::new(CountingConstraintSystem::<Fq>::default(), 10_000);
+    assert!(program.compile_constraints(&mut cs).is_err());
+
+    // Synthesis stops at the limit instead of unrolling every iteration.
+    assert!(cs.exceeded());
+    assert_eq!(cs.num_constraints(), 10_001);
+}
+
+fn inlining_options() -> CompilerOptions {
+    CompilerOptions {
+        function_inlining_enabled: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_inline() {
+    let input_string = include_str!("input/inline.in");
+    let program_string = include_str!("inline.leo");
+    let expected_string = include_str!("output/inline.out");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+    let called_bytes = get_output(program);
+    let called_string = std::str::from_utf8(called_bytes.bytes().as_slice()).unwrap();
+    assert_eq!(expected_string, called_string);
+
+    let program = parse_program_with_input_and_options(program_string, input_string, inlining_options()).unwrap();
+    let inlined_bytes = get_output(program);
+    assert_eq!(called_bytes, inlined_bytes);
+}
+
+#[test]
+fn test_inline_removes_calls() {
+    let input_string = include_str!("input/inline.in");
+    let program_string = include_str!("inline.leo");
+    let program = parse_program_with_input_and_options(program_string, input_string, inlining_options()).unwrap();
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::with_function_constraints();
+    program.compile_constraints(&mut cs).unwrap();
+
+    let counts = cs.constraints_per_function();
+    let count_of = |name: &str| {
+        counts
+            .iter()
+            .find(|(function, _)| function == name)
+            .map(|(_, count)| *count)
+            .unwrap_or(0)
+    };
+
+    assert_eq!(count_of("double"), 0);
+    assert_eq!(count_of("square"), 0);
+    // Functions with statements other than definitions are left as calls.
+    assert!(count_of("log_square") > 0);
+}
+
 #[test]
 fn test_empty() {
     let program_string = include_str!("empty.leo");
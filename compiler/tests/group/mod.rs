@@ -16,14 +16,13 @@
 
 use crate::{
     assert_satisfied, expect_compiler_error, expect_synthesis_error, field::field_to_decimal_string,
-    generate_main_input, parse_program, parse_program_with_input,
+    generate_main_input, parse_program, parse_program_with_input, test_rng,
 };
 use leo_ast::{GroupCoordinate, GroupTuple, GroupValue, InputValue, Span};
 
 use snarkvm_curves::edwards_bls12::EdwardsAffine;
 
-use rand::{Rng, SeedableRng};
-use rand_xorshift::XorShiftRng;
+use rand::Rng;
 
 pub fn group_element_to_input_value(g: EdwardsAffine) -> GroupValue {
     let x = field_to_decimal_string(g.x);
@@ -77,6 +76,22 @@ fn test_point() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_coordinates() {
+    let program_string = include_str!("coordinates.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_coordinates_fail() {
+    let program_string = include_str!("coordinates_fail.leo");
+    let program = parse_program(program_string);
+
+    assert!(program.is_err());
+}
+
 #[test]
 fn test_x_sign_high() {
     let program_string = include_str!("x_sign_high.leo");
@@ -181,7 +196,7 @@ fn test_input() {
 fn test_negate() {
     use std::ops::Neg;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -207,7 +222,7 @@ fn test_negate() {
 fn test_add() {
     use std::ops::Add;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -236,7 +251,7 @@ fn test_add() {
 fn test_sub() {
     use std::ops::Sub;
 
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -263,7 +278,7 @@ fn test_sub() {
 
 #[test]
 fn test_console_assert_pass() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -286,7 +301,7 @@ fn test_console_assert_pass() {
 
 #[test]
 fn test_console_assert_fail() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -315,7 +330,7 @@ fn test_console_assert_fail() {
 
 #[test]
 fn test_eq() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     for _ in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -359,7 +374,7 @@ fn test_eq() {
 
 #[test]
 fn test_ternary() {
-    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut rng = test_rng();
 
     let a: EdwardsAffine = rng.gen();
     let b: EdwardsAffine = rng.gen();
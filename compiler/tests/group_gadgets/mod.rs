@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::test_rng;
+use leo_asg::Span;
+use leo_compiler::{targets::edwards_bls12::EdwardsGroupType, GroupType};
+
+use rand::Rng;
+use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fq};
+use snarkvm_fields::Zero;
+use snarkvm_gadgets::traits::{
+    fields::FieldGadget,
+    utilities::{
+        alloc::AllocGadget,
+        boolean::{AllocatedBit, Boolean},
+    },
+};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+fn coordinates(group: &EdwardsGroupType) -> (Option<Fq>, Option<Fq>) {
+    match group {
+        EdwardsGroupType::Constant(constant) => (Some(constant.x), Some(constant.y)),
+        EdwardsGroupType::Allocated(allocated) => (allocated.x.get_value(), allocated.y.get_value()),
+    }
+}
+
+/// Multiplies an allocated `base` by `scalar`, returning the constraints spent and the result.
+fn mul_constraints(base: EdwardsAffine, scalar: &[bool], constant_scalar: bool) -> (usize, EdwardsGroupType) {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let span = Span::default();
+
+    let base = EdwardsGroupType::Constant(base)
+        .to_allocated(cs.ns(|| "base"), &span)
+        .unwrap();
+    let bits = scalar
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| {
+            if constant_scalar {
+                Boolean::constant(*bit)
+            } else {
+                Boolean::from(AllocatedBit::alloc(cs.ns(|| format!("bit {}", i)), || Ok(*bit)).unwrap())
+            }
+        })
+        .collect::<Vec<Boolean>>();
+
+    let before = cs.num_constraints();
+    let result = base.mul(cs.ns(|| "mul"), &bits, &span).unwrap();
+    assert!(cs.is_satisfied());
+
+    (cs.num_constraints() - before, result)
+}
+
+#[test]
+fn test_mul_constant_scalar_is_cheaper() {
+    let mut rng = test_rng();
+    let base: EdwardsAffine = rng.gen();
+    let scalar = (0..64).map(|_| rng.gen()).collect::<Vec<bool>>();
+
+    let (constant_cost, constant_result) = mul_constraints(base, &scalar, true);
+    let (variable_cost, variable_result) = mul_constraints(base, &scalar, false);
+
+    assert!(constant_cost < variable_cost);
+    assert_eq!(coordinates(&constant_result), coordinates(&variable_result));
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let bits = scalar
+        .iter()
+        .map(|bit| Boolean::constant(*bit))
+        .collect::<Vec<Boolean>>();
+    let native = EdwardsGroupType::Constant(base)
+        .mul(&mut cs, &bits, &Span::default())
+        .unwrap();
+
+    assert_eq!(cs.num_constraints(), 0);
+    assert_eq!(coordinates(&native), coordinates(&constant_result));
+}
+
+#[test]
+fn test_mul_constant_zero_scalar() {
+    let mut rng = test_rng();
+    let base: EdwardsAffine = rng.gen();
+
+    let (cost, result) = mul_constraints(base, &[false; 16], true);
+
+    assert_eq!(cost, 0);
+    assert_eq!(
+        coordinates(&result),
+        coordinates(&EdwardsGroupType::Constant(EdwardsAffine::zero()))
+    );
+}
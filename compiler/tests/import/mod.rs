@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, parse_program};
+use crate::{assert_satisfied, make_test_context, parse_program, parse_program_with_options};
+use leo_asg::{reform_ast, Asg};
+use leo_compiler::CompilerOptions;
+use leo_imports::ImportParser;
 
 use std::env::{current_dir, set_current_dir};
 
@@ -152,3 +155,50 @@ fn test_many_import_star() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_missing_import_offline() {
+    let program_string = include_str!("missing.leo");
+    let options = CompilerOptions {
+        offline: true,
+        ..Default::default()
+    };
+    let error = parse_program_with_options(program_string, options).err().unwrap();
+
+    assert!(error.to_string().contains("offline mode"));
+}
+
+#[test]
+#[ignore]
+fn test_prefetched_imports_match_sequential() {
+    set_local_dir();
+
+    let program_string = include_str!("many_import.leo");
+    let program = leo_parser::parse("many_import.leo", program_string).unwrap();
+
+    let sequential = Asg::new(make_test_context(), &program, &mut ImportParser::new(false)).unwrap();
+
+    let mut import_parser = ImportParser::new(false);
+    import_parser.prefetch(&program);
+    let prefetched = Asg::new(make_test_context(), &program, &mut import_parser).unwrap();
+
+    assert_eq!(reform_ast(sequential.as_repr()), reform_ast(prefetched.as_repr()));
+}
+
+#[test]
+#[ignore]
+fn test_diamond_parses_shared_package_once() {
+    set_local_dir();
+
+    let program_string = include_str!("diamond.leo");
+    let program = leo_parser::parse("diamond.leo", program_string).unwrap();
+
+    let mut import_parser = ImportParser::new(false);
+    Asg::new(make_test_context(), &program, &mut import_parser).unwrap();
+
+    let shared = current_dir().unwrap().join("src/diamond-shared.leo");
+    let parsed = import_parser.parsed_files();
+
+    assert_eq!(parsed.iter().filter(|file| **file == shared).count(), 1);
+    assert_eq!(parsed.len(), 3);
+}
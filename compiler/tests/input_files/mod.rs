@@ -19,3 +19,29 @@ mod program_input_and_program_state;
 mod program_input_constants;
 mod program_registers;
 mod program_state;
+
+use crate::{generate_main_input, generate_random_input_u32, test_rng};
+use leo_ast::MainInput;
+
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+fn generate_random_main_input<R: Rng>(rng: &mut R) -> MainInput {
+    generate_main_input(vec![
+        ("a", generate_random_input_u32(rng)),
+        ("b", generate_random_input_u32(rng)),
+    ])
+}
+
+#[test]
+fn test_seeded_inputs_are_reproducible() {
+    let first = generate_random_main_input(&mut XorShiftRng::seed_from_u64(42));
+    let second = generate_random_main_input(&mut XorShiftRng::seed_from_u64(42));
+    let other = generate_random_main_input(&mut XorShiftRng::seed_from_u64(43));
+
+    assert!(first == second);
+    assert!(first != other);
+
+    assert!(generate_random_main_input(&mut test_rng()) == generate_random_main_input(&mut test_rng()));
+}
@@ -14,8 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_compiler_error, parse_program_with_input, EdwardsTestCompiler};
+use crate::{
+    assert_satisfied,
+    expect_compiler_error,
+    get_output,
+    parse_program,
+    parse_program_with_input,
+    EdwardsTestCompiler,
+};
+use leo_ast::{InputMismatch, InputTemplate};
 use leo_compiler::errors::CompilerError;
+use leo_synthesizer::CircuitSynthesizer;
+
+use snarkvm_curves::{bls12_377::Bls12_377, edwards_bls12::Fq};
 
 fn expect_fail(program: EdwardsTestCompiler) {
     match expect_compiler_error(program) {
@@ -123,3 +134,95 @@ fn test_group_input() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_integer_input_type_mismatch() {
+    let program_string = include_str!("main_integer.leo");
+    let input_string = include_str!("input/main_integer_fail_type.in");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    expect_fail(program);
+}
+
+#[test]
+fn test_input_public() {
+    let program_string = include_str!("main_public.leo");
+    let input_string = include_str!("input/main_public.in");
+
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+    program.compile_constraints(&mut cs).unwrap();
+
+    // `a` is the only public variable, `b` is allocated privately
+    let a = Fq::from(7u64);
+    let b = Fq::from(5u64);
+    let public_variables = cs.public_variables.iter().cloned().collect::<Vec<_>>();
+    assert_eq!(public_variables, vec![a]);
+    assert!(!public_variables.contains(&b));
+    assert!(cs.private_variables.iter().any(|variable| *variable == b));
+
+    let public_inputs = program.public_inputs();
+    assert_eq!(public_inputs.len(), 1);
+    assert_eq!(public_inputs[0].0.variable.name.to_string(), "a");
+    assert!(public_inputs.iter().all(|(parameter, _)| parameter.variable.name.as_ref() != "b"));
+}
+
+#[test]
+fn test_input_template() {
+    let program_string = include_str!("main_template.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let template = InputTemplate::new(program.ast()).generate().unwrap();
+
+    assert!(template.contains("[main]\na: u32 = 0;\n"));
+    assert!(template.contains("[constants]\nd: i8 = 0;\n"));
+    assert!(template.contains("[registers]\nr0: u32 = 0;\n"));
+
+    let program = parse_program_with_input(program_string, &template).unwrap();
+
+    get_output(program);
+}
+
+#[test]
+fn test_input_template_circuit() {
+    let program_string = include_str!("main_template_circuit.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let template = InputTemplate::new(program.ast()).generate().unwrap();
+
+    assert!(template.contains("// p: Point = Point { x: 0, y: false };\n"));
+}
+
+#[test]
+fn test_verify_input() {
+    let program_string = include_str!("main_multiple.leo");
+    let input_string = include_str!("input/main_multiple.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    assert_eq!(program.verify_input(), Some(vec![]));
+}
+
+#[test]
+fn test_verify_input_mismatch() {
+    let program_string = include_str!("main_multiple.leo");
+    let input_string = include_str!("input/main_multiple_mismatch.in");
+    let program = parse_program_with_input(program_string, input_string).unwrap();
+
+    let mismatches = program.verify_input().unwrap();
+
+    assert_eq!(mismatches.len(), 3);
+    assert!(mismatches.contains(&InputMismatch::Type {
+        name: "a".to_string(),
+        expected: "bool".to_string(),
+    }));
+    assert!(mismatches.contains(&InputMismatch::Missing {
+        name: "b".to_string(),
+        section: "main",
+    }));
+    assert!(mismatches.contains(&InputMismatch::Extra {
+        name: "c".to_string(),
+        section: "main",
+    }));
+}
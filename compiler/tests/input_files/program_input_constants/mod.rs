@@ -74,6 +74,16 @@ fn test_input_fail_type() {
     expect_fail(program);
 }
 
+#[test]
+fn test_input_public_fail() {
+    let program_string = include_str!("main.leo");
+    let input_string = include_str!("input/main_public_fail.in");
+
+    let result = parse_program_with_input(program_string, input_string);
+
+    assert!(matches!(result, Err(CompilerError::InputParserError(_))));
+}
+
 #[test]
 fn test_input_multiple() {
     let program_string = include_str!("main_multiple.leo");
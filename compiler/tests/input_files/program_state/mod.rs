@@ -23,6 +23,21 @@ fn test_basic() {
     parse_state(state_string).unwrap();
 }
 
+#[test]
+fn test_public_inputs() {
+    let state_string = include_str!("input/basic.state");
+
+    let program = parse_state(state_string).unwrap();
+    let public_inputs = program.public_inputs();
+
+    // `root` is declared under `[[public]]`, the `[[private]]` `id` and `leaf` are left out
+    assert_eq!(public_inputs.len(), 1);
+    let (parameter, value) = &public_inputs[0];
+    assert_eq!(parameter.variable.name.to_string(), "root");
+    assert_eq!(parameter.type_.to_string(), "bool");
+    assert_eq!(value.as_ref().unwrap().to_string(), "true");
+}
+
 #[test]
 fn test_token_withdraw() {
     let state_string = include_str!("input/token_withdraw.state");
@@ -0,0 +1,522 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_asg::{ConstInt, IntegerType, Span};
+use leo_compiler::{AllocatedConstants, DivWithConstants, Integer};
+use leo_synthesizer::{CircuitSynthesizer, CountingConstraintSystem, SerializedCircuit};
+
+use snarkvm_curves::{bls12_377::Bls12_377, edwards_bls12::Fq};
+use snarkvm_gadgets::traits::utilities::{alloc::AllocGadget, eq::EqGadget, int::Int8, uint::UInt8};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+fn allocate_u8(cs: &mut TestConstraintSystem<Fq>, value: u8) -> Integer {
+    Integer::allocate_type(cs, &IntegerType::U8, "x", Some(value.to_string()), &Span::default()).unwrap()
+}
+
+#[test]
+fn test_is_one_of_member() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 5);
+
+    let set = [ConstInt::U8(1), ConstInt::U8(5), ConstInt::U8(9)];
+    let result = x.is_one_of(&mut cs, &set, &Span::default()).unwrap();
+
+    assert_eq!(result.get_value(), Some(true));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_is_one_of_not_member() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 4);
+
+    let set = [ConstInt::U8(1), ConstInt::U8(5), ConstInt::U8(9)];
+    let result = x.is_one_of(&mut cs, &set, &Span::default()).unwrap();
+
+    assert_eq!(result.get_value(), Some(false));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_is_one_of_empty_set() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 5);
+    let constraints = cs.num_constraints();
+
+    let result = x.is_one_of(&mut cs, &[], &Span::default()).unwrap();
+
+    assert_eq!(result.get_value(), Some(false));
+    assert_eq!(cs.num_constraints(), constraints);
+}
+
+#[test]
+fn test_is_one_of_type_mismatch() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 5);
+
+    let set = [ConstInt::U16(5)];
+
+    assert!(x.is_one_of(&mut cs, &set, &Span::default()).is_err());
+}
+
+#[test]
+fn test_count_ones() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 0xFF);
+
+    let count = x.count_ones(&mut cs, &Span::default()).unwrap();
+
+    assert_eq!(count.get_type(), IntegerType::U32);
+    assert_eq!(count.get_value(), Some("8".to_string()));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_count_ones_zero() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 0);
+
+    let count = x.count_ones(&mut cs, &Span::default()).unwrap();
+
+    assert_eq!(count.get_value(), Some("0".to_string()));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_count_zeros() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 0b0000_0110);
+
+    let count = x.count_zeros(&mut cs, &Span::default()).unwrap();
+
+    assert_eq!(count.get_value(), Some("6".to_string()));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_count_ones_constant() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = Integer::new(&ConstInt::U8(0b1010_1010));
+
+    let count = x.count_ones(&mut cs, &Span::default()).unwrap();
+
+    assert_eq!(count.get_value(), Some("4".to_string()));
+    assert_eq!(cs.num_constraints(), 0);
+}
+
+fn allocate_u32(cs: &mut TestConstraintSystem<Fq>, value: u32) -> Integer {
+    Integer::allocate_type(cs, &IntegerType::U32, "x", Some(value.to_string()), &Span::default()).unwrap()
+}
+
+#[test]
+fn test_leading_zeros() {
+    for (value, expected) in [(0u8, 8), (1, 7), (0b0001_0000, 3), (0xFF, 0)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u8(&mut cs, *value);
+
+        let count = x.leading_zeros(&mut cs, &Span::default()).unwrap();
+
+        assert_eq!(count.get_value(), Some(expected.to_string()));
+        assert!(cs.is_satisfied());
+    }
+
+    for (value, expected) in [(0u32, 32), (1, 31), (1 << 20, 11), (u32::MAX, 0)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u32(&mut cs, *value);
+
+        let count = x.leading_zeros(&mut cs, &Span::default()).unwrap();
+
+        assert_eq!(count.get_value(), Some(expected.to_string()));
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_trailing_zeros() {
+    for (value, expected) in [(0u8, 8), (1, 0), (0b0001_0000, 4), (0x80, 7)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u8(&mut cs, *value);
+
+        let count = x.trailing_zeros(&mut cs, &Span::default()).unwrap();
+
+        assert_eq!(count.get_value(), Some(expected.to_string()));
+        assert!(cs.is_satisfied());
+    }
+
+    for (value, expected) in [(0u32, 32), (1, 0), (1 << 20, 20), (u32::MAX, 0)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u32(&mut cs, *value);
+
+        let count = x.trailing_zeros(&mut cs, &Span::default()).unwrap();
+
+        assert_eq!(count.get_value(), Some(expected.to_string()));
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_is_power_of_two() {
+    for (value, expected) in [(8u32, true), (6, false), (0, false), (1 << 31, true)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u32(&mut cs, *value);
+
+        let result = x.is_power_of_two(&mut cs, &Span::default()).unwrap();
+
+        assert_eq!(result.get_value(), Some(*expected));
+        assert!(cs.is_satisfied());
+    }
+}
+
+const ROTATIONS: [(u32, u32); 5] = [
+    (0x8000_0001, 1),
+    (0x1234_5678, 4),
+    (0xDEAD_BEEF, 31),
+    (0xDEAD_BEEF, 36),
+    (7, 0),
+];
+
+#[test]
+fn test_rotate_left() {
+    for (value, n) in ROTATIONS.iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u32(&mut cs, *value);
+        let constraints = cs.num_constraints();
+
+        let result = x.rotate_left(*n);
+
+        assert_eq!(result.get_value(), Some(value.rotate_left(*n).to_string()));
+        assert_eq!(cs.num_constraints(), constraints);
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_rotate_right() {
+    for (value, n) in ROTATIONS.iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u32(&mut cs, *value);
+        let constraints = cs.num_constraints();
+
+        let result = x.rotate_right(*n);
+
+        assert_eq!(result.get_value(), Some(value.rotate_right(*n).to_string()));
+        assert_eq!(cs.num_constraints(), constraints);
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_rotate_constant_u8() {
+    let x = Integer::new(&ConstInt::U8(0b1000_0011));
+
+    assert_eq!(x.rotate_left(2).get_value(), Some(0b0000_1110u8.to_string()));
+    assert_eq!(x.rotate_right(10).get_value(), Some(0b1110_0000u8.to_string()));
+}
+
+#[test]
+fn test_bytes_le_round_trip() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u32(&mut cs, 0x1234_5678);
+    let constraints = cs.num_constraints();
+
+    let bytes = x.to_bytes_le();
+    let values = bytes.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+    assert_eq!(values, 0x1234_5678u32.to_le_bytes());
+
+    let result = Integer::from_bytes_le(&IntegerType::U32, &bytes, &Span::default()).unwrap();
+
+    assert_eq!(result.get_value(), x.get_value());
+    assert_eq!(cs.num_constraints(), constraints);
+}
+
+#[test]
+fn test_bytes_be_round_trip() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u32(&mut cs, 0x1234_5678);
+    let constraints = cs.num_constraints();
+
+    let bytes = x.to_bytes_be();
+    let values = bytes.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+    assert_eq!(values, 0x1234_5678u32.to_be_bytes());
+
+    let result = Integer::from_bytes_be(&IntegerType::U32, &bytes, &Span::default()).unwrap();
+
+    assert_eq!(result.get_value(), x.get_value());
+    assert_eq!(cs.num_constraints(), constraints);
+}
+
+#[test]
+fn test_from_bytes_wrong_length() {
+    let bytes = Integer::new(&ConstInt::U16(0x1234)).to_bytes_le();
+
+    assert!(Integer::from_bytes_le(&IntegerType::U32, &bytes, &Span::default()).is_err());
+}
+
+#[test]
+fn test_add_counting_constraint_system() {
+    let mut cs = CountingConstraintSystem::<Fq>::default();
+
+    let a = Integer::allocate_type(&mut cs, &IntegerType::U32, "a", Some("1".to_string()), &Span::default()).unwrap();
+    let b = Integer::allocate_type(&mut cs, &IntegerType::U32, "b", Some("2".to_string()), &Span::default()).unwrap();
+    let allocated = cs.num_constraints();
+
+    a.add(&mut cs, b, &Span::default()).unwrap();
+
+    assert!(allocated > 0);
+    assert!(cs.num_constraints() > allocated);
+    assert!(cs.num_private_variables() > 0);
+}
+
+#[test]
+fn test_display_alternate_includes_type() {
+    let constant = Integer::new(&ConstInt::U8(255));
+    assert_eq!(format!("{}", constant), "255");
+    assert_eq!(format!("{:#}", constant), "255u8");
+
+    let negative = Integer::new(&ConstInt::I16(-5));
+    assert_eq!(format!("{:#}", negative), "-5i16");
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let allocated = allocate_u8(&mut cs, 7);
+    assert_eq!(format!("{:#}", allocated), "7u8");
+}
+
+fn allocate_i32(cs: &mut TestConstraintSystem<Fq>, name: &str, value: i32) -> Integer {
+    Integer::allocate_type(cs, &IntegerType::I32, name, Some(value.to_string()), &Span::default()).unwrap()
+}
+
+#[test]
+fn test_div_rem_euclid_match_native() {
+    let pairs = [
+        (7i32, 3i32),
+        (-7, 3),
+        (7, -3),
+        (-7, -3),
+        (6, -3),
+        (-6, 3),
+        (0, 5),
+        (-1, i32::MIN),
+        (i32::MIN, 3),
+        (i32::MIN, i32::MAX),
+        (i32::MAX, -2),
+    ];
+
+    for (a, b) in pairs.iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_i32(&mut cs, "a", *a);
+        let y = allocate_i32(&mut cs, "b", *b);
+
+        let quotient = x.div_euclid(&mut cs.ns(|| "div"), &y, &Span::default()).unwrap();
+        let remainder = x.rem_euclid(&mut cs.ns(|| "rem"), &y, &Span::default()).unwrap();
+
+        assert_eq!(quotient.get_value(), Some(a.div_euclid(*b).to_string()));
+        assert_eq!(remainder.get_value(), Some(a.rem_euclid(*b).to_string()));
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_div_rem_euclid_constant() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = Integer::constant_from(-7i8, &IntegerType::I8).unwrap();
+    let y = Integer::constant_from(3i8, &IntegerType::I8).unwrap();
+
+    let (quotient, remainder) = x.divmod_euclid(&mut cs, &y, &Span::default()).unwrap();
+
+    assert_eq!(quotient.get_value(), Some("-3".to_string()));
+    assert_eq!(remainder.get_value(), Some("2".to_string()));
+}
+
+#[test]
+fn test_div_rem_euclid_unsigned() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u32(&mut cs, 17);
+    let y = Integer::constant_from(5u32, &IntegerType::U32).unwrap();
+
+    let (quotient, remainder) = x.divmod_euclid(&mut cs, &y, &Span::default()).unwrap();
+
+    assert_eq!(quotient.get_value(), Some("3".to_string()));
+    assert_eq!(remainder.get_value(), Some("2".to_string()));
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_div_euclid_by_zero() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_i32(&mut cs, "a", -7);
+    let y = allocate_i32(&mut cs, "b", 0);
+
+    assert!(x.div_euclid(&mut cs, &y, &Span::default()).is_err());
+}
+
+fn count_division_constraints<T: DivWithConstants>(
+    cs: &mut TestConstraintSystem<Fq>,
+    constants: &AllocatedConstants,
+    x: &T,
+    y: &T,
+) -> usize {
+    let before = cs.num_constraints();
+    let quotient = x.div_with_constants(cs.ns(|| format!("divide {}", before)), y, constants);
+    assert!(quotient.is_ok());
+
+    cs.num_constraints() - before
+}
+
+#[test]
+fn test_consecutive_divisions_share_constants() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = UInt8::alloc(cs.ns(|| "x"), || Ok(100u8)).unwrap();
+    let y = UInt8::alloc(cs.ns(|| "y"), || Ok(7u8)).unwrap();
+
+    let constants = AllocatedConstants::default();
+    let first = count_division_constraints(&mut cs, &constants, &x, &y);
+    let second = count_division_constraints(&mut cs, &constants, &x, &y);
+    let unshared = count_division_constraints(&mut cs, &AllocatedConstants::default(), &x, &y);
+
+    // The allocated `true`, `1` and `0` each enforce one boolean constraint per bit.
+    assert_eq!(first - second, 1 + 8 + 8);
+    assert_eq!(unshared, first);
+    assert!(cs.is_satisfied());
+
+    let x = Int8::alloc(cs.ns(|| "signed x"), || Ok(-100i8)).unwrap();
+    let y = Int8::alloc(cs.ns(|| "signed y"), || Ok(7i8)).unwrap();
+
+    // The `true` bit is already allocated by the unsigned divisions.
+    let first = count_division_constraints(&mut cs, &constants, &x, &y);
+    let second = count_division_constraints(&mut cs, &constants, &x, &y);
+
+    assert_eq!(first - second, 8 + 8);
+    assert!(cs.is_satisfied());
+}
+
+fn division_r1cs(numerator: i32) -> String {
+    let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+    let allocate = |cs: &mut CircuitSynthesizer<Bls12_377>, name: &str, value: i32| {
+        Integer::allocate_type(cs, &IntegerType::I32, name, Some(value.to_string()), &Span::default()).unwrap()
+    };
+    let x = allocate(&mut cs, "a", numerator);
+    let y = allocate(&mut cs, "b", 7);
+    let expected = allocate(&mut cs, "c", numerator / 7);
+
+    let quotient = x.div(&mut cs, y, &Span::default()).unwrap();
+    quotient.enforce_equal(cs.ns(|| "quotient"), &expected).unwrap();
+
+    SerializedCircuit::from(cs).to_r1cs_string()
+}
+
+#[test]
+fn test_div_zero_numerator_is_enforced() {
+    // Whether the numerator is zero is only known to the prover, so it must not change the circuit.
+    assert_eq!(division_r1cs(0), division_r1cs(100));
+}
+
+#[test]
+fn test_clamp() {
+    for (value, expected) in [(3u8, 10u8), (10, 10), (15, 15), (20, 20), (200, 20)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_u8(&mut cs, *value);
+        let min = Integer::constant_from(10u8, &IntegerType::U8).unwrap();
+        let max = Integer::constant_from(20u8, &IntegerType::U8).unwrap();
+
+        let clamped = x.clamp(&mut cs, &min, &max, &Span::default()).unwrap();
+
+        assert_eq!(clamped.get_value(), Some(expected.to_string()));
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_clamp_signed_allocated_bounds() {
+    for (value, expected) in [(-100i32, -5i32), (0, 0), (100, 5)].iter() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let x = allocate_i32(&mut cs, "x", *value);
+        let min = allocate_i32(&mut cs, "min", -5);
+        let max = allocate_i32(&mut cs, "max", 5);
+
+        let clamped = x.clamp(&mut cs, &min, &max, &Span::default()).unwrap();
+
+        assert_eq!(clamped.get_value(), Some(expected.to_string()));
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_clamp_inverted_constant_bounds() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let x = allocate_u8(&mut cs, 5);
+    let min = Integer::constant_from(20u8, &IntegerType::U8).unwrap();
+    let max = Integer::constant_from(10u8, &IntegerType::U8).unwrap();
+
+    assert!(x.clamp(&mut cs, &min, &max, &Span::default()).is_err());
+}
+
+#[test]
+fn test_constant_from_each_type() {
+    let types = [
+        IntegerType::U8,
+        IntegerType::U16,
+        IntegerType::U32,
+        IntegerType::U64,
+        IntegerType::U128,
+        IntegerType::I8,
+        IntegerType::I16,
+        IntegerType::I32,
+        IntegerType::I64,
+        IntegerType::I128,
+    ];
+
+    for type_ in types.iter() {
+        let integer = Integer::constant_from(100u8, type_).unwrap();
+
+        assert_eq!(integer.get_type(), *type_);
+        assert_eq!(integer.get_value(), Some("100".to_string()));
+    }
+}
+
+#[test]
+fn test_constant_from_matches_new() {
+    assert_eq!(
+        Integer::constant_from(-5i64, &IntegerType::I16),
+        Some(Integer::new(&ConstInt::I16(-5)))
+    );
+    assert_eq!(
+        Integer::constant_from(u64::MAX, &IntegerType::U128),
+        Some(Integer::new(&ConstInt::U128(u64::MAX as u128)))
+    );
+}
+
+#[test]
+fn test_constant_from_out_of_range() {
+    assert!(Integer::constant_from(256u16, &IntegerType::U8).is_none());
+    assert!(Integer::constant_from(-1i8, &IntegerType::U32).is_none());
+    assert!(Integer::constant_from(u128::MAX, &IntegerType::I128).is_none());
+}
+
+#[test]
+fn test_identical_adds_namespaces() {
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let span = Span::default();
+    let a = Integer::allocate_type(&mut cs.ns(|| "a"), &IntegerType::U8, "x", Some("1".to_string()), &span).unwrap();
+    let b = Integer::allocate_type(&mut cs.ns(|| "b"), &IntegerType::U8, "x", Some("1".to_string()), &span).unwrap();
+
+    // Both additions display as `enforce 1 + 1` at the same span.
+    let first = a.clone().add(&mut cs, b.clone(), &span).unwrap();
+    let second = a.add(&mut cs, b, &span).unwrap();
+
+    assert_eq!(first.get_value(), Some("2".to_string()));
+    assert_eq!(second.get_value(), Some("2".to_string()));
+    assert!(cs.is_satisfied());
+}
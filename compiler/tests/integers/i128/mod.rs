@@ -82,12 +82,27 @@ fn test_i128_mul() {
     TestI128::test_mul();
 }
 
+#[test]
+fn test_i128_mul_min_negative_one_fail() {
+    TestI128::test_mul_min_negative_one_fail();
+}
+
+#[test]
+fn test_i128_mul_min_one() {
+    TestI128::test_mul_min_one();
+}
+
 #[test]
 #[ignore] // takes several minutes
 fn test_i128_div() {
     TestI128::test_div();
 }
 
+#[test]
+fn test_i128_div_zero_numerator() {
+    TestI128::test_div_zero_numerator();
+}
+
 #[test]
 fn test_i128_pow() {
     TestI128::test_pow();
@@ -133,6 +148,16 @@ fn test_i128_ternary() {
     TestI128::test_ternary();
 }
 
+#[test]
+fn test_i128_input_invalid() {
+    TestI128::test_input_invalid();
+}
+
+#[test]
+fn test_i128_input_missing() {
+    TestI128::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
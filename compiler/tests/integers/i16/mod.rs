@@ -82,11 +82,26 @@ fn test_i16_mul() {
     TestI16::test_mul();
 }
 
+#[test]
+fn test_i16_mul_min_negative_one_fail() {
+    TestI16::test_mul_min_negative_one_fail();
+}
+
+#[test]
+fn test_i16_mul_min_one() {
+    TestI16::test_mul_min_one();
+}
+
 #[test]
 fn test_i16_div() {
     TestI16::test_div();
 }
 
+#[test]
+fn test_i16_div_zero_numerator() {
+    TestI16::test_div_zero_numerator();
+}
+
 #[test]
 fn test_i16_pow() {
     TestI16::test_pow();
@@ -132,6 +147,16 @@ fn test_i16_ternary() {
     TestI16::test_ternary();
 }
 
+#[test]
+fn test_i16_input_invalid() {
+    TestI16::test_input_invalid();
+}
+
+#[test]
+fn test_i16_input_missing() {
+    TestI16::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
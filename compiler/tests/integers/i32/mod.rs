@@ -82,11 +82,26 @@ fn test_i32_mul() {
     TestI32::test_mul();
 }
 
+#[test]
+fn test_i32_mul_min_negative_one_fail() {
+    TestI32::test_mul_min_negative_one_fail();
+}
+
+#[test]
+fn test_i32_mul_min_one() {
+    TestI32::test_mul_min_one();
+}
+
 #[test]
 fn test_i32_div() {
     TestI32::test_div();
 }
 
+#[test]
+fn test_i32_div_zero_numerator() {
+    TestI32::test_div_zero_numerator();
+}
+
 #[test]
 fn test_i32_pow() {
     TestI32::test_pow();
@@ -132,6 +147,16 @@ fn test_i32_ternary() {
     TestI32::test_ternary();
 }
 
+#[test]
+fn test_i32_input_invalid() {
+    TestI32::test_input_invalid();
+}
+
+#[test]
+fn test_i32_input_missing() {
+    TestI32::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
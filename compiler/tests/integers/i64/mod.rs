@@ -82,12 +82,27 @@ fn test_i64_mul() {
     TestI64::test_mul();
 }
 
+#[test]
+fn test_i64_mul_min_negative_one_fail() {
+    TestI64::test_mul_min_negative_one_fail();
+}
+
+#[test]
+fn test_i64_mul_min_one() {
+    TestI64::test_mul_min_one();
+}
+
 #[test]
 #[ignore] // takes 2 minutes
 fn test_i64_div() {
     TestI64::test_div();
 }
 
+#[test]
+fn test_i64_div_zero_numerator() {
+    TestI64::test_div_zero_numerator();
+}
+
 #[test]
 fn test_i64_pow() {
     TestI64::test_pow();
@@ -133,6 +148,16 @@ fn test_i64_ternary() {
     TestI64::test_ternary();
 }
 
+#[test]
+fn test_i64_input_invalid() {
+    TestI64::test_input_invalid();
+}
+
+#[test]
+fn test_i64_input_missing() {
+    TestI64::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
@@ -21,10 +21,16 @@ use crate::{
     generate_main_input,
     integers::{expect_computation_error, IntegerTester},
     parse_program,
+    parse_program_with_options,
+    EdwardsTestCompiler,
 };
 use leo_ast::InputValue;
+use leo_compiler::{CompilerOptions, OverflowBehavior};
 use leo_input::types::{I8Type, IntegerType, SignedIntegerType};
 
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
+
 test_int!(
     TestI8,
     i8,
@@ -82,11 +88,26 @@ fn test_i8_mul() {
     TestI8::test_mul();
 }
 
+#[test]
+fn test_i8_mul_min_negative_one_fail() {
+    TestI8::test_mul_min_negative_one_fail();
+}
+
+#[test]
+fn test_i8_mul_min_one() {
+    TestI8::test_mul_min_one();
+}
+
 #[test]
 fn test_i8_div() {
     TestI8::test_div();
 }
 
+#[test]
+fn test_i8_div_zero_numerator() {
+    TestI8::test_div_zero_numerator();
+}
+
 #[test]
 fn test_i8_pow() {
     TestI8::test_pow();
@@ -132,6 +153,16 @@ fn test_i8_ternary() {
     TestI8::test_ternary();
 }
 
+#[test]
+fn test_i8_input_invalid() {
+    TestI8::test_input_invalid();
+}
+
+#[test]
+fn test_i8_input_missing() {
+    TestI8::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
@@ -139,3 +170,81 @@ fn test_no_space_between_literal() {
 
     assert!(program.is_err());
 }
+
+#[test]
+fn test_i8_bounds() {
+    let program_string = include_str!("bounds.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+fn overflow_program(program_string: &str, inputs: Vec<(&str, &str)>, options: CompilerOptions) -> EdwardsTestCompiler {
+    let mut program = parse_program_with_options(program_string, options).unwrap();
+
+    let type_ = IntegerType::Signed(SignedIntegerType::I8Type(I8Type {}));
+    let main_input = generate_main_input(
+        inputs
+            .into_iter()
+            .map(|(name, value)| (name, Some(InputValue::Integer(type_.clone(), value.to_string()))))
+            .collect(),
+    );
+    program.set_main_input(main_input);
+
+    program
+}
+
+#[test]
+fn test_i8_overflow_checked() {
+    let release = CompilerOptions {
+        release: true,
+        ..Default::default()
+    };
+
+    for options in [CompilerOptions::default(), release] {
+        let mul_inputs = vec![("a", "100"), ("b", "3")];
+        let mul = overflow_program(include_str!("mul_overflow_wrapping.leo"), mul_inputs, options.clone());
+        let negate = overflow_program(include_str!("negate_min_wrapping.leo"), vec![("a", "-128")], options);
+
+        for program in [mul, negate] {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let result = program.compile_constraints(&mut cs);
+
+            assert!(result.is_err() || !cs.is_satisfied());
+        }
+    }
+}
+
+#[test]
+fn test_i8_mul_overflow_wrapping_release() {
+    let options = CompilerOptions {
+        release: true,
+        release_overflow: OverflowBehavior::Wrapping,
+        ..Default::default()
+    };
+    let program_string = include_str!("mul_overflow_wrapping.leo");
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    overflow_program(program_string, vec![("a", "100"), ("b", "3")], options)
+        .compile_constraints(&mut cs)
+        .unwrap();
+
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_i8_negate_min_wrapping_release() {
+    let options = CompilerOptions {
+        release: true,
+        release_overflow: OverflowBehavior::Wrapping,
+        ..Default::default()
+    };
+    let program_string = include_str!("negate_min_wrapping.leo");
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    overflow_program(program_string, vec![("a", "-128")], options)
+        .compile_constraints(&mut cs)
+        .unwrap();
+
+    assert!(cs.is_satisfied());
+}
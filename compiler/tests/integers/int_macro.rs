@@ -16,12 +16,16 @@
 
 macro_rules! test_int {
     ($name: ident, $type_: ty, $integer_type: expr, $gadget: ty) => {
+        use rand::Rng;
+
         pub struct $name {}
 
         impl $name {
             fn test_negate() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
 
                     let b = match a.checked_neg() {
                         Some(valid) => valid,
@@ -54,6 +58,59 @@ macro_rules! test_int {
 
                 assert_satisfied(program);
             }
+
+            fn test_mul_min_negative_one_fail() {
+                let min = <$type_>::MIN.to_string();
+
+                let program_string = include_str!("mul_overflow.leo");
+                let mut program = parse_program(program_string).unwrap();
+                let main_input = generate_main_input(vec![
+                    ("a", Some(InputValue::Integer($integer_type, min))),
+                    ("b", Some(InputValue::Integer($integer_type, "-1".to_string()))),
+                ]);
+
+                program.set_main_input(main_input);
+
+                expect_computation_error(program);
+            }
+
+            fn test_mul_min_one() {
+                let min = <$type_>::MIN.to_string();
+
+                let program_string = include_str!("mul.leo");
+                let mut program = parse_program(program_string).unwrap();
+                let main_input = generate_main_input(vec![
+                    ("a", Some(InputValue::Integer($integer_type, min.clone()))),
+                    ("b", Some(InputValue::Integer($integer_type, "1".to_string()))),
+                    ("c", Some(InputValue::Integer($integer_type, min))),
+                ]);
+
+                program.set_main_input(main_input);
+
+                assert_satisfied(program);
+            }
+
+            fn test_div_zero_numerator() {
+                let mut rng = crate::test_rng();
+
+                let mut b: $type_ = rng.gen();
+                while b == 0 {
+                    b = rng.gen();
+                }
+
+                // The numerator is an allocated input, so its zero value is only known at synthesis.
+                let program_string = include_str!("div.leo");
+                let mut program = parse_program(program_string).unwrap();
+                let main_input = generate_main_input(vec![
+                    ("a", Some(InputValue::Integer($integer_type, "0".to_string()))),
+                    ("b", Some(InputValue::Integer($integer_type, b.to_string()))),
+                    ("c", Some(InputValue::Integer($integer_type, "0".to_string()))),
+                ]);
+
+                program.set_main_input(main_input);
+
+                assert_satisfied(program);
+            }
         }
 
         impl IntegerTester for $name {
@@ -86,9 +143,11 @@ macro_rules! test_int {
             }
 
             fn test_add() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     let c = match a.checked_add(b) {
                         Some(valid) => valid,
@@ -111,9 +170,11 @@ macro_rules! test_int {
             }
 
             fn test_sub() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     if b.checked_neg().is_none() {
                         continue;
@@ -140,9 +201,11 @@ macro_rules! test_int {
             }
 
             fn test_mul() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     let c = match a.checked_mul(b) {
                         Some(valid) => valid,
@@ -165,9 +228,11 @@ macro_rules! test_int {
             }
 
             fn test_div() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // make sure that we can calculate the inverse of each number
                     // Leo signed integer division is non-wrapping. Thus attempting to calculate a
@@ -210,9 +275,11 @@ macro_rules! test_int {
             }
 
             fn test_pow() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // rust specific conversion see https://doc.rust-lang.org/std/primitive.u8.html#method.checked_pow
                     let c = match a.checked_pow(b as u32) {
@@ -236,9 +303,11 @@ macro_rules! test_int {
             }
 
             fn test_eq() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -274,9 +343,11 @@ macro_rules! test_int {
             }
 
             fn test_ne() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test a != a == false
 
@@ -312,9 +383,11 @@ macro_rules! test_int {
             }
 
             fn test_ge() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -350,9 +423,11 @@ macro_rules! test_int {
             }
 
             fn test_gt() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -388,9 +463,11 @@ macro_rules! test_int {
             }
 
             fn test_le() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -426,9 +503,11 @@ macro_rules! test_int {
             }
 
             fn test_lt() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -464,8 +543,10 @@ macro_rules! test_int {
             }
 
             fn test_console_assert() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
 
                     // test equal
                     let program_string = include_str!("console_assert.leo");
@@ -481,7 +562,7 @@ macro_rules! test_int {
                     assert_satisfied(program);
 
                     // test not equal
-                    let b: $type_ = rand::random();
+                    let b: $type_ = rng.gen();
 
                     if a == b {
                         continue;
@@ -501,8 +582,10 @@ macro_rules! test_int {
             }
 
             fn test_ternary() {
-                let a: $type_ = rand::random();
-                let b: $type_ = rand::random();
+                let mut rng = crate::test_rng();
+
+                let a: $type_ = rng.gen();
+                let b: $type_ = rng.gen();
 
                 let program_string = include_str!("ternary.leo");
                 let mut program = parse_program(program_string).unwrap();
@@ -533,6 +616,50 @@ macro_rules! test_int {
 
                 assert_satisfied(program);
             }
+
+            fn test_input_invalid() {
+                let program_string = include_str!("input.leo");
+
+                let invalid = vec![
+                    format!("{}0", <$type_>::MAX),
+                    format!("{}0", <$type_>::MIN),
+                    "abc".to_string(),
+                    "1.5".to_string(),
+                ];
+
+                for value in invalid {
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, value.clone()))),
+                        ("b", Some(InputValue::Integer($integer_type, "1".to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    let error = expect_compiler_error(program).to_string();
+                    assert!(error.contains(&format!("failed to parse `{}`", value)));
+                    assert!(error.contains(&format!("`a: {}`", stringify!($type_))));
+                }
+            }
+
+            fn test_input_missing() {
+                let program_string = include_str!("input.leo");
+                let mut program = parse_program(program_string).unwrap();
+
+                let main_input = generate_main_input(vec![
+                    ("a", None),
+                    ("b", Some(InputValue::Integer($integer_type, "1".to_string()))),
+                ]);
+
+                program.set_main_input(main_input);
+
+                let error = expect_compiler_error(program).to_string();
+                assert!(error.contains(&format!(
+                    "expected integer input `a: {}` not found",
+                    stringify!($type_)
+                )));
+            }
         }
     };
 }
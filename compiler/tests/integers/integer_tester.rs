@@ -14,8 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{expect_compiler_error, EdwardsTestCompiler};
+use crate::{assert_satisfied, expect_compiler_error, generate_main_input, parse_program, EdwardsTestCompiler};
+use leo_ast::InputValue;
 use leo_compiler::errors::{CompilerError, ExpressionError, FunctionError, IntegerError, StatementError};
+use leo_input::types::IntegerType;
 
 pub trait IntegerTester {
     /// Tests defining the smalled value that can be represented by the integer type
@@ -68,6 +70,12 @@ pub trait IntegerTester {
 
     /// Test ternary if bool ? num_1 : num_2;
     fn test_ternary();
+
+    /// Tests that malformed and out of range input values fail with an error
+    fn test_input_invalid();
+
+    /// Tests that a missing input value fails with an error naming the parameter
+    fn test_input_missing();
 }
 
 pub(crate) fn expect_computation_error(program: EdwardsTestCompiler) {
@@ -81,3 +89,102 @@ pub(crate) fn expect_computation_error(program: EdwardsTestCompiler) {
         ),
     }
 }
+
+/// A binary integer operation compared against native Rust arithmetic.
+#[derive(Clone, Copy, Debug)]
+pub enum IntegerOperation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl IntegerOperation {
+    fn symbol(self) -> &'static str {
+        match self {
+            IntegerOperation::Add => "+",
+            IntegerOperation::Sub => "-",
+            IntegerOperation::Mul => "*",
+            IntegerOperation::Div => "/",
+        }
+    }
+}
+
+/// Asserts that Leo evaluates `a <operation> b` to `expected`.
+/// An `expected` value of `None` means the native operation overflowed or divided by zero,
+/// in which case Leo must fail as well.
+pub(crate) fn check_integer_operation(
+    operation: IntegerOperation,
+    integer_type: IntegerType,
+    a: String,
+    b: String,
+    expected: Option<String>,
+) {
+    let program_string = format!(
+        "function main(a: {type_}, b: {type_}, c: {type_}) {{\n    console.assert(a {op} b == c);\n}}\n",
+        type_ = integer_type,
+        op = operation.symbol(),
+    );
+    let mut program = parse_program(&program_string).unwrap();
+
+    let c = expected.clone().unwrap_or_else(|| b.clone());
+    let main_input = generate_main_input(vec![
+        ("a", Some(InputValue::Integer(integer_type.clone(), a))),
+        ("b", Some(InputValue::Integer(integer_type.clone(), b))),
+        ("c", Some(InputValue::Integer(integer_type, c))),
+    ]);
+
+    program.set_main_input(main_input);
+
+    match expected {
+        Some(_) => assert_satisfied(program),
+        None => {
+            expect_compiler_error(program);
+        }
+    }
+}
+
+/// Generates a proptest comparing add, sub, mul and div against native arithmetic.
+/// Operands are biased towards `MIN`, `MAX` and zero, which fixed random loops rarely hit.
+macro_rules! test_integer_operations_property {
+    ($type_: ty, $integer_type: expr) => {
+        proptest::proptest! {
+            #![proptest_config(proptest::test_runner::Config::with_cases(32))]
+
+            #[test]
+            fn test_operations_match_native(
+                a in proptest::prop_oneof![
+                    1 => proptest::strategy::Just(<$type_>::MIN),
+                    1 => proptest::strategy::Just(<$type_>::MAX),
+                    1 => proptest::strategy::Just(0 as $type_),
+                    5 => proptest::arbitrary::any::<$type_>(),
+                ],
+                b in proptest::prop_oneof![
+                    1 => proptest::strategy::Just(<$type_>::MIN),
+                    1 => proptest::strategy::Just(<$type_>::MAX),
+                    1 => proptest::strategy::Just(0 as $type_),
+                    5 => proptest::arbitrary::any::<$type_>(),
+                ],
+            ) {
+                use crate::integers::{check_integer_operation, IntegerOperation};
+
+                let operations = [
+                    (IntegerOperation::Add, a.checked_add(b)),
+                    (IntegerOperation::Sub, a.checked_sub(b)),
+                    (IntegerOperation::Mul, a.checked_mul(b)),
+                    (IntegerOperation::Div, a.checked_div(b)),
+                ];
+
+                for (operation, expected) in operations.iter() {
+                    check_integer_operation(
+                        *operation,
+                        $integer_type,
+                        a.to_string(),
+                        b.to_string(),
+                        expected.map(|c| c.to_string()),
+                    );
+                }
+            }
+        }
+    };
+}
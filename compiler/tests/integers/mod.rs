@@ -20,9 +20,12 @@ pub mod int_macro;
 #[macro_use]
 pub mod uint_macro;
 
+#[macro_use]
 pub mod integer_tester;
 pub use self::integer_tester::*;
 
+pub mod gadgets;
+
 // must be below macro definitions!
 pub mod u128;
 pub mod u16;
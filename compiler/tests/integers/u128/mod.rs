@@ -117,6 +117,16 @@ fn test_u128_ternary() {
     TestU128::test_ternary();
 }
 
+#[test]
+fn test_u128_input_invalid() {
+    TestU128::test_input_invalid();
+}
+
+#[test]
+fn test_u128_input_missing() {
+    TestU128::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
@@ -117,6 +117,16 @@ fn test_u16_ternary() {
     TestU16::test_ternary();
 }
 
+#[test]
+fn test_u16_input_invalid() {
+    TestU16::test_input_invalid();
+}
+
+#[test]
+fn test_u16_input_missing() {
+    TestU16::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
@@ -24,6 +24,10 @@ use crate::{
 };
 use leo_ast::InputValue;
 use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+use leo_synthesizer::CountingConstraintSystem;
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::ConstraintSystem;
 
 test_uint!(
     TestU32,
@@ -117,6 +121,16 @@ fn test_u32_ternary() {
     TestU32::test_ternary();
 }
 
+#[test]
+fn test_u32_input_invalid() {
+    TestU32::test_input_invalid();
+}
+
+#[test]
+fn test_u32_input_missing() {
+    TestU32::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
@@ -124,3 +138,28 @@ fn test_no_space_between_literal() {
 
     assert!(program.is_err());
 }
+
+fn count_square_constraints(program_string: &str) -> usize {
+    let mut program = parse_program(program_string).unwrap();
+    let main_input = generate_main_input(vec![(
+        "a",
+        Some(InputValue::Integer(
+            IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {})),
+            "7".to_string(),
+        )),
+    )]);
+    program.set_main_input(main_input);
+
+    let mut cs = CountingConstraintSystem::<Fq>::default();
+    program.compile_constraints(&mut cs).unwrap();
+
+    cs.num_constraints()
+}
+
+#[test]
+fn test_u32_square_pow_constraints() {
+    let pow = count_square_constraints(include_str!("square_pow.leo"));
+    let mul = count_square_constraints(include_str!("square_mul.leo"));
+
+    assert_eq!(pow, mul);
+}
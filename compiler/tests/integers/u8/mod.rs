@@ -21,10 +21,16 @@ use crate::{
     generate_main_input,
     integers::IntegerTester,
     parse_program,
+    parse_program_with_options,
+    EdwardsTestCompiler,
 };
 use leo_ast::InputValue;
+use leo_compiler::{CompilerOptions, OverflowBehavior};
 use leo_input::types::{IntegerType, U8Type, UnsignedIntegerType};
 
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
+
 test_uint!(
     TestU8,
     u8,
@@ -32,6 +38,8 @@ test_uint!(
     UInt8
 );
 
+test_integer_operations_property!(u8, IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {})));
+
 #[test]
 fn test_u8_min() {
     TestU8::test_min();
@@ -117,6 +125,16 @@ fn test_u8_ternary() {
     TestU8::test_ternary();
 }
 
+#[test]
+fn test_u8_input_invalid() {
+    TestU8::test_input_invalid();
+}
+
+#[test]
+fn test_u8_input_missing() {
+    TestU8::test_input_missing();
+}
+
 #[test]
 fn test_no_space_between_literal() {
     let program_string = include_str!("no_space_between_literal.leo");
@@ -124,3 +142,93 @@ fn test_no_space_between_literal() {
 
     assert!(program.is_err());
 }
+
+#[test]
+fn test_u8_bounds() {
+    let program_string = include_str!("bounds.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_u8_pow_constant() {
+    let program_string = include_str!("pow_constant.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_u8_pow_constant_overflow() {
+    let program_string = include_str!("pow_constant_overflow.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let error = expect_compiler_error(program).to_string();
+    assert!(error.contains("constant operation `2 ** 9` overflows its integer type"));
+}
+
+fn overflow_program(program_string: &str, options: CompilerOptions) -> EdwardsTestCompiler {
+    let mut program = parse_program_with_options(program_string, options).unwrap();
+
+    let type_ = IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {}));
+    let main_input = generate_main_input(vec![
+        ("a", Some(InputValue::Integer(type_.clone(), "200".to_string()))),
+        ("b", Some(InputValue::Integer(type_, "100".to_string()))),
+    ]);
+    program.set_main_input(main_input);
+
+    program
+}
+
+#[test]
+fn test_u8_overflow_checked() {
+    let wrapping_debug = CompilerOptions {
+        release_overflow: OverflowBehavior::Wrapping,
+        ..Default::default()
+    };
+    let release = CompilerOptions {
+        release: true,
+        ..Default::default()
+    };
+
+    // Debug builds always check, and release builds check unless wrapping is selected.
+    for options in [CompilerOptions::default(), wrapping_debug, release] {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let result = overflow_program(include_str!("overflow.leo"), options).compile_constraints(&mut cs);
+
+        assert!(result.is_err() || !cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_u8_overflow_wrapping_release() {
+    let options = CompilerOptions {
+        release: true,
+        release_overflow: OverflowBehavior::Wrapping,
+        ..Default::default()
+    };
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    overflow_program(include_str!("overflow.leo"), options)
+        .compile_constraints(&mut cs)
+        .unwrap();
+
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_u8_mul_overflow_wrapping_release() {
+    let options = CompilerOptions {
+        release: true,
+        release_overflow: OverflowBehavior::Wrapping,
+        ..Default::default()
+    };
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    overflow_program(include_str!("mul_overflow_wrapping.leo"), options)
+        .compile_constraints(&mut cs)
+        .unwrap();
+
+    assert!(cs.is_satisfied());
+}
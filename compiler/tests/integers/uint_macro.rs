@@ -16,6 +16,8 @@
 
 macro_rules! test_uint {
     ($name: ident, $type_: ty, $integer_type: expr, $gadget: ty) => {
+        use rand::Rng;
+
         pub struct $name {}
 
         impl IntegerTester for $name {
@@ -48,9 +50,11 @@ macro_rules! test_uint {
             }
 
             fn test_add() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     let c = match a.checked_add(b) {
                         Some(valid) => valid,
@@ -73,9 +77,11 @@ macro_rules! test_uint {
             }
 
             fn test_sub() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     let c = match a.checked_sub(b) {
                         Some(valid) => valid,
@@ -98,9 +104,11 @@ macro_rules! test_uint {
             }
 
             fn test_mul() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     let c = match a.checked_mul(b) {
                         Some(valid) => valid,
@@ -123,9 +131,11 @@ macro_rules! test_uint {
             }
 
             fn test_div() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     let c = match a.checked_div(b) {
                         Some(valid) => valid,
@@ -148,9 +158,11 @@ macro_rules! test_uint {
             }
 
             fn test_pow() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // rust specific conversion see https://doc.rust-lang.org/std/primitive.u8.html#method.checked_pow
                     let c = match a.checked_pow(b as u32) {
@@ -174,9 +186,11 @@ macro_rules! test_uint {
             }
 
             fn test_eq() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -212,9 +226,11 @@ macro_rules! test_uint {
             }
 
             fn test_ne() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test a != a == false
 
@@ -250,9 +266,11 @@ macro_rules! test_uint {
             }
 
             fn test_ge() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -288,9 +306,11 @@ macro_rules! test_uint {
             }
 
             fn test_gt() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -326,9 +346,11 @@ macro_rules! test_uint {
             }
 
             fn test_le() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -364,9 +386,11 @@ macro_rules! test_uint {
             }
 
             fn test_lt() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
-                    let b: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
+                    let b: $type_ = rng.gen();
 
                     // test equal
 
@@ -402,8 +426,10 @@ macro_rules! test_uint {
             }
 
             fn test_console_assert() {
+                let mut rng = crate::test_rng();
+
                 for _ in 0..10 {
-                    let a: $type_ = rand::random();
+                    let a: $type_ = rng.gen();
 
                     // test equal
                     let program_string = include_str!("console_assert.leo");
@@ -419,7 +445,7 @@ macro_rules! test_uint {
                     assert_satisfied(program);
 
                     // test not equal
-                    let b: $type_ = rand::random();
+                    let b: $type_ = rng.gen();
 
                     if a == b {
                         continue;
@@ -439,8 +465,10 @@ macro_rules! test_uint {
             }
 
             fn test_ternary() {
-                let a: $type_ = rand::random();
-                let b: $type_ = rand::random();
+                let mut rng = crate::test_rng();
+
+                let a: $type_ = rng.gen();
+                let b: $type_ = rng.gen();
 
                 let program_string = include_str!("ternary.leo");
                 let mut program = parse_program(program_string).unwrap();
@@ -471,6 +499,50 @@ macro_rules! test_uint {
 
                 assert_satisfied(program);
             }
+
+            fn test_input_invalid() {
+                let program_string = include_str!("input.leo");
+
+                let invalid = vec![
+                    format!("{}0", <$type_>::MAX),
+                    "-1".to_string(),
+                    "abc".to_string(),
+                    "1.5".to_string(),
+                ];
+
+                for value in invalid {
+                    let mut program = parse_program(program_string).unwrap();
+
+                    let main_input = generate_main_input(vec![
+                        ("a", Some(InputValue::Integer($integer_type, value.clone()))),
+                        ("b", Some(InputValue::Integer($integer_type, "1".to_string()))),
+                    ]);
+
+                    program.set_main_input(main_input);
+
+                    let error = expect_compiler_error(program).to_string();
+                    assert!(error.contains(&format!("failed to parse `{}`", value)));
+                    assert!(error.contains(&format!("`a: {}`", stringify!($type_))));
+                }
+            }
+
+            fn test_input_missing() {
+                let program_string = include_str!("input.leo");
+                let mut program = parse_program(program_string).unwrap();
+
+                let main_input = generate_main_input(vec![
+                    ("a", None),
+                    ("b", Some(InputValue::Integer($integer_type, "1".to_string()))),
+                ]);
+
+                program.set_main_input(main_input);
+
+                let error = expect_compiler_error(program).to_string();
+                assert!(error.contains(&format!(
+                    "expected integer input `a: {}` not found",
+                    stringify!($type_)
+                )));
+            }
         }
     };
 }
@@ -20,6 +20,7 @@
 
 pub mod address;
 pub mod array;
+pub mod array_gadgets;
 pub mod boolean;
 pub mod canonicalization;
 pub mod circuits;
@@ -28,8 +29,10 @@ pub mod console;
 pub mod core;
 pub mod definition;
 // pub mod field;
+pub mod field_gadgets;
 pub mod function;
 // pub mod group;
+pub mod group_gadgets;
 pub mod import;
 pub mod input_files;
 pub mod integers;
@@ -39,11 +42,12 @@ pub mod syntax;
 pub mod tuples;
 
 use leo_asg::{new_alloc_context, new_context, AsgContext};
-use leo_ast::{InputValue, MainInput};
+use leo_ast::{Ast, InputValue, MainInput, SpanSerialization};
 use leo_compiler::{
     compiler::Compiler,
     errors::CompilerError,
     group::targets::edwards_bls12::EdwardsGroupType,
+    CompilerOptions,
     ConstrainedValue,
     OutputBytes,
 };
@@ -52,11 +56,22 @@ use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
 use snarkvm_curves::edwards_bls12::Fq;
 use snarkvm_r1cs::TestConstraintSystem;
 
-use std::path::PathBuf;
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+use std::{env, fs, path::PathBuf};
 
 pub const TEST_OUTPUT_DIRECTORY: &str = "/output/";
 const EMPTY_FILE: &str = "";
 
+/// Environment variable that pins the seed of randomized tests, e.g. `LEO_TEST_SEED=42 cargo test`.
+pub const TEST_SEED_VARIABLE: &str = "LEO_TEST_SEED";
+const DEFAULT_TEST_SEED: u64 = 1231275789;
+
+/// Environment variable that rewrites snapshots instead of comparing them, e.g. `LEO_UPDATE_SNAPSHOTS=1 cargo test`.
+pub const UPDATE_SNAPSHOTS_VARIABLE: &str = "LEO_UPDATE_SNAPSHOTS";
+
 pub type EdwardsTestCompiler = Compiler<'static, Fq, EdwardsGroupType>;
 pub type EdwardsConstrainedValue = ConstrainedValue<'static, Fq, EdwardsGroupType>;
 
@@ -82,6 +97,18 @@ pub(crate) fn parse_program(program_string: &str) -> Result<EdwardsTestCompiler,
     Ok(compiler)
 }
 
+pub(crate) fn parse_program_with_options(
+    program_string: &str,
+    options: CompilerOptions,
+) -> Result<EdwardsTestCompiler, CompilerError> {
+    let mut compiler = new_compiler();
+
+    compiler.set_options(options);
+    compiler.parse_program_from_string(program_string)?;
+
+    Ok(compiler)
+}
+
 pub(crate) fn parse_input(input_string: &str) -> Result<EdwardsTestCompiler, CompilerError> {
     let mut compiler = new_compiler();
     let path = PathBuf::new();
@@ -138,6 +165,21 @@ pub fn parse_program_with_state(
     Ok(compiler)
 }
 
+pub(crate) fn parse_program_with_input_and_options(
+    program_string: &str,
+    input_string: &str,
+    options: CompilerOptions,
+) -> Result<EdwardsTestCompiler, CompilerError> {
+    let mut compiler = new_compiler();
+    let path = PathBuf::new();
+
+    compiler.set_options(options);
+    compiler.parse_input(input_string, &path, EMPTY_FILE, &path)?;
+    compiler.parse_program_from_string(program_string)?;
+
+    Ok(compiler)
+}
+
 pub fn parse_program_with_input_and_state(
     program_string: &str,
     input_string: &str,
@@ -197,3 +239,57 @@ pub(crate) fn generate_test_input_u32(number: u32) -> Option<InputValue> {
         number.to_string(),
     ))
 }
+
+pub(crate) fn generate_random_input_u32<R: Rng>(rng: &mut R) -> Option<InputValue> {
+    generate_test_input_u32(rng.gen())
+}
+
+/// Returns the seed of randomized tests, read from `LEO_TEST_SEED` when it is set.
+pub(crate) fn test_seed() -> u64 {
+    match env::var(TEST_SEED_VARIABLE) {
+        Ok(seed) => seed
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a u64, found `{}`", TEST_SEED_VARIABLE, seed)),
+        Err(_) => DEFAULT_TEST_SEED,
+    }
+}
+
+/// Returns a seeded rng for randomized tests.
+pub(crate) fn test_rng() -> XorShiftRng {
+    XorShiftRng::seed_from_u64(test_seed())
+}
+
+/// Compares `actual` to the snapshot at `snapshot`, relative to the `tests` directory.
+/// The snapshot is written instead when `LEO_UPDATE_SNAPSHOTS` is set.
+pub(crate) fn assert_snapshot(snapshot: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join(snapshot);
+
+    if env::var_os(UPDATE_SNAPSHOTS_VARIABLE).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {:?}, run with {}=1 to create it",
+            path, UPDATE_SNAPSHOTS_VARIABLE
+        )
+    });
+
+    assert_eq!(
+        expected, actual,
+        "snapshot {:?} changed, run with {}=1 to accept the new output",
+        path, UPDATE_SNAPSHOTS_VARIABLE
+    );
+}
+
+/// Compares the canonicalized AST of `program` to the snapshot at `snapshot`.
+/// Spans are omitted so that only changes to the structure of the program are caught.
+pub(crate) fn assert_ast_snapshot(snapshot: &str, program: &EdwardsTestCompiler) {
+    let json = Ast::new(program.ast().clone())
+        .to_json_string_with_spans(SpanSerialization::Omitted)
+        .unwrap();
+
+    assert_snapshot(snapshot, &json);
+}
@@ -14,8 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_asg_error, generate_main_input, parse_program};
+use crate::{
+    assert_satisfied,
+    expect_asg_error,
+    expect_compiler_error,
+    generate_main_input,
+    generate_test_input_u32,
+    parse_program,
+};
 use leo_ast::InputValue;
+use leo_input::types::{I8Type, IntegerType, SignedIntegerType, U8Type, UnsignedIntegerType};
+use leo_synthesizer::CountingConstraintSystem;
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
 
 #[test]
 fn test_let() {
@@ -73,6 +85,152 @@ fn test_array_mut() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_array_index_const_mut() {
+    let program_string = include_str!("array_index_const_mut.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_index_runtime_mut() {
+    let program_string = include_str!("array_index_runtime_mut.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("i", generate_test_input_u32(2))]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_index_runtime_narrow_mut() {
+    let program_string = include_str!("array_index_runtime_narrow_mut.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![
+        (
+            "i",
+            Some(InputValue::Integer(
+                IntegerType::Signed(SignedIntegerType::I8Type(I8Type {})),
+                "5".to_string(),
+            )),
+        ),
+        (
+            "j",
+            Some(InputValue::Integer(
+                IntegerType::Unsigned(UnsignedIntegerType::U8Type(U8Type {})),
+                "5".to_string(),
+            )),
+        ),
+    ]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_index_runtime_negative_fail() {
+    let program_string = include_str!("array_index_runtime_negative_fail.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![(
+        "i",
+        Some(InputValue::Integer(
+            IntegerType::Signed(SignedIntegerType::I8Type(I8Type {})),
+            "-1".to_string(),
+        )),
+    )]);
+
+    program.set_main_input(main_input);
+
+    // A negative index must not satisfy the circuit by silently writing nothing.
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.compile_constraints(&mut cs).unwrap();
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_array_index_runtime_operation_mut() {
+    let program_string = include_str!("array_index_runtime_operation_mut.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("i", generate_test_input_u32(1))]);
+
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_index_runtime_operation_setup() {
+    let program_string = include_str!("array_index_runtime_operation_setup.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("i", None)]);
+
+    program.set_main_input(main_input);
+
+    // Without an index value, the unselected `0u8 - 1u8` must not be evaluated.
+    let mut cs = CountingConstraintSystem::<Fq>::default();
+    program.compile_constraints(&mut cs).unwrap();
+}
+
+#[test]
+fn test_array_index_out_of_bounds() {
+    let program_string = include_str!("array_index_out_of_bounds.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_array_range_mut() {
+    let program_string = include_str!("array_range_mut.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_range_length_fail() {
+    let program_string = include_str!("array_range_length_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_array_range_runtime_mut() {
+    let program_string = include_str!("array_range_runtime_mut.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_array_range_length_runtime_fail() {
+    let program_string = include_str!("array_range_length_runtime_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let error = expect_compiler_error(program);
+
+    assert!(error
+        .to_string()
+        .contains("Cannot assign array of length `1` to array range of length `2`"));
+}
+
+#[test]
+fn test_array_range_empty() {
+    let program_string = include_str!("array_range_empty.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
 #[test]
 fn test_array_tuple_mut() {
     let bytes = include_str!("array_tuple_mut.leo");
@@ -14,8 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, expect_asg_error, generate_main_input, parse_program, parse_program_with_input};
+use crate::{
+    assert_satisfied,
+    expect_asg_error,
+    expect_compiler_error,
+    generate_main_input,
+    parse_program,
+    parse_program_with_input,
+    parse_program_with_options,
+    EdwardsTestCompiler,
+};
 use leo_ast::InputValue;
+use leo_compiler::CompilerOptions;
+use leo_input::types::{IntegerType, U32Type, UnsignedIntegerType};
+
+use snarkvm_curves::edwards_bls12::Fq;
+use snarkvm_r1cs::TestConstraintSystem;
 
 pub mod conditional;
 
@@ -57,6 +71,39 @@ fn test_iteration_basic() {
     assert_satisfied(program);
 }
 
+fn loop_optimization_options() -> CompilerOptions {
+    CompilerOptions {
+        loop_optimization_enabled: true,
+        ..Default::default()
+    }
+}
+
+fn iteration_invariant_constraints(options: CompilerOptions) -> usize {
+    let program_string = include_str!("iteration_invariant.leo");
+    let mut program = parse_program_with_options(program_string, options).unwrap();
+
+    let main_input = generate_main_input(vec![
+        ("a", u32_input(3)),
+        ("b", u32_input(5)),
+    ]);
+    program.set_main_input(main_input);
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.compile_constraints(&mut cs).unwrap();
+    assert!(cs.is_satisfied());
+
+    cs.num_constraints()
+}
+
+#[test]
+fn test_iteration_invariant_hoisted() {
+    let unoptimized = iteration_invariant_constraints(CompilerOptions::default());
+    let optimized = iteration_invariant_constraints(loop_optimization_options());
+
+    // The invariant multiplication is synthesized once instead of once per iteration.
+    assert!(optimized < unoptimized);
+}
+
 #[test]
 fn test_num_returns_fail() {
     let program_string = include_str!("num_returns_fail.leo");
@@ -97,3 +144,194 @@ fn test_iteration_variable() {
 
     assert_satisfied(program);
 }
+
+#[test]
+fn test_iteration_descending() {
+    let program_string = include_str!("iteration_descending.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_step() {
+    let program_string = include_str!("iteration_step.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_descending_step() {
+    let program_string = include_str!("iteration_descending_step.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_step_zero_fail() {
+    let program_string = include_str!("iteration_step_zero_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_iteration_inclusive() {
+    let program_string = include_str!("iteration_inclusive.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_break() {
+    let program_string = include_str!("iteration_break.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_continue() {
+    let program_string = include_str!("iteration_continue.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_iteration_break_nested() {
+    let program_string = include_str!("iteration_break_nested.leo");
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+fn u32_input(value: u32) -> Option<InputValue> {
+    Some(InputValue::Integer(
+        IntegerType::Unsigned(UnsignedIntegerType::U32Type(U32Type {})),
+        value.to_string(),
+    ))
+}
+
+fn num_constraints(mut program: EdwardsTestCompiler, a: u32) -> usize {
+    let main_input = generate_main_input(vec![("a", u32_input(a))]);
+    program.set_main_input(main_input);
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    program.compile_constraints(&mut cs).unwrap();
+    assert!(cs.is_satisfied());
+
+    cs.num_constraints()
+}
+
+#[test]
+fn test_iteration_break_input() {
+    let program_string = include_str!("iteration_break_input.leo");
+    let program = parse_program(program_string).unwrap();
+
+    num_constraints(program, 5);
+}
+
+#[test]
+fn test_iteration_break_const_eliminates_iterations() {
+    let program_string = include_str!("iteration_break_const.leo");
+    let unrolled_string = include_str!("iteration_break_unrolled.leo");
+
+    let program = num_constraints(parse_program(program_string).unwrap(), 5);
+    let unrolled = num_constraints(parse_program(unrolled_string).unwrap(), 5);
+
+    assert_eq!(program, unrolled);
+}
+
+#[test]
+fn test_break_outside_loop_fail() {
+    let program_string = include_str!("break_outside_loop_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+// Require statement
+
+fn require_program(a: u32) -> EdwardsTestCompiler {
+    let program_string = include_str!("require.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u32_input(a))]);
+    program.set_main_input(main_input);
+
+    program
+}
+
+#[test]
+fn test_require() {
+    assert_satisfied(require_program(5));
+}
+
+#[test]
+fn test_require_fail() {
+    let error = expect_compiler_error(require_program(12)).to_string();
+
+    assert!(error.contains("a is too large: 12"));
+}
+
+// Const assertion
+
+#[test]
+fn test_const_assert() {
+    let program_string = include_str!("const_assert.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u32_input(3))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_assert_let() {
+    let program_string = include_str!("const_assert_let.leo");
+    let mut program = parse_program(program_string).unwrap();
+
+    let main_input = generate_main_input(vec![("a", u32_input(3))]);
+    program.set_main_input(main_input);
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_const_assert_let_reassigned_fail() {
+    let program_string = include_str!("const_assert_let_reassigned_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_const_assert_fail() {
+    let program_string = include_str!("const_assert_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
+#[test]
+fn test_const_assert_loop_fail() {
+    let program_string = include_str!("const_assert_loop_fail.leo");
+    let program = parse_program(program_string).unwrap();
+
+    let error = expect_compiler_error(program).to_string();
+
+    assert!(error.contains("const assertion failed"));
+}
+
+#[test]
+fn test_const_assert_nonconst_fail() {
+    let program_string = include_str!("const_assert_nonconst_fail.leo");
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{assert_satisfied, parse_program};
+use crate::{assert_satisfied, expect_asg_error, parse_program};
 
 #[test]
 fn test_tuple_basic() {
@@ -79,6 +79,24 @@ fn test_function_typed() {
     assert_satisfied(program);
 }
 
+#[test]
+fn test_function_return_sugar() {
+    let program_string = include_str!("function_return_sugar.leo");
+
+    let program = parse_program(program_string).unwrap();
+
+    assert_satisfied(program);
+}
+
+#[test]
+fn test_function_return_sugar_arity_fail() {
+    let program_string = include_str!("function_return_sugar_arity_fail.leo");
+
+    let error = parse_program(program_string).err().unwrap();
+
+    expect_asg_error(error);
+}
+
 #[test]
 fn test_function_multiple() {
     let progam_string = include_str!("function_multiple.leo");
@@ -114,6 +114,18 @@ impl ImportParserError {
         Self::new_from_span(message, &identifier.span)
     }
 
+    ///
+    /// Failed to import a package name while dependencies cannot be fetched.
+    ///
+    pub fn unknown_package_offline(identifier: Identifier) -> Self {
+        let message = format!(
+            "Cannot find imported package `{}` in source files or import directory, and packages cannot be fetched in offline mode.",
+            identifier.name
+        );
+
+        Self::new_from_span(message, &identifier.span)
+    }
+
     pub fn io_error(span: &Span, path: &str, error: std::io::Error) -> Self {
         let message = format!("cannot read imported file '{}': {:?}", path, error,);
 
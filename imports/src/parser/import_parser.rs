@@ -18,16 +18,62 @@ use crate::errors::ImportParserError;
 use leo_asg::{AsgContext, AsgConvertError, ImportResolver, Program, Span};
 
 use indexmap::{IndexMap, IndexSet};
-use std::env::current_dir;
+use std::{
+    collections::HashMap,
+    env::current_dir,
+    path::{Path, PathBuf},
+};
+
+/// Syntax trees of the package files parsed during a build, keyed by file path.
+///
+/// The cache is handed down to the parsers of nested imports, so each file is parsed once.
+#[derive(Clone, Default)]
+pub(crate) struct ParsedFiles {
+    programs: HashMap<PathBuf, leo_ast::Program>,
+    order: Vec<PathBuf>,
+}
+
+impl ParsedFiles {
+    pub(crate) fn get(&self, file_path: &Path) -> Option<&leo_ast::Program> {
+        self.programs.get(file_path)
+    }
+
+    pub(crate) fn insert(&mut self, file_path: PathBuf, program: leo_ast::Program) {
+        self.order.push(file_path.clone());
+        self.programs.insert(file_path, program);
+    }
+}
 
 /// Stores imported packages.
 ///
 /// A program can import one or more packages. A package can be found locally in the source
 /// directory, foreign in the imports directory, or part of the core package list.
+///
+/// In offline mode, packages missing from the local directories are reported as unavailable offline.
 #[derive(Clone, Default)]
 pub struct ImportParser<'a> {
     partial_imports: IndexSet<String>,
     imports: IndexMap<String, Program<'a>>,
+    pub(crate) parsed: ParsedFiles,
+    offline: bool,
+}
+
+impl<'a> ImportParser<'a> {
+    pub fn new(offline: bool) -> Self {
+        Self {
+            offline,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns the paths of the package files parsed so far, in the order they were parsed.
+    pub fn parsed_files(&self) -> &[PathBuf] {
+        &self.parsed.order
+    }
 }
 
 //todo: handle relative imports relative to file...
@@ -45,15 +91,19 @@ impl<'a> ImportResolver<'a> for ImportParser<'a> {
         if let Some(program) = self.imports.get(&full_path) {
             return Ok(Some(program.clone()));
         }
-        let mut imports = Self::default();
+        let mut imports = Self::new(self.offline);
         let path =
             current_dir().map_err(|x| -> AsgConvertError { ImportParserError::current_directory_error(x).into() })?;
 
+        // Share the parsed files with the parser of the package's own imports.
+        imports.parsed = std::mem::take(&mut self.parsed);
+
         self.partial_imports.insert(full_path.clone());
         let program = imports
             .parse_package(context, path, package_segments, span)
             .map_err(|x| -> AsgConvertError { x.into() })?;
         self.partial_imports.remove(&full_path);
+        self.parsed = imports.parsed;
         self.imports.insert(full_path, program.clone());
         Ok(Some(program))
     }
@@ -23,3 +23,6 @@ pub use self::import_parser::*;
 
 pub mod parse_package;
 pub use self::parse_package::*;
+
+pub mod prefetch;
+pub use self::prefetch::*;
@@ -24,20 +24,30 @@ static SOURCE_DIRECTORY_NAME: &str = "src/";
 static IMPORTS_DIRECTORY_NAME: &str = "imports/";
 
 impl<'a> ImportParser<'a> {
-    fn parse_package_access(
-        &mut self,
-        context: AsgContext<'a>,
-        package: &DirEntry,
+    fn locate_package_access(
+        &self,
+        package: DirEntry,
         remaining_segments: &[&str],
         span: &Span,
-    ) -> Result<Program<'a>, ImportParserError> {
+    ) -> Result<DirEntry, ImportParserError> {
         if !remaining_segments.is_empty() {
-            return self.parse_package(context, package.path(), remaining_segments, span);
+            return self.locate_package(package.path(), remaining_segments, span);
         }
-        let program = Self::parse_import_file(package, span)?;
-        let asg = leo_asg::Program::new(context, &program, self)?;
 
-        Ok(asg)
+        Ok(package)
+    }
+
+    ///
+    /// Returns the error for a package that cannot be found in the local directories.
+    ///
+    fn unknown_package(&self, package_name: &str, span: &Span) -> ImportParserError {
+        let identifier = Identifier::new_with_span(package_name, span.clone());
+
+        if self.is_offline() {
+            ImportParserError::unknown_package_offline(identifier)
+        } else {
+            ImportParserError::unknown_package(identifier)
+        }
     }
 
     ///
@@ -48,10 +58,26 @@ impl<'a> ImportParser<'a> {
     pub(crate) fn parse_package(
         &mut self,
         context: AsgContext<'a>,
-        mut path: PathBuf,
+        path: PathBuf,
         segments: &[&str],
         span: &Span,
     ) -> Result<Program<'a>, ImportParserError> {
+        let package = self.locate_package(path, segments, span)?;
+        let program = self.parse_import_file(&package, span)?;
+        let asg = leo_asg::Program::new(context, &program, self)?;
+
+        Ok(asg)
+    }
+
+    ///
+    /// Returns the directory entry of the package file or directory named by `segments`.
+    ///
+    pub(crate) fn locate_package(
+        &self,
+        mut path: PathBuf,
+        segments: &[&str],
+        span: &Span,
+    ) -> Result<DirEntry, ImportParserError> {
         let error_path = path.clone();
         let package_name = segments[0];
 
@@ -113,21 +139,15 @@ impl<'a> ImportParser<'a> {
                     package_name,
                     span.clone(),
                 ))),
-                (Some(source_entry), None) => self.parse_package_access(context, &source_entry, &segments[1..], span),
-                (None, Some(import_entry)) => self.parse_package_access(context, &import_entry, &segments[1..], span),
-                (None, None) => Err(ImportParserError::unknown_package(Identifier::new_with_span(
-                    package_name,
-                    span.clone(),
-                ))),
+                (Some(source_entry), None) => self.locate_package_access(source_entry, &segments[1..], span),
+                (None, Some(import_entry)) => self.locate_package_access(import_entry, &segments[1..], span),
+                (None, None) => Err(self.unknown_package(package_name, span)),
             }
         } else {
             // Enforce local package access with no found imports directory
             match matched_source_entry {
-                Some(source_entry) => self.parse_package_access(context, &source_entry, &segments[1..], span),
-                None => Err(ImportParserError::unknown_package(Identifier::new_with_span(
-                    package_name,
-                    span.clone(),
-                ))),
+                Some(source_entry) => self.locate_package_access(source_entry, &segments[1..], span),
+                None => Err(self.unknown_package(package_name, span)),
             }
         }
     }
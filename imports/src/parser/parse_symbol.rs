@@ -17,17 +17,17 @@
 use crate::{errors::ImportParserError, ImportParser};
 use leo_ast::{Program, Span};
 
-use std::fs::DirEntry;
+use std::{fs::DirEntry, path::PathBuf};
 
 static LIBRARY_FILE: &str = "src/lib.leo";
 
 impl<'a> ImportParser<'a> {
     ///
-    /// Returns a Leo syntax tree from a given package.
+    /// Returns the path of the Leo file of a given package, and the name of the package.
     ///
-    /// Builds an abstract syntax tree from the given file and then builds the Leo syntax tree.
+    /// The file of a package directory is its library file.
     ///
-    pub(crate) fn parse_import_file(package: &DirEntry, span: &Span) -> Result<Program, ImportParserError> {
+    pub(crate) fn import_file_path(package: &DirEntry, span: &Span) -> Result<(PathBuf, String), ImportParserError> {
         // Get the package file type.
         let file_type = package
             .file_type()
@@ -49,12 +49,31 @@ impl<'a> ImportParser<'a> {
             }
         }
 
-        let file_path_str = file_path.to_str().unwrap_or_default();
+        Ok((file_path, file_name))
+    }
 
-        // Build the package abstract syntax tree.
-        let program_string =
-            &std::fs::read_to_string(&file_path).map_err(|x| ImportParserError::io_error(span, file_path_str, x))?;
-        let mut ast = leo_parser::parse(&file_path_str, &program_string)?;
+    ///
+    /// Returns a Leo syntax tree from a given package.
+    ///
+    /// Builds an abstract syntax tree from the given file and then builds the Leo syntax tree.
+    /// Each file is parsed once per build, later imports of the package reuse its syntax tree.
+    ///
+    pub(crate) fn parse_import_file(&mut self, package: &DirEntry, span: &Span) -> Result<Program, ImportParserError> {
+        let (file_path, file_name) = Self::import_file_path(package, span)?;
+
+        let mut ast = match self.parsed.get(&file_path) {
+            Some(ast) => ast.clone(),
+            None => {
+                let file_path_str = file_path.to_str().unwrap_or_default();
+
+                // Build the package abstract syntax tree.
+                let program_string = &std::fs::read_to_string(&file_path)
+                    .map_err(|x| ImportParserError::io_error(span, file_path_str, x))?;
+                let ast = leo_parser::parse(&file_path_str, &program_string)?;
+                self.parsed.insert(file_path.clone(), ast.clone());
+                ast
+            }
+        };
         ast.name = file_name;
         Ok(ast)
     }
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ImportParser;
+use leo_asg::{imported_packages, Span};
+
+use indexmap::IndexSet;
+use std::{collections::HashSet, env::current_dir, fs, path::PathBuf, thread};
+
+impl<'a> ImportParser<'a> {
+    ///
+    /// Reads and parses the files of every package imported by `program`, directly or through
+    /// other packages, so that resolving the imports does not read them again.
+    /// Files parsed earlier in the build are skipped.
+    ///
+    /// The files of each level of the import graph are read on separate threads. Syntax trees
+    /// cannot be sent between threads, so they are parsed on this thread in import order.
+    /// Missing or invalid packages are skipped here and reported when the imports are resolved,
+    /// as are recursive imports.
+    ///
+    pub fn prefetch(&mut self, program: &leo_ast::Program) {
+        let path = match current_dir() {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+
+        let mut visited = HashSet::new();
+        let mut level = Self::unvisited_packages(program, &mut visited);
+
+        while !level.is_empty() {
+            // Locate the files of the packages in this level.
+            let files = level
+                .iter()
+                .filter_map(|segments| {
+                    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+                    let package = self.locate_package(path.clone(), &segments, &Span::default()).ok()?;
+                    let (file_path, _) = Self::import_file_path(&package, &Span::default()).ok()?;
+                    Some(file_path)
+                })
+                .filter(|file_path| self.parsed.get(file_path).is_none())
+                .collect::<IndexSet<PathBuf>>();
+
+            // Read the files in parallel, joining the threads in order.
+            let handles = files
+                .into_iter()
+                .map(|file_path| {
+                    thread::spawn(move || {
+                        let source = fs::read_to_string(&file_path);
+                        (file_path, source)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut next_level = vec![];
+            for handle in handles {
+                let (file_path, source) = match handle.join() {
+                    Ok((file_path, Ok(source))) => (file_path, source),
+                    _ => continue,
+                };
+
+                if let Ok(ast) = leo_parser::parse(file_path.to_str().unwrap_or_default(), &source) {
+                    next_level.extend(Self::unvisited_packages(&ast, &mut visited));
+                    self.parsed.insert(file_path, ast);
+                }
+            }
+
+            level = next_level;
+        }
+    }
+
+    ///
+    /// Returns the packages imported by `program` that are not core packages and were not visited yet.
+    ///
+    fn unvisited_packages(program: &leo_ast::Program, visited: &mut HashSet<Vec<String>>) -> Vec<Vec<String>> {
+        imported_packages(program)
+            .into_iter()
+            .map(|(segments, _)| segments)
+            .filter(|segments| segments.first().map(String::as_str) != Some("core"))
+            .filter(|segments| visited.insert(segments.clone()))
+            .collect()
+    }
+}
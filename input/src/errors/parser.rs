@@ -18,6 +18,7 @@ use crate::{
     ast::Rule,
     errors::SyntaxError as InputSyntaxError,
     expressions::{ArrayInlineExpression, Expression},
+    parameters::Parameter,
     sections::Header,
     tables::Table,
     types::{DataType, Type},
@@ -135,6 +136,15 @@ impl InputParserError {
         Self::new_from_span(message, &span)
     }
 
+    pub fn public_constant(parameter: &Parameter) -> Self {
+        let message = format!(
+            "the constant input `{}` cannot be public, constants are not allocated in the circuit",
+            parameter.variable
+        );
+
+        Self::new_from_span(message, &parameter.span)
+    }
+
     pub fn public_section(header: Header) -> Self {
         let message = format!("the section header `{}` is not a public section", header);
         let span = header.span();
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ast::Rule, common::Identifier, types::Type};
+use crate::{ast::Rule, common::Identifier, tables::Public, types::Type};
 
 use pest::Span;
 use pest_ast::FromPest;
@@ -22,6 +22,7 @@ use pest_ast::FromPest;
 #[derive(Clone, Debug, FromPest, PartialEq)]
 #[pest_ast(rule(Rule::parameter))]
 pub struct Parameter<'ast> {
+    pub public: Option<Public<'ast>>,
     pub variable: Identifier<'ast>,
     pub type_: Type<'ast>,
     #[pest_ast(outer())]
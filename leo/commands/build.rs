@@ -14,42 +14,238 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{commands::Command, context::Context};
+use crate::{
+    commands::{
+        watch::{on_source_writes, watch_source, DEFAULT_WATCH_INTERVAL},
+        Command,
+        Test,
+        TestRerun,
+    },
+    context::Context,
+};
+use leo_ast::InputTemplate;
 use leo_compiler::{
     compiler::{thread_leaked_context, Compiler},
-    group::targets::edwards_bls12::EdwardsGroupType,
+    group::targets::{edwards_bls12::EdwardsGroupType, edwards_sw6::EdwardsSw6GroupType},
+    CompilerOptions,
+    GroupType,
+    OverflowBehavior,
 };
 use leo_package::{
     inputs::*,
     outputs::{ChecksumFile, CircuitFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
     source::{LibraryFile, MainFile, LIBRARY_FILENAME, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
-use leo_synthesizer::{CircuitSynthesizer, SerializedCircuit};
+use leo_synthesizer::{CircuitSynthesizer, ConstraintLimit, SerializedCircuit};
+
+use notify::DebouncedEvent;
 
-use anyhow::Result;
-use snarkvm_curves::{bls12_377::Bls12_377, edwards_bls12::Fq};
+use anyhow::{anyhow, Error, Result};
+use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, edwards_bls12::Fq, traits::PairingEngine};
 use snarkvm_r1cs::ConstraintSystem;
+use std::{
+    fmt,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 use structopt::StructOpt;
 use tracing::span::Span;
 
+/// The curve a program is synthesized against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildTarget {
+    /// The Edwards curve embedded in BLS12-377, proven with Groth16 over BLS12-377.
+    EdwardsBls12,
+    /// The Edwards curve embedded in BW6-761, synthesized over the scalar field of BW6-761.
+    EdwardsSw6,
+}
+
+impl BuildTarget {
+    /// Names of all targets accepted by `--target`.
+    pub const NAMES: &'static [&'static str] = &["edwards_bls12", "edwards_sw6"];
+}
+
+impl Default for BuildTarget {
+    fn default() -> Self {
+        BuildTarget::EdwardsBls12
+    }
+}
+
+impl FromStr for BuildTarget {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "edwards_bls12" => Ok(BuildTarget::EdwardsBls12),
+            "edwards_sw6" => Ok(BuildTarget::EdwardsSw6),
+            _ => Err(anyhow!(
+                "Unsupported build target `{}`, expected one of: {}",
+                s,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BuildTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildTarget::EdwardsBls12 => write!(f, "edwards_bls12"),
+            BuildTarget::EdwardsSw6 => write!(f, "edwards_sw6"),
+        }
+    }
+}
+
+/// The built main program and whether its checksum changed, if the package has a main file.
+pub type BuildOutput<F, G> = Option<(Compiler<'static, F, G>, bool)>;
+
 /// Compile and build program command
-#[derive(StructOpt, Debug)]
+#[derive(StructOpt, Debug, Default)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
-pub struct Build {}
+pub struct Build {
+    #[structopt(long, help = "Resolve imports from local directories only, never fetching packages")]
+    pub offline: bool,
+
+    #[structopt(long, help = "Build in release mode, stripping `console.debug` statements")]
+    pub release: bool,
+
+    #[structopt(
+        long,
+        requires = "release",
+        help = "Wrap integer arithmetic and negation on overflow instead of failing, in release mode only"
+    )]
+    pub wrapping: bool,
+
+    #[structopt(long, help = "Hoist loop-invariant expressions out of loops before unrolling them")]
+    pub optimize_loops: bool,
+
+    #[structopt(
+        short = "D",
+        long = "deny",
+        possible_values = &["warnings"],
+        number_of_values = 1,
+        help = "Treat the given diagnostics as errors, e.g. `-D warnings`"
+    )]
+    pub deny: Vec<String>,
+
+    #[structopt(
+        long,
+        default_value = "edwards_bls12",
+        possible_values = BuildTarget::NAMES,
+        help = "Curve to synthesize the program against"
+    )]
+    pub target: BuildTarget,
+
+    #[structopt(
+        long,
+        help = "Write an input file skeleton for the signature of `main` instead of building"
+    )]
+    pub emit_input_template: bool,
+
+    #[structopt(
+        long,
+        help = "Abort synthesis once the program needs more than this many constraints"
+    )]
+    pub max_constraints: Option<usize>,
+
+    #[structopt(long, help = "Write the synthesized R1CS constraints to a file in text form")]
+    pub dump_constraints: Option<PathBuf>,
+
+    #[structopt(
+        long,
+        help = "Write the witness assignment to a file in text form, it includes the private inputs"
+    )]
+    pub emit_witness: Option<PathBuf>,
+
+    #[structopt(long, help = "Print the public inputs a verifier needs, in order, after synthesis")]
+    pub show_public_inputs: bool,
+
+    #[structopt(long, help = "Print the changes canonicalization makes to the main program AST")]
+    pub print_ast_diff: bool,
+
+    #[structopt(
+        long,
+        help = "Watch the source directory and rerun the tests whenever a file is saved"
+    )]
+    pub watch_test: bool,
+
+    #[structopt(
+        parse(from_os_str),
+        help = "Read the main program from this file instead of `src/main.leo`, or from stdin with `-`"
+    )]
+    pub source: Option<PathBuf>,
+}
 
-impl Command for Build {
-    type Input = ();
-    type Output = Option<(Compiler<'static, Fq, EdwardsGroupType>, bool)>;
+impl Build {
+    /// Returns the compiler options selected by the build flags.
+    fn compiler_options(&self) -> CompilerOptions {
+        CompilerOptions {
+            offline: self.offline,
+            release: self.release,
+            release_overflow: if self.wrapping {
+                OverflowBehavior::Wrapping
+            } else {
+                OverflowBehavior::Checked
+            },
+            loop_optimization_enabled: self.optimize_loops,
+            deny_warnings: self.deny.iter().any(|lint| lint == "warnings"),
+            ..Default::default()
+        }
+    }
 
-    fn log_span(&self) -> Span {
-        tracing::span!(tracing::Level::INFO, "Build")
+    /// Returns a compiler for `main_file_path` over the scalar field of `E` and the group gadgets `G`.
+    fn compiler<E: PairingEngine, G: GroupType<E::Fr>>(
+        &self,
+        package_name: String,
+        main_file_path: PathBuf,
+        output_directory: PathBuf,
+    ) -> Compiler<'static, E::Fr, G> {
+        let mut program =
+            Compiler::<E::Fr, G>::new(package_name, main_file_path, output_directory, thread_leaked_context());
+        program.set_options(self.compiler_options());
+        program
     }
 
-    fn prelude(&self, _: Context) -> Result<Self::Input> {
-        Ok(())
+    /// Returns the main program source selected by the `source` argument, if any.
+    ///
+    /// Imports are still resolved relative to the package source directory.
+    fn read_source(&self, mut stdin: impl Read) -> Result<Option<String>> {
+        match &self.source {
+            Some(source) if source.as_os_str() == "-" => {
+                let mut content = String::new();
+                stdin
+                    .read_to_string(&mut content)
+                    .map_err(|e| anyhow!("Cannot read program from stdin: {}", e))?;
+                Ok(Some(content))
+            }
+            Some(source) => fs::read_to_string(source)
+                .map(Some)
+                .map_err(|e| anyhow!("Cannot read program {}: {}", source.display(), e)),
+            None => Ok(None),
+        }
     }
 
-    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+    /// Builds the package, reading the main program from `stdin` if the `source` argument is `-`.
+    pub fn apply_with_stdin(self, context: Context, stdin: impl Read) -> Result<<Self as Command>::Output> {
+        let main_source = self.read_source(stdin)?;
+
+        match self.target {
+            BuildTarget::EdwardsBls12 => self.build::<Bls12_377, EdwardsGroupType>(context, main_source),
+            // Only programs of the default target can be set up and proven, so none is returned.
+            BuildTarget::EdwardsSw6 => self
+                .build::<BW6_761, EdwardsSw6GroupType>(context, main_source)
+                .map(|_| None),
+        }
+    }
+
+    /// Builds the package over the group gadgets `G`, synthesizing its constraints for the pairing engine `E`.
+    fn build<E: PairingEngine, G: GroupType<E::Fr>>(
+        &self,
+        context: Context,
+        main_source: Option<String>,
+    ) -> Result<BuildOutput<E::Fr, G>> {
         let path = context.dir()?;
         let package_name = context.manifest()?.get_package_name();
 
@@ -64,6 +260,7 @@ impl Command for Build {
         output_directory.push(OUTPUTS_DIRECTORY_NAME);
 
         tracing::info!("Starting...");
+        tracing::debug!("Target - {}", self.target);
 
         // Compile the package starting with the lib.leo file
         if LibraryFile::exists_at(&package_path) {
@@ -76,17 +273,13 @@ impl Command for Build {
             tracing::info!("Compiling library... ({:?})", lib_file_path);
 
             // Compile the library file but do not output
-            let _program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
-                package_name.clone(),
-                lib_file_path,
-                output_directory.clone(),
-                thread_leaked_context(),
-            )?;
+            let mut program = self.compiler::<E, G>(package_name.clone(), lib_file_path, output_directory.clone());
+            program.parse_program()?;
             tracing::info!("Complete");
         };
 
         // Compile the main.leo file along with constraints
-        if MainFile::exists_at(&package_path) {
+        if main_source.is_some() || MainFile::exists_at(&package_path) {
             // Create the output directory
             OutputsDirectory::create(&package_path)?;
 
@@ -95,6 +288,16 @@ impl Command for Build {
             main_file_path.push(SOURCE_DIRECTORY_NAME);
             main_file_path.push(MAIN_FILENAME);
 
+            if self.emit_input_template {
+                return self.write_input_template::<E, G>(
+                    &package_name,
+                    &package_path,
+                    main_file_path,
+                    main_source.as_deref(),
+                    output_directory,
+                );
+            }
+
             // Load the input file at `package_name.in`
             let (input_string, input_path) = InputFile::new(&package_name).read_from(&path)?;
 
@@ -102,39 +305,100 @@ impl Command for Build {
             let (state_string, state_path) = StateFile::new(&package_name).read_from(&path)?;
 
             // Log compilation of files to console
-            tracing::info!("Compiling main program... ({:?})", main_file_path);
+            match &self.source {
+                Some(source) => tracing::info!("Compiling main program... ({:?})", source),
+                None => tracing::info!("Compiling main program... ({:?})", main_file_path),
+            }
 
             // Load the program at `main_file_path`
-            let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
-                package_name.clone(),
-                main_file_path,
-                output_directory,
-                &input_string,
-                &input_path,
-                &state_string,
-                &state_path,
-                thread_leaked_context(),
-            )?;
+            let mut program = self.compiler::<E, G>(package_name.clone(), main_file_path, output_directory);
+            program.parse_input(&input_string, &input_path, &state_string, &state_path)?;
+            match &main_source {
+                Some(source) => program.parse_program_from_string(source)?,
+                None => program.parse_program()?,
+            }
+
+            if self.print_ast_diff {
+                let diffs = program.canonicalization_diff()?;
+                if diffs.is_empty() {
+                    tracing::info!("Canonicalization made no changes to the AST");
+                }
+                for diff in diffs {
+                    tracing::info!("{}", diff);
+                }
+            }
 
             // Compute the current program checksum
             let program_checksum = program.checksum()?;
 
             // Generate the program on the constraint system and verify correctness
             {
-                let mut cs = CircuitSynthesizer::<Bls12_377> {
-                    constraints: Default::default(),
-                    public_variables: Default::default(),
-                    private_variables: Default::default(),
-                    namespaces: Default::default(),
-                };
                 let temporary_program = program.clone();
-                let output = temporary_program.compile_constraints(&mut cs)?;
+                let (cs, output) = match self.max_constraints {
+                    Some(limit) => {
+                        let mut cs = ConstraintLimit::new(CircuitSynthesizer::<E>::with_function_constraints(), limit);
+                        let output = temporary_program.compile_constraints(&mut cs);
+
+                        // Report the limit rather than the error of the interrupted gadget
+                        if cs.exceeded() {
+                            return Err(anyhow!(
+                                "Synthesis aborted after exceeding the limit of {} constraints set by --max-constraints",
+                                limit
+                            ));
+                        }
+                        (cs.into_inner(), output?)
+                    }
+                    None => {
+                        let mut cs = CircuitSynthesizer::<E>::with_function_constraints();
+                        let output = temporary_program.compile_constraints(&mut cs)?;
+                        (cs, output)
+                    }
+                };
 
                 tracing::debug!("Compiled output - {:#?}", output);
                 tracing::info!("Number of constraints - {:#?}", cs.num_constraints());
 
+                // Report which functions contribute the most constraints
+                let constraints_per_function = cs.constraints_per_function();
+                for (function, count) in constraints_per_function.iter() {
+                    tracing::info!("  {} - {}", function, count);
+                }
+
+                // Fail the build if a function exceeds its `@max_constraints` budget
+                program.check_constraint_budgets(&constraints_per_function)?;
+
                 // Serialize the circuit
                 let circuit_object = SerializedCircuit::from(cs);
+
+                // Write the constraint matrices if requested, they can be large
+                if let Some(dump_path) = &self.dump_constraints {
+                    fs::write(dump_path, circuit_object.to_r1cs_string())?;
+                    tracing::info!("Constraints written to {:?}", dump_path);
+                }
+
+                // Write the witness if requested, it reveals the private inputs
+                if let Some(witness_path) = &self.emit_witness {
+                    fs::write(witness_path, circuit_object.to_witness_string())?;
+                    tracing::warn!("Witness written to {:?}, it contains the private inputs", witness_path);
+                }
+
+                // List the public inputs a verifier will need alongside the proof
+                if self.show_public_inputs {
+                    let public_inputs = program.public_inputs();
+                    if public_inputs.is_empty() {
+                        tracing::info!("Public inputs - none");
+                    } else {
+                        tracing::info!("Public inputs -");
+                    }
+                    for (parameter, value) in public_inputs.iter() {
+                        match value {
+                            Some(value) => {
+                                tracing::info!("  {}: {} = {}", parameter.variable.name, parameter.type_, value)
+                            }
+                            None => tracing::info!("  {}: {} = <unset>", parameter.variable.name, parameter.type_),
+                        }
+                    }
+                }
                 let json = circuit_object.to_json_string().unwrap();
                 // println!("json: {}", json);
 
@@ -177,3 +441,85 @@ impl Command for Build {
         Ok(None)
     }
 }
+
+impl Build {
+    /// Runs the tests once, then again for every write event, reporting only results that changed.
+    pub fn watch_tests_on(
+        &self,
+        context: Context,
+        events: impl IntoIterator<Item = DebouncedEvent>,
+    ) -> Result<TestRerun> {
+        let test = Test { files: vec![] };
+        let mut rerun = TestRerun::default();
+
+        let mut run_tests = || match rerun.rerun(&test, &context) {
+            Ok(changed) if changed.is_empty() => tracing::info!("Test results unchanged"),
+            Ok(changed) => changed.iter().for_each(|results| results.report()),
+            Err(e) => tracing::error!("Error {:?}", e),
+        };
+
+        run_tests();
+        on_source_writes(events, run_tests);
+
+        Ok(rerun)
+    }
+
+    /// Writes an input file skeleton for the signature of `main` to the package inputs directory.
+    fn write_input_template<E: PairingEngine, G: GroupType<E::Fr>>(
+        &self,
+        package_name: &str,
+        package_path: &Path,
+        main_file_path: PathBuf,
+        main_source: Option<&str>,
+        output_directory: PathBuf,
+    ) -> Result<BuildOutput<E::Fr, G>> {
+        let input_file = InputFile::new(package_name);
+        if input_file.exists_at(package_path) {
+            return Err(anyhow!(
+                "Input file {} already exists, remove it to generate a new template",
+                input_file.filename()
+            ));
+        }
+
+        let mut program = self.compiler::<E, G>(package_name.to_string(), main_file_path, output_directory);
+        match main_source {
+            Some(source) => program.parse_program_from_string(source)?,
+            None => program.parse_program()?,
+        }
+
+        let template = InputTemplate::new(program.ast())
+            .generate()
+            .ok_or_else(|| anyhow!("Program has no main function to generate an input template for"))?;
+
+        let filename = input_file.filename();
+        InputsDirectory::create(package_path)?;
+        input_file.write_contents_to(package_path, &template)?;
+
+        tracing::info!("Input template written to {}", filename);
+
+        Ok(None)
+    }
+}
+
+impl Command for Build {
+    type Input = ();
+    type Output = BuildOutput<Fq, EdwardsGroupType>;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Build")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        if self.watch_test {
+            let (_watcher, events) = watch_source(DEFAULT_WATCH_INTERVAL)?;
+            self.watch_tests_on(context, events.iter())?;
+            return Ok(None);
+        }
+
+        self.apply_with_stdin(context, io::stdin())
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::{
+    compiler::{thread_leaked_context, Compiler},
+    group::targets::edwards_bls12::EdwardsGroupType,
+    CompilerOptions,
+};
+use leo_package::{
+    outputs::OUTPUTS_DIRECTORY_NAME,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use anyhow::{anyhow, Result};
+use snarkvm_curves::edwards_bls12::Fq;
+use std::{fs, path::PathBuf};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Emit the call graph of the main program in Graphviz format
+#[derive(StructOpt, Debug, Default)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Graph {
+    #[structopt(long, help = "Resolve imports from local directories only, never fetching packages")]
+    pub offline: bool,
+
+    #[structopt(long, help = "Write the call graph to a file instead of printing it")]
+    pub output: Option<PathBuf>,
+}
+
+impl Command for Graph {
+    type Input = ();
+    type Output = String;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Graph")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let package_name = context.manifest()?.get_package_name();
+
+        // Sanitize the package path to the root directory
+        let mut package_path = path;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(anyhow!("Package has no {} to graph", MAIN_FILENAME));
+        }
+
+        let mut main_file_path = package_path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let mut output_directory = package_path;
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        let mut program = Compiler::<Fq, EdwardsGroupType>::new(
+            package_name,
+            main_file_path,
+            output_directory,
+            thread_leaked_context(),
+        );
+        program.set_options(CompilerOptions {
+            offline: self.offline,
+            ..Default::default()
+        });
+        program.parse_program()?;
+
+        let dot = program.call_graph().to_dot();
+        match &self.output {
+            Some(output) => {
+                fs::write(output, &dot)?;
+                tracing::info!("Call graph written to {}", output.display());
+            }
+            None => print!("{}", dot),
+        }
+
+        Ok(dot)
+    }
+}
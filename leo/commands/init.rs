@@ -25,7 +25,10 @@ use tracing::span::Span;
 /// Init Leo project command within current directory
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
-pub struct Init {}
+pub struct Init {
+    #[structopt(long, help = "Set the package author")]
+    pub author: Option<String>,
+}
 
 impl Command for Init {
     type Input = ();
@@ -58,9 +61,10 @@ impl Command for Init {
             return Err(anyhow!("Invalid Leo project name"));
         }
 
-        let username = read_username().ok();
+        let (author, author_source) = resolve_author(self.author);
+        tracing::info!("Using author from {}", author_source);
 
-        LeoPackage::initialize(&package_name, false, &path, username)?;
+        LeoPackage::initialize(&package_name, false, &path, author)?;
 
         Ok(())
     }
@@ -22,7 +22,7 @@ use tracing::span::Span;
 
 // local program commands
 pub mod build;
-pub use build::Build;
+pub use build::{Build, BuildTarget};
 
 pub mod clean;
 pub use clean::Clean;
@@ -30,6 +30,9 @@ pub use clean::Clean;
 pub mod deploy;
 pub use deploy::Deploy;
 
+pub mod graph;
+pub use graph::Graph;
+
 pub mod init;
 pub use init::Init;
 
@@ -49,11 +52,14 @@ pub mod setup;
 pub use setup::Setup;
 
 pub mod test;
-pub use test::Test;
+pub use test::{Test, TestRerun, TestResults};
 
 pub mod update;
 pub use update::{Automatic as UpdateAutomatic, Update};
 
+pub mod verify_input;
+pub use verify_input::VerifyInput;
+
 pub mod watch;
 pub use watch::Watch;
 
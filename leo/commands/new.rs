@@ -15,10 +15,10 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{commands::Command, config::*, context::Context};
-use leo_package::LeoPackage;
+use leo_package::{source::Template, LeoPackage};
 
 use anyhow::{anyhow, Result};
-use std::{env::current_dir, fs};
+use std::fs;
 use structopt::StructOpt;
 use tracing::span::Span;
 
@@ -27,7 +27,16 @@ use tracing::span::Span;
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
 pub struct New {
     #[structopt(name = "NAME", help = "Set package name")]
-    name: String,
+    pub name: String,
+
+    #[structopt(long, help = "Create a library package instead of a binary")]
+    pub lib: bool,
+
+    #[structopt(long, help = "Set the package author")]
+    pub author: Option<String>,
+
+    #[structopt(long, help = "Scaffold the package from a built-in template")]
+    pub template: Option<String>,
 }
 
 impl Command for New {
@@ -42,17 +51,29 @@ impl Command for New {
         Ok(())
     }
 
-    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         // Check that the given package name is valid.
         let package_name = self.name;
         if !LeoPackage::is_package_name_valid(&package_name) {
             return Err(anyhow!("Invalid Leo project name"));
         }
 
-        let username = read_username().ok();
+        // Check that the requested template exists before anything is written to disk.
+        if let Some(template) = &self.template {
+            if Template::find(template).is_none() {
+                return Err(anyhow!(
+                    "Unknown template {:?}, available templates: {}",
+                    template,
+                    Template::names().join(", ")
+                ));
+            }
+        }
+
+        let (author, author_source) = resolve_author(self.author);
+        tracing::info!("Using author from {}", author_source);
 
         // Derive the package directory path.
-        let mut path = current_dir()?;
+        let mut path = context.dir()?;
         path.push(&package_name);
 
         // Verify the package directory path does not exist yet.
@@ -63,7 +84,7 @@ impl Command for New {
         // Create the package directory
         fs::create_dir_all(&path).map_err(|err| anyhow!("Could not create directory {}", err))?;
 
-        LeoPackage::initialize(&package_name, false, &path, username)?;
+        LeoPackage::initialize_with_template(&package_name, self.lib, &path, author, self.template.as_deref())?;
 
         Ok(())
     }
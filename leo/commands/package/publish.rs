@@ -50,7 +50,7 @@ impl Command for Publish {
 
     /// Build program before publishing
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        (Build {}).execute(context)
+        Build::default().execute(context)
     }
 
     fn apply(self, context: Context, _input: Self::Input) -> Result<Self::Output> {
@@ -17,6 +17,7 @@
 use super::setup::Setup;
 use crate::{commands::Command, context::Context};
 use leo_package::outputs::ProofFile;
+use leo_synthesizer::CircuitSynthesizer;
 use snarkvm_algorithms::{
     snark::groth16::{Groth16, PreparedVerifyingKey, Proof},
     traits::SNARK,
@@ -39,7 +40,7 @@ pub struct Prove {
 
 impl Command for Prove {
     type Input = <Setup as Command>::Output;
-    type Output = (Proof<Bls12_377>, PreparedVerifyingKey<Bls12_377>);
+    type Output = (Proof<Bls12_377>, PreparedVerifyingKey<Bls12_377>, Vec<Fr>);
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Proving")
@@ -67,6 +68,11 @@ impl Command for Prove {
         program_proof.write(&mut proof)?;
         ProofFile::new(&package_name).write_to(&path, &proof)?;
 
-        Ok((program_proof, prepared_verifying_key))
+        // Collect the public inputs the proof is verified against, in allocation order
+        let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+        program.compile_constraints(&mut cs)?;
+        let public_inputs = cs.public_variables.iter().cloned().collect();
+
+        Ok((program_proof, prepared_verifying_key, public_inputs))
     }
 }
@@ -46,14 +46,14 @@ impl Command for Run {
     }
 
     fn apply(self, _context: Context, input: Self::Input) -> Result<Self::Output> {
-        let (proof, prepared_verifying_key) = input;
+        let (proof, prepared_verifying_key, public_inputs) = input;
 
         tracing::info!("Starting...");
 
         // Run the verifier
         let is_success = Groth16::<Bls12_377, Compiler<Fr, EdwardsGroupType>, Vec<Fr>>::verify(
             &prepared_verifying_key,
-            &vec![],
+            &public_inputs,
             &proof,
         )?;
 
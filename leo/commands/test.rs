@@ -27,7 +27,7 @@ use leo_package::{
 
 use anyhow::{anyhow, Result};
 use snarkvm_curves::edwards_bls12::Fq;
-use std::{convert::TryFrom, path::PathBuf, time::Instant};
+use std::{collections::HashMap, convert::TryFrom, path::PathBuf, time::Instant};
 use structopt::StructOpt;
 use tracing::span::Span;
 
@@ -39,19 +39,69 @@ pub struct Test {
     pub(crate) files: Vec<PathBuf>,
 }
 
-impl Command for Test {
-    type Input = ();
-    type Output = ();
+/// The outcome of running the tests in one file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestResults {
+    pub file: PathBuf,
+    pub passed: u32,
+    pub failed: u32,
+    pub time_taken: u128,
+}
 
-    fn log_span(&self) -> Span {
-        tracing::span!(tracing::Level::INFO, "Test")
+impl TestResults {
+    /// Logs the outcome, as an error if any test failed.
+    pub fn report(&self) {
+        if self.failed == 0 {
+            tracing::info!(
+                "Tests passed in {} milliseconds. {} passed; {} failed;\n",
+                self.time_taken,
+                self.passed,
+                self.failed
+            );
+        } else {
+            tracing::error!(
+                "Tests failed in {} milliseconds. {} passed; {} failed;\n",
+                self.time_taken,
+                self.passed,
+                self.failed
+            );
+        }
+    }
+}
+
+/// Reruns tests, remembering the previous results of each file to report only what changed.
+#[derive(Debug, Default)]
+pub struct TestRerun {
+    previous: HashMap<PathBuf, (u32, u32)>,
+    runs: usize,
+}
+
+impl TestRerun {
+    /// Runs the tests and returns the results of files whose outcome differs from the previous run.
+    pub fn rerun(&mut self, test: &Test, context: &Context) -> Result<Vec<TestResults>> {
+        self.runs += 1;
+
+        let results = test.run(context)?;
+        let changed = results
+            .into_iter()
+            .filter(|results| {
+                let outcome = (results.passed, results.failed);
+                self.previous.insert(results.file.clone(), outcome) != Some(outcome)
+            })
+            .collect();
+
+        Ok(changed)
     }
 
-    fn prelude(&self, _: Context) -> Result<Self::Input> {
-        Ok(())
+    /// Returns how many times the tests were run.
+    pub fn runs(&self) -> usize {
+        self.runs
     }
+}
 
-    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+impl Test {
+    /// Runs the tests in every selected file, returning the results of each file.
+    pub fn run(&self, context: &Context) -> Result<Vec<TestResults>> {
         // Get the package name
         let package_name = context.manifest()?.get_package_name();
 
@@ -90,6 +140,7 @@ impl Command for Test {
         OutputsDirectory::create(&package_path)?;
 
         // Finally test every passed file
+        let mut results = Vec::new();
         for file_path in to_test {
             tracing::info!("Running tests in file {:?}", file_path);
 
@@ -104,7 +155,7 @@ impl Command for Test {
             let timer = Instant::now();
             let program = Compiler::<Fq, EdwardsGroupType>::parse_program_without_input(
                 package_name.clone(),
-                file_path,
+                file_path.clone(),
                 output_directory.clone(),
                 thread_leaked_context(),
             )?;
@@ -113,21 +164,33 @@ impl Command for Test {
             let (passed, failed) = temporary_program.compile_test_constraints(input_pairs)?;
             let time_taken = timer.elapsed().as_millis();
 
-            if failed == 0 {
-                tracing::info!(
-                    "Tests passed in {} milliseconds. {} passed; {} failed;\n",
-                    time_taken,
-                    passed,
-                    failed
-                );
-            } else {
-                tracing::error!(
-                    "Tests failed in {} milliseconds. {} passed; {} failed;\n",
-                    time_taken,
-                    passed,
-                    failed
-                );
-            }
+            results.push(TestResults {
+                file: file_path,
+                passed,
+                failed,
+                time_taken,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+impl Command for Test {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Test")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        for results in self.run(&context)? {
+            results.report();
         }
 
         Ok(())
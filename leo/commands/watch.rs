@@ -17,15 +17,54 @@
 use super::build::Build;
 use crate::{commands::Command, context::Context};
 
-use std::{sync::mpsc::channel, time::Duration};
+use std::{
+    sync::mpsc::{channel, Receiver},
+    time::Duration,
+};
 
 use anyhow::{anyhow, Result};
-use notify::{watcher, DebouncedEvent, RecursiveMode, Watcher};
+use notify::{watcher, DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
 use structopt::StructOpt;
 use tracing::span::Span;
 
 const LEO_SOURCE_DIR: &str = "src/";
 
+/// Seconds to wait for changes to settle before reporting them.
+pub(crate) const DEFAULT_WATCH_INTERVAL: u64 = 3;
+
+/// Starts watching the Leo source directory, events are received for as long as the watcher lives.
+pub(crate) fn watch_source(interval: u64) -> Result<(RecommendedWatcher, Receiver<DebouncedEvent>)> {
+    let (tx, rx) = channel();
+    let mut watcher = watcher(tx, Duration::from_secs(interval)).unwrap();
+
+    watcher.watch(LEO_SOURCE_DIR, RecursiveMode::Recursive).map_err(|e| {
+        anyhow!(
+            "Unable to watch, check that directory contains Leo.toml file. Error: {}",
+            e
+        )
+    })?;
+
+    tracing::info!("Watching Leo source code");
+
+    Ok((watcher, rx))
+}
+
+/// Calls `on_write` for every write event, until the events run out.
+pub(crate) fn on_source_writes(events: impl IntoIterator<Item = DebouncedEvent>, mut on_write: impl FnMut()) {
+    for event in events {
+        match event {
+            // See changes on the write event
+            DebouncedEvent::Write(_write) => on_write(),
+
+            // Watch error
+            DebouncedEvent::Error(e, _path) => tracing::error!("watch error: {:?}", e),
+
+            // Other events
+            _event => {}
+        }
+    }
+}
+
 /// Watch file changes in src/ directory and run Build Command
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
@@ -48,41 +87,20 @@ impl Command for Watch {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        let (tx, rx) = channel();
-        let mut watcher = watcher(tx, Duration::from_secs(self.interval)).unwrap();
-
-        watcher.watch(LEO_SOURCE_DIR, RecursiveMode::Recursive).map_err(|e| {
-            anyhow!(
-                "Unable to watch, check that directory contains Leo.toml file. Error: {}",
-                e
-            )
-        })?;
-
-        tracing::info!("Watching Leo source code");
-
-        loop {
-            match rx.recv() {
-                // See changes on the write event
-                Ok(DebouncedEvent::Write(_write)) => {
-                    match (Build {}).execute(context.clone()) {
-                        Ok(_output) => {
-                            tracing::info!("Built successfully");
-                        }
-                        Err(e) => {
-                            // Syntax error
-                            tracing::error!("Error {:?}", e);
-                        }
-                    };
-                }
-                // Other events
-                Ok(_event) => {}
+        let (_watcher, events) = watch_source(self.interval)?;
 
-                // Watch error
+        on_source_writes(events.iter(), || {
+            match Build::default().execute(context.clone()) {
+                Ok(_output) => {
+                    tracing::info!("Built successfully");
+                }
                 Err(e) => {
-                    tracing::error!("watch error: {:?}", e)
-                    // TODO (howardwu): Add graceful termination.
+                    // Syntax error
+                    tracing::error!("Error {:?}", e);
                 }
-            }
-        }
+            };
+        });
+
+        Ok(())
     }
 }
@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use std::{
+    fmt,
     fs::{
         create_dir_all,
         File,
@@ -23,6 +24,7 @@ use std::{
     io,
     io::prelude::*,
     path::{Path, PathBuf},
+    process,
 };
 
 use anyhow::Error;
@@ -135,39 +137,160 @@ impl Config {
     }
 }
 
-pub fn write_token_and_username(token: &str, username: &str) -> Result<(), io::Error> {
-    let config_dir = LEO_CONFIG_DIRECTORY.clone();
+/// Stores the Aleo PM credentials in a directory, by default the Leo config directory.
+#[derive(Clone, Debug)]
+pub struct CredentialStore {
+    directory: PathBuf,
+}
+
+impl Default for CredentialStore {
+    fn default() -> Self {
+        Self::new(LEO_CONFIG_DIRECTORY.to_path_buf())
+    }
+}
+
+impl CredentialStore {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    pub fn credentials_path(&self) -> PathBuf {
+        self.directory.join(LEO_CREDENTIALS_FILE)
+    }
+
+    pub fn username_path(&self) -> PathBuf {
+        self.directory.join(LEO_USERNAME_FILE)
+    }
+
+    /// Writes the token and username, making the credentials file readable by the owner only.
+    pub fn write(&self, token: &str, username: &str) -> Result<(), io::Error> {
+        // Create Leo config directory if it not exists
+        if !self.directory.exists() {
+            create_dir_all(&self.directory)?;
+        }
+
+        let mut credentials = create_private_file(&self.credentials_path())?;
+        credentials.write_all(&token.as_bytes())?;
 
-    // Create Leo config directory if it not exists
-    if !Path::new(&config_dir).exists() {
-        create_dir_all(&config_dir)?;
+        let mut username_file = File::create(&self.username_path())?;
+        username_file.write_all(&username.as_bytes())?;
+
+        Ok(())
     }
 
-    let mut credentials = File::create(&LEO_CREDENTIALS_PATH.to_path_buf())?;
-    credentials.write_all(&token.as_bytes())?;
+    pub fn read_token(&self) -> Result<String, io::Error> {
+        fs::read_to_string(&self.credentials_path())
+    }
+
+    pub fn read_username(&self) -> Result<String, io::Error> {
+        fs::read_to_string(&self.username_path())
+    }
+
+    pub fn remove(&self) -> Result<(), io::Error> {
+        fs::remove_file(&self.credentials_path())?;
+        fs::remove_file(&self.username_path())?;
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn create_private_file(path: &Path) -> Result<File, io::Error> {
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
 
-    let mut username_file = File::create(&LEO_USERNAME_PATH.to_path_buf())?;
-    username_file.write_all(&username.as_bytes())?;
+    // The mode above only applies to new files, so restrict existing ones as well.
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
 
-    Ok(())
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn create_private_file(path: &Path) -> Result<File, io::Error> {
+    File::create(path)
+}
+
+pub fn write_token_and_username(token: &str, username: &str) -> Result<(), io::Error> {
+    CredentialStore::default().write(token, username)
 }
 
 pub fn read_token() -> Result<String, io::Error> {
-    let mut credentials = File::open(&LEO_CREDENTIALS_PATH.to_path_buf())?;
-    let mut buf = String::new();
-    credentials.read_to_string(&mut buf)?;
-    Ok(buf)
+    CredentialStore::default().read_token()
 }
 
 pub fn read_username() -> Result<String, io::Error> {
-    let mut username = File::open(&LEO_USERNAME_PATH.to_path_buf())?;
-    let mut buf = String::new();
-    username.read_to_string(&mut buf)?;
-    Ok(buf)
+    CredentialStore::default().read_username()
 }
 
 pub fn remove_token_and_username() -> Result<(), io::Error> {
-    fs::remove_file(&LEO_CREDENTIALS_PATH.to_path_buf())?;
-    fs::remove_file(&LEO_USERNAME_PATH.to_path_buf())?;
-    Ok(())
+    CredentialStore::default().remove()
+}
+
+/// The source an author name for a new package was resolved from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorSource {
+    Flag,
+    Config,
+    Git,
+    Placeholder,
+}
+
+impl fmt::Display for AuthorSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthorSource::Flag => write!(f, "--author flag"),
+            AuthorSource::Config => write!(f, "Leo config"),
+            AuthorSource::Git => write!(f, "git user.name"),
+            AuthorSource::Placeholder => write!(f, "placeholder"),
+        }
+    }
+}
+
+/// Resolves the author of a new package from the given flag, the logged in username,
+/// the git `user.name` and finally the manifest placeholder, in that order.
+pub fn resolve_author(flag: Option<String>) -> (Option<String>, AuthorSource) {
+    resolve_author_from(flag, read_username().ok(), read_git_username())
+}
+
+/// Picks the first nonempty author out of the given candidates.
+/// Returns `None` with `AuthorSource::Placeholder` if none of them is set.
+pub fn resolve_author_from(
+    flag: Option<String>,
+    config: Option<String>,
+    git: Option<String>,
+) -> (Option<String>, AuthorSource) {
+    let candidates = vec![
+        (flag, AuthorSource::Flag),
+        (config, AuthorSource::Config),
+        (git, AuthorSource::Git),
+    ];
+
+    for (author, source) in candidates {
+        if let Some(author) = author.map(|author| author.trim().to_string()) {
+            if !author.is_empty() {
+                return (Some(author), source);
+            }
+        }
+    }
+
+    (None, AuthorSource::Placeholder)
+}
+
+/// Reads the `user.name` from the git configuration, if git is installed and it is set.
+pub fn read_git_username() -> Option<String> {
+    let output = process::Command::new("git")
+        .args(&["config", "user.name"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout).ok()
 }
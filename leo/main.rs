@@ -27,6 +27,7 @@ use commands::{
     Clean,
     Command,
     Deploy,
+    Graph,
     Init,
     Lint,
     New,
@@ -35,6 +36,7 @@ use commands::{
     Setup,
     Test,
     Update,
+    VerifyInput,
     Watch,
 };
 
@@ -86,6 +88,18 @@ enum CommandOpts {
         command: Build,
     },
 
+    #[structopt(about = "Print the call graph of the current package in Graphviz format")]
+    Graph {
+        #[structopt(flatten)]
+        command: Graph,
+    },
+
+    #[structopt(about = "Check a program input file against the signature of `main`")]
+    VerifyInput {
+        #[structopt(flatten)]
+        command: VerifyInput,
+    },
+
     #[structopt(about = "Run a program setup")]
     Setup {
         #[structopt(flatten)]
@@ -200,6 +214,8 @@ fn main() {
         CommandOpts::Init { command } => command.try_execute(context),
         CommandOpts::New { command } => command.try_execute(context),
         CommandOpts::Build { command } => command.try_execute(context),
+        CommandOpts::Graph { command } => command.try_execute(context),
+        CommandOpts::VerifyInput { command } => command.try_execute(context),
         CommandOpts::Setup { command } => command.try_execute(context),
         CommandOpts::Prove { command } => command.try_execute(context),
         CommandOpts::Test { command } => command.try_execute(context),
@@ -14,24 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 
 use anyhow::Result;
+use structopt::{clap::ErrorKind, StructOpt};
 
 use crate::{
     commands::{
         package::{Login, Logout},
         Build,
+        BuildTarget,
         Command,
+        New,
         Prove,
         Run,
         Setup,
         Test,
+        TestRerun,
         Update,
         UpdateAutomatic,
     },
+    config::{resolve_author_from, AuthorSource, CredentialStore},
     context::{create_context, Context},
 };
+use leo_package::source::Template;
+use notify::DebouncedEvent;
 
 /// Path to the only complex Leo program that we have
 /// - relative to source dir - where Cargo.toml is located
@@ -39,13 +46,150 @@ const PEDERSEN_HASH_PATH: &str = "./examples/pedersen-hash/";
 
 #[test]
 pub fn build_pedersen_hash() -> Result<()> {
-    (Build {}).apply(context()?, ())?;
+    Build::default().apply(context()?, ())?;
+    Ok(())
+}
+
+#[test]
+pub fn build_pedersen_hash_with_target() -> Result<()> {
+    let target = "edwards_bls12".parse::<BuildTarget>()?;
+    assert_eq!(target, BuildTarget::EdwardsBls12);
+
+    let build = Build {
+        target,
+        ..Default::default()
+    };
+    assert!(build.apply(context()?, ())?.is_some());
+    Ok(())
+}
+
+#[test]
+pub fn build_second_target() -> Result<()> {
+    let dir = temp_dir("build_second_target")?;
+
+    let new = New {
+        name: "hello".to_string(),
+        lib: false,
+        author: None,
+        template: None,
+    };
+    new.apply(create_context(dir.clone())?, ())?;
+
+    let build = Build {
+        target: "edwards_sw6".parse::<BuildTarget>()?,
+        ..Default::default()
+    };
+    // Programs of a non-default target are built, but not handed on to `leo setup`.
+    assert!(build.apply(create_context(dir.join("hello"))?, ())?.is_none());
+    assert!(dir.join("hello/outputs/hello.json").exists());
+
+    Ok(())
+}
+
+#[test]
+pub fn build_unsupported_target() {
+    let error = "bls12_381".parse::<BuildTarget>().unwrap_err();
+
+    assert!(error.to_string().contains("Unsupported build target `bls12_381`"));
+    assert!(error.to_string().contains("edwards_bls12, edwards_sw6"));
+}
+
+#[test]
+pub fn build_target_flag() {
+    let build = Build::from_iter_safe(&["build", "--target", "edwards_bls12"]).unwrap();
+    assert_eq!(build.target, BuildTarget::EdwardsBls12);
+
+    let build = Build::from_iter_safe(&["build", "--target", "edwards_sw6"]).unwrap();
+    assert_eq!(build.target, BuildTarget::EdwardsSw6);
+
+    let error = Build::from_iter_safe(&["build", "--target", "bls12_381"]).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::InvalidValue);
+    assert!(error.message.contains("edwards_bls12"));
+}
+
+#[test]
+pub fn build_pedersen_hash_dump_constraints() -> Result<()> {
+    let dump_path = std::env::temp_dir().join("pedersen_hash_constraints.r1cs");
+
+    let build = Build {
+        dump_constraints: Some(dump_path.clone()),
+        ..Default::default()
+    };
+    build.apply(context()?, ())?;
+
+    let dump = fs::read_to_string(&dump_path)?;
+    assert!(dump.starts_with("public_variables "));
+    assert!(dump.contains("constraint 0\nA: "));
+
+    fs::remove_file(dump_path)?;
+    Ok(())
+}
+
+#[test]
+pub fn build_pedersen_hash_emit_witness() -> Result<()> {
+    let witness_path = std::env::temp_dir().join("pedersen_hash_witness.txt");
+
+    let build = Build {
+        emit_witness: Some(witness_path.clone()),
+        ..Default::default()
+    };
+    build.apply(context()?, ())?;
+
+    let witness = fs::read_to_string(&witness_path)?;
+    let count = |header: &str| -> usize {
+        witness
+            .lines()
+            .find_map(|line| line.strip_prefix(header))
+            .and_then(|count| count.parse().ok())
+            .unwrap()
+    };
+    let public_variables = count("public_variables ");
+    let private_variables = count("private_variables ");
+
+    assert!(private_variables > 0);
+    assert_eq!(
+        witness.lines().filter(|line| line.starts_with("public[")).count(),
+        public_variables
+    );
+    assert_eq!(
+        witness.lines().filter(|line| line.starts_with("private[")).count(),
+        private_variables
+    );
+
+    fs::remove_file(witness_path)?;
+    Ok(())
+}
+
+#[test]
+pub fn build_pedersen_hash_from_stdin() -> Result<()> {
+    let source = fs::read_to_string(PathBuf::from(PEDERSEN_HASH_PATH).join("src/main.leo"))?;
+
+    let build = Build {
+        source: Some(PathBuf::from("-")),
+        ..Default::default()
+    };
+    let (program, _) = build.apply_with_stdin(context()?, source.as_bytes())?.unwrap();
+
+    assert_eq!(program.ast().functions.len(), 1);
+    Ok(())
+}
+
+#[test]
+pub fn build_from_stdin_syntax_error() -> Result<()> {
+    let build = Build {
+        source: Some(PathBuf::from("-")),
+        ..Default::default()
+    };
+
+    assert!(build
+        .apply_with_stdin(context()?, "function main( {".as_bytes())
+        .is_err());
     Ok(())
 }
 
 #[test]
 pub fn setup_pedersen_hash() -> Result<()> {
-    let build = (Build {}).apply(context()?, ())?;
+    let build = Build::default().apply(context()?, ())?;
     (Setup { skip_key_check: false }).apply(context()?, build.clone())?;
     (Setup { skip_key_check: true }).apply(context()?, build)?;
     Ok(())
@@ -53,7 +197,7 @@ pub fn setup_pedersen_hash() -> Result<()> {
 
 #[test]
 pub fn prove_pedersen_hash() -> Result<()> {
-    let build = (Build {}).apply(context()?, ())?;
+    let build = Build::default().apply(context()?, ())?;
     let setup = (Setup { skip_key_check: false }).apply(context()?, build)?;
     (Prove { skip_key_check: false }).apply(context()?, setup.clone())?;
     (Prove { skip_key_check: true }).apply(context()?, setup)?;
@@ -62,7 +206,7 @@ pub fn prove_pedersen_hash() -> Result<()> {
 
 #[test]
 pub fn run_pedersen_hash() -> Result<()> {
-    let build = (Build {}).apply(context()?, ())?;
+    let build = Build::default().apply(context()?, ())?;
     let setup = (Setup { skip_key_check: false }).apply(context()?, build)?;
     let prove = (Prove { skip_key_check: false }).apply(context()?, setup)?;
     (Run { skip_key_check: false }).apply(context()?, prove.clone())?;
@@ -80,6 +224,32 @@ pub fn test_pedersen_hash() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn test_rerun_reports_only_changes() -> Result<()> {
+    let test = Test { files: vec![] };
+    let mut rerun = TestRerun::default();
+
+    assert_eq!(rerun.rerun(&test, &context()?)?.len(), 1);
+    assert!(rerun.rerun(&test, &context()?)?.is_empty());
+    assert_eq!(rerun.runs(), 2);
+    Ok(())
+}
+
+#[test]
+pub fn build_watch_test_reruns_on_write() -> Result<()> {
+    let main_file = PathBuf::from(PEDERSEN_HASH_PATH).join("src/main.leo");
+    let events = vec![
+        DebouncedEvent::NoticeWrite(main_file.clone()),
+        DebouncedEvent::Write(main_file),
+    ];
+
+    let rerun = Build::default().watch_tests_on(context()?, events)?;
+
+    // The tests run once on start and again for the write.
+    assert_eq!(rerun.runs(), 2);
+    Ok(())
+}
+
 #[test]
 pub fn test_logout() -> Result<()> {
     (Logout {}).apply(context()?, ())?;
@@ -149,6 +319,148 @@ pub fn leo_update_and_update_automatic() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn new_from_template() -> Result<()> {
+    let dir = temp_dir("new_from_template")?;
+
+    let new = New {
+        name: "token".to_string(),
+        lib: false,
+        author: None,
+        template: Some("token".to_string()),
+    };
+    new.apply(create_context(dir.clone())?, ())?;
+
+    let main = fs::read_to_string(dir.join("token/src/main.leo"))?;
+    assert_eq!(main, Template::find("token").unwrap().main);
+
+    Ok(())
+}
+
+#[test]
+pub fn new_from_unknown_template() -> Result<()> {
+    let dir = temp_dir("new_from_unknown_template")?;
+
+    let new = New {
+        name: "unknown".to_string(),
+        lib: false,
+        author: None,
+        template: Some("unknown".to_string()),
+    };
+    let result = new.apply(create_context(dir.clone())?, ());
+
+    assert!(result.unwrap_err().to_string().contains("token"));
+    assert!(!dir.join("unknown").exists());
+
+    Ok(())
+}
+
+#[test]
+pub fn new_writes_gitignore_and_readme() -> Result<()> {
+    let dir = temp_dir("new_writes_gitignore_and_readme")?;
+
+    for (name, lib) in &[("binary", false), ("library", true)] {
+        let new = New {
+            name: name.to_string(),
+            lib: *lib,
+            author: None,
+            template: None,
+        };
+        new.apply(create_context(dir.clone())?, ())?;
+
+        let package = dir.join(name);
+        assert_eq!(fs::read_to_string(package.join(".gitignore"))?, "outputs/\n");
+        assert!(fs::read_to_string(package.join("README.md"))?.starts_with(&format!("# {}\n", name)));
+        assert_eq!(package.join("src/lib.leo").exists(), *lib);
+        assert_eq!(package.join("src/main.leo").exists(), !*lib);
+    }
+
+    Ok(())
+}
+
+#[test]
+pub fn new_with_author_flag() -> Result<()> {
+    let dir = temp_dir("new_with_author_flag")?;
+
+    let new = New {
+        name: "authored".to_string(),
+        lib: false,
+        author: Some("flag-user".to_string()),
+        template: None,
+    };
+    new.apply(create_context(dir.clone())?, ())?;
+
+    let manifest = fs::read_to_string(dir.join("authored/Leo.toml"))?;
+    assert!(manifest.contains("author = \"flag-user\""));
+
+    Ok(())
+}
+
+#[test]
+pub fn author_flag_overrides_config() {
+    let (author, source) = resolve_author_from(
+        Some("flag-user".to_string()),
+        Some("config-user".to_string()),
+        Some("git-user".to_string()),
+    );
+
+    assert_eq!(author.as_deref(), Some("flag-user"));
+    assert_eq!(source, AuthorSource::Flag);
+
+    let (author, source) = resolve_author_from(None, Some("config-user".to_string()), Some("git-user".to_string()));
+
+    assert_eq!(author.as_deref(), Some("config-user"));
+    assert_eq!(source, AuthorSource::Config);
+
+    let (author, source) = resolve_author_from(None, None, Some("git-user\n".to_string()));
+
+    assert_eq!(author.as_deref(), Some("git-user"));
+    assert_eq!(source, AuthorSource::Git);
+}
+
+#[test]
+pub fn author_placeholder_fallback() {
+    let (author, source) = resolve_author_from(None, Some(" ".to_string()), None);
+
+    assert_eq!(author, None);
+    assert_eq!(source, AuthorSource::Placeholder);
+}
+
+#[test]
+pub fn credential_store_write_and_remove() -> Result<()> {
+    let store = CredentialStore::new(temp_dir("credential_store")?.join(".leo"));
+
+    store.write("token", "user")?;
+    assert_eq!(store.read_token()?, "token");
+    assert_eq!(store.read_username()?, "user");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs::metadata(store.credentials_path())?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    store.remove()?;
+    assert!(!store.credentials_path().exists());
+    assert!(!store.username_path().exists());
+    assert!(store.read_token().is_err());
+
+    Ok(())
+}
+
+/// Create an empty temporary directory for a test
+fn temp_dir(name: &str) -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join("leo-tests").join(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+
+    Ok(dir)
+}
+
 /// Create context for Pedersen Hash example
 fn context() -> Result<Context> {
     let path = PathBuf::from(&PEDERSEN_HASH_PATH);
@@ -25,6 +25,12 @@ pub enum PackageError {
 
     #[error("Invalid project name: {:?}", _0)]
     InvalidPackageName(String),
+
+    #[error("Cannot initialize library package {:?} from a template", _0)]
+    TemplateForLibrary(String),
+
+    #[error("Unknown template {:?}, available templates: {}", _0, _1)]
+    UnknownTemplate(String, String),
 }
 
 impl From<std::io::Error> for PackageError {
@@ -16,7 +16,7 @@
 
 //! The `program.in` file.
 
-use crate::{errors::InputFileError, inputs::INPUTS_DIRECTORY_NAME};
+use crate::{errors::InputFileError, inputs::INPUTS_DIRECTORY_NAME, source::Template};
 
 use serde::Deserialize;
 use std::{
@@ -34,12 +34,23 @@ pub static INPUT_FILE_EXTENSION: &str = ".in";
 #[derive(Deserialize)]
 pub struct InputFile {
     pub package_name: String,
+    #[serde(skip)]
+    pub template: Option<&'static Template>,
 }
 
 impl InputFile {
     pub fn new(package_name: &str) -> Self {
         Self {
             package_name: package_name.to_string(),
+            template: None,
+        }
+    }
+
+    /// Creates an input file whose contents are taken from the given template.
+    pub fn from_template(package_name: &str, template: &'static Template) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+            template: Some(template),
         }
     }
 
@@ -70,7 +81,19 @@ impl InputFile {
         Ok(file.write_all(self.template().as_bytes())?)
     }
 
+    /// Writes the given contents to the input file.
+    pub fn write_contents_to(self, path: &Path, contents: &str) -> Result<(), InputFileError> {
+        let path = self.setup_file_path(path);
+
+        let mut file = File::create(&path)?;
+        Ok(file.write_all(contents.as_bytes())?)
+    }
+
     fn template(&self) -> String {
+        if let Some(template) = self.template {
+            return template.input.to_string();
+        }
+
         format!(
             r#"// The program input for {}/src/main.leo
 [main]
@@ -42,6 +42,24 @@ impl LeoPackage {
         package::Package::initialize(package_name, is_lib, path, author)
     }
 
+    /// Initializes a Leo package at the given path from the embedded template with the given name.
+    pub fn initialize_with_template(
+        package_name: &str,
+        is_lib: bool,
+        path: &Path,
+        author: Option<String>,
+        template: Option<&str>,
+    ) -> Result<(), PackageError> {
+        let template = match template {
+            Some(name) => Some(source::Template::find(name).ok_or_else(|| {
+                PackageError::UnknownTemplate(name.to_string(), source::Template::names().join(", "))
+            })?),
+            None => None,
+        };
+
+        package::Package::initialize_with_template(package_name, is_lib, path, author, template)
+    }
+
     /// Returns `true` if the given Leo package name is valid.
     pub fn is_package_name_valid(package_name: &str) -> bool {
         package::Package::is_package_name_valid(package_name)
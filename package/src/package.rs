@@ -19,7 +19,7 @@ use crate::{
     imports::ImportsDirectory,
     inputs::{InputFile, InputsDirectory, StateFile},
     root::{Gitignore, Manifest, README},
-    source::{LibraryFile, MainFile, SourceDirectory},
+    source::{LibraryFile, MainFile, SourceDirectory, Template},
 };
 
 use serde::Deserialize;
@@ -203,6 +203,22 @@ impl Package {
         path: &Path,
         author: Option<String>,
     ) -> Result<(), PackageError> {
+        Self::initialize_with_template(package_name, is_lib, path, author, None)
+    }
+
+    /// Creates a package at the given path, taking the source and input files from a template if one is given.
+    pub fn initialize_with_template(
+        package_name: &str,
+        is_lib: bool,
+        path: &Path,
+        author: Option<String>,
+        template: Option<&'static Template>,
+    ) -> Result<(), PackageError> {
+        // Templates only provide binary packages.
+        if is_lib && template.is_some() {
+            return Err(PackageError::TemplateForLibrary(package_name.to_owned()));
+        }
+
         // First, verify that this directory is not already initialized as a Leo package.
         {
             if !Self::can_initialize(package_name, is_lib, path) {
@@ -241,13 +257,19 @@ impl Package {
                 InputsDirectory::create(&path)?;
 
                 // Create the input file in the inputs directory.
-                InputFile::new(&package_name).write_to(&path)?;
+                match template {
+                    Some(template) => InputFile::from_template(&package_name, template).write_to(&path)?,
+                    None => InputFile::new(&package_name).write_to(&path)?,
+                }
 
                 // Create the state file in the inputs directory.
                 StateFile::new(&package_name).write_to(&path)?;
 
                 // Create the main file in the source directory.
-                MainFile::new(&package_name).write_to(&path)?;
+                match template {
+                    Some(template) => MainFile::from_template(&package_name, template).write_to(&path)?,
+                    None => MainFile::new(&package_name).write_to(&path)?,
+                }
             }
         }
         // Next, verify that a valid Leo package has been initialized in this directory
@@ -16,7 +16,10 @@
 
 //! The `main.leo` file.
 
-use crate::{errors::MainFileError, source::directory::SOURCE_DIRECTORY_NAME};
+use crate::{
+    errors::MainFileError,
+    source::{directory::SOURCE_DIRECTORY_NAME, Template},
+};
 
 use serde::Deserialize;
 use std::{borrow::Cow, fs::File, io::Write, path::Path};
@@ -26,12 +29,23 @@ pub static MAIN_FILENAME: &str = "main.leo";
 #[derive(Deserialize)]
 pub struct MainFile {
     pub package_name: String,
+    #[serde(skip)]
+    pub template: Option<&'static Template>,
 }
 
 impl MainFile {
     pub fn new(package_name: &str) -> Self {
         Self {
             package_name: package_name.to_string(),
+            template: None,
+        }
+    }
+
+    /// Creates a main file whose contents are taken from the given template.
+    pub fn from_template(package_name: &str, template: &'static Template) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+            template: Some(template),
         }
     }
 
@@ -64,6 +78,10 @@ impl MainFile {
     }
 
     fn template(&self) -> String {
+        if let Some(template) = self.template {
+            return template.main.to_string();
+        }
+
         format!(
             r#"// The '{}' main function.
 function main(a: u32, b: u32) -> u32 {{
@@ -22,3 +22,6 @@ pub use library::*;
 
 pub mod main;
 pub use main::*;
+
+pub mod template;
+pub use template::*;
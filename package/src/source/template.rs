@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A named project template that can be used to scaffold a new package.
+pub struct Template {
+    pub name: &'static str,
+    pub main: &'static str,
+    pub input: &'static str,
+}
+
+/// The templates embedded in this crate.
+pub static TEMPLATES: &[Template] = &[Template {
+    name: "token",
+    main: include_str!("templates/token/main.leo"),
+    input: include_str!("templates/token/input.in"),
+}];
+
+impl Template {
+    /// Returns the embedded template with the given name, if one exists.
+    pub fn find(name: &str) -> Option<&'static Template> {
+        TEMPLATES.iter().find(|template| template.name == name)
+    }
+
+    /// Returns the names of all embedded templates.
+    pub fn names() -> Vec<&'static str> {
+        TEMPLATES.iter().map(|template| template.name).collect()
+    }
+}
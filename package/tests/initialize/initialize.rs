@@ -19,7 +19,7 @@ use leo_package::{
     inputs::{InputFile, InputsDirectory, StateFile},
     package::Package,
     root::Manifest,
-    source::{LibraryFile, MainFile, SourceDirectory},
+    source::{LibraryFile, MainFile, SourceDirectory, Template},
 };
 
 const TEST_PACKAGE_NAME: &str = "test-package";
@@ -60,6 +60,31 @@ fn initialize_valid_package_with_author() {
     assert!(Package::is_initialized(TEST_PACKAGE_NAME, false, &test_directory));
 }
 
+#[test]
+fn initialize_valid_package_with_template() {
+    let test_directory = test_dir();
+    let template = Template::find("token").unwrap();
+
+    // Initialize a package from the template at the `test_directory`
+    assert!(Package::initialize_with_template(TEST_PACKAGE_NAME, false, &test_directory, None, Some(template)).is_ok());
+
+    // Ensure a package is initialized at the `test_directory`
+    assert!(Package::is_initialized(TEST_PACKAGE_NAME, false, &test_directory));
+
+    // Ensure the main file was taken from the template
+    let main = std::fs::read_to_string(test_directory.join(MainFile::filename())).unwrap();
+    assert_eq!(main, template.main);
+}
+
+#[test]
+fn initialize_fails_with_template_for_library() {
+    let test_directory = test_dir();
+    let template = Template::find("token").unwrap();
+
+    // Attempt to initialize a library package from the template at the `test_directory`
+    assert!(Package::initialize_with_template(TEST_PACKAGE_NAME, true, &test_directory, None, Some(template)).is_err());
+}
+
 #[test]
 #[ignore]
 fn initialize_fails_with_invalid_package_names() {
@@ -28,6 +28,9 @@ pub enum SyntaxError {
 
     #[error("{}", _0)]
     DeprecatedError(#[from] DeprecatedError),
+
+    #[error("{}", _0.iter().map(|error| error.to_string()).collect::<Vec<_>>().join("\n\n"))]
+    Multiple(Vec<SyntaxError>),
 }
 
 impl LeoError for SyntaxError {}
@@ -48,10 +51,28 @@ impl SyntaxError {
         SyntaxError::Error(FormattedError::new_from_span(message, span))
     }
 
+    ///
+    /// Returns a single error for the given errors, which must not be empty.
+    ///
+    pub fn from_errors(mut errors: Vec<SyntaxError>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            SyntaxError::Multiple(errors)
+        }
+    }
+
     pub fn unexpected_eof(span: &Span) -> Self {
         Self::new_from_span("unexpected EOF".to_string(), span)
     }
 
+    pub fn expression_too_deep(max_depth: usize, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("expression is nested too deeply, the maximum depth is {}", max_depth),
+            span,
+        )
+    }
+
     pub fn unexpected_whitespace(left: &str, right: &str, span: &Span) -> Self {
         Self::new_from_span(
             format!("Unexpected white space between terms {} and {}", left, right),
@@ -111,4 +132,18 @@ impl SyntaxError {
     pub fn illegal_self_const(span: &Span) -> Self {
         Self::new_from_span("cannot have const self".to_string(), span)
     }
+
+    pub fn unknown_impl_circuit(name: &str, span: &Span) -> Self {
+        Self::new_from_span(
+            format!("cannot implement methods for undeclared circuit '{}'", name),
+            span,
+        )
+    }
+
+    pub fn unbalanced_format_brace(span: &Span) -> Self {
+        Self::new_from_span(
+            "unbalanced brace in format string -- use '{{' and '}}' for literal braces".to_string(),
+            span,
+        )
+    }
 }
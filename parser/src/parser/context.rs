@@ -20,13 +20,26 @@ use crate::{assert_no_whitespace, tokenizer::*, SyntaxError, SyntaxResult, Token
 use leo_ast::*;
 use tendril::format_tendril;
 
+/// The maximum depth that expressions may be nested to by default.
+/// Deeper expressions are rejected, as they may overflow the stack while parsing.
+pub const DEFAULT_MAX_EXPRESSION_DEPTH: usize = 32;
+
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
 pub struct ParserContext {
     tokens: Vec<SpannedToken>,
+    // the number of `{` left unclosed before each token, indexed like `tokens`
+    brace_depths: Vec<usize>,
     end_span: Span,
     // true if parsing an expression for an if statement -- means circuit inits are not legal
     pub(crate) fuzzy_struct_state: bool,
+    // errors that parsing has recovered from, reported once parsing is finished
+    pub(crate) errors: Vec<SyntaxError>,
+    // comments removed from the tokens, associated with the token that follows them
+    comments: Comments,
+    // the number of expressions currently being parsed within each other
+    pub(crate) expression_depth: usize,
+    max_expression_depth: usize,
 }
 
 impl Iterator for ParserContext {
@@ -42,12 +55,28 @@ impl ParserContext {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     ///
     pub fn new(mut tokens: Vec<SpannedToken>) -> Self {
+        let comments = collect_comments(&tokens);
         tokens.reverse();
         // todo: performance optimization here: drain filter
         tokens = tokens
             .into_iter()
             .filter(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)))
             .collect();
+        let mut depth = 0usize;
+        let mut brace_depths = tokens
+            .iter()
+            .rev()
+            .map(|token| {
+                let token_depth = depth;
+                match token.token {
+                    Token::LeftCurly => depth += 1,
+                    Token::RightCurly => depth = depth.saturating_sub(1),
+                    _ => (),
+                }
+                token_depth
+            })
+            .collect::<Vec<usize>>();
+        brace_depths.reverse();
         ParserContext {
             end_span: tokens
                 .iter()
@@ -55,7 +84,107 @@ impl ParserContext {
                 .map(|x| x.span.clone())
                 .unwrap_or_default(),
             tokens,
+            brace_depths,
             fuzzy_struct_state: false,
+            errors: Vec::new(),
+            comments,
+            expression_depth: 0,
+            max_expression_depth: DEFAULT_MAX_EXPRESSION_DEPTH,
+        }
+    }
+
+    ///
+    /// Returns the comments of the tokens, which are not part of the parsed AST.
+    ///
+    pub fn comments(&self) -> &Comments {
+        &self.comments
+    }
+
+    ///
+    /// Sets the maximum depth that expressions may be nested to before parsing fails.
+    ///
+    pub fn set_max_expression_depth(&mut self, max_expression_depth: usize) {
+        self.max_expression_depth = max_expression_depth;
+    }
+
+    ///
+    /// Returns an expression too deep [`SyntaxError`] if parsing `additional` more nested
+    /// expressions would exceed the maximum expression depth.
+    ///
+    pub fn check_expression_depth(&self, additional: usize) -> SyntaxResult<()> {
+        if self.expression_depth + additional > self.max_expression_depth {
+            let span = self.tokens.last().map(|x| &x.span).unwrap_or(&self.end_span);
+            return Err(SyntaxError::expression_too_deep(self.max_expression_depth, span));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the given parsing result if no errors were recovered from while parsing.
+    /// Otherwise, returns the recovered errors along with the error in the result, if any.
+    ///
+    pub fn finish<T>(&mut self, result: SyntaxResult<T>) -> SyntaxResult<T> {
+        let mut errors = std::mem::take(&mut self.errors);
+        match result {
+            Ok(_) if errors.is_empty() => result,
+            Ok(_) => Err(SyntaxError::from_errors(errors)),
+            Err(error) => {
+                errors.push(error);
+                Err(SyntaxError::from_errors(errors))
+            }
+        }
+    }
+
+    ///
+    /// Skips the tokens of a statement that failed to parse, up to and including the next `;`
+    /// or the end of a block. Stops before a `}` that closes the enclosing block.
+    ///
+    pub fn recover_statement(&mut self) {
+        let mut depth = 0usize;
+        while let Some(token) = self.tokens.last() {
+            match token.token {
+                Token::LeftCurly => depth += 1,
+                Token::RightCurly if depth == 0 => return,
+                Token::RightCurly => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.tokens.pop();
+                        return;
+                    }
+                }
+                Token::Semicolon if depth == 0 => {
+                    self.tokens.pop();
+                    return;
+                }
+                _ => (),
+            }
+            self.tokens.pop();
+        }
+    }
+
+    ///
+    /// Skips the tokens of a top-level item that failed to parse, up to the start of the next item.
+    /// `remaining` is the number of tokens left before the item was parsed.
+    ///
+    /// Items nested in braces, such as circuit member functions, are skipped with the failed item.
+    ///
+    pub fn recover_item(&mut self, remaining: usize) {
+        let item_depth = remaining.checked_sub(1).map_or(0, |index| self.brace_depths[index]);
+
+        // Always make progress, even if the error occurred on the first token of the item.
+        if self.tokens.len() == remaining {
+            self.tokens.pop();
+        }
+        while let Some(token) = self.tokens.last() {
+            if self.brace_depths[self.tokens.len() - 1] <= item_depth
+                && matches!(
+                    token.token,
+                    Token::Import | Token::Circuit | Token::Impl | Token::Function | Token::At
+                )
+            {
+                return;
+            }
+            self.tokens.pop();
         }
     }
 
@@ -104,6 +233,13 @@ impl ParserContext {
         !self.tokens.is_empty()
     }
 
+    ///
+    /// Returns the number of tokens left to parse.
+    ///
+    pub fn remaining(&self) -> usize {
+        self.tokens.len()
+    }
+
     ///
     /// Removes the next token if it exists and returns it, or [None] if
     /// the next token does not exist.
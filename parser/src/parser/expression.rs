@@ -61,6 +61,16 @@ impl ParserContext {
     /// Otherwise, tries to parse the next token using [`parse_disjunctive_expression`].
     ///
     pub fn parse_conditional_expression(&mut self) -> SyntaxResult<Expression> {
+        // Every nested expression is parsed through here, so limit the depth of recursion.
+        self.check_expression_depth(1)?;
+        self.expression_depth += 1;
+        let result = self.parse_conditional_expression_inner();
+        self.expression_depth -= 1;
+
+        result
+    }
+
+    fn parse_conditional_expression_inner(&mut self) -> SyntaxResult<Expression> {
         // Try to parse the next expression. Try BinaryOperation::Or.
         let mut expr = self.parse_disjunctive_expression()?;
 
@@ -358,6 +368,7 @@ impl ParserContext {
         while let Some(token) = self.eat_any(&[Token::Not, Token::Minus]) {
             ops.push(token);
         }
+        self.check_expression_depth(ops.len())?;
         let mut inner = self.parse_postfix_expression()?;
         for op in ops.into_iter().rev() {
             let operation = match op.token {
@@ -403,8 +414,9 @@ impl ParserContext {
         while let Some(token) = self.eat_any(&[Token::LeftSquare, Token::Dot, Token::LeftParen, Token::DoubleColon]) {
             match token.token {
                 Token::LeftSquare => {
-                    if self.eat(Token::DotDot).is_some() {
-                        let right = if self.peek_token().as_ref() != &Token::RightSquare {
+                    if let Some(range) = self.eat_any(&[Token::DotDot, Token::DotDotEq]) {
+                        let inclusive = range.token == Token::DotDotEq;
+                        let right = if inclusive || self.peek_token().as_ref() != &Token::RightSquare {
                             Some(Box::new(self.parse_expression()?))
                         } else {
                             None
@@ -416,13 +428,15 @@ impl ParserContext {
                             array: Box::new(expr),
                             left: None,
                             right,
+                            inclusive,
                         });
                         continue;
                     }
 
                     let left = self.parse_expression()?;
-                    if self.eat(Token::DotDot).is_some() {
-                        let right = if self.peek_token().as_ref() != &Token::RightSquare {
+                    if let Some(range) = self.eat_any(&[Token::DotDot, Token::DotDotEq]) {
+                        let inclusive = range.token == Token::DotDotEq;
+                        let right = if inclusive || self.peek_token().as_ref() != &Token::RightSquare {
                             Some(Box::new(self.parse_expression()?))
                         } else {
                             None
@@ -434,6 +448,7 @@ impl ParserContext {
                             array: Box::new(expr),
                             left: Some(Box::new(left)),
                             right,
+                            inclusive,
                         });
                     } else {
                         let end = self.expect(Token::RightSquare)?;
@@ -517,6 +532,7 @@ impl ParserContext {
     ///
     pub fn parse_circuit_expression(&mut self, identifier: Identifier) -> SyntaxResult<Expression> {
         self.expect(Token::LeftCurly)?;
+        let mut base = None;
         let mut members = Vec::new();
         let end_span;
         loop {
@@ -524,18 +540,26 @@ impl ParserContext {
                 end_span = end.span;
                 break;
             }
-            let name = self.expect_ident()?;
-            if self.eat(Token::Colon).is_some() {
-                let expression = self.parse_expression()?;
-                members.push(CircuitImpliedVariableDefinition {
-                    identifier: name,
-                    expression: Some(expression),
-                });
+            if let Some(spread) = self.eat(Token::DotDot) {
+                // Members that are not given explicitly are taken from the base value.
+                if base.is_some() {
+                    return Err(SyntaxError::unexpected_str(&spread.token, "ident", &spread.span));
+                }
+                base = Some(Box::new(self.parse_expression()?));
             } else {
-                members.push(CircuitImpliedVariableDefinition {
-                    identifier: name.clone(),
-                    expression: None,
-                });
+                let name = self.expect_ident()?;
+                if self.eat(Token::Colon).is_some() {
+                    let expression = self.parse_expression()?;
+                    members.push(CircuitImpliedVariableDefinition {
+                        identifier: name,
+                        expression: Some(expression),
+                    });
+                } else {
+                    members.push(CircuitImpliedVariableDefinition {
+                        identifier: name.clone(),
+                        expression: None,
+                    });
+                }
             }
             if self.eat(Token::Comma).is_none() {
                 end_span = self.expect(Token::RightCurly)?;
@@ -545,6 +569,7 @@ impl ParserContext {
         Ok(Expression::CircuitInit(CircuitInitExpression {
             span: &identifier.span + &end_span,
             name: identifier,
+            base,
             members,
         }))
     }
@@ -615,6 +640,27 @@ impl ParserContext {
                 element: Box::new(first),
                 dimensions,
             }))
+        } else if self.eat(Token::Pipe).is_some() {
+            let element = match first {
+                SpreadOrExpression::Spread(first) => {
+                    let span = span + first.span();
+                    return Err(SyntaxError::spread_in_array_init(&span));
+                }
+                SpreadOrExpression::Expression(x) => x,
+            };
+            let variable = self.expect_ident()?;
+            self.expect(Token::In)?;
+            let start = self.parse_expression()?;
+            self.expect(Token::DotDot)?;
+            let stop = self.parse_expression()?;
+            let end = self.expect(Token::RightSquare)?;
+            Ok(Expression::ArrayComprehension(ArrayComprehensionExpression {
+                span: span + &end,
+                element: Box::new(element),
+                variable,
+                start: Box::new(start),
+                stop: Box::new(stop),
+            }))
         } else {
             let end_span;
             let mut elements = vec![first];
@@ -643,10 +689,35 @@ impl ParserContext {
         }
     }
 
+    ///
+    /// Returns an [`Expression`] AST node if the next tokens represent a
+    /// lambda expression, `|acc, x| acc + x`.
+    ///
+    pub fn parse_lambda_expression(&mut self, span: &Span) -> SyntaxResult<Expression> {
+        let mut parameters = Vec::new();
+        loop {
+            if self.eat(Token::Pipe).is_some() {
+                break;
+            }
+            parameters.push(self.expect_ident()?);
+            if self.eat(Token::Comma).is_none() {
+                self.expect(Token::Pipe)?;
+                break;
+            }
+        }
+        let body = self.parse_expression()?;
+        Ok(Expression::Lambda(LambdaExpression {
+            span: span + body.span(),
+            parameters,
+            body: Box::new(body),
+        }))
+    }
+
     ///
     /// Returns an [`Expression`] AST node if the next token is a primary expression:
     /// - Literals: field, group, unsigned integer, signed integer, boolean, address
     /// - Aggregate types: array, tuple
+    /// - Lambdas
     /// - Identifiers: variables, keywords
     /// - self
     ///
@@ -691,6 +762,7 @@ impl ParserContext {
             Token::AddressLit(value) => Expression::Value(ValueExpression::Address(value, span)),
             Token::LeftParen => self.parse_tuple_expression(&span)?,
             Token::LeftSquare => self.parse_array_expression(&span)?,
+            Token::Pipe => self.parse_lambda_expression(&span)?,
             Token::Ident(name) => {
                 let ident = Identifier { name, span };
                 if !self.fuzzy_struct_state && self.peek_token().as_ref() == &Token::LeftCurly {
@@ -710,6 +782,23 @@ impl ParserContext {
                     Expression::Identifier(ident)
                 }
             }
+            Token::I8
+            | Token::I16
+            | Token::I32
+            | Token::I64
+            | Token::I128
+            | Token::U8
+            | Token::U16
+            | Token::U32
+            | Token::U64
+            | Token::U128
+                if self.peek_token().as_ref() == &Token::DoubleColon =>
+            {
+                Expression::Identifier(Identifier {
+                    name: token.to_string().into(),
+                    span,
+                })
+            }
             Token::Input | Token::LittleSelf => {
                 let ident = Identifier {
                     name: token.to_string().into(),
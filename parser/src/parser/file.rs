@@ -31,52 +31,80 @@ impl ParserContext {
         // let mut tests = IndexMap::new();
 
         while self.has_next() {
-            let token = self.peek()?;
-            match &token.token {
-                Token::Import => {
-                    imports.push(self.parse_import()?);
-                }
-                Token::Circuit => {
-                    let (id, circuit) = self.parse_circuit()?;
-                    circuits.insert(id, circuit);
-                }
-                Token::Function | Token::At => {
-                    let (id, function) = self.parse_function_declaration()?;
-                    functions.insert(id, function);
-                }
-                Token::Ident(ident) if ident.as_ref() == "test" => {
-                    return Err(SyntaxError::DeprecatedError(DeprecatedError::test_function(
-                        &token.span,
-                    )));
-                    // self.expect(Token::Test)?;
-                    // let (id, function) = self.parse_function_declaration()?;
-                    // tests.insert(id, TestFunction {
-                    //     function,
-                    //     input_file: None,
-                    // });
-                }
-                _ => {
-                    return Err(SyntaxError::unexpected(
-                        &token.token,
-                        &[
-                            Token::Import,
-                            Token::Circuit,
-                            Token::Function,
-                            Token::Ident("test".into()),
-                            Token::At,
-                        ],
-                        &token.span,
-                    ));
-                }
+            let remaining = self.remaining();
+            if let Err(error) = self.parse_item(&mut imports, &mut circuits, &mut functions) {
+                // Report the error once parsing is finished and continue with the next item.
+                self.errors.push(error);
+                self.recover_item(remaining);
             }
         }
-        Ok(Program {
+        let program = Program {
             name: String::new(),
             expected_input: Vec::new(),
             imports,
             circuits,
             functions,
-        })
+        };
+        self.finish(Ok(program))
+    }
+
+    ///
+    /// Parses the next top-level import, circuit, or function into the given collections.
+    ///
+    fn parse_item(
+        &mut self,
+        imports: &mut Vec<ImportStatement>,
+        circuits: &mut IndexMap<Identifier, Circuit>,
+        functions: &mut IndexMap<Identifier, Function>,
+    ) -> SyntaxResult<()> {
+        let token = self.peek()?;
+        match &token.token {
+            Token::Import => {
+                imports.push(self.parse_import()?);
+            }
+            Token::Circuit => {
+                let (id, circuit) = self.parse_circuit()?;
+                circuits.insert(id, circuit);
+            }
+            Token::Impl => {
+                let (id, functions) = self.parse_impl()?;
+                // Identifiers hash their span, so look the circuit up by name.
+                match circuits.values_mut().find(|circuit| circuit.circuit_name == id) {
+                    Some(circuit) => circuit.members.extend(functions),
+                    None => return Err(SyntaxError::unknown_impl_circuit(&id.name, &id.span)),
+                }
+            }
+            Token::Function | Token::At => {
+                let (id, function) = self.parse_function_declaration()?;
+                functions.insert(id, function);
+            }
+            Token::Ident(ident) if ident.as_ref() == "test" => {
+                return Err(SyntaxError::DeprecatedError(DeprecatedError::test_function(
+                    &token.span,
+                )));
+                // self.expect(Token::Test)?;
+                // let (id, function) = self.parse_function_declaration()?;
+                // tests.insert(id, TestFunction {
+                //     function,
+                //     input_file: None,
+                // });
+            }
+            _ => {
+                return Err(SyntaxError::unexpected(
+                    &token.token,
+                    &[
+                        Token::Import,
+                        Token::Circuit,
+                        Token::Impl,
+                        Token::Function,
+                        Token::Ident("test".into()),
+                        Token::At,
+                    ],
+                    &token.span,
+                ));
+            }
+        }
+        Ok(())
     }
 
     ///
@@ -308,6 +336,22 @@ impl ParserContext {
         }))
     }
 
+    ///
+    /// Returns an [`(Identifier, Vec<CircuitMember>)`] tuple of AST nodes if the next tokens
+    /// represent an impl block of member functions for the named circuit.
+    ///
+    pub fn parse_impl(&mut self) -> SyntaxResult<(Identifier, Vec<CircuitMember>)> {
+        self.expect(Token::Impl)?;
+        let name = self.expect_ident()?;
+        self.expect(Token::LeftCurly)?;
+        let mut functions = Vec::new();
+        while self.eat(Token::RightCurly).is_none() {
+            let function = self.parse_function_declaration()?;
+            functions.push(CircuitMember::CircuitFunction(function.1));
+        }
+        Ok((name, functions))
+    }
+
     ///
     /// Returns a [`FunctionInput`] AST node if the next tokens represent a function parameter.
     ///
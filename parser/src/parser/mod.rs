@@ -37,7 +37,23 @@ pub type SyntaxResult<T> = Result<T, SyntaxError>;
 
 /// Creates a new program from a given file path and source code text.
 pub fn parse(path: &str, source: &str) -> SyntaxResult<Program> {
+    parse_with_max_expression_depth(path, source, DEFAULT_MAX_EXPRESSION_DEPTH)
+}
+
+/// Creates a new program from a given file path and source code text,
+/// rejecting expressions nested deeper than `max_expression_depth`.
+pub fn parse_with_max_expression_depth(path: &str, source: &str, max_expression_depth: usize) -> SyntaxResult<Program> {
     let mut tokens = ParserContext::new(crate::tokenize(path, source.into())?);
+    tokens.set_max_expression_depth(max_expression_depth);
 
     tokens.parse_program()
 }
+
+/// Creates a new program from a given file path and source code text,
+/// along with the comments that were removed from it.
+pub fn parse_with_comments(path: &str, source: &str) -> SyntaxResult<(Program, Comments)> {
+    let mut tokens = ParserContext::new(crate::tokenize(path, source.into())?);
+    let comments = tokens.comments().clone();
+
+    Ok((tokens.parse_program()?, comments))
+}
@@ -50,7 +50,7 @@ impl ParserContext {
                 identifier = Self::construct_assignee_access(*expr.tuple, accesses)?;
                 accesses.push(AssigneeAccess::Tuple(expr.index, expr.span));
             }
-            Expression::ArrayRangeAccess(expr) => {
+            Expression::ArrayRangeAccess(expr) if !expr.inclusive => {
                 identifier = Self::construct_assignee_access(*expr.array, accesses)?;
                 accesses.push(AssigneeAccess::ArrayRange(
                     expr.left.map(|x| *x),
@@ -88,9 +88,13 @@ impl ParserContext {
     pub fn parse_statement(&mut self) -> SyntaxResult<Statement> {
         match &self.peek()?.token {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
+            Token::Break => Ok(Statement::Break(self.parse_break_statement()?)),
+            Token::Continue => Ok(Statement::Continue(self.parse_continue_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(self.parse_loop_statement()?)),
             Token::Console => Ok(Statement::Console(self.parse_console_statement()?)),
+            Token::Require => Ok(Statement::Require(self.parse_require_statement()?)),
+            Token::ConstAssert => Ok(Statement::ConstAssert(self.parse_const_assert_statement()?)),
             Token::Let | Token::Const => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
             _ => Ok(self.parse_assign_statement()?),
@@ -148,9 +152,17 @@ impl ParserContext {
         let mut statements = Vec::new();
         loop {
             match self.eat(Token::RightCurly) {
-                None => {
-                    statements.push(self.parse_statement()?);
-                }
+                None => match self.parse_statement() {
+                    Ok(statement) => statements.push(statement),
+                    Err(error) => {
+                        // Report the error once parsing is finished and continue with the next statement.
+                        self.errors.push(error);
+                        self.recover_statement();
+                        if !self.has_next() {
+                            return Err(self.eof());
+                        }
+                    }
+                },
                 Some(end) => {
                     return Ok(Block {
                         span: start + end.span,
@@ -166,7 +178,18 @@ impl ParserContext {
     ///
     pub fn parse_return_statement(&mut self) -> SyntaxResult<ReturnStatement> {
         let start = self.expect(Token::Return)?;
-        let expr = self.parse_expression()?;
+        let mut expr = self.parse_expression()?;
+        // `return a, b;` is sugar for `return (a, b);`.
+        if self.peek_token().as_ref() == &Token::Comma {
+            let mut elements = vec![expr];
+            while self.eat(Token::Comma).is_some() {
+                elements.push(self.parse_expression()?);
+            }
+            expr = Expression::TupleInit(TupleInitExpression {
+                span: elements[0].span() + elements[elements.len() - 1].span(),
+                elements,
+            });
+        }
         self.expect(Token::Semicolon)?;
 
         Ok(ReturnStatement {
@@ -175,6 +198,26 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Returns a [`BreakStatement`] AST node if the next tokens represent a break statement.
+    ///
+    pub fn parse_break_statement(&mut self) -> SyntaxResult<BreakStatement> {
+        let start = self.expect(Token::Break)?;
+        let end = self.expect(Token::Semicolon)?;
+
+        Ok(BreakStatement { span: start + end })
+    }
+
+    ///
+    /// Returns a [`ContinueStatement`] AST node if the next tokens represent a continue statement.
+    ///
+    pub fn parse_continue_statement(&mut self) -> SyntaxResult<ContinueStatement> {
+        let start = self.expect(Token::Continue)?;
+        let end = self.expect(Token::Semicolon)?;
+
+        Ok(ContinueStatement { span: start + end })
+    }
+
     ///
     /// Returns a [`ConditionalStatement`] AST node if the next tokens represent a conditional statement.
     ///
@@ -206,9 +249,16 @@ impl ParserContext {
         let ident = self.expect_ident()?;
         self.expect(Token::In)?;
         let start = self.parse_expression()?;
-        self.expect(Token::DotDot)?;
+        let inclusive = self.expect_oneof(&[Token::DotDot, Token::DotDotEq])?.token == Token::DotDotEq;
         self.fuzzy_struct_state = true;
         let stop = self.parse_conditional_expression()?;
+        let step = match self.peek_token().as_ref() {
+            Token::Ident(ident) if ident.as_ref() == "step" => {
+                self.expect_any()?;
+                Some(self.parse_conditional_expression()?)
+            }
+            _ => None,
+        };
         self.fuzzy_struct_state = false;
         let block = self.parse_block()?;
 
@@ -217,6 +267,8 @@ impl ParserContext {
             variable: ident,
             start,
             stop,
+            inclusive,
+            step,
             block,
         })
     }
@@ -237,19 +289,28 @@ impl ParserContext {
             SpannedToken { token, span } => return Err(SyntaxError::unexpected_str(&token, "formatted string", &span)),
         };
         let mut parameters = Vec::new();
-        while self.eat(Token::Comma).is_some() {
+        while self.eat(Token::Comma).is_some() && self.peek_token().as_ref() != &Token::RightParen {
             let param = self.parse_expression()?;
             parameters.push(param);
         }
 
+        let parts = parts
+            .into_iter()
+            .map(|x| match x {
+                crate::FormatStringPart::Const(value) => {
+                    if !value.contains(['{', '}']) {
+                        return Ok(FormatStringPart::Const(value));
+                    }
+                    unescape_format_braces(&value)
+                        .map(|value| FormatStringPart::Const(value.into()))
+                        .ok_or_else(|| SyntaxError::unbalanced_format_brace(&start_span))
+                }
+                crate::FormatStringPart::Container => Ok(FormatStringPart::Container),
+            })
+            .collect::<SyntaxResult<Vec<_>>>()?;
+
         Ok(FormatString {
-            parts: parts
-                .into_iter()
-                .map(|x| match x {
-                    crate::FormatStringPart::Const(value) => FormatStringPart::Const(value),
-                    crate::FormatStringPart::Container => FormatStringPart::Container,
-                })
-                .collect(),
+            parts,
             span: &start_span + parameters.last().map(|x| x.span()).unwrap_or(&start_span),
             parameters,
         })
@@ -266,15 +327,30 @@ impl ParserContext {
         let function = match &*function.name {
             "assert" => {
                 let expr = self.parse_expression()?;
+                self.eat(Token::Comma);
                 ConsoleFunction::Assert(expr)
             }
+            "assert_eq" => {
+                let left = self.parse_expression()?;
+                self.expect(Token::Comma)?;
+                let right = self.parse_expression()?;
+                self.eat(Token::Comma);
+                ConsoleFunction::AssertEq(left, right)
+            }
+            "assert_neq" => {
+                let left = self.parse_expression()?;
+                self.expect(Token::Comma)?;
+                let right = self.parse_expression()?;
+                self.eat(Token::Comma);
+                ConsoleFunction::AssertNeq(left, right)
+            }
             "debug" => ConsoleFunction::Debug(self.parse_formatted_string()?),
             "error" => ConsoleFunction::Error(self.parse_formatted_string()?),
             "log" => ConsoleFunction::Log(self.parse_formatted_string()?),
             x => {
                 return Err(SyntaxError::unexpected_ident(
                     &x,
-                    &["assert", "debug", "error", "log"],
+                    &["assert", "assert_eq", "assert_neq", "debug", "error", "log"],
                     &function.span,
                 ));
             }
@@ -288,6 +364,41 @@ impl ParserContext {
         })
     }
 
+    ///
+    /// Returns a [`RequireStatement`] AST node if the next tokens represent a require statement.
+    ///
+    pub fn parse_require_statement(&mut self) -> SyntaxResult<RequireStatement> {
+        let keyword = self.expect(Token::Require)?;
+        self.expect(Token::LeftParen)?;
+        let condition = self.parse_expression()?;
+        self.expect(Token::Comma)?;
+        let message = self.parse_formatted_string()?;
+        let end = self.expect(Token::RightParen)?;
+        self.expect(Token::Semicolon)?;
+
+        Ok(RequireStatement {
+            span: keyword + end,
+            condition,
+            message,
+        })
+    }
+
+    ///
+    /// Returns a [`ConstAssertStatement`] AST node if the next tokens represent a const assertion.
+    ///
+    pub fn parse_const_assert_statement(&mut self) -> SyntaxResult<ConstAssertStatement> {
+        let keyword = self.expect(Token::ConstAssert)?;
+        self.expect(Token::LeftParen)?;
+        let condition = self.parse_expression()?;
+        let end = self.expect(Token::RightParen)?;
+        self.expect(Token::Semicolon)?;
+
+        Ok(ConstAssertStatement {
+            span: keyword + end,
+            condition,
+        })
+    }
+
     ///
     /// Returns a [`VariableName`] AST node if the next tokens represent a variable name with
     /// valid keywords.
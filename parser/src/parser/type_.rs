@@ -69,7 +69,7 @@ impl ParserContext {
                     let token = self.peek()?;
                     return Err(SyntaxError::unexpected_str(&token.token, "int", &token.span));
                 }
-                if self.eat(Token::Comma).is_none() {
+                if self.eat(Token::Comma).is_none() || self.peek_token().as_ref() == &Token::RightParen {
                     break;
                 }
             }
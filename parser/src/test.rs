@@ -188,7 +188,8 @@ fn run_individual_expression_test(path: &str, source: &str) -> Result<Expression
     }
     let mut tokens = ParserContext::new(tokenizer);
 
-    let parsed = tokens.parse_expression().map_err(strip_path_syntax_error)?;
+    let parsed = tokens.parse_expression();
+    let parsed = tokens.finish(parsed).map_err(strip_path_syntax_error)?;
     not_fully_consumed(&mut tokens)?;
 
     Ok(parsed)
@@ -207,19 +208,25 @@ fn run_individual_statement_test(path: &str, source: &str) -> Result<Statement,
     }
     let mut tokens = ParserContext::new(tokenizer);
 
-    let parsed = tokens.parse_statement().map_err(strip_path_syntax_error)?;
+    let parsed = tokens.parse_statement();
+    let parsed = tokens.finish(parsed).map_err(strip_path_syntax_error)?;
     not_fully_consumed(&mut tokens)?;
 
     Ok(parsed)
 }
 
-fn strip_path_syntax_error(mut err: SyntaxError) -> String {
-    let inner = match &mut err {
+fn strip_path(err: &mut SyntaxError) {
+    let inner = match err {
         SyntaxError::DeprecatedError(DeprecatedError::Error(x)) => x,
         SyntaxError::Error(x) => x,
         SyntaxError::TokenError(TokenError::Error(x)) => x,
+        SyntaxError::Multiple(errors) => return errors.iter_mut().for_each(strip_path),
     };
     inner.path = Arc::new("test".to_string());
+}
+
+fn strip_path_syntax_error(mut err: SyntaxError) -> String {
+    strip_path(&mut err);
     err.to_string()
 }
 
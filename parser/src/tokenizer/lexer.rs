@@ -205,6 +205,8 @@ impl Token {
             b'.' => {
                 if let Some(len) = eat(input, "...") {
                     return (len, Some(Token::DotDotDot));
+                } else if let Some(len) = eat(input, "..=") {
+                    return (len, Some(Token::DotDotEq));
                 } else if let Some(len) = eat(input, "..") {
                     return (len, Some(Token::DotDot));
                 }
@@ -286,6 +288,7 @@ impl Token {
                 //     return (len, Some(Token::BitOrEq));
                 // }
                 // return (1, Some(Token::BitOr));
+                return (1, Some(Token::Pipe));
             }
             // b'^' => {
             //     if let Some(len) = eat(input, "^=") {
@@ -310,9 +313,12 @@ impl Token {
                     "address" => Token::Address,
                     "as" => Token::As,
                     "bool" => Token::Bool,
+                    "break" => Token::Break,
                     "circuit" => Token::Circuit,
                     "console" => Token::Console,
                     "const" => Token::Const,
+                    "const_assert" => Token::ConstAssert,
+                    "continue" => Token::Continue,
                     "else" => Token::Else,
                     "false" => Token::False,
                     "field" => Token::Field,
@@ -325,11 +331,13 @@ impl Token {
                     "i64" => Token::I64,
                     "i128" => Token::I128,
                     "if" => Token::If,
+                    "impl" => Token::Impl,
                     "import" => Token::Import,
                     "in" => Token::In,
                     "input" => Token::Input,
                     "let" => Token::Let,
                     "mut" => Token::Mut,
+                    "require" => Token::Require,
                     "return" => Token::Return,
                     "Self" => Token::BigSelf,
                     "self" => Token::LittleSelf,
@@ -383,3 +391,19 @@ pub(crate) fn check_address(address: &str) -> bool {
         .skip(5)
         .all(|x| x.is_ascii_lowercase() || x.is_ascii_digit())
 }
+
+///
+/// Returns the given constant part of a format string with `{{` and `}}` unescaped into `{` and `}`.
+/// Returns [`None`] if the part contains a brace that is not escaped.
+///
+pub(crate) fn unescape_format_braces(part: &str) -> Option<String> {
+    let mut out = String::with_capacity(part.len());
+    let mut chars = part.chars();
+    while let Some(c) = chars.next() {
+        if (c == '{' || c == '}') && chars.next() != Some(c) {
+            return None;
+        }
+        out.push(c);
+    }
+    Some(out)
+}
@@ -28,7 +28,7 @@ pub(crate) mod lexer;
 pub(crate) use self::lexer::*;
 
 use crate::TokenError;
-use leo_ast::Span;
+use leo_ast::{Comments, Span};
 use tendril::StrTendril;
 
 /// Creates a new vector of spanned tokens from a given file path and source code text.
@@ -104,6 +104,26 @@ pub(crate) fn tokenize(path: &str, input: StrTendril) -> Result<Vec<SpannedToken
     Ok(tokens)
 }
 
+/// Collects the comments in the given tokens, associating each with the first token that follows it.
+pub(crate) fn collect_comments(tokens: &[SpannedToken]) -> Comments {
+    let mut comments = Comments::default();
+    let mut pending = vec![];
+    for token in tokens {
+        match &token.token {
+            Token::CommentLine(comment) | Token::CommentBlock(comment) => pending.push(comment.clone()),
+            _ => {
+                for comment in pending.drain(..) {
+                    comments.push_leading(token.span.line_start, comment);
+                }
+            }
+        }
+    }
+    for comment in pending {
+        comments.push_trailing(comment);
+    }
+    comments
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +234,7 @@ mod tests {
         {{
         }}
         ||
+        |
         ?
         // test
         /* test */
@@ -225,10 +246,10 @@ mod tests {
         for SpannedToken { token, .. } in tokens.iter() {
             output += &format!("{} ", token.to_string());
         }
-        // & &= | |= ^ ^= ~ << <<= >> >>= >>> >>>= % %= ||= &&=
+        // & &= |= ^ ^= ~ << <<= >> >>= >>> >>>= % %= ||= &&=
         assert_eq!(
             output,
-            r#""test" "test{}test" "test{}" "{}test" "test{" "test}" "test{test" "test}test" "te{{}}" aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8 test_ident 12345 address as bool circuit const else false field for function group i128 i64 i32 i16 i8 if import in input let mut return static string test true u128 u64 u32 u16 u8 self Self console ! != && ( ) * ** **= *= + += , - -= -> _ . .. ... / /= : :: ; < <= = == > >= @ [ ] { { } } || ? // test
+            r#""test" "test{}test" "test{}" "{}test" "test{" "test}" "test{test" "test}test" "te{{}}" aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8 test_ident 12345 address as bool circuit const else false field for function group i128 i64 i32 i16 i8 if import in input let mut return static string test true u128 u64 u32 u16 u8 self Self console ! != && ( ) * ** **= *= + += , - -= -> _ . .. ... / /= : :: ; < <= = == > >= @ [ ] { { } } || | ? // test
  /* test */ // "#
         );
     }
@@ -80,6 +80,7 @@ pub enum Token {
     Comma,
     Dot,
     DotDot,
+    DotDotEq,
     DotDotDot,
     Semicolon,
     Colon,
@@ -87,6 +88,7 @@ pub enum Token {
     Question,
     Arrow,
     Underscore,
+    Pipe,
 
     // Syntactic Grammr
     // Types
@@ -115,16 +117,21 @@ pub enum Token {
 
     // Regular Keywords
     As,
+    Break,
     Circuit,
     Console,
     Const,
+    ConstAssert,
+    Continue,
     Else,
     For,
     Function,
     If,
+    Impl,
     In,
     Let,
     Mut,
+    Require,
     Return,
     Static,
     String,
@@ -156,9 +163,12 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Address,
     Token::As,
     Token::Bool,
+    Token::Break,
     Token::Circuit,
     Token::Console,
     Token::Const,
+    Token::ConstAssert,
+    Token::Continue,
     Token::Else,
     Token::False,
     Token::Field,
@@ -171,11 +181,13 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::I64,
     Token::I128,
     Token::If,
+    Token::Impl,
     Token::Import,
     Token::In,
     Token::Input,
     Token::Let,
     Token::Mut,
+    Token::Require,
     Token::Return,
     Token::BigSelf,
     Token::LittleSelf,
@@ -249,6 +261,7 @@ impl fmt::Display for Token {
             Comma => write!(f, ","),
             Dot => write!(f, "."),
             DotDot => write!(f, ".."),
+            DotDotEq => write!(f, "..="),
             DotDotDot => write!(f, "..."),
             Semicolon => write!(f, ";"),
             Colon => write!(f, ":"),
@@ -256,6 +269,7 @@ impl fmt::Display for Token {
             Question => write!(f, "?"),
             Arrow => write!(f, "->"),
             Underscore => write!(f, "_"),
+            Pipe => write!(f, "|"),
 
             U8 => write!(f, "u8"),
             U16 => write!(f, "u16"),
@@ -279,16 +293,21 @@ impl fmt::Display for Token {
             Import => write!(f, "import"),
 
             As => write!(f, "as"),
+            Break => write!(f, "break"),
             Circuit => write!(f, "circuit"),
             Console => write!(f, "console"),
             Const => write!(f, "const"),
+            ConstAssert => write!(f, "const_assert"),
+            Continue => write!(f, "continue"),
             Else => write!(f, "else"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
             If => write!(f, "if"),
+            Impl => write!(f, "impl"),
             In => write!(f, "in"),
             Let => write!(f, "let"),
             Mut => write!(f, "mut"),
+            Require => write!(f, "require"),
             Return => write!(f, "return"),
             Static => write!(f, "static"),
             String => write!(f, "string"),
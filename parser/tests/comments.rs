@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Comments;
+
+/// A program with comments ahead of its circuit, members, functions, and nested statements, and at its end.
+const PROGRAM: &str = "// A point in the plane.
+circuit Point {
+    // The horizontal coordinate.
+    x: u32,
+
+    // Returns the origin.
+    function origin() -> Self {
+        return Self { x: 0u32 };
+    }
+}
+
+/* The entry point. */
+@test
+function main() {
+    // unused
+    for i in 0..2 {
+        // nested
+        console.log(\"{}\", i);
+    }
+    return ();
+}
+
+// The end.
+";
+
+#[test]
+fn test_comments_attached_to_following_token() {
+    let (_, comments) = leo_parser::parse_with_comments("test", PROGRAM).unwrap();
+
+    assert_eq!(comments.len(), 7);
+    assert_eq!(comments.leading(2)[0].trim_end(), "// A point in the plane.");
+    assert_eq!(comments.leading(4)[0].trim_end(), "// The horizontal coordinate.");
+    assert_eq!(comments.leading(7)[0].trim_end(), "// Returns the origin.");
+    assert_eq!(&*comments.leading(13)[0], "/* The entry point. */");
+    assert_eq!(comments.leading(16)[0].trim_end(), "// unused");
+    assert_eq!(comments.leading(18)[0].trim_end(), "// nested");
+    assert_eq!(comments.trailing()[0].trim_end(), "// The end.");
+}
+
+#[test]
+fn test_comments_written_with_program() {
+    let (program, comments) = leo_parser::parse_with_comments("test", PROGRAM).unwrap();
+    let output = program.to_string_with_comments(&comments);
+
+    for comment in PROGRAM
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("//") || line.starts_with("/*"))
+    {
+        assert_eq!(output.matches(comment).count(), 1, "`{}` is not written once", comment);
+    }
+
+    let position = |text: &str| output.find(text).unwrap();
+    assert!(position("// A point in the plane.") < position("circuit Point"));
+    assert!(position("// The horizontal coordinate.") < position("x: u32"));
+    assert!(position("// Returns the origin.") < position("function origin"));
+    assert!(position("/* The entry point. */") < position("function main"));
+    assert!(position("// unused") < position("for i in"));
+    assert!(position("// nested") < position("console.log"));
+    assert!(position("// The end.") > position("return ()"));
+
+    // Without comments the program is written as it is displayed.
+    assert_eq!(program.to_string_with_comments(&Comments::default()), program.to_string());
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_parser::{parser, DEFAULT_MAX_EXPRESSION_DEPTH};
+
+/// Returns a program whose `main` function returns a value nested in `depth` parentheses.
+/// Each parenthesis is on its own line to keep tokenizing the program fast.
+fn nested_program(depth: usize) -> String {
+    format!(
+        "function main() -> u8 {{\n    return {}1u8{};\n}}",
+        "(\n".repeat(depth),
+        ")\n".repeat(depth)
+    )
+}
+
+#[test]
+fn test_nested_expression_within_depth() {
+    let program_string = nested_program(DEFAULT_MAX_EXPRESSION_DEPTH / 2);
+
+    assert!(parser::parse("test", &program_string).is_ok());
+}
+
+#[test]
+fn test_pathological_nesting_fails_with_depth_error() {
+    let program_string = nested_program(100_000);
+    let error = parser::parse("test", &program_string).unwrap_err();
+
+    assert!(error.to_string().contains("expression is nested too deeply"));
+}
+
+#[test]
+fn test_pathological_unary_nesting_fails_with_depth_error() {
+    let program_string = format!(
+        "function main() -> bool {{\n    return {}true;\n}}",
+        "!\n".repeat(100_000)
+    );
+    let error = parser::parse("test", &program_string).unwrap_err();
+
+    assert!(error.to_string().contains("expression is nested too deeply"));
+}
+
+#[test]
+fn test_ternary_chain_fails_with_depth_error() {
+    let program_string = format!(
+        "function main() -> u8 {{\n    return {}0u8;\n}}",
+        "true ? 1u8 :\n".repeat(100_000)
+    );
+    let error = parser::parse("test", &program_string).unwrap_err();
+
+    assert!(error.to_string().contains("expression is nested too deeply"));
+}
+
+#[test]
+fn test_configured_max_expression_depth() {
+    let program_string = nested_program(8);
+
+    assert!(parser::parse_with_max_expression_depth("test", &program_string, 16).is_ok());
+
+    let error = parser::parse_with_max_expression_depth("test", &program_string, 4).unwrap_err();
+    assert!(error.to_string().contains("the maximum depth is 4"));
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_parser::SyntaxError;
+
+/// Returns the line of each error reported for the given program.
+fn error_lines(program_string: &str) -> Vec<usize> {
+    let errors = match leo_parser::parse("test", program_string).unwrap_err() {
+        SyntaxError::Multiple(errors) => errors,
+        error => vec![error],
+    };
+
+    errors
+        .into_iter()
+        .map(|error| match error {
+            SyntaxError::Error(error) => error.line_start,
+            error => panic!("unexpected error {}", error),
+        })
+        .collect()
+}
+
+#[test]
+fn test_recover_from_statement_errors() {
+    let program_string = "function main() {
+    let a = ;
+    let b = 1u8;
+    let c = 1u8 +;
+}";
+
+    assert_eq!(error_lines(program_string), vec![2, 4]);
+}
+
+#[test]
+fn test_recover_from_item_errors() {
+    let program_string = "circuit Foo {
+    x u8,
+}
+
+function main() {
+    return;
+}
+
+function bar( {
+}";
+
+    assert_eq!(error_lines(program_string), vec![2, 6, 9]);
+}
+
+#[test]
+fn test_recover_from_circuit_member_errors() {
+    let program_string = "circuit Foo {
+    x: u8,
+
+    function bar( {
+    }
+
+    function baz() {
+        let c = 1u8;
+    }
+}
+
+function main() {
+    let b = 1u8 +;
+}";
+
+    assert_eq!(error_lines(program_string), vec![4, 13]);
+}
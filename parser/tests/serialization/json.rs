@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::Ast;
 #[cfg(not(feature = "ci_skip"))]
 use leo_ast::Program;
+use leo_ast::{Ast, SpanSerialization};
 use leo_parser::SyntaxError;
 
 use std::path::{Path, PathBuf};
@@ -88,6 +88,68 @@ fn test_serialize_deserialize_serialize() {
     assert_eq!(serialized_ast, reserialized_ast);
 }
 
+#[test]
+fn test_serialize_deterministic() {
+    let program_filepath = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/serialization/declaration_order.leo");
+
+    // Serializes two separately parsed asts of the same program.
+    let serialized_ast = to_ast(&program_filepath).unwrap().to_json_string().unwrap();
+    let reserialized_ast = to_ast(&program_filepath).unwrap().to_json_string().unwrap();
+
+    assert_eq!(serialized_ast, reserialized_ast);
+
+    // Circuits and functions are serialized in declaration order.
+    let ast = Ast::from_json_string(&serialized_ast).unwrap();
+    let circuits: Vec<_> = ast.as_repr().circuits.keys().map(|c| c.name.to_string()).collect();
+    let functions: Vec<_> = ast.as_repr().functions.keys().map(|f| f.name.to_string()).collect();
+
+    assert_eq!(circuits, vec!["Zeta", "Alpha", "Mu"]);
+    assert_eq!(functions, vec!["zeta", "alpha", "main"]);
+}
+
+#[test]
+fn test_serialize_relative_spans() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let program_filepath = manifest_dir.join("tests/serialization/main.leo");
+    let program_string = std::fs::read_to_string(&program_filepath).unwrap();
+
+    // Parses the Leo file under its absolute path.
+    let ast = leo_parser::parse_ast(program_filepath.to_str().unwrap(), &program_string).unwrap();
+    assert!(ast.to_json_string().unwrap().contains(manifest_dir.to_str().unwrap()));
+
+    let relative = ast
+        .to_json_string_with_spans(SpanSerialization::Relative(manifest_dir.clone()))
+        .unwrap();
+    assert!(!relative.contains(manifest_dir.to_str().unwrap()));
+    assert!(relative.contains("tests/serialization/main.leo"));
+
+    // Paths outside the base directory are reduced to their file name.
+    let elsewhere = ast
+        .to_json_string_with_spans(SpanSerialization::Relative(PathBuf::from("/nonexistent")))
+        .unwrap();
+    assert!(!elsewhere.contains(manifest_dir.to_str().unwrap()));
+    assert!(elsewhere.contains("\"path\": \"main.leo\""));
+
+    // Spans are serialized as parsed again afterwards.
+    assert!(ast.to_json_string().unwrap().contains(manifest_dir.to_str().unwrap()));
+}
+
+#[test]
+fn test_serialize_omitted_spans() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let program_filepath = manifest_dir.join("tests/serialization/main.leo");
+    let program_string = std::fs::read_to_string(&program_filepath).unwrap();
+
+    let ast = leo_parser::parse_ast(program_filepath.to_str().unwrap(), &program_string).unwrap();
+    let omitted = ast.to_json_string_with_spans(SpanSerialization::Omitted).unwrap();
+
+    assert!(!omitted.contains("main.leo"));
+    assert!(!omitted.contains("return 1 + 1"));
+
+    // Omitted spans still deserialize, as default spans.
+    Ast::from_json_string(&omitted).unwrap();
+}
+
 #[test]
 fn test_generic_parser_error() {
     let error_result = {
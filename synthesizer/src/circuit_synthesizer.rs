@@ -18,6 +18,14 @@ use snarkvm_curves::traits::PairingEngine;
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{ConstraintSystem, Index, LinearCombination, OptionalVec, SynthesisError, Variable};
 
+use std::collections::BTreeMap;
+
+/// The prefix of the namespace the compiler opens for every function call.
+const FUNCTION_CALL_NAMESPACE: &str = "function call ";
+
+/// The function that constraints outside of any function call are attributed to.
+const MAIN_FUNCTION_NAME: &str = "main";
+
 #[derive(Default)]
 pub struct Namespace {
     constraint_indices: Vec<usize>,
@@ -51,6 +59,76 @@ pub struct CircuitSynthesizer<E: PairingEngine> {
 
     // Technical namespaces used to remove of out-of-scope objects.
     pub namespaces: Vec<Namespace>,
+
+    // Whether constraints are counted per function, which evaluates the name of every namespace.
+    pub count_function_constraints: bool,
+
+    // Names of the functions called in each open namespace, if any.
+    pub function_namespaces: Vec<Option<String>>,
+
+    // Number of constraints enforced within each function.
+    pub function_constraints: BTreeMap<String, usize>,
+}
+
+impl<E: PairingEngine> Default for CircuitSynthesizer<E> {
+    fn default() -> Self {
+        CircuitSynthesizer {
+            constraints: Default::default(),
+            public_variables: Default::default(),
+            private_variables: Default::default(),
+            namespaces: Default::default(),
+            count_function_constraints: false,
+            function_namespaces: Default::default(),
+            function_constraints: Default::default(),
+        }
+    }
+}
+
+impl<E: PairingEngine> CircuitSynthesizer<E> {
+    ///
+    /// Returns a synthesizer that also counts the constraints of each function.
+    ///
+    pub fn with_function_constraints() -> Self {
+        Self {
+            count_function_constraints: true,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Returns the number of constraints attributed to each function, largest first.
+    ///
+    /// Constraints are attributed to the innermost enclosing function call, or to `main`.
+    /// No constraints are counted unless the synthesizer was created with [`Self::with_function_constraints`].
+    ///
+    pub fn constraints_per_function(&self) -> Vec<(String, usize)> {
+        let mut counts = self
+            .function_constraints
+            .iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect::<Vec<_>>();
+
+        counts.sort_by(|(a_name, a_count), (b_name, b_count)| b_count.cmp(a_count).then(a_name.cmp(b_name)));
+
+        counts
+    }
+
+    /// Returns the name of the innermost function call.
+    fn current_function(&self) -> &str {
+        self.function_namespaces
+            .iter()
+            .rev()
+            .find_map(|name| name.as_deref())
+            .unwrap_or(MAIN_FUNCTION_NAME)
+    }
+}
+
+/// Returns the function name of a `function call {name} {line}:{column}` namespace.
+fn function_call_name(namespace: &str) -> Option<String> {
+    let call = namespace.strip_prefix(FUNCTION_CALL_NAMESPACE)?;
+    let location = call.rfind(' ')?;
+
+    Some(call[..location].to_string())
 }
 
 impl<E: PairingEngine> ConstraintSystem<E::Fr> for CircuitSynthesizer<E> {
@@ -102,17 +180,29 @@ impl<E: PairingEngine> ConstraintSystem<E::Fr> for CircuitSynthesizer<E> {
         if let Some(ref mut ns) = self.namespaces.last_mut() {
             ns.constraint_indices.push(index);
         }
+
+        if self.count_function_constraints {
+            let function = self.current_function().to_string();
+            *self.function_constraints.entry(function).or_insert(0) += 1;
+        }
     }
 
-    fn push_namespace<NR, N>(&mut self, _: N)
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
     where
         NR: AsRef<str>,
         N: FnOnce() -> NR,
     {
         self.namespaces.push(Namespace::default());
+        if self.count_function_constraints {
+            self.function_namespaces.push(function_call_name(name_fn().as_ref()));
+        }
     }
 
     fn pop_namespace(&mut self) {
+        if self.count_function_constraints {
+            self.function_namespaces.pop();
+        }
+
         // Todo @ljedrz: Fix constraint system optimizations.
         // if let Some(ns) = self.namespaces.pop() {
         //     for idx in ns.constraint_indices {
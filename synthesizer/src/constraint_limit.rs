@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSystem, LinearCombination, SynthesisError, Variable};
+
+use std::marker::PhantomData;
+
+///
+/// A constraint system that aborts synthesis once more than a given number of constraints is enforced.
+///
+/// Constraints past the limit are dropped, and every later allocation fails, which stops the gadgets
+/// that are still running. Callers should check [`ConstraintLimit::exceeded`] when synthesis fails
+/// to report the limit instead of the error of whichever gadget was interrupted.
+///
+pub struct ConstraintLimit<F: Field, CS: ConstraintSystem<F>> {
+    cs: CS,
+    limit: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintLimit<F, CS> {
+    pub fn new(cs: CS, limit: usize) -> Self {
+        ConstraintLimit {
+            cs,
+            limit,
+            _field: PhantomData,
+        }
+    }
+
+    /// Returns the maximum number of constraints.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns `true` if synthesis enforced more constraints than the limit.
+    pub fn exceeded(&self) -> bool {
+        self.cs.num_constraints() > self.limit
+    }
+
+    /// Returns the wrapped constraint system.
+    pub fn into_inner(self) -> CS {
+        self.cs
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for ConstraintLimit<F, CS> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        if self.exceeded() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.cs.alloc(annotation, f)
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        if self.exceeded() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.cs.alloc_input(annotation, f)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        if !self.exceeded() {
+            self.cs.enforce(annotation, a, b, c);
+        }
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.push_namespace(name_fn);
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.pop_namespace();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.cs.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.cs.num_private_variables()
+    }
+}
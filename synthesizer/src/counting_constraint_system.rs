@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+
+use std::marker::PhantomData;
+
+///
+/// A constraint system that counts variables and constraints without storing them.
+///
+/// Gadgets run against it as they would against a full constraint system, which makes it suitable
+/// for analyses that only need to know that synthesis succeeds, or how many constraints it needs.
+/// Variable assignments are never evaluated.
+///
+pub struct CountingConstraintSystem<F: Field> {
+    num_public_variables: usize,
+    num_private_variables: usize,
+    num_constraints: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> Default for CountingConstraintSystem<F> {
+    fn default() -> Self {
+        CountingConstraintSystem {
+            num_public_variables: 0,
+            num_private_variables: 0,
+            num_constraints: 0,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for CountingConstraintSystem<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.num_private_variables;
+        self.num_private_variables += 1;
+        Ok(Variable::new_unchecked(Index::Private(index)))
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.num_public_variables;
+        self.num_public_variables += 1;
+        Ok(Variable::new_unchecked(Index::Public(index)))
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+}
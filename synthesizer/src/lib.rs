@@ -17,6 +17,12 @@
 pub mod circuit_synthesizer;
 pub use self::circuit_synthesizer::*;
 
+pub mod constraint_limit;
+pub use self::constraint_limit::*;
+
+pub mod counting_constraint_system;
+pub use self::counting_constraint_system::*;
+
 pub mod serialized_circuit;
 pub use self::serialized_circuit::*;
 
@@ -45,6 +45,69 @@ impl SerializedCircuit {
     pub fn from_json_string(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+
+    ///
+    /// Returns the constraints of the circuit in a plain R1CS text format.
+    ///
+    /// The format starts with three header lines giving the number of public variables, private
+    /// variables and constraints. Each constraint `i` follows as a `constraint i` line and three
+    /// rows `A`, `B` and `C`, which enforce `A * B = C`. A row is a sum of `coefficient*variable`
+    /// terms with base 10 coefficients, where a variable is `public[j]` or `private[j]`. An empty
+    /// row is written as `0`.
+    ///
+    pub fn to_r1cs_string(&self) -> String {
+        fn write_row(output: &mut String, label: &str, row: &[(SerializedField, SerializedIndex)]) {
+            let terms = row
+                .iter()
+                .map(|(coefficient, index)| match index {
+                    SerializedIndex::Public(index) => format!("{}*public[{}]", coefficient.0, index),
+                    SerializedIndex::Private(index) => format!("{}*private[{}]", coefficient.0, index),
+                })
+                .collect::<Vec<_>>();
+
+            if terms.is_empty() {
+                output.push_str(&format!("{}: 0\n", label));
+            } else {
+                output.push_str(&format!("{}: {}\n", label, terms.join(" + ")));
+            }
+        }
+
+        let mut output = String::new();
+        output.push_str(&format!("public_variables {}\n", self.num_public_variables));
+        output.push_str(&format!("private_variables {}\n", self.num_private_variables));
+        output.push_str(&format!("constraints {}\n", self.num_constraints));
+
+        for i in 0..self.num_constraints {
+            output.push_str(&format!("constraint {}\n", i));
+            write_row(&mut output, "A", &self.at[i]);
+            write_row(&mut output, "B", &self.bt[i]);
+            write_row(&mut output, "C", &self.ct[i]);
+        }
+
+        output
+    }
+
+    ///
+    /// Returns the variable assignments of the circuit in a plain text format.
+    ///
+    /// The format starts with two header lines giving the number of public and private variables.
+    /// Each variable follows on a `public[j] = value` or `private[j] = value` line, with base 10
+    /// values. The private assignments include the private inputs of the program.
+    ///
+    pub fn to_witness_string(&self) -> String {
+        let mut output = String::new();
+        output.push_str(&format!("public_variables {}\n", self.num_public_variables));
+        output.push_str(&format!("private_variables {}\n", self.num_private_variables));
+
+        for (i, value) in self.public_variables.iter().enumerate() {
+            output.push_str(&format!("public[{}] = {}\n", i, value.0));
+        }
+        for (i, value) in self.private_variables.iter().enumerate() {
+            output.push_str(&format!("private[{}] = {}\n", i, value.0));
+        }
+
+        output
+    }
 }
 
 impl<E: PairingEngine> From<CircuitSynthesizer<E>> for SerializedCircuit {
@@ -174,7 +237,7 @@ impl TryFrom<SerializedCircuit> for CircuitSynthesizer<Bls12_377> {
             constraints,
             public_variables,
             private_variables,
-            namespaces: Default::default(),
+            ..Default::default()
         })
     }
 }
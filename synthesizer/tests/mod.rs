@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_synthesizer::{CircuitSynthesizer, SerializedCircuit};
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+use snarkvm_fields::{Field, One};
+use snarkvm_r1cs::ConstraintSystem;
+
+#[test]
+fn test_single_addition_r1cs_string() {
+    let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+
+    let one = cs.alloc_input(|| "one", || Ok(Fr::default())).unwrap();
+    let a = cs.alloc(|| "a", || Ok(Fr::default())).unwrap();
+    let b = cs.alloc(|| "b", || Ok(Fr::default())).unwrap();
+    let c = cs.alloc(|| "c", || Ok(Fr::default())).unwrap();
+
+    // (a + b) * 1 = c
+    cs.enforce(|| "a + b = c", |lc| lc + a + b, |lc| lc + one, |lc| lc + c);
+
+    let expected = "\
+public_variables 1
+private_variables 3
+constraints 1
+constraint 0
+A: 1*private[0] + 1*private[1]
+B: 1*public[0]
+C: 1*private[2]
+";
+
+    assert_eq!(SerializedCircuit::from(cs).to_r1cs_string(), expected);
+}
+
+#[test]
+fn test_empty_row_r1cs_string() {
+    let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+
+    let a = cs.alloc(|| "a", || Ok(Fr::default())).unwrap();
+
+    // a * a = 0
+    cs.enforce(|| "a * a = 0", |lc| lc + a, |lc| lc + a, |lc| lc);
+
+    let dump = SerializedCircuit::from(cs).to_r1cs_string();
+
+    assert!(dump.ends_with("A: 1*private[0]\nB: 1*private[0]\nC: 0\n"));
+}
+
+#[test]
+fn test_single_addition_witness_string() {
+    let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+
+    let one = cs.alloc_input(|| "one", || Ok(Fr::one())).unwrap();
+    let a = cs.alloc(|| "a", || Ok(Fr::one())).unwrap();
+    let b = cs.alloc(|| "b", || Ok(Fr::one())).unwrap();
+    let c = cs.alloc(|| "c", || Ok(Fr::one().double())).unwrap();
+
+    // (a + b) * 1 = c
+    cs.enforce(|| "a + b = c", |lc| lc + a + b, |lc| lc + one, |lc| lc + c);
+
+    let expected = "\
+public_variables 1
+private_variables 3
+public[0] = 1
+private[0] = 1
+private[1] = 1
+private[2] = 2
+";
+
+    assert_eq!(SerializedCircuit::from(cs).to_witness_string(), expected);
+}
+
+#[test]
+fn test_function_constraints_counted_only_when_enabled() {
+    let enforce_in_call = |cs: &mut CircuitSynthesizer<Bls12_377>| {
+        let a = cs.alloc(|| "a", || Ok(Fr::one())).unwrap();
+        let mut call = cs.ns(|| "function call double 1:1");
+        call.enforce(
+            || "a * 1 = a",
+            |lc| lc + a,
+            |lc| lc + CircuitSynthesizer::<Bls12_377>::one(),
+            |lc| lc + a,
+        );
+    };
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::default();
+    // Namespace names are not evaluated unless constraints are counted per function.
+    cs.ns(|| -> &str { panic!("namespace name evaluated") });
+    enforce_in_call(&mut cs);
+    assert!(cs.constraints_per_function().is_empty());
+
+    let mut cs = CircuitSynthesizer::<Bls12_377>::with_function_constraints();
+    enforce_in_call(&mut cs);
+    assert_eq!(cs.constraints_per_function(), vec![("double".to_string(), 1)]);
+}